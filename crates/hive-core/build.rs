@@ -0,0 +1,16 @@
+fn main() {
+    println!("cargo:rerun-if-changed=proto/hive.proto");
+
+    // The gRPC service is generated only when the `grpc` feature is on, with
+    // a bundled protoc so building needs nothing installed
+    #[cfg(feature = "grpc")]
+    {
+        let protoc = protoc_bin_vendored::protoc_bin_path().expect("no bundled protoc for this platform");
+        std::env::set_var("PROTOC", protoc);
+        tonic_prost_build::configure()
+            .build_client(true)
+            .build_server(true)
+            .compile_protos(&["proto/hive.proto"], &["proto"])
+            .expect("cannot compile proto/hive.proto");
+    }
+}
@@ -34,7 +34,7 @@ impl ConnectionAnimation {
     }
 
     /// Update animation state, returns true if animation is complete
-    pub fn update(&mut self, dt: f32) -> bool {
+    pub fn update(&mut self, _dt: f32) -> bool {
         let age = self.created_at.elapsed();
 
         match self.state {
@@ -0,0 +1,170 @@
+pub mod pulse;
+pub mod connection;
+
+pub use pulse::{PulseAnimation, StatusAnimation};
+pub use connection::ConnectionAnimation;
+
+use std::time::{Duration, Instant};
+
+/// Target frame rate
+pub const TARGET_FPS: u32 = 30;
+
+/// Frame duration for target FPS
+pub const FRAME_DURATION: Duration = Duration::from_millis(1000 / TARGET_FPS as u64);
+
+/// Simulation steps per second, whatever the frame rate
+pub const TICK_RATE: u32 = 30;
+
+/// Seconds of field time one simulation step covers
+pub const TICK_STEP: f32 = 1.0 / TICK_RATE as f32;
+
+/// Most time one frame catches up on; a longer stall (a suspended terminal,
+/// say) drops the rest rather than running a burst of steps
+const MAX_CATCH_UP: f32 = 1.0;
+
+/// Animation loop state.
+///
+/// Frames are drawn at the target FPS, but the field advances in fixed
+/// [`TICK_STEP`]s: each frame runs the [`fixed_steps`](Self::fixed_steps) due
+/// since the last one and draws agents [`interpolation`](Self::interpolation)
+/// of the way into the next, so the frame rate and long frames change neither
+/// animation speeds nor collisions.
+pub struct AnimationLoop {
+    last_frame: Instant,
+    frame_duration: Duration,
+    /// Seconds of field time not yet simulated
+    accumulator: f32,
+    frame_count: u64,
+    fps_sample_start: Instant,
+    fps_sample_count: u32,
+    current_fps: u32,
+    /// Seconds of animation played so far, shared by every animated glyph
+    time: f32,
+}
+
+impl AnimationLoop {
+    pub fn new() -> Self {
+        let now = Instant::now();
+        Self {
+            last_frame: now,
+            frame_duration: FRAME_DURATION,
+            accumulator: 0.0,
+            frame_count: 0,
+            fps_sample_start: now,
+            fps_sample_count: 0,
+            current_fps: TARGET_FPS,
+            time: 0.0,
+        }
+    }
+
+    /// Draw `fps` frames per second instead of [`TARGET_FPS`]
+    pub fn with_fps(mut self, fps: u32) -> Self {
+        self.set_fps(fps);
+        self
+    }
+
+    /// Change the frame rate aimed for
+    pub fn set_fps(&mut self, fps: u32) {
+        self.frame_duration = Duration::from_secs_f32(1.0 / fps.max(1) as f32);
+        self.current_fps = fps;
+    }
+
+    /// Frames per second aimed for
+    pub fn target_fps(&self) -> u32 {
+        (1.0 / self.frame_duration.as_secs_f32()).round() as u32
+    }
+
+    /// Check if it's time for a new frame
+    pub fn should_render(&self) -> bool {
+        self.last_frame.elapsed() >= self.frame_duration
+    }
+
+    /// Number of simulation steps due since the last frame. Call once per
+    /// frame, before [`frame_rendered`](Self::frame_rendered).
+    pub fn fixed_steps(&mut self) -> u32 {
+        self.steps_for(self.delta_time())
+    }
+
+    /// Add `elapsed` seconds to the backlog and take the whole steps out of it
+    fn steps_for(&mut self, elapsed: f32) -> u32 {
+        self.accumulator = (self.accumulator + elapsed).min(MAX_CATCH_UP);
+        let steps = (self.accumulator / TICK_STEP) as u32;
+        self.accumulator -= steps as f32 * TICK_STEP;
+        steps
+    }
+
+    /// How far into the next simulation step this frame is, from 0 to 1,
+    /// for drawing agents between their last two positions
+    pub fn interpolation(&self) -> f32 {
+        (self.accumulator / TICK_STEP).clamp(0.0, 1.0)
+    }
+
+    /// Get delta time since last frame
+    pub fn delta_time(&self) -> f32 {
+        self.last_frame.elapsed().as_secs_f32()
+    }
+
+    /// Mark frame as rendered
+    pub fn frame_rendered(&mut self) {
+        self.time += self.delta_time();
+        self.last_frame = Instant::now();
+        self.frame_count += 1;
+        self.fps_sample_count += 1;
+
+        // Update FPS calculation every second
+        if self.fps_sample_start.elapsed() >= Duration::from_secs(1) {
+            self.current_fps = self.fps_sample_count;
+            self.fps_sample_count = 0;
+            self.fps_sample_start = Instant::now();
+        }
+    }
+
+    /// Animation clock in seconds, advanced once per rendered frame
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+
+    /// Get current FPS
+    pub fn fps(&self) -> u32 {
+        self.current_fps
+    }
+
+    /// Get total frame count
+    pub fn frame_count(&self) -> u64 {
+        self.frame_count
+    }
+
+    /// Time until next frame
+    pub fn time_until_next_frame(&self) -> Duration {
+        self.frame_duration.saturating_sub(self.last_frame.elapsed())
+    }
+}
+
+impl Default for AnimationLoop {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steps_do_not_depend_on_frame_length() {
+        // One second as 60 short frames, 4 long ones or a single stall
+        for frames in [vec![1.0 / 60.0; 60], vec![0.25; 4], vec![1.0]] {
+            let mut animation = AnimationLoop::new();
+            let steps: u32 = frames.iter().map(|&elapsed| animation.steps_for(elapsed)).sum();
+            assert!((TICK_RATE - 1..=TICK_RATE).contains(&steps), "{} steps", steps);
+        }
+
+        // Half a step left over is drawn halfway to the next
+        let mut animation = AnimationLoop::new();
+        assert_eq!(animation.steps_for(TICK_STEP * 2.5), 2);
+        assert!((animation.interpolation() - 0.5).abs() < 1e-3);
+
+        // A long stall is not made up for
+        assert_eq!(animation.steps_for(60.0), (MAX_CATCH_UP / TICK_STEP) as u32);
+    }
+}
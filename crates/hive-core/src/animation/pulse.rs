@@ -0,0 +1,154 @@
+use std::f32::consts::PI;
+
+use crate::event::AgentStatus;
+
+/// Pulse animation for agent brightness
+#[derive(Debug, Clone)]
+pub struct PulseAnimation {
+    phase: f32,
+    frequency: f32,
+    min_value: f32,
+    max_value: f32,
+}
+
+impl PulseAnimation {
+    pub fn new(frequency: f32) -> Self {
+        Self {
+            phase: 0.0,
+            frequency,
+            min_value: 0.6,
+            max_value: 1.0,
+        }
+    }
+
+    /// Update animation state
+    pub fn update(&mut self, dt: f32) {
+        self.phase = (self.phase + dt * self.frequency * 2.0 * PI) % (2.0 * PI);
+    }
+
+    /// Get current value
+    pub fn value(&self) -> f32 {
+        let normalized = (self.phase.sin() + 1.0) / 2.0;
+        self.min_value + normalized * (self.max_value - self.min_value)
+    }
+
+    /// Set the intensity (affects pulse amplitude)
+    pub fn set_intensity(&mut self, intensity: f32) {
+        self.frequency = 1.0 + intensity * 2.0;
+        self.min_value = 0.5 + intensity * 0.3;
+        self.max_value = 0.8 + intensity * 0.2;
+    }
+}
+
+impl Default for PulseAnimation {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+/// Breathing animation (slower, more organic)
+pub fn breathing(time: f32, speed: f32) -> f32 {
+    let t = time * speed;
+    // Combine multiple sine waves for more organic feel
+    let base = (t * PI).sin();
+    let harmonic = (t * PI * 2.0).sin() * 0.2;
+    (base + harmonic + 1.0) / 2.4 * 0.4 + 0.6
+}
+
+/// Heartbeat animation (quick pulse followed by pause)
+pub fn heartbeat(time: f32, bpm: f32) -> f32 {
+    let period = 60.0 / bpm;
+    let t = (time % period) / period;
+
+    if t < 0.1 {
+        // First beat
+        let x = t / 0.1;
+        (x * PI).sin()
+    } else if t < 0.2 {
+        // First beat down
+        let x = (t - 0.1) / 0.1;
+        (1.0 - x) * (x * PI).cos().abs()
+    } else if t < 0.25 {
+        // Second beat
+        let x = (t - 0.2) / 0.05;
+        (x * PI).sin() * 0.7
+    } else {
+        // Rest
+        0.0
+    }
+}
+
+/// Flicker animation (random-ish noise)
+pub fn flicker(time: f32, intensity: f32) -> f32 {
+    let noise = ((time * 17.0).sin() * (time * 31.0).cos() + 1.0) / 2.0;
+    1.0 - noise * intensity * 0.3
+}
+
+/// How an agent's glyph is animated while it is in a given status
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusAnimation {
+    /// Quick pulse while busy (driven by the agent's own pulse phase)
+    Pulse,
+    /// Slow breathing brightness
+    Breathe,
+    /// Glyph flips between two frames, like an hourglass turned over
+    Flip,
+    /// Unsteady brightness
+    Flicker,
+    /// No animation
+    Still,
+}
+
+/// Seconds each frame of a [`StatusAnimation::Flip`] is shown
+const FLIP_PERIOD: f32 = 1.2;
+
+impl StatusAnimation {
+    /// Animation profile of `status`
+    pub fn for_status(status: &AgentStatus) -> Self {
+        match status {
+            AgentStatus::Active => StatusAnimation::Pulse,
+            AgentStatus::Thinking => StatusAnimation::Breathe,
+            AgentStatus::Waiting => StatusAnimation::Flip,
+            AgentStatus::Error => StatusAnimation::Flicker,
+            AgentStatus::Idle => StatusAnimation::Still,
+        }
+    }
+
+    /// Brightness multiplier at `time` (seconds); `None` leaves the agent's
+    /// usual brightness
+    pub fn brightness(self, time: f32) -> Option<f32> {
+        match self {
+            StatusAnimation::Breathe => Some(breathing(time, 0.4)),
+            StatusAnimation::Flicker => Some(flicker(time, 1.0)),
+            StatusAnimation::Pulse | StatusAnimation::Flip | StatusAnimation::Still => None,
+        }
+    }
+
+    /// Which of two glyph frames shows at `time` (always 0 unless flipping)
+    pub fn frame(self, time: f32) -> usize {
+        match self {
+            StatusAnimation::Flip => (time / FLIP_PERIOD).rem_euclid(2.0) as usize,
+            _ => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_profiles() {
+        let waiting = StatusAnimation::for_status(&AgentStatus::Waiting);
+        assert_eq!(waiting.frame(0.0), 0);
+        assert_eq!(waiting.frame(FLIP_PERIOD + 0.1), 1);
+        assert_eq!(waiting.frame(2.0 * FLIP_PERIOD + 0.1), 0);
+
+        let thinking = StatusAnimation::for_status(&AgentStatus::Thinking);
+        let levels: Vec<f32> = (0..20).filter_map(|i| thinking.brightness(i as f32 * 0.25)).collect();
+        assert!(levels.iter().all(|b| (0.5..=1.0).contains(b)));
+        assert!(levels.iter().any(|&b| b < 0.75) && levels.iter().any(|&b| b > 0.85));
+
+        assert_eq!(StatusAnimation::for_status(&AgentStatus::Idle).brightness(1.0), None);
+    }
+}
@@ -0,0 +1,215 @@
+//! Line formats of events files.
+//!
+//! Events are JSON lines by default. Pipelines that cannot easily emit JSON
+//! can write agent updates as CSV instead, one per line:
+//!
+//! ```text
+//! timestamp,agent_id,status,intensity,focus,message
+//! 1706812345,explorer-1,active,0.8,auth;jwt,Reading the middleware
+//! ```
+//!
+//! Focus keywords are separated by `;`. Fields containing commas or quotes
+//! are quoted, with `""` standing for a quote. A header line is ignored.
+//!
+//! Very busy swarms writing to a socket can send MessagePack instead, which
+//! is cheaper to produce and to parse. It is binary, so events are framed
+//! rather than split into lines: each is a 4-byte big-endian length followed
+//! by that many bytes of MessagePack, with structs encoded as maps (e.g.
+//! `rmp_serde::to_vec_named`), the same shape as the JSON.
+
+use std::fmt;
+use std::str::FromStr;
+
+use super::intern::intern;
+use super::types::{AgentStatus, AgentUpdate, HiveEvent};
+
+/// Largest MessagePack frame accepted; a longer length prefix means the
+/// stream is corrupt rather than a huge event
+pub const MAX_FRAME_BYTES: usize = 16 << 20;
+
+/// Format of the lines of an events file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EventFormat {
+    /// One JSON event per line
+    #[default]
+    Json,
+    /// `timestamp,agent_id,status,intensity,focus,message` agent updates
+    Csv,
+    /// Length-prefixed MessagePack events (socket and WebSocket sources only)
+    MsgPack,
+}
+
+impl EventFormat {
+    /// Whether events come in length-prefixed frames rather than lines
+    pub fn is_framed(self) -> bool {
+        self == Self::MsgPack
+    }
+
+    /// Parse one line; `Ok(None)` for lines that carry no event (blank
+    /// lines, the CSV header)
+    pub fn parse_line(self, line: &str) -> Result<Option<HiveEvent>, String> {
+        let line = line.trim_end_matches(['\r', '\n']);
+        if line.trim().is_empty() {
+            return Ok(None);
+        }
+        match self {
+            Self::Json => serde_json::from_str(line).map(Some).map_err(|e| e.to_string()),
+            Self::Csv => parse_csv(line),
+            Self::MsgPack => Err("msgpack events are framed, not lines".to_string()),
+        }
+    }
+
+    /// Parse one MessagePack frame, without its length prefix
+    pub fn parse_frame(frame: &[u8]) -> Result<HiveEvent, String> {
+        rmp_serde::from_slice(frame).map_err(|e| e.to_string())
+    }
+}
+
+/// Split `data` into length-prefixed frames. Whatever follows the last
+/// whole frame (an incomplete frame, or a length over [`MAX_FRAME_BYTES`])
+/// is returned as the rest.
+pub fn split_frames(mut data: &[u8]) -> (Vec<&[u8]>, &[u8]) {
+    let mut frames = Vec::new();
+    while let Some((prefix, rest)) = data.split_first_chunk::<4>() {
+        let len = u32::from_be_bytes(*prefix) as usize;
+        if len > MAX_FRAME_BYTES || rest.len() < len {
+            break;
+        }
+        let (frame, rest) = rest.split_at(len);
+        frames.push(frame);
+        data = rest;
+    }
+    (frames, data)
+}
+
+impl FromStr for EventFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "json" | "jsonl" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            "msgpack" | "messagepack" => Ok(Self::MsgPack),
+            other => Err(format!("unknown event format {:?} (expected json, csv or msgpack)", other)),
+        }
+    }
+}
+
+impl fmt::Display for EventFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Json => "json",
+            Self::Csv => "csv",
+            Self::MsgPack => "msgpack",
+        })
+    }
+}
+
+fn parse_csv(line: &str) -> Result<Option<HiveEvent>, String> {
+    let fields = split_csv(line)?;
+    let [timestamp, agent_id, status, intensity, focus, message] = <[String; 6]>::try_from(fields)
+        .map_err(|fields| format!("expected 6 fields, got {}", fields.len()))?;
+    if timestamp.trim() == "timestamp" {
+        return Ok(None);
+    }
+
+    let status = match status.trim().to_ascii_lowercase().as_str() {
+        "active" => AgentStatus::Active,
+        "thinking" => AgentStatus::Thinking,
+        "waiting" => AgentStatus::Waiting,
+        "idle" => AgentStatus::Idle,
+        "error" => AgentStatus::Error,
+        other => return Err(format!("unknown status {:?}", other)),
+    };
+    Ok(Some(HiveEvent::AgentUpdate(AgentUpdate {
+        agent_id: intern(agent_id.trim()),
+        status,
+        focus: focus
+            .split(';')
+            .map(str::trim)
+            .filter(|keyword| !keyword.is_empty())
+            .map(intern)
+            .collect(),
+        intensity: intensity.trim().parse().map_err(|_| format!("bad intensity {:?}", intensity))?,
+        message,
+        timestamp: timestamp.trim().parse().map_err(|_| format!("bad timestamp {:?}", timestamp))?,
+    })))
+}
+
+/// Split a CSV line into fields, unquoting quoted ones
+fn split_csv(line: &str) -> Result<Vec<String>, String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut chars = line.chars().peekable();
+    let mut quoted = false;
+
+    while let Some(c) = chars.next() {
+        match (c, quoted) {
+            ('"', false) if field.is_empty() => quoted = true,
+            ('"', true) if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            ('"', true) => quoted = false,
+            (',', false) => fields.push(std::mem::take(&mut field)),
+            (c, _) => field.push(c),
+        }
+    }
+    if quoted {
+        return Err("unterminated quote".to_string());
+    }
+    fields.push(field);
+    Ok(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_agent_update() {
+        let format: EventFormat = "csv".parse().unwrap();
+        assert!(matches!(format.parse_line("timestamp,agent_id,status,intensity,focus,message"), Ok(None)));
+
+        let line = r#"1706812345,explorer-1,Active,0.8,auth; jwt,"Reading ""middleware"", then tests""#;
+        let Ok(Some(HiveEvent::AgentUpdate(update))) = format.parse_line(line) else {
+            panic!("not an agent update");
+        };
+        assert_eq!(update.timestamp, 1706812345);
+        assert_eq!(&*update.agent_id, "explorer-1");
+        assert_eq!(update.status, AgentStatus::Active);
+        assert_eq!(update.intensity, 0.8);
+        assert_eq!(update.focus, [intern("auth"), intern("jwt")]);
+        assert_eq!(update.message, r#"Reading "middleware", then tests"#);
+
+        assert!(format.parse_line("1,a,busy,0.5,,").is_err());
+        assert!(format.parse_line("1,a,idle,0.5").is_err());
+    }
+
+    #[test]
+    fn test_msgpack_frames() {
+        let event: HiveEvent = serde_json::from_str(
+            r#"{"type":"agent_update","agent_id":"atlas","status":"active","focus":["auth"],"intensity":0.5,"message":"","timestamp":1}"#,
+        )
+        .unwrap();
+        let body = rmp_serde::to_vec_named(&event).unwrap();
+        let mut data = Vec::new();
+        for _ in 0..2 {
+            data.extend((body.len() as u32).to_be_bytes());
+            data.extend(&body);
+        }
+        data.extend([0, 0]);
+
+        let (frames, rest) = split_frames(&data);
+        assert_eq!(frames.len(), 2);
+        assert_eq!(rest, [0, 0]);
+        let Ok(HiveEvent::AgentUpdate(update)) = EventFormat::parse_frame(frames[1]) else {
+            panic!("not an agent update");
+        };
+        assert_eq!(&*update.agent_id, "atlas");
+        assert_eq!(update.focus, [intern("auth")]);
+
+        assert!(EventFormat::parse_frame(b"not msgpack").is_err());
+        assert_eq!(split_frames(&u32::MAX.to_be_bytes()).0.len(), 0);
+    }
+}
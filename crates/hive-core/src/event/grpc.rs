@@ -0,0 +1,287 @@
+//! gRPC ingestion endpoint (`grpc` feature).
+//!
+//! Typed clients (Go, Python, anything with protoc) stream events with the
+//! `StreamEvents` call of the `hive.v1.Hive` service in `proto/hive.proto`.
+//! The generated messages mirror the JSON events and convert into the
+//! [`HiveEvent`] types; an event with none of its kinds set is counted and
+//! dropped, like an unreadable line of a file.
+
+use std::io;
+use std::net::{SocketAddr, TcpListener as StdTcpListener, ToSocketAddrs};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use tokio::net::TcpListener;
+use tokio::sync::mpsc as tokio_mpsc;
+use tokio::task::JoinHandle;
+use tonic::transport::server::TcpIncoming;
+use tonic::transport::Server;
+use tonic::{Request, Response, Status, Streaming};
+
+use super::intern::intern;
+use super::types::{AgentStatus, AgentUpdate, Connection, HiveEvent, Landmark};
+
+/// Messages and client/server stubs generated from `proto/hive.proto`
+pub mod proto {
+    tonic::include_proto!("hive.v1");
+}
+
+use proto::hive_server::{Hive, HiveServer};
+
+/// Serves the `Hive` gRPC service and sends the events streamed to it to a
+/// channel
+pub struct GrpcServer {
+    local_addr: SocketAddr,
+    /// Events without a kind, across all clients
+    skipped: Arc<AtomicUsize>,
+    /// Clients streaming right now
+    clients: Arc<AtomicUsize>,
+    /// Server task; aborting it drops every stream
+    task: JoinHandle<()>,
+}
+
+impl GrpcServer {
+    /// Listen on `addr` and forward events to `event_tx` until dropped. Must
+    /// be called from within a Tokio runtime.
+    pub fn bind(addr: impl ToSocketAddrs, event_tx: tokio_mpsc::Sender<HiveEvent>) -> io::Result<Self> {
+        let listener = StdTcpListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+        let listener = TcpListener::from_std(listener)?;
+        let local_addr = listener.local_addr()?;
+
+        let skipped = Arc::new(AtomicUsize::new(0));
+        let clients = Arc::new(AtomicUsize::new(0));
+        let closed = event_tx.clone();
+        let service = HiveService {
+            event_tx,
+            skipped: skipped.clone(),
+            clients: clients.clone(),
+        };
+        let task = tokio::spawn(async move {
+            // Serving only ends with an error on the listener itself, which
+            // leaves nothing to serve
+            let _ = Server::builder()
+                .add_service(HiveServer::new(service))
+                .serve_with_incoming_shutdown(TcpIncoming::from(listener), async move { closed.closed().await })
+                .await;
+        });
+
+        Ok(Self {
+            local_addr,
+            skipped,
+            clients,
+            task,
+        })
+    }
+
+    /// Address the server listens on (with the actual port when bound to 0)
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Number of events dropped for having no kind so far
+    pub fn skipped_lines(&self) -> usize {
+        self.skipped.load(Ordering::Relaxed)
+    }
+
+    /// Number of clients streaming right now
+    pub fn clients(&self) -> usize {
+        self.clients.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for GrpcServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+struct HiveService {
+    event_tx: tokio_mpsc::Sender<HiveEvent>,
+    skipped: Arc<AtomicUsize>,
+    clients: Arc<AtomicUsize>,
+}
+
+impl HiveService {
+    async fn forward(&self, mut events: Streaming<proto::Event>) -> Result<proto::StreamSummary, Status> {
+        let mut summary = proto::StreamSummary::default();
+        while let Some(event) = events.message().await? {
+            let Ok(event) = HiveEvent::try_from(event) else {
+                self.skipped.fetch_add(1, Ordering::Relaxed);
+                summary.skipped += 1;
+                continue;
+            };
+            if self.event_tx.send(event).await.is_err() {
+                return Err(Status::unavailable("hive is shutting down"));
+            }
+            summary.received += 1;
+        }
+        Ok(summary)
+    }
+}
+
+#[tonic::async_trait]
+impl Hive for HiveService {
+    async fn stream_events(
+        &self,
+        request: Request<Streaming<proto::Event>>,
+    ) -> Result<Response<proto::StreamSummary>, Status> {
+        self.clients.fetch_add(1, Ordering::Relaxed);
+        let summary = self.forward(request.into_inner()).await;
+        self.clients.fetch_sub(1, Ordering::Relaxed);
+        summary.map(Response::new)
+    }
+}
+
+impl From<proto::AgentStatus> for AgentStatus {
+    fn from(status: proto::AgentStatus) -> Self {
+        match status {
+            proto::AgentStatus::Active => AgentStatus::Active,
+            proto::AgentStatus::Thinking => AgentStatus::Thinking,
+            proto::AgentStatus::Waiting => AgentStatus::Waiting,
+            proto::AgentStatus::Unspecified | proto::AgentStatus::Idle => AgentStatus::Idle,
+            proto::AgentStatus::Error => AgentStatus::Error,
+        }
+    }
+}
+
+impl From<AgentStatus> for proto::AgentStatus {
+    fn from(status: AgentStatus) -> Self {
+        match status {
+            AgentStatus::Active => proto::AgentStatus::Active,
+            AgentStatus::Thinking => proto::AgentStatus::Thinking,
+            AgentStatus::Waiting => proto::AgentStatus::Waiting,
+            AgentStatus::Idle => proto::AgentStatus::Idle,
+            AgentStatus::Error => proto::AgentStatus::Error,
+        }
+    }
+}
+
+impl From<proto::AgentUpdate> for AgentUpdate {
+    fn from(update: proto::AgentUpdate) -> Self {
+        Self {
+            agent_id: intern(&update.agent_id),
+            // Statuses from a newer client read as idle
+            status: update.status().into(),
+            focus: update.focus.iter().map(|keyword| intern(keyword)).collect(),
+            intensity: update.intensity,
+            message: update.message,
+            timestamp: update.timestamp,
+        }
+    }
+}
+
+impl From<proto::Connection> for Connection {
+    fn from(connection: proto::Connection) -> Self {
+        Self {
+            from: intern(&connection.from),
+            to: intern(&connection.to),
+            label: connection.label,
+            timestamp: connection.timestamp,
+            ttl_ms: connection.ttl_ms,
+        }
+    }
+}
+
+impl From<proto::Landmark> for Landmark {
+    fn from(landmark: proto::Landmark) -> Self {
+        Self {
+            id: landmark.id,
+            label: landmark.label,
+            keywords: landmark.keywords,
+            timestamp: landmark.timestamp,
+        }
+    }
+}
+
+impl TryFrom<proto::Event> for HiveEvent {
+    type Error = io::Error;
+
+    /// Fails for an event with none of its kinds set
+    fn try_from(event: proto::Event) -> io::Result<Self> {
+        match event.event {
+            Some(proto::event::Event::AgentUpdate(update)) => Ok(HiveEvent::AgentUpdate(update.into())),
+            Some(proto::event::Event::Connection(connection)) => Ok(HiveEvent::Connection(connection.into())),
+            Some(proto::event::Event::Landmark(landmark)) => Ok(HiveEvent::Landmark(landmark.into())),
+            None => Err(io::Error::new(io::ErrorKind::InvalidData, "event has no type")),
+        }
+    }
+}
+
+impl From<HiveEvent> for proto::Event {
+    fn from(event: HiveEvent) -> Self {
+        let event = match event {
+            HiveEvent::AgentUpdate(update) => proto::event::Event::AgentUpdate(proto::AgentUpdate {
+                agent_id: update.agent_id.to_string(),
+                status: proto::AgentStatus::from(update.status).into(),
+                focus: update.focus.iter().map(|keyword| keyword.to_string()).collect(),
+                intensity: update.intensity,
+                message: update.message,
+                timestamp: update.timestamp,
+            }),
+            HiveEvent::Connection(connection) => proto::event::Event::Connection(proto::Connection {
+                from: connection.from.to_string(),
+                to: connection.to.to_string(),
+                label: connection.label,
+                timestamp: connection.timestamp,
+                ttl_ms: connection.ttl_ms,
+            }),
+            HiveEvent::Landmark(landmark) => proto::event::Event::Landmark(proto::Landmark {
+                id: landmark.id,
+                label: landmark.label,
+                keywords: landmark.keywords,
+                timestamp: landmark.timestamp,
+            }),
+        };
+        Self { event: Some(event) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proto::hive_client::HiveClient;
+
+    #[tokio::test]
+    async fn test_streamed_events_are_forwarded() {
+        let (event_tx, mut event_rx) = tokio_mpsc::channel(16);
+        let server = GrpcServer::bind("127.0.0.1:0", event_tx).unwrap();
+        let mut client = HiveClient::connect(format!("http://{}", server.local_addr())).await.unwrap();
+
+        let update = proto::Event {
+            event: Some(proto::event::Event::AgentUpdate(proto::AgentUpdate {
+                agent_id: "atlas".to_string(),
+                status: proto::AgentStatus::Thinking.into(),
+                focus: vec!["auth".to_string(), "jwt".to_string()],
+                intensity: 0.5,
+                message: "Reading".to_string(),
+                timestamp: 1,
+            })),
+        };
+        let connection: HiveEvent = serde_json::from_str(
+            r#"{"type": "connection", "from": "atlas", "to": "nova", "label": "", "timestamp": 2, "ttl_ms": 500}"#,
+        )
+        .unwrap();
+        let events = vec![update, proto::Event { event: None }, connection.into()];
+        let summary = client.stream_events(futures_util::stream::iter(events)).await.unwrap().into_inner();
+        assert_eq!((summary.received, summary.skipped), (2, 1));
+        assert_eq!(server.skipped_lines(), 1);
+
+        match event_rx.recv().await.unwrap() {
+            HiveEvent::AgentUpdate(update) => {
+                assert_eq!(&*update.agent_id, "atlas");
+                assert_eq!(update.status, AgentStatus::Thinking);
+                assert_eq!(update.focus.len(), 2);
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+        match event_rx.recv().await.unwrap() {
+            HiveEvent::Connection(connection) => {
+                assert_eq!((&*connection.from, &*connection.to), ("atlas", "nova"));
+                assert_eq!(connection.ttl_ms, Some(500));
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+        assert_eq!(server.clients(), 0);
+    }
+}
@@ -0,0 +1,141 @@
+//! Shared strings for agent IDs and focus keywords.
+//!
+//! The same few IDs and keywords arrive in event after event, and the field
+//! keeps copies of them in its maps, trails and statistics. They are
+//! deserialized straight into [`Arc<str>`]s from one process-wide table, so an
+//! ID or keyword seen before costs no allocation, and copying it anywhere is a
+//! reference count bump. The table is split into shards by hash, each with its
+//! own lock, so the parse pool's workers rarely wait on one another.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::{BuildHasher, RandomState};
+use std::sync::{Arc, Mutex, OnceLock};
+
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer};
+
+/// Strings kept in a shard at least this long before pruning starts
+const MIN_PRUNE_LEN: usize = 256;
+
+/// Number of shards; a power of two
+const SHARDS: usize = 32;
+
+struct Table {
+    strings: HashSet<Arc<str>>,
+    /// Size at which unused strings are next dropped
+    prune_at: usize,
+}
+
+struct Shards {
+    /// Picks the shard of a string
+    hasher: RandomState,
+    tables: [Mutex<Table>; SHARDS],
+}
+
+/// The shard `s` belongs in
+fn table(s: &str) -> &'static Mutex<Table> {
+    static SHARDED: OnceLock<Shards> = OnceLock::new();
+    let shards = SHARDED.get_or_init(|| Shards {
+        hasher: RandomState::new(),
+        tables: std::array::from_fn(|_| {
+            Mutex::new(Table {
+                strings: HashSet::new(),
+                prune_at: MIN_PRUNE_LEN,
+            })
+        }),
+    });
+    &shards.tables[shards.hasher.hash_one(s) as usize & (SHARDS - 1)]
+}
+
+/// The shared copy of `s`
+pub fn intern(s: &str) -> Arc<str> {
+    let mut table = table(s).lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(shared) = table.strings.get(s) {
+        return shared.clone();
+    }
+
+    // Drop the strings nothing else holds any more (agents long evicted,
+    // one-off keywords) whenever the shard doubles
+    if table.strings.len() >= table.prune_at {
+        table.strings.retain(|shared| Arc::strong_count(shared) > 1);
+        table.prune_at = (table.strings.len() * 2).max(MIN_PRUNE_LEN);
+    }
+    let shared: Arc<str> = Arc::from(s);
+    table.strings.insert(shared.clone());
+    shared
+}
+
+/// A string deserialized as its shared copy, looked up from the input
+/// without copying it first
+struct Shared(Arc<str>);
+
+impl<'de> Deserialize<'de> for Shared {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct SharedVisitor;
+
+        impl Visitor<'_> for SharedVisitor {
+            type Value = Shared;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a string")
+            }
+
+            fn visit_str<E: de::Error>(self, s: &str) -> Result<Shared, E> {
+                Ok(Shared(intern(s)))
+            }
+        }
+
+        deserializer.deserialize_str(SharedVisitor)
+    }
+}
+
+/// Deserialize a string as its shared copy (for `#[serde(deserialize_with)]`)
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Arc<str>, D::Error> {
+    Shared::deserialize(deserializer).map(|shared| shared.0)
+}
+
+/// Deserialize a list of strings as their shared copies
+pub fn deserialize_all<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Arc<str>>, D::Error> {
+    let strings = Vec::<Shared>::deserialize(deserializer)?;
+    Ok(strings.into_iter().map(|shared| shared.0).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_equal_strings_share_one_allocation() {
+        let first = intern("intern-test-atlas");
+        let second = intern(&String::from("intern-test-atlas"));
+        assert!(Arc::ptr_eq(&first, &second));
+        assert!(!Arc::ptr_eq(&first, &intern("intern-test-nova")));
+
+        #[derive(Deserialize)]
+        struct Update {
+            #[serde(deserialize_with = "deserialize")]
+            id: Arc<str>,
+            #[serde(deserialize_with = "deserialize_all")]
+            focus: Vec<Arc<str>>,
+        }
+        let update: Update =
+            serde_json::from_str(r#"{"id":"intern-test-atlas","focus":["intern-test-nova","esc\"aped"]}"#).unwrap();
+        assert!(Arc::ptr_eq(&update.id, &first));
+        assert_eq!(&*update.focus[1], "esc\"aped");
+    }
+
+    #[test]
+    fn test_threads_share_one_copy() {
+        let ids: Vec<String> = (0..100).map(|n| format!("intern-test-agent-{}", n)).collect();
+        let interned: Vec<Vec<Arc<str>>> = std::thread::scope(|scope| {
+            let workers: Vec<_> = (0..4)
+                .map(|_| scope.spawn(|| ids.iter().map(|id| intern(id)).collect::<Vec<_>>()))
+                .collect();
+            workers.into_iter().map(|worker| worker.join().unwrap()).collect()
+        });
+        for copies in &interned[1..] {
+            assert!(copies.iter().zip(&interned[0]).all(|(copy, first)| Arc::ptr_eq(copy, first)));
+        }
+    }
+}
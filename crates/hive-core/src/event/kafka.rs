@@ -0,0 +1,236 @@
+//! Kafka topic consumer (`kafka` feature).
+//!
+//! Agent platforms that already produce their events to a Kafka topic can be
+//! tailed directly. Hive reads every partition of one topic without joining a
+//! consumer group or committing offsets, starting at the end of each partition
+//! so only new events show. Asked to start from the beginning instead, it
+//! first reads what the partitions held when it connected (the backlog) and
+//! reports when it has caught up. Each message holds one or more JSON event
+//! lines.
+
+use std::collections::HashMap;
+use std::io;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use rdkafka::config::ClientConfig;
+use rdkafka::consumer::{Consumer, StreamConsumer};
+use rdkafka::{Message, Offset, TopicPartitionList};
+use tokio::sync::mpsc as tokio_mpsc;
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+
+use super::format::EventFormat;
+use super::types::HiveEvent;
+use super::watcher::ReadEvents;
+
+/// Delay before the first retry
+const MIN_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Longest delay between retries
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// How long to wait for the brokers to describe the topic
+const METADATA_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Reads a Kafka topic and sends the events in it to a channel
+pub struct KafkaConsumer {
+    topic: String,
+    /// Message lines that could not be parsed
+    skipped: Arc<AtomicUsize>,
+    /// Times the topic could not be read
+    drops: Arc<AtomicUsize>,
+    /// Set until the backlog has been read
+    replaying: Arc<AtomicBool>,
+    /// Assign and read loop; aborting it closes the consumer
+    task: JoinHandle<()>,
+}
+
+impl KafkaConsumer {
+    /// Read `topic` from the brokers in `brokers` (comma-separated
+    /// `host:port`) and forward events to `event_tx` until dropped, starting
+    /// from the oldest retained messages if `from_beginning` is set and from
+    /// new ones otherwise. Fails only if the consumer cannot be created; an
+    /// unreachable cluster or missing topic is retried. Must be called from
+    /// within a Tokio runtime.
+    pub fn connect(
+        brokers: &str,
+        topic: &str,
+        from_beginning: bool,
+        event_tx: tokio_mpsc::Sender<HiveEvent>,
+    ) -> io::Result<Self> {
+        if topic.is_empty() {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "no topic"));
+        }
+        let consumer: StreamConsumer = ClientConfig::new()
+            .set("bootstrap.servers", brokers)
+            .set("group.id", "hive")
+            .set("client.id", "hive")
+            .set("enable.auto.commit", "false")
+            .create()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        let skipped = Arc::new(AtomicUsize::new(0));
+        let drops = Arc::new(AtomicUsize::new(0));
+        let replaying = Arc::new(AtomicBool::new(from_beginning));
+        let task = tokio::spawn(consume(
+            consumer,
+            topic.to_string(),
+            from_beginning,
+            event_tx,
+            skipped.clone(),
+            drops.clone(),
+            replaying.clone(),
+        ));
+
+        Ok(Self {
+            topic: topic.to_string(),
+            skipped,
+            drops,
+            replaying,
+            task,
+        })
+    }
+
+    /// Topic read
+    pub fn topic(&self) -> &str {
+        &self.topic
+    }
+
+    /// Number of message lines that could not be parsed so far
+    pub fn skipped_lines(&self) -> usize {
+        self.skipped.load(Ordering::Relaxed)
+    }
+
+    /// Number of times the topic could not be read
+    pub fn drops(&self) -> usize {
+        self.drops.load(Ordering::Relaxed)
+    }
+
+    /// Whether the messages sent are still from the backlog
+    pub fn replaying(&self) -> bool {
+        self.replaying.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for KafkaConsumer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Assign every partition of `topic` to `consumer`, at its start or its end.
+/// Returns, when starting at the beginning, the offset each non-empty
+/// partition ends at now.
+fn assign(consumer: &StreamConsumer, topic: &str, from_beginning: bool) -> io::Result<HashMap<i32, i64>> {
+    let metadata = consumer.fetch_metadata(Some(topic), METADATA_TIMEOUT).map_err(io::Error::other)?;
+    let partitions: Vec<i32> = match metadata.topics().first() {
+        Some(found) if found.error().is_none() && !found.partitions().is_empty() => {
+            found.partitions().iter().map(|partition| partition.id()).collect()
+        }
+        _ => return Err(io::Error::new(io::ErrorKind::NotFound, format!("no topic {}", topic))),
+    };
+
+    let mut assignment = TopicPartitionList::new();
+    let mut backlog = HashMap::new();
+    for partition in partitions {
+        let offset = if from_beginning { Offset::Beginning } else { Offset::End };
+        assignment.add_partition_offset(topic, partition, offset).map_err(io::Error::other)?;
+        if from_beginning {
+            let (low, high) = consumer
+                .fetch_watermarks(topic, partition, METADATA_TIMEOUT)
+                .map_err(io::Error::other)?;
+            if high > low {
+                backlog.insert(partition, high);
+            }
+        }
+    }
+    consumer.assign(&assignment).map_err(io::Error::other)?;
+    Ok(backlog)
+}
+
+async fn consume(
+    consumer: StreamConsumer,
+    topic: String,
+    from_beginning: bool,
+    event_tx: tokio_mpsc::Sender<HiveEvent>,
+    skipped: Arc<AtomicUsize>,
+    drops: Arc<AtomicUsize>,
+    replaying: Arc<AtomicBool>,
+) {
+    // Metadata calls block, so they run on a thread of their own, which
+    // quitting does not wait for
+    let consumer = Arc::new(consumer);
+    let mut backoff = MIN_BACKOFF;
+    let mut backlog = loop {
+        let (assigned_tx, assigned_rx) = oneshot::channel();
+        let assigning = consumer.clone();
+        let name = topic.clone();
+        std::thread::spawn(move || assigned_tx.send(assign(&assigning, &name, from_beginning)));
+        match assigned_rx.await {
+            Ok(Ok(backlog)) => break backlog,
+            Ok(Err(_)) => drops.fetch_add(1, Ordering::Relaxed),
+            Err(_) => return,
+        };
+        tokio::select! {
+            _ = tokio::time::sleep(backoff) => {}
+            _ = event_tx.closed() => return,
+        }
+        backoff = (backoff * 2).min(MAX_BACKOFF);
+    };
+    replaying.store(!backlog.is_empty(), Ordering::Relaxed);
+
+    backoff = MIN_BACKOFF;
+    loop {
+        let read = match consumer.recv().await {
+            Ok(message) => {
+                if backlog.get(&message.partition()).is_some_and(|&end| message.offset() + 1 >= end) {
+                    backlog.remove(&message.partition());
+                }
+                message
+                    .payload()
+                    .map(|payload| ReadEvents::from_lines(payload, 1, EventFormat::Json))
+                    .unwrap_or_default()
+            }
+            Err(_) => {
+                // The client reconnects by itself; wait before asking again
+                drops.fetch_add(1, Ordering::Relaxed);
+                tokio::select! {
+                    _ = tokio::time::sleep(backoff) => {}
+                    _ = event_tx.closed() => return,
+                }
+                backoff = (backoff * 2).min(MAX_BACKOFF);
+                continue;
+            }
+        };
+        backoff = MIN_BACKOFF;
+
+        skipped.fetch_add(read.skipped, Ordering::Relaxed);
+        for event in read.events {
+            if event_tx.send(event).await.is_err() {
+                return; // Channel closed
+            }
+        }
+        if backlog.is_empty() {
+            replaying.store(false, Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_unreachable_cluster_is_retried() {
+        let (event_tx, _event_rx) = tokio_mpsc::channel(1);
+        assert!(KafkaConsumer::connect("127.0.0.1:1", "", false, event_tx.clone()).is_err());
+
+        let consumer = KafkaConsumer::connect("127.0.0.1:1", "hive.events", true, event_tx).unwrap();
+        assert_eq!(consumer.topic(), "hive.events");
+        // Nothing read yet, so the backlog is still to come
+        assert!(consumer.replaying());
+        assert_eq!(consumer.skipped_lines(), 0);
+    }
+}
@@ -0,0 +1,139 @@
+//! Background loading of the events already in a file.
+//!
+//! Big files can take a while to read, so the initial load runs on its own
+//! thread while the UI shows its progress, and can be cancelled to only follow
+//! the file from now on.
+
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+
+use super::format::EventFormat;
+use super::watcher::ReadEvents;
+
+/// An initial load in progress
+pub struct InitialLoad {
+    /// Bytes read so far
+    read: Arc<AtomicU64>,
+    /// Bytes to read
+    total: u64,
+    cancelled: Arc<AtomicBool>,
+    result: mpsc::Receiver<io::Result<ReadEvents>>,
+}
+
+impl InitialLoad {
+    /// Start reading the first `len` bytes of `path` on a background thread
+    pub fn start(path: &Path, len: u64, format: EventFormat) -> Self {
+        let read = Arc::new(AtomicU64::new(0));
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let (tx, result) = mpsc::channel();
+
+        let path = path.to_path_buf();
+        let (counter, cancel) = (read.clone(), cancelled.clone());
+        thread::spawn(move || {
+            let loaded = File::open(&path).and_then(|file| {
+                let reader = Progress {
+                    read: counter,
+                    cancelled: cancel,
+                    inner: file.take(len),
+                };
+                ReadEvents::from_reader(reader, format)
+            });
+            let _ = tx.send(loaded);
+        });
+
+        Self {
+            read,
+            total: len,
+            cancelled,
+            result,
+        }
+    }
+
+    /// Bytes read so far and bytes to read
+    pub fn bytes(&self) -> (u64, u64) {
+        (self.read.load(Ordering::Relaxed).min(self.total), self.total)
+    }
+
+    /// Share of the file read, from 0 to 1
+    pub fn progress(&self) -> f32 {
+        let (read, total) = self.bytes();
+        if total == 0 {
+            1.0
+        } else {
+            read as f32 / total as f32
+        }
+    }
+
+    /// Stop reading; [`try_finish`](Self::try_finish) then reports an error
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// The events read, once the load is over
+    pub fn try_finish(&self) -> Option<io::Result<ReadEvents>> {
+        match self.result.try_recv() {
+            Ok(result) => Some(result),
+            Err(mpsc::TryRecvError::Empty) => None,
+            Err(mpsc::TryRecvError::Disconnected) => {
+                Some(Err(io::Error::other("loader thread stopped")))
+            }
+        }
+    }
+}
+
+/// Reader counting the bytes read through it, and failing once cancelled
+struct Progress<R> {
+    read: Arc<AtomicU64>,
+    cancelled: Arc<AtomicBool>,
+    inner: R,
+}
+
+impl<R: Read> Read for Progress<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.cancelled.load(Ordering::Relaxed) {
+            // Not `Interrupted`, which readers retry
+            return Err(io::Error::other("load cancelled"));
+        }
+        let n = self.inner.read(buf)?;
+        self.read.fetch_add(n as u64, Ordering::Relaxed);
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    fn finish(load: &InitialLoad) -> io::Result<ReadEvents> {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        loop {
+            if let Some(result) = load.try_finish() {
+                return result;
+            }
+            assert!(Instant::now() < deadline, "load did not finish");
+            thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    #[test]
+    fn test_loads_up_to_len() {
+        let path = std::env::temp_dir().join(format!("hive-load-{}.jsonl", std::process::id()));
+        let first = r#"{"type":"landmark","id":"a","label":"A","keywords":[],"timestamp":1}"#;
+        let second = r#"{"type":"landmark","id":"b","label":"B","keywords":[],"timestamp":2}"#;
+        std::fs::write(&path, format!("{}\n{}\n", first, second)).unwrap();
+
+        let load = InitialLoad::start(&path, first.len() as u64 + 1, EventFormat::Json);
+        let read = finish(&load).unwrap();
+        assert_eq!(read.events.len(), 1);
+        assert_eq!(load.progress(), 1.0);
+
+        let load = InitialLoad::start(&path, 1 << 20, EventFormat::Json);
+        assert_eq!(finish(&load).unwrap().events.len(), 2);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,43 @@
+pub mod format;
+#[cfg(feature = "grpc")]
+pub mod grpc;
+pub mod intern;
+#[cfg(feature = "kafka")]
+pub mod kafka;
+pub mod loader;
+#[cfg(feature = "nats")]
+pub mod nats;
+pub mod parse;
+#[cfg(feature = "redis")]
+pub mod pubsub;
+#[cfg(unix)]
+pub mod socket;
+#[cfg(feature = "http")]
+pub mod stream;
+pub mod types;
+pub mod watcher;
+#[cfg(feature = "ws")]
+pub mod websocket;
+pub mod queue;
+
+pub use format::EventFormat;
+#[cfg(feature = "grpc")]
+pub use grpc::GrpcServer;
+pub use intern::intern;
+#[cfg(feature = "kafka")]
+pub use kafka::KafkaConsumer;
+pub use loader::InitialLoad;
+#[cfg(feature = "nats")]
+pub use nats::NatsSubscriber;
+pub use parse::ParsePool;
+#[cfg(feature = "redis")]
+pub use pubsub::RedisSubscriber;
+#[cfg(unix)]
+pub use socket::SocketListener;
+#[cfg(feature = "http")]
+pub use stream::EventStream;
+pub use types::*;
+pub use watcher::{FileWatcher, ReadEvents};
+#[cfg(feature = "ws")]
+pub use websocket::WebSocketServer;
+pub use queue::{create_event_queue, EventSender, EventReceiver};
@@ -0,0 +1,158 @@
+//! NATS subject subscription (`nats` feature).
+//!
+//! Agents on distributed workers publish events to NATS subjects, and Hive
+//! subscribes to one subject, which may use NATS wildcards so that per-agent
+//! subjects such as `hive.events.agent-42` (`hive.events.*`) or whole trees
+//! (`hive.events.>`) all feed the same field. Each message holds one or more
+//! JSON event lines. The client reconnects on its own when the server goes
+//! away, and the subscription carries over.
+
+use std::io;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use async_nats::{ConnectOptions, Event, ServerAddr};
+use futures_util::StreamExt;
+use tokio::sync::mpsc as tokio_mpsc;
+use tokio::task::JoinHandle;
+
+use super::format::EventFormat;
+use super::types::HiveEvent;
+use super::watcher::ReadEvents;
+
+/// Subscribes to a NATS subject and sends the events published on it to a
+/// channel
+pub struct NatsSubscriber {
+    subject: String,
+    /// Message lines that could not be parsed
+    skipped: Arc<AtomicUsize>,
+    /// Times the connection to the server was lost
+    drops: Arc<AtomicUsize>,
+    /// Connect and read loop; aborting it closes the connection
+    task: JoinHandle<()>,
+}
+
+impl NatsSubscriber {
+    /// Subscribe to `subject` on the server at `url` and forward events to
+    /// `event_tx` until dropped. Fails only for an invalid URL or subject; an
+    /// unreachable server is retried. Must be called from within a Tokio
+    /// runtime.
+    pub fn connect(url: &str, subject: &str, event_tx: tokio_mpsc::Sender<HiveEvent>) -> io::Result<Self> {
+        let server: ServerAddr = url.parse()?;
+        check_subject(subject)?;
+
+        let skipped = Arc::new(AtomicUsize::new(0));
+        let drops = Arc::new(AtomicUsize::new(0));
+        let task = tokio::spawn(subscribe(server, subject.to_string(), event_tx, skipped.clone(), drops.clone()));
+
+        Ok(Self {
+            subject: subject.to_string(),
+            skipped,
+            drops,
+            task,
+        })
+    }
+
+    /// Subject (possibly with wildcards) subscribed to
+    pub fn subject(&self) -> &str {
+        &self.subject
+    }
+
+    /// Number of message lines that could not be parsed so far
+    pub fn skipped_lines(&self) -> usize {
+        self.skipped.load(Ordering::Relaxed)
+    }
+
+    /// Number of times the connection to the server was lost
+    pub fn drops(&self) -> usize {
+        self.drops.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for NatsSubscriber {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// A subject is dot-separated tokens; `*` stands for one token and `>`, last,
+/// for the rest
+fn check_subject(subject: &str) -> io::Result<()> {
+    let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidInput, format!("invalid subject {:?}: {}", subject, message));
+    if subject.is_empty() {
+        return Err(invalid("it is empty"));
+    }
+    if subject.contains(char::is_whitespace) {
+        return Err(invalid("it contains whitespace"));
+    }
+    let tokens: Vec<&str> = subject.split('.').collect();
+    for (idx, token) in tokens.iter().enumerate() {
+        if token.is_empty() {
+            return Err(invalid("it has an empty token"));
+        }
+        if *token == ">" && idx != tokens.len() - 1 {
+            return Err(invalid("`>` must be the last token"));
+        }
+    }
+    Ok(())
+}
+
+async fn subscribe(
+    server: ServerAddr,
+    subject: String,
+    event_tx: tokio_mpsc::Sender<HiveEvent>,
+    skipped: Arc<AtomicUsize>,
+    drops: Arc<AtomicUsize>,
+) {
+    let options = ConnectOptions::new()
+        .name("hive")
+        .retry_on_initial_connect()
+        .max_reconnects(None)
+        .event_callback(move |event| {
+            let drops = drops.clone();
+            async move {
+                if matches!(event, Event::Disconnected) {
+                    drops.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+        });
+    // With retries on, connecting and subscribing only fail once the client
+    // is closed
+    let Ok(client) = options.connect(server).await else {
+        return;
+    };
+    let Ok(mut messages) = client.subscribe(subject).await else {
+        return;
+    };
+    while let Some(message) = messages.next().await {
+        let read = ReadEvents::from_lines(&message.payload, 1, EventFormat::Json);
+        skipped.fetch_add(read.skipped, Ordering::Relaxed);
+        for event in read.events {
+            if event_tx.send(event).await.is_err() {
+                return; // Channel closed
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_wildcard_subjects_are_accepted() {
+        for subject in ["hive.events", "hive.events.*", "hive.*.status", "hive.events.>", ">"] {
+            assert!(check_subject(subject).is_ok(), "{}", subject);
+        }
+        for subject in ["", "hive..events", "hive.>.status", "hive events", "hive.events."] {
+            assert!(check_subject(subject).is_err(), "{}", subject);
+        }
+
+        let (event_tx, _event_rx) = tokio_mpsc::channel(1);
+        assert!(NatsSubscriber::connect("http://localhost", "hive.events", event_tx.clone()).is_err());
+        assert!(NatsSubscriber::connect("nats://127.0.0.1:1", "hive events", event_tx.clone()).is_err());
+        // An unreachable server is not an error; it is retried
+        let subscriber = NatsSubscriber::connect("nats://127.0.0.1:1", "hive.events.*", event_tx).unwrap();
+        assert_eq!(subscriber.subject(), "hive.events.*");
+    }
+}
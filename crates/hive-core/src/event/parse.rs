@@ -0,0 +1,158 @@
+//! Parsing event lines on a small pool of worker threads.
+//!
+//! Deserializing is most of the cost of reading events, so big batches of
+//! lines (a large file being loaded, or a source writing thousands of events a
+//! second) are cut into chunks that worker threads parse side by side, and the
+//! results are put back together in line order. Small batches are parsed on
+//! the calling thread, where handing them over would cost more than it saves.
+
+use std::sync::{mpsc, Arc, Mutex, OnceLock};
+use std::thread;
+
+use super::format::EventFormat;
+use super::watcher::ReadEvents;
+
+/// Most worker threads in the shared pool
+const MAX_WORKERS: usize = 4;
+
+/// Bytes of whole lines handed to a worker at a time
+const CHUNK_BYTES: usize = 64 * 1024;
+
+/// A chunk of lines for a worker to parse
+struct Job {
+    /// Position of the chunk in its batch
+    index: usize,
+    lines: Vec<u8>,
+    /// Line number (from 1) of the first line of the chunk
+    first_line: usize,
+    format: EventFormat,
+    done: mpsc::Sender<(usize, ReadEvents)>,
+}
+
+/// Worker threads parsing chunks of event lines
+pub struct ParsePool {
+    jobs: mpsc::Sender<Job>,
+    workers: usize,
+    chunk_bytes: usize,
+}
+
+impl ParsePool {
+    /// Start `workers` threads; they stop when the pool is dropped
+    pub fn new(workers: usize) -> Self {
+        let (jobs, queue) = mpsc::channel::<Job>();
+        let queue = Arc::new(Mutex::new(queue));
+        for _ in 0..workers {
+            let queue = queue.clone();
+            thread::spawn(move || loop {
+                // Hold the lock only while taking the next job
+                let job = match queue.lock() {
+                    Ok(queue) => queue.recv(),
+                    Err(_) => return,
+                };
+                let Ok(job) = job else {
+                    return;
+                };
+                let read = ReadEvents::from_lines(&job.lines, job.first_line, job.format);
+                let _ = job.done.send((job.index, read));
+            });
+        }
+        Self {
+            jobs,
+            workers,
+            chunk_bytes: CHUNK_BYTES,
+        }
+    }
+
+    /// Pool shared by every reader, with a worker per core up to four
+    pub fn shared() -> &'static ParsePool {
+        static POOL: OnceLock<ParsePool> = OnceLock::new();
+        POOL.get_or_init(|| {
+            let cores = thread::available_parallelism().map_or(1, |n| n.get());
+            ParsePool::new(cores.min(MAX_WORKERS))
+        })
+    }
+
+    /// Parse `lines` (whole lines, the first of them numbered `first_line`),
+    /// in chunks across the workers when there are enough of them
+    pub fn parse(&self, lines: &[u8], first_line: usize, format: EventFormat) -> ReadEvents {
+        if self.workers < 2 || lines.len() <= self.chunk_bytes {
+            return ReadEvents::from_lines(lines, first_line, format);
+        }
+
+        let (done, results) = mpsc::channel();
+        let mut chunks = 0;
+        let mut line = first_line;
+        let mut rest = lines;
+        while !rest.is_empty() {
+            // Cut after the first newline past the chunk size
+            let end = if rest.len() <= self.chunk_bytes {
+                rest.len()
+            } else {
+                rest[self.chunk_bytes..]
+                    .iter()
+                    .position(|&b| b == b'\n')
+                    .map_or(rest.len(), |at| self.chunk_bytes + at + 1)
+            };
+            let (chunk, tail) = rest.split_at(end);
+            let job = Job {
+                index: chunks,
+                lines: chunk.to_vec(),
+                first_line: line,
+                format,
+                done: done.clone(),
+            };
+            if let Err(mpsc::SendError(job)) = self.jobs.send(job) {
+                // No workers left; parse it here
+                let _ = done.send((job.index, ReadEvents::from_lines(&job.lines, job.first_line, format)));
+            }
+            line += chunk.iter().filter(|&&b| b == b'\n').count();
+            chunks += 1;
+            rest = tail;
+        }
+        drop(done);
+
+        // Put the chunks back in order
+        let mut parsed: Vec<Option<ReadEvents>> = (0..chunks).map(|_| None).collect();
+        for (index, read) in results {
+            parsed[index] = Some(read);
+        }
+        let mut read = ReadEvents::default();
+        for chunk in parsed.into_iter().flatten() {
+            read.append(chunk);
+        }
+        read
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunks_are_reassembled_in_order() {
+        let mut lines = String::new();
+        for n in 0..500 {
+            if n % 100 == 7 {
+                lines.push_str("{\"type\":\"agent_upd\n");
+            }
+            lines.push_str(&format!(
+                "{{\"type\":\"landmark\",\"id\":\"l{}\",\"label\":\"L\",\"keywords\":[],\"timestamp\":{}}}\n",
+                n, n
+            ));
+        }
+
+        let pool = ParsePool {
+            chunk_bytes: 256,
+            ..ParsePool::new(3)
+        };
+        let parallel = pool.parse(lines.as_bytes(), 1, EventFormat::Json);
+        let serial = ReadEvents::from_lines(lines.as_bytes(), 1, EventFormat::Json);
+
+        let timestamps = |read: &ReadEvents| read.events.iter().map(|event| event.timestamp()).collect::<Vec<_>>();
+        assert_eq!(timestamps(&parallel), (0..500).collect::<Vec<_>>());
+        assert_eq!(timestamps(&parallel), timestamps(&serial));
+        assert_eq!(parallel.skipped, 5);
+        assert_eq!(parallel.first_skipped, Some(8));
+        assert_eq!(serial.first_skipped, Some(8));
+    }
+}
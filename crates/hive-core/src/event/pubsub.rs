@@ -0,0 +1,156 @@
+//! Redis pub/sub input.
+//!
+//! Coordinators that already publish agent status on a Redis channel can feed
+//! Hive directly: it subscribes to the channel named by the last path segment
+//! of a `redis://host[:port]/channel` URL (a pattern such as `agents.*` is
+//! subscribed to with PSUBSCRIBE) and reads every message as one or more JSON
+//! event lines. A lost connection is made again with a growing delay.
+
+use std::io;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures_util::StreamExt;
+use tokio::sync::mpsc as tokio_mpsc;
+use tokio::task::JoinHandle;
+
+use super::format::EventFormat;
+use super::types::HiveEvent;
+use super::watcher::ReadEvents;
+
+/// Delay before the first reconnect
+const MIN_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Longest delay between reconnects
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Subscribes to a Redis channel and sends the events published on it to a
+/// channel
+pub struct RedisSubscriber {
+    channel: String,
+    /// Message lines that could not be parsed
+    skipped: Arc<AtomicUsize>,
+    /// Times the connection was lost or could not be made
+    drops: Arc<AtomicUsize>,
+    /// Subscribe and read loop; aborting it closes the connection
+    task: JoinHandle<()>,
+}
+
+impl RedisSubscriber {
+    /// Subscribe to the channel in `url` and forward events to `event_tx`
+    /// until dropped. Fails only for a URL that names no channel or server;
+    /// an unreachable server is retried. Must be called from within a Tokio
+    /// runtime.
+    pub fn connect(url: &str, event_tx: tokio_mpsc::Sender<HiveEvent>) -> io::Result<Self> {
+        let (server, channel) = split_channel(url)?;
+        let client = redis::Client::open(server).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        let skipped = Arc::new(AtomicUsize::new(0));
+        let drops = Arc::new(AtomicUsize::new(0));
+        let task = tokio::spawn(subscribe(client, channel.clone(), event_tx, skipped.clone(), drops.clone()));
+
+        Ok(Self {
+            channel,
+            skipped,
+            drops,
+            task,
+        })
+    }
+
+    /// Channel (or pattern) subscribed to
+    pub fn channel(&self) -> &str {
+        &self.channel
+    }
+
+    /// Number of message lines that could not be parsed so far
+    pub fn skipped_lines(&self) -> usize {
+        self.skipped.load(Ordering::Relaxed)
+    }
+
+    /// Number of times the connection was lost or could not be made
+    pub fn drops(&self) -> usize {
+        self.drops.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for RedisSubscriber {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Split `redis://host/channel` into the server URL and the channel
+fn split_channel(url: &str) -> io::Result<(String, String)> {
+    let invalid = |message| io::Error::new(io::ErrorKind::InvalidInput, message);
+    let Some((scheme, rest)) = url.split_once("://") else {
+        return Err(invalid("expected a redis://host/channel URL"));
+    };
+    let Some((server, channel)) = rest.rsplit_once('/') else {
+        return Err(invalid("no channel after the host"));
+    };
+    if server.is_empty() || channel.is_empty() {
+        return Err(invalid("no host or channel"));
+    }
+    Ok((format!("{}://{}", scheme, server), channel.to_string()))
+}
+
+async fn subscribe(
+    client: redis::Client,
+    channel: String,
+    event_tx: tokio_mpsc::Sender<HiveEvent>,
+    skipped: Arc<AtomicUsize>,
+    drops: Arc<AtomicUsize>,
+) {
+    let pattern = channel.contains(['*', '?', '[']);
+    let mut backoff = MIN_BACKOFF;
+    loop {
+        if let Ok(mut pubsub) = client.get_async_pubsub().await {
+            let subscribed = if pattern {
+                pubsub.psubscribe(&channel).await
+            } else {
+                pubsub.subscribe(&channel).await
+            };
+            if subscribed.is_ok() {
+                backoff = MIN_BACKOFF;
+                let mut messages = pubsub.on_message();
+                while let Some(message) = messages.next().await {
+                    let read = ReadEvents::from_lines(message.get_payload_bytes(), 1, EventFormat::Json);
+                    skipped.fetch_add(read.skipped, Ordering::Relaxed);
+                    for event in read.events {
+                        if event_tx.send(event).await.is_err() {
+                            return; // Channel closed
+                        }
+                    }
+                }
+            }
+        }
+        drops.fetch_add(1, Ordering::Relaxed);
+
+        tokio::select! {
+            _ = tokio::time::sleep(backoff) => {}
+            _ = event_tx.closed() => return,
+        }
+        backoff = (backoff * 2).min(MAX_BACKOFF);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_channel_is_the_last_path_segment() {
+        assert_eq!(
+            split_channel("redis://coordinator/swarm").unwrap(),
+            ("redis://coordinator".to_string(), "swarm".to_string())
+        );
+        assert_eq!(
+            split_channel("rediss://:secret@10.0.0.2:6380/2/agents.*").unwrap(),
+            ("rediss://:secret@10.0.0.2:6380/2".to_string(), "agents.*".to_string())
+        );
+        assert!(split_channel("redis://coordinator").is_err());
+        assert!(split_channel("redis://coordinator/").is_err());
+        assert!(split_channel("coordinator/swarm").is_err());
+    }
+}
@@ -0,0 +1,287 @@
+//! Unix domain socket input.
+//!
+//! Agents on the same machine connect to the socket and write events as JSON
+//! lines, the same as they would append to an events file, without the file
+//! growing on disk or the watcher's polling delay. Any number of writers can
+//! be connected at once; their events are interleaved line by line. With
+//! [`EventFormat::MsgPack`] writers send length-prefixed MessagePack frames
+//! instead of lines.
+
+use std::io;
+use std::os::unix::net::UnixStream as StdUnixStream;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncRead, AsyncReadExt, BufReader};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::mpsc as tokio_mpsc;
+use tokio::task::{JoinHandle, JoinSet};
+
+use super::format::{EventFormat, MAX_FRAME_BYTES};
+use super::types::HiveEvent;
+use super::watcher::ReadEvents;
+
+/// Longest line read from a writer; past this it is not sending lines
+const MAX_LINE_BYTES: usize = MAX_FRAME_BYTES;
+
+/// Pause after a failed accept, which tends to fail again straight away
+/// (e.g. out of file descriptors)
+const ACCEPT_BACKOFF: Duration = Duration::from_millis(100);
+
+/// Accepts writers on a Unix socket and sends their events to a channel
+pub struct SocketListener {
+    path: PathBuf,
+    /// Lines that could not be parsed, across all writers
+    skipped: Arc<AtomicUsize>,
+    /// Writers connected right now
+    writers: Arc<AtomicUsize>,
+    /// Accept loop; aborting it drops every connection
+    task: JoinHandle<()>,
+}
+
+impl SocketListener {
+    /// Create the socket at `path` and forward events to `event_tx` until
+    /// dropped, which removes the socket again. A socket left behind by a
+    /// crashed run is replaced; one another process still listens on is an
+    /// error. Must be called from within a Tokio runtime.
+    pub fn bind(path: impl AsRef<Path>, event_tx: tokio_mpsc::Sender<HiveEvent>) -> io::Result<Self> {
+        Self::with_format(path, event_tx, EventFormat::Json)
+    }
+
+    /// Like [`SocketListener::bind`], reading events in `format`
+    pub fn with_format(
+        path: impl AsRef<Path>,
+        event_tx: tokio_mpsc::Sender<HiveEvent>,
+        format: EventFormat,
+    ) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        if path.exists() {
+            if StdUnixStream::connect(&path).is_ok() {
+                return Err(io::Error::new(io::ErrorKind::AddrInUse, "another process is listening on it"));
+            }
+            std::fs::remove_file(&path)?;
+        }
+        let listener = UnixListener::bind(&path)?;
+
+        let skipped = Arc::new(AtomicUsize::new(0));
+        let writers = Arc::new(AtomicUsize::new(0));
+        let task = tokio::spawn(accept_writers(listener, event_tx, format, skipped.clone(), writers.clone()));
+
+        Ok(Self {
+            path,
+            skipped,
+            writers,
+            task,
+        })
+    }
+
+    /// Path of the socket
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Number of lines (or frames) that could not be parsed so far
+    pub fn skipped_lines(&self) -> usize {
+        self.skipped.load(Ordering::Relaxed)
+    }
+
+    /// Number of writers connected right now
+    pub fn writers(&self) -> usize {
+        self.writers.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for SocketListener {
+    fn drop(&mut self) {
+        self.task.abort();
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+async fn accept_writers(
+    listener: UnixListener,
+    event_tx: tokio_mpsc::Sender<HiveEvent>,
+    format: EventFormat,
+    skipped: Arc<AtomicUsize>,
+    writers: Arc<AtomicUsize>,
+) {
+    // Owned here, so the connections close when the listener is dropped
+    let mut connections = JoinSet::new();
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => {
+                let Ok((stream, _)) = accepted else {
+                    tokio::time::sleep(ACCEPT_BACKOFF).await;
+                    continue;
+                };
+                connections.spawn(read_writer(stream, event_tx.clone(), format, skipped.clone(), writers.clone()));
+            }
+            Some(_) = connections.join_next() => {}
+            _ = event_tx.closed() => return,
+        }
+    }
+}
+
+async fn read_writer(
+    stream: UnixStream,
+    event_tx: tokio_mpsc::Sender<HiveEvent>,
+    format: EventFormat,
+    skipped: Arc<AtomicUsize>,
+    writers: Arc<AtomicUsize>,
+) {
+    writers.fetch_add(1, Ordering::Relaxed);
+    let mut reader = BufReader::new(stream);
+    let mut buf = Vec::new();
+    loop {
+        // A last line without its newline when the writer hangs up still counts
+        let n = match format {
+            EventFormat::MsgPack => read_frame(&mut reader, &mut buf).await,
+            _ => read_line(&mut reader, &mut buf).await,
+        };
+        let read = match n {
+            Ok(0) => break,
+            Ok(_) if format.is_framed() => ReadEvents::from_frames(&buf),
+            Ok(_) => ReadEvents::from_lines(&buf, 1, format),
+            Err(e) => {
+                // After an oversized frame or line there is no telling where
+                // the next one starts
+                if e.kind() == io::ErrorKind::InvalidData {
+                    skipped.fetch_add(1, Ordering::Relaxed);
+                }
+                break;
+            }
+        };
+        buf.clear();
+        skipped.fetch_add(read.skipped, Ordering::Relaxed);
+        for event in read.events {
+            if event_tx.send(event).await.is_err() {
+                writers.fetch_sub(1, Ordering::Relaxed);
+                return; // Channel closed
+            }
+        }
+    }
+    writers.fetch_sub(1, Ordering::Relaxed);
+}
+
+/// Read one line, newline included, into `buf`; 0 at the end of the stream
+async fn read_line(reader: &mut (impl AsyncBufRead + Unpin), buf: &mut Vec<u8>) -> io::Result<usize> {
+    let n = reader.take(MAX_LINE_BYTES as u64 + 1).read_until(b'\n', buf).await?;
+    if n > MAX_LINE_BYTES && buf.last() != Some(&b'\n') {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "line over the size limit"));
+    }
+    Ok(n)
+}
+
+/// Read one length-prefixed frame, prefix included, into `buf`; 0 at the
+/// end of the stream
+async fn read_frame(reader: &mut (impl AsyncRead + Unpin), buf: &mut Vec<u8>) -> io::Result<usize> {
+    let mut prefix = [0; 4];
+    match reader.read_exact(&mut prefix).await {
+        Ok(_) => {}
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(0),
+        Err(e) => return Err(e),
+    }
+    let len = u32::from_be_bytes(prefix) as usize;
+    if len > MAX_FRAME_BYTES {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "frame over the size limit"));
+    }
+    buf.extend(prefix);
+    buf.resize(prefix.len() + len, 0);
+    reader.read_exact(&mut buf[prefix.len()..]).await?;
+    Ok(buf.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::AsyncWriteExt;
+
+    fn update(id: &str) -> String {
+        format!(
+            "{{\"type\":\"agent_update\",\"agent_id\":\"{}\",\"status\":\"active\",\"focus\":[],\"intensity\":0.5,\"message\":\"\",\"timestamp\":1}}\n",
+            id
+        )
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_writers_feed_one_channel() {
+        let path = std::env::temp_dir().join(format!("hive-socket-{}.sock", std::process::id()));
+        let (event_tx, mut event_rx) = tokio_mpsc::channel(16);
+        let listener = SocketListener::bind(&path, event_tx).unwrap();
+
+        let mut first = UnixStream::connect(&path).await.unwrap();
+        let mut second = UnixStream::connect(&path).await.unwrap();
+        first.write_all(update("atlas").as_bytes()).await.unwrap();
+        second.write_all(format!("not json\n{}", update("nova")).as_bytes()).await.unwrap();
+        first.write_all(update("sage").as_bytes()).await.unwrap();
+
+        let mut ids = Vec::new();
+        for _ in 0..3 {
+            match event_rx.recv().await.unwrap() {
+                HiveEvent::AgentUpdate(update) => ids.push(update.agent_id.to_string()),
+                other => panic!("unexpected event {:?}", other),
+            }
+        }
+        ids.sort();
+        assert_eq!(ids, ["atlas", "nova", "sage"]);
+        assert_eq!(listener.skipped_lines(), 1);
+        assert_eq!(listener.writers(), 2);
+
+        // A second listener on the same path is refused; the socket goes
+        // away with the first
+        let (other_tx, _other_rx) = tokio_mpsc::channel(1);
+        assert!(SocketListener::bind(&path, other_tx).is_err());
+        drop(listener);
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_msgpack_frames() {
+        let path = std::env::temp_dir().join(format!("hive-socket-msgpack-{}.sock", std::process::id()));
+        let (event_tx, mut event_rx) = tokio_mpsc::channel(16);
+        let listener = SocketListener::with_format(&path, event_tx, EventFormat::MsgPack).unwrap();
+
+        let event: HiveEvent = serde_json::from_str(&update("atlas")).unwrap();
+        let body = rmp_serde::to_vec_named(&event).unwrap();
+        let mut data = Vec::new();
+        for body in [&body[..], b"junk"] {
+            data.extend((body.len() as u32).to_be_bytes());
+            data.extend(body);
+        }
+        // Written in two pieces, split inside the first frame
+        let mut writer = UnixStream::connect(&path).await.unwrap();
+        writer.write_all(&data[..6]).await.unwrap();
+        writer.flush().await.unwrap();
+        writer.write_all(&data[6..]).await.unwrap();
+        drop(writer);
+
+        match event_rx.recv().await.unwrap() {
+            HiveEvent::AgentUpdate(update) => assert_eq!(&*update.agent_id, "atlas"),
+            other => panic!("unexpected event {:?}", other),
+        }
+        while listener.writers() > 0 {
+            tokio::task::yield_now().await;
+        }
+        assert_eq!(listener.skipped_lines(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_overlong_line_drops_the_writer() {
+        let path = std::env::temp_dir().join(format!("hive-socket-long-{}.sock", std::process::id()));
+        let (event_tx, mut event_rx) = tokio_mpsc::channel(16);
+        let listener = SocketListener::bind(&path, event_tx).unwrap();
+
+        let mut writer = UnixStream::connect(&path).await.unwrap();
+        let mut data = vec![b'x'; MAX_LINE_BYTES + 1];
+        data.extend(update("atlas").as_bytes());
+        // The listener hangs up partway, so the write may fail
+        let _ = writer.write_all(&data).await;
+        while listener.writers() > 0 || listener.skipped_lines() == 0 {
+            tokio::task::yield_now().await;
+        }
+        assert_eq!(listener.skipped_lines(), 1);
+        assert!(event_rx.try_recv().is_err());
+    }
+}
@@ -0,0 +1,250 @@
+//! Remote event stream over HTTP.
+//!
+//! Hive subscribes to a URL serving either Server-Sent Events (each `data:`
+//! payload holding one or more JSON events) or a chunked response of event
+//! lines, as written to an events file. Whole lines go through the same parse
+//! pool as lines appended to a file. When the connection drops or cannot be
+//! made, it is retried with a growing delay, reset once a connection succeeds.
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::mpsc as tokio_mpsc;
+use tokio::task::JoinHandle;
+
+use super::format::EventFormat;
+use super::parse::ParsePool;
+use super::types::HiveEvent;
+use super::watcher::ReadEvents;
+
+/// Delay before the first reconnect
+const MIN_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Longest delay between reconnects
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Follows an event stream at a URL and sends its events to a channel
+pub struct EventStream {
+    url: String,
+    /// Lines (or SSE payload lines) that could not be parsed
+    skipped: Arc<AtomicUsize>,
+    /// Times the connection was lost or could not be made
+    drops: Arc<AtomicUsize>,
+    connected: Arc<AtomicBool>,
+    /// Connect and read loop; aborting it closes the connection
+    task: JoinHandle<()>,
+}
+
+impl EventStream {
+    /// Subscribe to `url` and forward events to `event_tx` until dropped.
+    /// Plain responses are read as lines in `format`, SSE payloads as JSON.
+    /// Must be called from within a Tokio runtime.
+    pub fn connect(url: impl Into<String>, event_tx: tokio_mpsc::Sender<HiveEvent>, format: EventFormat) -> Self {
+        let url = url.into();
+        let skipped = Arc::new(AtomicUsize::new(0));
+        let drops = Arc::new(AtomicUsize::new(0));
+        let connected = Arc::new(AtomicBool::new(false));
+        let task = tokio::spawn(follow(
+            url.clone(),
+            event_tx,
+            format,
+            skipped.clone(),
+            drops.clone(),
+            connected.clone(),
+        ));
+
+        Self {
+            url,
+            skipped,
+            drops,
+            connected,
+            task,
+        }
+    }
+
+    /// URL of the stream
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Number of lines that could not be parsed so far
+    pub fn skipped_lines(&self) -> usize {
+        self.skipped.load(Ordering::Relaxed)
+    }
+
+    /// Number of times the connection was lost or could not be made
+    pub fn drops(&self) -> usize {
+        self.drops.load(Ordering::Relaxed)
+    }
+
+    /// Whether a response is being read right now
+    pub fn connected(&self) -> bool {
+        self.connected.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for EventStream {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn follow(
+    url: String,
+    event_tx: tokio_mpsc::Sender<HiveEvent>,
+    format: EventFormat,
+    skipped: Arc<AtomicUsize>,
+    drops: Arc<AtomicUsize>,
+    connected: Arc<AtomicBool>,
+) {
+    let client = reqwest::Client::new();
+    let mut backoff = MIN_BACKOFF;
+    loop {
+        let request = client
+            .get(&url)
+            .header(reqwest::header::ACCEPT, "text/event-stream, application/x-ndjson, */*");
+        if let Ok(mut response) = request.send().await.and_then(|r| r.error_for_status()) {
+            connected.store(true, Ordering::Relaxed);
+            backoff = MIN_BACKOFF;
+
+            let sse = response
+                .headers()
+                .get(reqwest::header::CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .is_some_and(|value| value.starts_with("text/event-stream"));
+            let mut decoder = Decoder::new(sse, format);
+            while let Ok(Some(chunk)) = response.chunk().await {
+                let read = decoder.feed(&chunk);
+                skipped.fetch_add(read.skipped, Ordering::Relaxed);
+                for event in read.events {
+                    if event_tx.send(event).await.is_err() {
+                        return; // Channel closed
+                    }
+                }
+            }
+            connected.store(false, Ordering::Relaxed);
+        }
+        drops.fetch_add(1, Ordering::Relaxed);
+
+        tokio::select! {
+            _ = tokio::time::sleep(backoff) => {}
+            _ = event_tx.closed() => return,
+        }
+        backoff = (backoff * 2).min(MAX_BACKOFF);
+    }
+}
+
+/// Turns the chunks of a response into events, keeping a partial last line
+/// for the next chunk
+struct Decoder {
+    sse: bool,
+    format: EventFormat,
+    /// Bytes after the last newline
+    partial: Vec<u8>,
+    /// Payload lines of the SSE message being received
+    data: Vec<u8>,
+}
+
+impl Decoder {
+    fn new(sse: bool, format: EventFormat) -> Self {
+        Self {
+            sse,
+            format: if sse { EventFormat::Json } else { format },
+            partial: Vec::new(),
+            data: Vec::new(),
+        }
+    }
+
+    fn feed(&mut self, chunk: &[u8]) -> ReadEvents {
+        self.partial.extend_from_slice(chunk);
+        let Some(end) = self.partial.iter().rposition(|&b| b == b'\n') else {
+            return ReadEvents::default();
+        };
+        let lines: Vec<u8> = self.partial.drain(..=end).collect();
+        if !self.sse {
+            return ParsePool::shared().parse(&lines, 1, self.format);
+        }
+
+        // Finished messages' payloads, one line each
+        let mut payloads = Vec::new();
+        for line in lines.split_inclusive(|&b| b == b'\n') {
+            let line = line.strip_suffix(b"\n").unwrap_or(line);
+            let line = line.strip_suffix(b"\r").unwrap_or(line);
+            if line.is_empty() {
+                // A blank line ends the message
+                payloads.append(&mut self.data);
+            } else if let Some(data) = line.strip_prefix(b"data:") {
+                self.data.extend_from_slice(data.strip_prefix(b" ").unwrap_or(data));
+                self.data.push(b'\n');
+            }
+            // Comments (keep-alives) and the event, id and retry fields
+            // carry no events
+        }
+        ParsePool::shared().parse(&payloads, 1, self.format)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    fn update(id: &str) -> String {
+        format!(
+            r#"{{"type":"agent_update","agent_id":"{}","status":"active","focus":[],"intensity":0.5,"message":"","timestamp":1}}"#,
+            id
+        )
+    }
+
+    #[test]
+    fn test_sse_payloads_are_split_across_chunks() {
+        let mut decoder = Decoder::new(true, EventFormat::Csv);
+        let stream = format!(": keep-alive\nevent: hive\ndata: {}\n\ndata: not json\n\ndata: {}\r\n\r\n", update("atlas"), update("nova"));
+        let (first, second) = stream.split_at(40);
+
+        let mut read = decoder.feed(first.as_bytes());
+        assert!(read.events.is_empty());
+        read.append(decoder.feed(second.as_bytes()));
+        assert_eq!(read.events.len(), 2);
+        assert_eq!(read.skipped, 1);
+    }
+
+    #[tokio::test]
+    async fn test_reconnects_after_the_stream_ends() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/events", listener.local_addr().unwrap());
+        let responses = [
+            ("text/event-stream", format!("data: {}\n\n", update("atlas"))),
+            ("application/x-ndjson", format!("{}\n{{\"type\":\"agent_upd\n", update("nova"))),
+        ];
+        tokio::spawn(async move {
+            for (content_type, body) in responses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = [0; 1024];
+                let _ = socket.read(&mut request).await.unwrap();
+                let head = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: {}\r\ncontent-length: {}\r\nconnection: close\r\n\r\n",
+                    content_type,
+                    body.len()
+                );
+                socket.write_all(head.as_bytes()).await.unwrap();
+                socket.write_all(body.as_bytes()).await.unwrap();
+            }
+        });
+
+        let (event_tx, mut event_rx) = tokio_mpsc::channel(16);
+        let stream = EventStream::connect(url, event_tx, EventFormat::Json);
+        let mut ids = Vec::new();
+        for _ in 0..2 {
+            match event_rx.recv().await.unwrap() {
+                HiveEvent::AgentUpdate(update) => ids.push(update.agent_id.to_string()),
+                other => panic!("unexpected event {:?}", other),
+            }
+        }
+        assert_eq!(ids, ["atlas", "nova"]);
+        assert_eq!(stream.skipped_lines(), 1);
+        assert!(stream.drops() >= 1);
+    }
+}
@@ -0,0 +1,175 @@
+use std::sync::Arc;
+
+use schemars::schema::RootSchema;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::intern::{self, intern};
+
+/// Represents a unique identifier for an agent, shared (see [`intern`])
+pub type AgentId = Arc<str>;
+
+/// A focus keyword, shared like agent IDs
+pub type Keyword = Arc<str>;
+
+/// Represents a unique identifier for a landmark
+pub type LandmarkId = String;
+
+/// Status of an agent
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AgentStatus {
+    Active,
+    Thinking,
+    Waiting,
+    #[default]
+    Idle,
+    Error,
+}
+
+/// An event from an agent updating its state
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AgentUpdate {
+    #[serde(deserialize_with = "intern::deserialize")]
+    pub agent_id: AgentId,
+    pub status: AgentStatus,
+    /// Keywords the agent is working on; they place it on the field
+    #[serde(deserialize_with = "intern::deserialize_all")]
+    pub focus: Vec<Keyword>,
+    /// How hard the agent is working, from 0 to 1
+    pub intensity: f32,
+    pub message: String,
+    /// Unix timestamp in seconds
+    pub timestamp: u64,
+}
+
+/// A connection event between two agents
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Connection {
+    #[serde(deserialize_with = "intern::deserialize")]
+    pub from: AgentId,
+    #[serde(deserialize_with = "intern::deserialize")]
+    pub to: AgentId,
+    pub label: String,
+    pub timestamp: u64,
+    /// How long the line stays fully visible, in milliseconds (default 3000)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl_ms: Option<u64>,
+}
+
+/// A landmark definition for semantic positioning
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Landmark {
+    pub id: LandmarkId,
+    pub label: String,
+    pub keywords: Vec<String>,
+    pub timestamp: u64,
+}
+
+/// All possible event types that can be received
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum HiveEvent {
+    AgentUpdate(AgentUpdate),
+    Connection(Connection),
+    Landmark(Landmark),
+}
+
+impl HiveEvent {
+    /// JSON Schema of one line of an events file
+    pub fn schema() -> RootSchema {
+        schemars::schema_for!(HiveEvent)
+    }
+
+    pub fn timestamp(&self) -> u64 {
+        match self {
+            HiveEvent::AgentUpdate(e) => e.timestamp,
+            HiveEvent::Connection(e) => e.timestamp,
+            HiveEvent::Landmark(e) => e.timestamp,
+        }
+    }
+
+    /// Prefix the agent IDs this event refers to with `source/`, so agents
+    /// from different sources never collide. Landmarks are shared and left
+    /// untouched.
+    pub fn tag_source(&mut self, source: &str) {
+        match self {
+            HiveEvent::AgentUpdate(e) => e.agent_id = intern(&format!("{}/{}", source, e.agent_id)),
+            HiveEvent::Connection(e) => {
+                e.from = intern(&format!("{}/{}", source, e.from));
+                e.to = intern(&format!("{}/{}", source, e.to));
+            }
+            HiveEvent::Landmark(_) => {}
+        }
+    }
+}
+
+/// A timestamped event for history tracking
+#[derive(Debug, Clone)]
+pub struct TimestampedEvent {
+    pub event: HiveEvent,
+    pub received_at: std::time::Instant,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_agent_update() {
+        let json = r#"{"type": "agent_update", "agent_id": "explorer-1", "status": "active", "focus": ["auth", "jwt"], "intensity": 0.8, "message": "Testing", "timestamp": 123}"#;
+        let event: HiveEvent = serde_json::from_str(json).unwrap();
+        match event {
+            HiveEvent::AgentUpdate(u) => {
+                assert_eq!(&*u.agent_id, "explorer-1");
+                assert_eq!(u.status, AgentStatus::Active);
+            }
+            _ => panic!("Wrong event type"),
+        }
+    }
+
+    #[test]
+    fn test_parse_connection() {
+        let json = r#"{"type": "connection", "from": "a", "to": "b", "label": "test", "timestamp": 123}"#;
+        let event: HiveEvent = serde_json::from_str(json).unwrap();
+        assert!(matches!(event, HiveEvent::Connection(_)));
+    }
+
+    #[test]
+    fn test_connection_ttl_is_optional() {
+        let json = r#"{"type": "connection", "from": "a", "to": "b", "label": "", "timestamp": 0, "ttl_ms": 10000}"#;
+        match serde_json::from_str(json).unwrap() {
+            HiveEvent::Connection(c) => assert_eq!(c.ttl_ms, Some(10000)),
+            _ => panic!("Wrong event type"),
+        }
+
+        let json = r#"{"type": "connection", "from": "a", "to": "b", "label": "", "timestamp": 0}"#;
+        let event: HiveEvent = serde_json::from_str(json).unwrap();
+        assert!(!serde_json::to_string(&event).unwrap().contains("ttl_ms"));
+    }
+
+    #[test]
+    fn test_schema_lists_event_types() {
+        let schema = serde_json::to_value(HiveEvent::schema()).unwrap();
+        let tags: Vec<_> = schema["oneOf"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|variant| variant["properties"]["type"]["enum"][0].as_str().unwrap())
+            .collect();
+        assert_eq!(tags, ["agent_update", "connection", "landmark"]);
+    }
+
+    #[test]
+    fn test_tag_source_prefixes_agent_ids() {
+        let mut event: HiveEvent = serde_json::from_str(
+            r#"{"type": "connection", "from": "a", "to": "b", "label": "", "timestamp": 0}"#,
+        )
+        .unwrap();
+        event.tag_source("ci");
+        match event {
+            HiveEvent::Connection(c) => assert_eq!((&*c.from, &*c.to), ("ci/a", "ci/b")),
+            _ => panic!("Wrong event type"),
+        }
+    }
+}
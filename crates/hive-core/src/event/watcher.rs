@@ -0,0 +1,271 @@
+use notify::{Config, RecommendedWatcher, RecursiveMode, Watcher};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use tokio::sync::mpsc as tokio_mpsc;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
+
+use super::format::{split_frames, EventFormat};
+use super::loader::InitialLoad;
+use super::parse::ParsePool;
+use super::types::HiveEvent;
+
+/// Bytes of lines read before handing them to the parse pool
+const READ_BATCH_BYTES: usize = 1 << 20;
+
+/// Events read from a file, with the lines that could not be parsed
+/// (e.g. a half-written tail left by a crash)
+#[derive(Debug, Default)]
+pub struct ReadEvents {
+    pub events: Vec<HiveEvent>,
+    /// Number of lines skipped
+    pub skipped: usize,
+    /// Line number (from 1) of the first skipped line
+    pub first_skipped: Option<usize>,
+}
+
+impl ReadEvents {
+    /// Read events from `reader`, one per line in `format`, skipping lines
+    /// that are not valid events (including invalid UTF-8) and carrying on
+    /// with the next one.
+    pub fn from_reader(reader: impl Read, format: EventFormat) -> std::io::Result<Self> {
+        let mut read = Self::default();
+        let mut reader = BufReader::new(reader);
+        let mut batch = Vec::new();
+        let mut number = 1;
+        loop {
+            // Whole lines, a batch at a time, parsed on the pool
+            batch.clear();
+            let mut lines = 0;
+            while batch.len() < READ_BATCH_BYTES && reader.read_until(b'\n', &mut batch)? > 0 {
+                lines += 1;
+            }
+            if lines == 0 {
+                return Ok(read);
+            }
+            read.append(ParsePool::shared().parse(&batch, number, format));
+            number += lines;
+        }
+    }
+
+    /// Parse `lines`, the first of them numbered `first_line`. A last line
+    /// without its newline counts as a line.
+    pub fn from_lines(lines: &[u8], first_line: usize, format: EventFormat) -> Self {
+        let mut read = Self::default();
+        for (number, line) in (first_line..).zip(lines.split_inclusive(|&b| b == b'\n')) {
+            read.parse_line(line, number, format);
+        }
+        read
+    }
+
+    /// Parse length-prefixed MessagePack frames. Trailing bytes that do not
+    /// make a whole frame count as one skipped event.
+    pub fn from_frames(data: &[u8]) -> Self {
+        let mut read = Self::default();
+        let (frames, rest) = split_frames(data);
+        for (number, frame) in (1..).zip(frames) {
+            match EventFormat::parse_frame(frame) {
+                Ok(event) => read.events.push(event),
+                Err(_) => read.skip(number),
+            }
+        }
+        if !rest.is_empty() {
+            read.skip(read.events.len() + read.skipped + 1);
+        }
+        read
+    }
+
+    /// Add the events and skipped lines of `other`, read after these
+    pub fn append(&mut self, other: ReadEvents) {
+        self.events.extend(other.events);
+        self.skipped += other.skipped;
+        self.first_skipped = self.first_skipped.or(other.first_skipped);
+    }
+
+    fn parse_line(&mut self, line: &[u8], number: usize, format: EventFormat) {
+        match format.parse_line(&String::from_utf8_lossy(line)) {
+            Ok(Some(event)) => self.events.push(event),
+            Ok(None) => {}
+            Err(_) => self.skip(number),
+        }
+    }
+
+    fn skip(&mut self, number: usize) {
+        self.skipped += 1;
+        self.first_skipped.get_or_insert(number);
+    }
+}
+
+/// Watches a file for new events and sends them to a channel
+pub struct FileWatcher {
+    _watcher: RecommendedWatcher,
+    file_path: std::path::PathBuf,
+    format: EventFormat,
+    /// Size of the file when following it started
+    start: u64,
+    /// Lines skipped while following the file
+    skipped: Arc<AtomicUsize>,
+}
+
+impl FileWatcher {
+    /// Create a new file watcher that monitors the given path for JSON lines
+    pub fn new(
+        path: impl AsRef<Path>,
+        event_tx: tokio_mpsc::Sender<HiveEvent>,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_format(path, event_tx, EventFormat::Json)
+    }
+
+    /// Create a file watcher for events written in `format`
+    pub fn with_format(
+        path: impl AsRef<Path>,
+        event_tx: tokio_mpsc::Sender<HiveEvent>,
+        format: EventFormat,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let file_path = path.as_ref().to_path_buf();
+
+        // Create the file if it doesn't exist
+        if !file_path.exists() {
+            std::fs::write(&file_path, "")?;
+        }
+
+        // Get initial file size
+        let initial_position = std::fs::metadata(&file_path)
+            .map(|m| m.len())
+            .unwrap_or(0);
+
+        let (tx, rx) = mpsc::channel();
+
+        let watcher = RecommendedWatcher::new(
+            move |res| {
+                if let Ok(event) = res {
+                    let _ = tx.send(event);
+                }
+            },
+            Config::default(),
+        )?;
+
+        let skipped = Arc::new(AtomicUsize::new(0));
+        let mut file_watcher = Self {
+            _watcher: watcher,
+            file_path: file_path.clone(),
+            format,
+            start: initial_position,
+            skipped: skipped.clone(),
+        };
+
+        // Start watching the file
+        file_watcher._watcher.watch(&file_path, RecursiveMode::NonRecursive)?;
+
+        // Spawn a task to handle file change events
+        let watch_path = file_path.clone();
+        let mut last_pos = initial_position;
+
+        tokio::spawn(async move {
+            loop {
+                // Check for notify events
+                match rx.recv_timeout(std::time::Duration::from_millis(100)) {
+                    Ok(_event) => {
+                        // File changed, read new lines
+                        if let Ok(new_events) = read_new_lines(&watch_path, &mut last_pos, format) {
+                            skipped.fetch_add(new_events.skipped, Ordering::Relaxed);
+                            for event in new_events.events {
+                                if event_tx.send(event).await.is_err() {
+                                    return; // Channel closed
+                                }
+                            }
+                        }
+                    }
+                    Err(mpsc::RecvTimeoutError::Timeout) => {
+                        // Periodically check for changes even without notify events
+                        if let Ok(new_events) = read_new_lines(&watch_path, &mut last_pos, format) {
+                            skipped.fetch_add(new_events.skipped, Ordering::Relaxed);
+                            for event in new_events.events {
+                                if event_tx.send(event).await.is_err() {
+                                    return;
+                                }
+                            }
+                        }
+                    }
+                    Err(mpsc::RecvTimeoutError::Disconnected) => {
+                        return;
+                    }
+                }
+            }
+        });
+
+        Ok(file_watcher)
+    }
+
+    /// Read all existing events from the file (for replay/initial load)
+    pub fn read_all_events(&self) -> ReadEvents {
+        File::open(&self.file_path)
+            .and_then(|file| ReadEvents::from_reader(file, self.format))
+            .unwrap_or_default()
+    }
+
+    /// Start loading the events written before following started, in the
+    /// background
+    pub fn load_existing(&self) -> InitialLoad {
+        InitialLoad::start(&self.file_path, self.start, self.format)
+    }
+
+    /// Number of lines that could not be parsed since the file was opened
+    /// (not counting those in [`read_all_events`](Self::read_all_events))
+    pub fn skipped_lines(&self) -> usize {
+        self.skipped.load(Ordering::Relaxed)
+    }
+}
+
+/// Read new lines from the file starting at the given position. A last line
+/// without its newline is left for the next read, as the writer may still be
+/// in the middle of it.
+fn read_new_lines(path: &Path, last_position: &mut u64, format: EventFormat) -> Result<ReadEvents, std::io::Error> {
+    let mut file = File::open(path)?;
+    let current_size = file.metadata()?.len();
+
+    // If file was truncated, start from beginning
+    if current_size < *last_position {
+        *last_position = 0;
+    }
+
+    // Seek to last known position
+    file.seek(SeekFrom::Start(*last_position))?;
+
+    let mut reader = BufReader::new(file);
+    let mut lines = Vec::new();
+    loop {
+        let start = lines.len();
+        let n = reader.read_until(b'\n', &mut lines)?;
+        if n == 0 || lines.last() != Some(&b'\n') {
+            lines.truncate(start);
+            break;
+        }
+        *last_position += n as u64;
+    }
+
+    // Line numbers are only reported for whole-file reads
+    Ok(ParsePool::shared().parse(&lines, 1, format))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_corrupted_lines_are_skipped_and_counted() {
+        let mut data = Vec::new();
+        data.extend_from_slice(br#"{"type":"agent_update","agent_id":"a","status":"active","focus":[],"intensity":0.5,"message":"","timestamp":1}"#);
+        data.extend_from_slice(b"\n\xff\xfe garbage\n\n");
+        data.extend_from_slice(br#"{"type":"agent_update","agent_id":"b","status":"idle","focus":[],"intensity":0.1,"message":"","timestamp":2}"#);
+        // Tail cut off by a crash
+        data.extend_from_slice(br#"
+{"type":"agent_upd"#);
+
+        let read = ReadEvents::from_reader(&data[..], EventFormat::Json).unwrap();
+        assert_eq!(read.events.len(), 2);
+        assert_eq!(read.skipped, 2);
+        assert_eq!(read.first_skipped, Some(2));
+    }
+}
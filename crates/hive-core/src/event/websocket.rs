@@ -0,0 +1,220 @@
+//! WebSocket ingestion endpoint.
+//!
+//! Browser-based or remote agents that cannot write to a local file connect
+//! to `ws://<addr>` and send [`HiveEvent`] JSON as text (or binary) frames.
+//! A frame holds one event, or several separated by newlines. Frames that do
+//! not parse are counted and dropped, like unreadable lines of a file. With
+//! [`EventFormat::MsgPack`], binary frames hold length-prefixed MessagePack
+//! events instead, one or more per frame; text frames are still JSON.
+
+use std::io;
+use std::net::{SocketAddr, TcpListener as StdTcpListener, ToSocketAddrs};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use futures_util::StreamExt;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc as tokio_mpsc;
+use tokio::task::{JoinHandle, JoinSet};
+use tokio_tungstenite::tungstenite::Message;
+
+use super::format::EventFormat;
+use super::types::HiveEvent;
+use super::watcher::ReadEvents;
+
+/// Accepts WebSocket clients and sends the events they push to a channel
+pub struct WebSocketServer {
+    local_addr: SocketAddr,
+    /// Event lines that could not be parsed, across all clients
+    skipped: Arc<AtomicUsize>,
+    /// Clients connected right now
+    clients: Arc<AtomicUsize>,
+    /// Accept loop; aborting it drops every client connection
+    task: JoinHandle<()>,
+}
+
+impl WebSocketServer {
+    /// Listen on `addr` and forward events to `event_tx` until dropped. Must
+    /// be called from within a Tokio runtime.
+    pub fn bind(addr: impl ToSocketAddrs, event_tx: tokio_mpsc::Sender<HiveEvent>) -> io::Result<Self> {
+        Self::with_format(addr, event_tx, EventFormat::Json)
+    }
+
+    /// Like [`WebSocketServer::bind`], reading events in `format`
+    pub fn with_format(
+        addr: impl ToSocketAddrs,
+        event_tx: tokio_mpsc::Sender<HiveEvent>,
+        format: EventFormat,
+    ) -> io::Result<Self> {
+        let listener = StdTcpListener::bind(addr)?;
+        listener.set_nonblocking(true)?;
+        let listener = TcpListener::from_std(listener)?;
+        let local_addr = listener.local_addr()?;
+
+        let skipped = Arc::new(AtomicUsize::new(0));
+        let clients = Arc::new(AtomicUsize::new(0));
+        let task = tokio::spawn(accept_clients(listener, event_tx, format, skipped.clone(), clients.clone()));
+
+        Ok(Self {
+            local_addr,
+            skipped,
+            clients,
+            task,
+        })
+    }
+
+    /// Address the server listens on (with the actual port when bound to 0)
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Number of event lines that could not be parsed so far
+    pub fn skipped_lines(&self) -> usize {
+        self.skipped.load(Ordering::Relaxed)
+    }
+
+    /// Number of clients connected right now
+    pub fn clients(&self) -> usize {
+        self.clients.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for WebSocketServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn accept_clients(
+    listener: TcpListener,
+    event_tx: tokio_mpsc::Sender<HiveEvent>,
+    format: EventFormat,
+    skipped: Arc<AtomicUsize>,
+    clients: Arc<AtomicUsize>,
+) {
+    // Owned here, so the connections close when the server is dropped
+    let mut connections = JoinSet::new();
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => {
+                // A failed accept (e.g. out of file descriptors) only loses
+                // that client
+                let Ok((stream, _)) = accepted else {
+                    continue;
+                };
+                connections.spawn(serve_client(stream, event_tx.clone(), format, skipped.clone(), clients.clone()));
+            }
+            Some(_) = connections.join_next() => {}
+            _ = event_tx.closed() => return,
+        }
+    }
+}
+
+async fn serve_client(
+    stream: TcpStream,
+    event_tx: tokio_mpsc::Sender<HiveEvent>,
+    format: EventFormat,
+    skipped: Arc<AtomicUsize>,
+    clients: Arc<AtomicUsize>,
+) {
+    let Ok(mut socket) = tokio_tungstenite::accept_async(stream).await else {
+        return;
+    };
+    clients.fetch_add(1, Ordering::Relaxed);
+
+    let lines = if format.is_framed() { EventFormat::Json } else { format };
+    while let Some(Ok(message)) = socket.next().await {
+        let read = match message {
+            Message::Text(text) => ReadEvents::from_reader(text.as_bytes(), lines),
+            Message::Binary(data) if format.is_framed() => Ok(ReadEvents::from_frames(&data)),
+            Message::Binary(data) => ReadEvents::from_reader(&data[..], lines),
+            Message::Close(_) => break,
+            // Pings are answered by the socket itself
+            _ => continue,
+        };
+        let Ok(read) = read else {
+            continue;
+        };
+        skipped.fetch_add(read.skipped, Ordering::Relaxed);
+        for event in read.events {
+            if event_tx.send(event).await.is_err() {
+                clients.fetch_sub(1, Ordering::Relaxed);
+                return; // Channel closed
+            }
+        }
+    }
+    clients.fetch_sub(1, Ordering::Relaxed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::SinkExt;
+
+    #[tokio::test]
+    async fn test_frames_are_forwarded_as_events() {
+        let (event_tx, mut event_rx) = tokio_mpsc::channel(16);
+        let server = WebSocketServer::bind("127.0.0.1:0", event_tx).unwrap();
+
+        let stream = TcpStream::connect(server.local_addr()).await.unwrap();
+        let url = format!("ws://{}", server.local_addr());
+        let (mut socket, _) = tokio_tungstenite::client_async(url, stream).await.unwrap();
+
+        let update = |id: &str| {
+            format!(
+                r#"{{"type":"agent_update","agent_id":"{}","status":"active","focus":[],"intensity":0.5,"message":"","timestamp":1}}"#,
+                id
+            )
+        };
+        socket.send(Message::Text(update("atlas"))).await.unwrap();
+        // Several events in one frame, one of them broken
+        let batch = format!("{}\n{{\"type\":\"agent_upd\n{}", update("nova"), update("sage"));
+        socket.send(Message::Binary(batch.into_bytes())).await.unwrap();
+
+        let mut ids = Vec::new();
+        for _ in 0..3 {
+            match event_rx.recv().await.unwrap() {
+                HiveEvent::AgentUpdate(update) => ids.push(update.agent_id.to_string()),
+                other => panic!("unexpected event {:?}", other),
+            }
+        }
+        assert_eq!(ids, ["atlas", "nova", "sage"]);
+        assert_eq!(server.skipped_lines(), 1);
+        assert_eq!(server.clients(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_msgpack_binary_frames() {
+        let (event_tx, mut event_rx) = tokio_mpsc::channel(16);
+        let server = WebSocketServer::with_format("127.0.0.1:0", event_tx, EventFormat::MsgPack).unwrap();
+
+        let stream = TcpStream::connect(server.local_addr()).await.unwrap();
+        let url = format!("ws://{}", server.local_addr());
+        let (mut socket, _) = tokio_tungstenite::client_async(url, stream).await.unwrap();
+
+        let mut data = Vec::new();
+        for id in ["atlas", "nova"] {
+            let event: HiveEvent = serde_json::from_str(&format!(
+                r#"{{"type":"agent_update","agent_id":"{}","status":"active","focus":[],"intensity":0.5,"message":"","timestamp":1}}"#,
+                id
+            ))
+            .unwrap();
+            let body = rmp_serde::to_vec_named(&event).unwrap();
+            data.extend((body.len() as u32).to_be_bytes());
+            data.extend(body);
+        }
+        // A truncated frame at the end
+        data.extend([0, 0, 0, 9, 1]);
+        socket.send(Message::Binary(data)).await.unwrap();
+
+        let mut ids = Vec::new();
+        for _ in 0..2 {
+            match event_rx.recv().await.unwrap() {
+                HiveEvent::AgentUpdate(update) => ids.push(update.agent_id.to_string()),
+                other => panic!("unexpected event {:?}", other),
+            }
+        }
+        assert_eq!(ids, ["atlas", "nova"]);
+        assert_eq!(server.skipped_lines(), 1);
+    }
+}
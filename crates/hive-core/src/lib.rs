@@ -0,0 +1,46 @@
+//! Core swarm model for Hive.
+//!
+//! `hive-core` contains everything Hive knows about agents that is independent
+//! of how they are drawn: the event wire format, the field state that events are
+//! applied to, semantic positioning, and the animation primitives that drive
+//! smooth movement. The `hive` binary is a terminal frontend built on top of
+//! this crate; other tools can use it to embed the swarm model or to build an
+//! alternative frontend.
+//!
+//! # Modules
+//!
+//! - [`event`]: the [`HiveEvent`](event::HiveEvent) JSON lines format, the event
+//!   queue, the [`FileWatcher`](event::FileWatcher) that tails an events file
+//!   and the [`WebSocketServer`](event::WebSocketServer) and (on Unix)
+//!   `SocketListener` agents push to.
+//! - [`state`]: the [`Field`](state::Field) (agents, connections, landmarks) and
+//!   the replay [`History`](state::History).
+//! - [`positioning`]: normalized [`Position`](positioning::Position)s, the
+//!   keyword-based [`SemanticPositioner`](positioning::SemanticPositioner) and
+//!   collision avoidance.
+//! - [`animation`]: frame pacing and easing/pulse helpers.
+//! - [`symbols`]: agent shape and status glyphs with ASCII fallbacks.
+//!
+//! # Example
+//!
+//! ```
+//! use hive_core::event::HiveEvent;
+//! use hive_core::state::Field;
+//!
+//! let line = r#"{"type": "agent_update", "agent_id": "atlas", "status": "active",
+//!     "focus": ["database"], "intensity": 0.8, "message": "Indexing", "timestamp": 0}"#;
+//! let event: HiveEvent = serde_json::from_str(line).unwrap();
+//!
+//! let mut field = Field::new();
+//! field.process_event(&event);
+//! field.tick(1.0 / 30.0);
+//!
+//! let atlas = &field.agents["atlas"];
+//! assert!(atlas.target_position.y > 0.5); // database work sits low on the field
+//! ```
+
+pub mod animation;
+pub mod event;
+pub mod positioning;
+pub mod state;
+pub mod symbols;
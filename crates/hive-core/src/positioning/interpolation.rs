@@ -1,6 +1,6 @@
-use super::Position;
+//! Easing functions for smooth animations
 
-/// Easing functions for smooth animations
+use super::Position;
 
 /// Ease out cubic - fast start, slow end
 pub fn ease_out_cubic(t: f32) -> f32 {
@@ -59,7 +59,7 @@ pub fn smooth_step(edge0: f32, edge1: f32, x: f32) -> f32 {
 
 /// Perlin-like noise for organic movement (simplified)
 pub fn pseudo_noise(x: f32, y: f32, seed: u32) -> f32 {
-    let n = (x * 12.9898 + y * 78.233 + seed as f32).sin() * 43758.5453;
+    let n = (x * 12.9898 + y * 78.233 + seed as f32).sin() * 43_758.547;
     n.fract()
 }
 
@@ -6,6 +6,12 @@ pub use semantic::SemanticPositioner;
 pub use interpolation::*;
 pub use spatial::{CollisionAvoidance, SpatialHash};
 
+/// Share of the field kept clear along each edge unless configured otherwise
+pub const DEFAULT_MARGIN: f32 = 0.05;
+
+/// Widest margin allowed, leaving a fifth of the field to place agents in
+pub const MAX_MARGIN: f32 = 0.4;
+
 /// A 2D position in normalized coordinates (0.0 to 1.0)
 #[derive(Debug, Clone, PartialEq)]
 pub struct Position {
@@ -51,9 +57,15 @@ impl Position {
 
     /// Clamp position to valid range
     pub fn clamp(&self) -> Position {
+        self.clamp_to(DEFAULT_MARGIN)
+    }
+
+    /// Clamp position to the field less `margin` along each edge
+    pub fn clamp_to(&self, margin: f32) -> Position {
+        let margin = margin.clamp(0.0, MAX_MARGIN);
         Position {
-            x: self.x.clamp(0.05, 0.95),
-            y: self.y.clamp(0.05, 0.95),
+            x: self.x.clamp(margin, 1.0 - margin),
+            y: self.y.clamp(margin, 1.0 - margin),
         }
     }
 }
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use super::Position;
+use super::{Position, DEFAULT_MARGIN};
 use crate::state::field::StoredLandmark;
 use crate::event::LandmarkId;
 
@@ -10,6 +10,8 @@ pub struct SemanticPositioner {
     keyword_cache: HashMap<String, Position>,
     /// Predefined concept clusters
     concept_clusters: Vec<ConceptCluster>,
+    /// Share of the field kept clear along each edge
+    margin: f32,
 }
 
 /// A predefined concept cluster for semantic positioning
@@ -25,6 +27,7 @@ impl SemanticPositioner {
         let mut positioner = Self {
             keyword_cache: HashMap::new(),
             concept_clusters: Vec::new(),
+            margin: DEFAULT_MARGIN,
         };
 
         // Initialize default concept clusters
@@ -33,6 +36,12 @@ impl SemanticPositioner {
         positioner
     }
 
+    /// Keep positions `margin` (a share of the field) away from its edges
+    pub fn set_margin(&mut self, margin: f32) {
+        self.margin = margin;
+        self.keyword_cache.clear();
+    }
+
     /// Initialize predefined concept clusters for common programming domains
     fn init_default_clusters(&mut self) {
         // Top-left: Frontend/UI
@@ -129,7 +138,7 @@ impl SemanticPositioner {
     /// Calculate position for a set of focus keywords
     pub fn calculate_position(
         &mut self,
-        focus: &[String],
+        focus: &[impl AsRef<str>],
         landmarks: &HashMap<LandmarkId, StoredLandmark>,
     ) -> Position {
         if focus.is_empty() {
@@ -141,7 +150,7 @@ impl SemanticPositioner {
         let mut weighted_y = 0.0;
 
         for keyword in focus {
-            let kw_lower = keyword.to_lowercase();
+            let kw_lower = keyword.as_ref().to_lowercase();
 
             // Check landmarks first
             let mut found_landmark = false;
@@ -177,7 +186,7 @@ impl SemanticPositioner {
         }
 
         if total_weight > 0.0 {
-            Position::new(weighted_x / total_weight, weighted_y / total_weight).clamp()
+            Position::new(weighted_x / total_weight, weighted_y / total_weight).clamp_to(self.margin)
         } else {
             Position::new(0.5, 0.5)
         }
@@ -217,13 +226,13 @@ impl SemanticPositioner {
                 cluster.center.x + angle.cos() * distance,
                 cluster.center.y + angle.sin() * distance,
             )
-            .clamp()
+            .clamp_to(self.margin)
         } else {
             // No cluster match - use hash-based positioning
             let hash = hash_string(keyword);
             let x = ((hash % 1000) as f32 / 1000.0) * 0.7 + 0.15;
             let y = (((hash / 1000) % 1000) as f32 / 1000.0) * 0.7 + 0.15;
-            Position::new(x, y)
+            Position::new(x, y).clamp_to(self.margin)
         }
     }
 
@@ -243,7 +252,7 @@ impl SemanticPositioner {
             y_sum += pos.y;
         }
 
-        Position::new(x_sum / keywords.len() as f32, y_sum / keywords.len() as f32).clamp()
+        Position::new(x_sum / keywords.len() as f32, y_sum / keywords.len() as f32).clamp_to(self.margin)
     }
 }
 
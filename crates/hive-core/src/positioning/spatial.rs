@@ -44,7 +44,7 @@ impl SpatialHash {
 
         for (i, pos) in positions.iter().enumerate() {
             let cell = self.position_to_cell(pos);
-            self.cells.entry(cell).or_insert_with(Vec::new).push(i);
+            self.cells.entry(cell).or_default().push(i);
         }
     }
 
@@ -0,0 +1,128 @@
+//! How hard each agent worked recently.
+
+use std::collections::{HashMap, VecDeque};
+
+use crate::event::AgentId;
+
+use super::agent::Agent;
+
+/// Default length of the rolling window in seconds
+pub const DEFAULT_ACTIVITY_WINDOW: f32 = 300.0;
+
+/// Seconds of field time summed into one bucket
+const BUCKET_SECS: f32 = 5.0;
+
+/// Intensity-seconds of each agent over a rolling window of field time
+///
+/// Time is counted as the field runs, so pausing stops the window and
+/// playback speed stretches it, like the dwell times.
+#[derive(Debug, Clone)]
+pub struct ActivityWindow {
+    /// Buckets of `BUCKET_SECS`, newest last
+    buckets: VecDeque<HashMap<AgentId, f32>>,
+    /// Field time counted into the newest bucket
+    bucket_elapsed: f32,
+    /// Buckets kept
+    capacity: usize,
+}
+
+impl ActivityWindow {
+    /// A window of `secs` seconds (at least one bucket)
+    pub fn new(secs: f32) -> Self {
+        let mut buckets = VecDeque::new();
+        buckets.push_back(HashMap::new());
+        Self {
+            buckets,
+            bucket_elapsed: 0.0,
+            capacity: ((secs / BUCKET_SECS).ceil() as usize).max(1),
+        }
+    }
+
+    /// Length of the window in seconds
+    pub fn secs(&self) -> f32 {
+        self.capacity as f32 * BUCKET_SECS
+    }
+
+    /// Credit `dt` seconds at their current intensity to `agents`
+    pub fn record<'a>(&mut self, agents: impl IntoIterator<Item = &'a Agent>, dt: f32) {
+        if self.bucket_elapsed >= BUCKET_SECS {
+            self.bucket_elapsed = 0.0;
+            self.buckets.push_back(HashMap::new());
+            while self.buckets.len() > self.capacity {
+                self.buckets.pop_front();
+            }
+        }
+        self.bucket_elapsed += dt;
+
+        let bucket = self.buckets.back_mut().expect("window keeps a bucket");
+        for agent in agents {
+            if agent.intensity <= 0.0 {
+                continue;
+            }
+            let work = agent.intensity * dt;
+            match bucket.get_mut(&agent.id) {
+                Some(total) => *total += work,
+                None => {
+                    bucket.insert(agent.id.clone(), work);
+                }
+            }
+        }
+    }
+
+    /// Intensity-seconds per agent over the window, busiest first
+    pub fn ranking(&self) -> Vec<(&AgentId, f32)> {
+        let mut totals: HashMap<&AgentId, f32> = HashMap::new();
+        for bucket in &self.buckets {
+            for (agent, work) in bucket {
+                *totals.entry(agent).or_default() += work;
+            }
+        }
+        let mut ranking: Vec<_> = totals.into_iter().collect();
+        ranking.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        ranking
+    }
+
+    /// Forget an agent, e.g. once it is evicted
+    pub fn remove(&mut self, agent: &str) {
+        for bucket in &mut self.buckets {
+            bucket.remove(agent);
+        }
+    }
+}
+
+impl Default for ActivityWindow {
+    fn default() -> Self {
+        Self::new(DEFAULT_ACTIVITY_WINDOW)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn agent(id: &str, intensity: f32) -> Agent {
+        let mut agent = Agent::new(id.into(), 0);
+        agent.intensity = intensity;
+        agent
+    }
+
+    #[test]
+    fn test_ranking_over_rolling_window() {
+        let mut window = ActivityWindow::new(10.0);
+        let (atlas, nova) = (agent("atlas", 1.0), agent("nova", 0.5));
+
+        // Atlas works alone for 10s, then only nova for 10s
+        for _ in 0..10 {
+            window.record([&atlas], 1.0);
+        }
+        let ranking = window.ranking();
+        assert_eq!(ranking, [(&"atlas".into(), 10.0)]);
+
+        for _ in 0..10 {
+            window.record([&nova], 1.0);
+        }
+        let ranking = window.ranking();
+        assert_eq!(ranking[0], (&"nova".into(), 5.0));
+        assert!(ranking.iter().all(|(id, _)| &id[..] != "atlas"));
+    }
+}
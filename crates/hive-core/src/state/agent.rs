@@ -0,0 +1,498 @@
+use crate::animation::pulse::StatusAnimation;
+use crate::event::{AgentId, AgentStatus, AgentUpdate, Keyword};
+use crate::positioning::Position;
+use crate::symbols::{detect_unicode, get_agent_shape, get_status_indicator, Symbol};
+use std::collections::{BTreeMap, VecDeque};
+use std::time::Instant;
+
+/// Maximum number of trail points to keep
+const MAX_TRAIL_LENGTH: usize = 50;
+
+/// Maximum number of focus-change waypoints to keep
+const MAX_WAYPOINTS: usize = 8;
+
+/// How far (in normalized field units) the target must jump to leave a waypoint
+const FOCUS_JUMP_DISTANCE: f32 = 0.25;
+
+/// Seconds a status change takes to animate
+pub const STATUS_TRANSITION_SECS: f32 = 0.8;
+
+/// Updates closer together than this (in seconds of field time) move the
+/// agent smoothly enough without prediction
+const PREDICTION_MIN_INTERVAL: f32 = 0.5;
+
+/// Updates further apart than this are too stale to extrapolate from
+const PREDICTION_MAX_INTERVAL: f32 = 30.0;
+
+/// Represents the visual state of an agent
+#[derive(Debug, Clone)]
+pub struct Agent {
+    pub id: AgentId,
+    pub status: AgentStatus,
+    pub focus: Vec<Keyword>,
+    pub intensity: f32,
+    pub message: String,
+
+    /// Current rendered position
+    pub position: Position,
+    /// Position before the last tick, for drawing between ticks
+    pub previous_position: Position,
+    /// Target position (where the agent is moving toward)
+    pub target_position: Position,
+
+    /// How fast the target moved between the last two updates, in field
+    /// units per second (zero unless motion prediction is on)
+    pub velocity: (f32, f32),
+    /// Seconds of field time since the last update
+    since_update: f32,
+    /// Seconds of field time between the last two updates
+    update_interval: f32,
+
+    /// Trail of recent positions for rendering
+    pub trail: VecDeque<TrailPoint>,
+
+    /// Places the agent left after a big change of focus, oldest first
+    pub waypoints: VecDeque<Waypoint>,
+
+    /// Animation state
+    pub pulse_phase: f32,
+    pub last_update: Instant,
+
+    /// Color index for consistent coloring
+    pub color_index: usize,
+
+    /// Shape index for unique agent shape (0-7 maps to AGENT_SHAPES)
+    pub shape_index: usize,
+
+    /// Free-form key/value annotations (set by scripts, not by events)
+    pub metadata: BTreeMap<String, String>,
+
+    /// Seconds left on the highlight flash (0.0 when not flashing)
+    pub flash_remaining: f32,
+
+    /// Index of the tagged event source this agent came from, if any
+    pub source: Option<usize>,
+
+    /// Friendly name shown instead of the ID
+    pub alias: Option<String>,
+
+    /// Status change being animated, if any
+    pub transition: Option<StatusTransition>,
+}
+
+/// An animated change from one status to another
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusTransition {
+    /// Status before the change
+    pub from: AgentStatus,
+    /// 0.0 when the status changed, 1.0 when the animation is over
+    pub progress: f32,
+}
+
+/// A point in the agent's movement trail
+#[derive(Debug, Clone)]
+pub struct TrailPoint {
+    pub position: Position,
+    pub timestamp: Instant,
+    pub intensity: f32,
+}
+
+/// Marker left where an agent departed after its focus changed
+#[derive(Debug, Clone)]
+pub struct Waypoint {
+    pub position: Position,
+    /// Main focus keyword the agent had there
+    pub focus: Keyword,
+    pub timestamp: Instant,
+}
+
+impl Agent {
+    /// Create a new agent with a color index (shape_index defaults to color_index)
+    pub fn new(id: AgentId, color_index: usize) -> Self {
+        Self::with_shape(id, color_index, color_index)
+    }
+
+    /// Create a new agent with explicit color and shape indices
+    pub fn with_shape(id: AgentId, color_index: usize, shape_index: usize) -> Self {
+        Self {
+            id,
+            status: AgentStatus::Idle,
+            focus: Vec::new(),
+            intensity: 0.0,
+            message: String::new(),
+            position: Position::new(0.5, 0.5),
+            previous_position: Position::new(0.5, 0.5),
+            target_position: Position::new(0.5, 0.5),
+            velocity: (0.0, 0.0),
+            since_update: 0.0,
+            update_interval: 0.0,
+            trail: VecDeque::with_capacity(MAX_TRAIL_LENGTH),
+            waypoints: VecDeque::new(),
+            pulse_phase: 0.0,
+            last_update: Instant::now(),
+            color_index,
+            shape_index,
+            metadata: BTreeMap::new(),
+            flash_remaining: 0.0,
+            source: None,
+            alias: None,
+            transition: None,
+        }
+    }
+
+    /// Name to show for this agent: its alias, or else its ID
+    pub fn display_name(&self) -> &str {
+        self.alias.as_deref().unwrap_or(&self.id)
+    }
+
+    /// Update agent state from an event
+    pub fn apply_update(&mut self, update: &AgentUpdate) {
+        if update.status != self.status {
+            self.transition = Some(StatusTransition {
+                from: self.status.clone(),
+                progress: 0.0,
+            });
+        }
+        self.status = update.status.clone();
+        self.focus = update.focus.clone();
+        self.intensity = update.intensity.clamp(0.0, 1.0);
+        self.message = update.message.clone();
+        self.last_update = Instant::now();
+        self.update_interval = self.since_update;
+        self.since_update = 0.0;
+    }
+
+    /// Set the target position for smooth movement
+    pub fn set_target(&mut self, target: Position) {
+        self.target_position = target;
+    }
+
+    /// Where to draw the agent `alpha` (0 to 1) of the way from its position
+    /// before the last tick to its current one
+    pub fn drawn_position(&self, alpha: f32) -> Position {
+        self.previous_position.lerp(&self.position, alpha)
+    }
+
+    /// Estimate the velocity the agent moves at from the update about to
+    /// move its target to `target`, so it keeps drifting that way until the
+    /// next one. Call before applying the update.
+    pub fn track_velocity(&mut self, target: &Position) {
+        let interval = self.since_update;
+        self.velocity = if (PREDICTION_MIN_INTERVAL..=PREDICTION_MAX_INTERVAL).contains(&interval) {
+            (
+                (target.x - self.target_position.x) / interval,
+                (target.y - self.target_position.y) / interval,
+            )
+        } else {
+            (0.0, 0.0)
+        };
+    }
+
+    /// Where the agent is heading: its target, or with a velocity, where the
+    /// target should be by now if it kept moving (for up to one update
+    /// interval, so a source that went quiet does not send it drifting off),
+    /// kept `margin` off the edges of the field
+    pub fn heading(&self, margin: f32) -> Position {
+        if self.velocity == (0.0, 0.0) {
+            return self.target_position.clone();
+        }
+        let ahead = self.since_update.min(self.update_interval);
+        Position::new(
+            self.target_position.x + self.velocity.0 * ahead,
+            self.target_position.y + self.velocity.1 * ahead,
+        )
+        .clamp_to(margin)
+    }
+
+    /// Leave a waypoint at the current target if `target` is far from it.
+    ///
+    /// Call before applying the update that moves the agent, so the waypoint
+    /// is labelled with the focus it is leaving.
+    pub fn mark_departure(&mut self, target: &Position) {
+        let Some(focus) = self.focus.first() else {
+            return;
+        };
+        if self.target_position.distance_to(target) < FOCUS_JUMP_DISTANCE {
+            return;
+        }
+
+        self.waypoints.push_back(Waypoint {
+            position: self.target_position.clone(),
+            focus: focus.clone(),
+            timestamp: Instant::now(),
+        });
+        while self.waypoints.len() > MAX_WAYPOINTS {
+            self.waypoints.pop_front();
+        }
+    }
+
+    /// Add current position to trail
+    pub fn record_trail(&mut self) {
+        // Only add if we've moved significantly
+        if let Some(last) = self.trail.back() {
+            let dist = self.position.distance_to(&last.position);
+            if dist < 0.01 {
+                return;
+            }
+        }
+
+        self.trail.push_back(TrailPoint {
+            position: self.position.clone(),
+            timestamp: Instant::now(),
+            intensity: self.intensity,
+        });
+
+        // Trim old trail points
+        while self.trail.len() > MAX_TRAIL_LENGTH {
+            self.trail.pop_front();
+        }
+    }
+
+    /// Start (or restart) a highlight flash lasting `seconds`
+    pub fn start_flash(&mut self, seconds: f32) {
+        self.flash_remaining = self.flash_remaining.max(seconds);
+    }
+
+    /// Update animation state (called every frame), moving toward the
+    /// heading within `margin` of the edges
+    pub fn tick(&mut self, dt: f32, margin: f32) {
+        self.previous_position = self.position.clone();
+        self.flash_remaining = (self.flash_remaining - dt).max(0.0);
+        if let Some(ref mut transition) = self.transition {
+            transition.progress += dt / STATUS_TRANSITION_SECS;
+            if transition.progress >= 1.0 {
+                self.transition = None;
+            }
+        }
+
+        // Update pulse animation
+        let pulse_speed = 2.0 + self.intensity * 3.0; // Faster pulse when more intense
+        self.pulse_phase = (self.pulse_phase + dt * pulse_speed) % (2.0 * std::f32::consts::PI);
+
+        // Smooth position interpolation toward target
+        self.since_update += dt;
+        let lerp_speed = 3.0 * dt;
+        self.position = self.position.lerp(&self.heading(margin), lerp_speed);
+
+        // Record trail periodically
+        self.record_trail();
+    }
+
+    /// Check if this agent should have pulsing animation
+    /// Only agents that are Active with high intensity (> 0.6) pulse
+    pub fn should_pulse(&self) -> bool {
+        self.status == AgentStatus::Active && self.intensity > 0.6
+    }
+
+    /// Get the current pulse brightness multiplier (0.0 to 1.0)
+    /// Only active agents with intensity > 0.6 actually pulse;
+    /// other agents have static brightness based on their intensity
+    pub fn pulse_brightness(&self) -> f32 {
+        if self.should_pulse() {
+            // Pulsing animation for highly active agents
+            let base = 0.85;
+            let variation = 0.15;
+            base + variation * (self.pulse_phase.sin() * 0.5 + 0.5)
+        } else {
+            // Static brightness based on intensity for all other agents
+            0.6 + self.intensity * 0.4
+        }
+    }
+
+    /// Brightness at animation time `time` (see [`AnimationLoop::time`]),
+    /// following the animation profile of the agent's status
+    ///
+    /// [`AnimationLoop::time`]: crate::animation::AnimationLoop::time
+    pub fn animated_brightness(&self, time: f32) -> f32 {
+        // Offset each agent so a room of thinking agents doesn't breathe in sync
+        let offset = self.color_index as f32 * 0.37;
+        StatusAnimation::for_status(&self.status)
+            .brightness(time + offset)
+            .unwrap_or_else(|| self.pulse_brightness())
+    }
+
+    /// Display symbol at animation time `time`: [`symbol`](Self::symbol),
+    /// with waiting agents flipping an hourglass
+    pub fn animated_symbol(&self, time: f32) -> &'static str {
+        match StatusAnimation::for_status(&self.status).frame(time) {
+            _ if self.status != AgentStatus::Waiting => self.symbol(),
+            0 => "⧖",
+            _ => "⧗",
+        }
+    }
+
+    /// Get a display symbol based on intensity and status (legacy, returns static str)
+    /// Use `symbol_char()` for the new symbol system with Unicode/ASCII support
+    pub fn symbol(&self) -> &'static str {
+        match self.status {
+            AgentStatus::Active => {
+                if self.intensity > 0.7 {
+                    "◉"
+                } else if self.intensity > 0.4 {
+                    "◐"
+                } else {
+                    "◍"
+                }
+            }
+            AgentStatus::Thinking => "◌",
+            AgentStatus::Waiting => "○",
+            AgentStatus::Idle => "·",
+            AgentStatus::Error => "✗",
+        }
+    }
+
+    /// Get the agent's shape symbol as a char based on shape_index
+    /// Uses the new symbol system with Unicode/ASCII fallback support
+    pub fn shape_symbol(&self, use_unicode: bool) -> char {
+        get_agent_shape(self.shape_index).render(use_unicode)
+    }
+
+    /// Get the agent's shape symbol, auto-detecting Unicode support
+    pub fn shape_symbol_auto(&self) -> char {
+        self.shape_symbol(detect_unicode())
+    }
+
+    /// Get the status indicator symbol as a char
+    pub fn status_symbol(&self, use_unicode: bool) -> char {
+        get_status_indicator(&self.status).render(use_unicode)
+    }
+
+    /// Get the status indicator symbol, auto-detecting Unicode support
+    pub fn status_symbol_auto(&self) -> char {
+        self.status_symbol(detect_unicode())
+    }
+
+    /// Get full symbol representation: shape + status indicator
+    /// Returns a tuple of (shape_char, status_char)
+    pub fn full_symbol(&self, use_unicode: bool) -> (char, char) {
+        (
+            self.shape_symbol(use_unicode),
+            self.status_symbol(use_unicode),
+        )
+    }
+
+    /// Get the Symbol struct for the agent's shape
+    pub fn get_shape(&self) -> &'static Symbol {
+        get_agent_shape(self.shape_index)
+    }
+
+    /// Get the Symbol struct for the agent's status indicator
+    pub fn get_status_indicator(&self) -> &'static Symbol {
+        get_status_indicator(&self.status)
+    }
+
+    /// Get short display name
+    pub fn short_name(&self) -> String {
+        if self.id.len() <= 8 {
+            self.id.to_string()
+        } else {
+            format!("{}…", &self.id[..7])
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::positioning::DEFAULT_MARGIN;
+
+    #[test]
+    fn test_big_focus_jump_leaves_waypoint() {
+        let mut agent = Agent::new("atlas".into(), 0);
+
+        // No focus yet, nothing to label
+        agent.mark_departure(&Position::new(0.9, 0.9));
+        assert!(agent.waypoints.is_empty());
+
+        agent.focus = vec!["auth".into(), "jwt".into()];
+        agent.set_target(Position::new(0.2, 0.2));
+        agent.mark_departure(&Position::new(0.25, 0.2));
+        assert!(agent.waypoints.is_empty());
+
+        agent.mark_departure(&Position::new(0.8, 0.7));
+        let waypoint = agent.waypoints.back().unwrap();
+        assert_eq!(&*waypoint.focus, "auth");
+        assert_eq!(waypoint.position, Position::new(0.2, 0.2));
+
+        for _ in 0..MAX_WAYPOINTS {
+            agent.mark_departure(&Position::new(0.8, 0.7));
+        }
+        assert_eq!(agent.waypoints.len(), MAX_WAYPOINTS);
+    }
+
+    #[test]
+    fn test_velocity_carries_agent_between_updates() {
+        let update = AgentUpdate {
+            agent_id: "atlas".into(),
+            status: AgentStatus::Active,
+            focus: vec![],
+            intensity: 0.5,
+            message: String::new(),
+            timestamp: 0,
+        };
+        let mut agent = Agent::new("atlas".into(), 0);
+        agent.apply_update(&update);
+        agent.set_target(Position::new(0.2, 0.5));
+
+        // Moved 0.2 across in 2s: keeps going at 0.1/s for up to 2s more
+        agent.tick(2.0, DEFAULT_MARGIN);
+        agent.track_velocity(&Position::new(0.4, 0.5));
+        agent.apply_update(&update);
+        agent.set_target(Position::new(0.4, 0.5));
+        assert!((agent.velocity.0 - 0.1).abs() < 1e-6 && agent.velocity.1 == 0.0);
+
+        agent.since_update = 1.0;
+        assert!((agent.heading(DEFAULT_MARGIN).x - 0.5).abs() < 1e-6);
+        agent.since_update = 10.0;
+        assert!((agent.heading(DEFAULT_MARGIN).x - 0.6).abs() < 1e-6);
+
+        // Updates too close together are not extrapolated
+        agent.since_update = 0.1;
+        agent.track_velocity(&Position::new(0.9, 0.5));
+        assert_eq!(agent.velocity, (0.0, 0.0));
+        assert_eq!(agent.heading(DEFAULT_MARGIN), agent.target_position);
+    }
+
+    #[test]
+    fn test_prediction_stays_within_the_margin() {
+        let mut agent = Agent::new("atlas".into(), 0);
+        agent.set_target(Position::new(0.75, 0.5));
+        agent.velocity = (1.0, 0.0);
+        agent.update_interval = 5.0;
+
+        for _ in 0..100 {
+            agent.tick(0.05, 0.2);
+        }
+        assert!((agent.heading(0.2).x - 0.8).abs() < 1e-6);
+        assert!(agent.position.x <= 0.8 + 1e-6, "at {:?}", agent.position);
+    }
+
+    #[test]
+    fn test_status_change_starts_transition() {
+        let update = |status: AgentStatus| AgentUpdate {
+            agent_id: "atlas".into(),
+            status,
+            focus: vec![],
+            intensity: 0.5,
+            message: String::new(),
+            timestamp: 0,
+        };
+        let mut agent = Agent::new("atlas".into(), 0);
+        agent.apply_update(&update(AgentStatus::Active));
+        agent.tick(STATUS_TRANSITION_SECS, DEFAULT_MARGIN);
+        assert_eq!(agent.transition, None);
+
+        // Same status again: nothing to animate
+        agent.apply_update(&update(AgentStatus::Active));
+        assert_eq!(agent.transition, None);
+
+        agent.apply_update(&update(AgentStatus::Error));
+        agent.tick(STATUS_TRANSITION_SECS / 2.0, DEFAULT_MARGIN);
+        let transition = agent.transition.clone().unwrap();
+        assert_eq!(transition.from, AgentStatus::Active);
+        assert!((transition.progress - 0.5).abs() < 1e-6);
+
+        agent.tick(STATUS_TRANSITION_SECS / 2.0, DEFAULT_MARGIN);
+        assert_eq!(agent.transition, None);
+    }
+}
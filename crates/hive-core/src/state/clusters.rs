@@ -0,0 +1,147 @@
+//! Clusters of agents found from their positions, labelled by their focus.
+//!
+//! Agents closer than [`LINK_DISTANCE`] to each other are chained into one
+//! cluster; clusters of at least [`MIN_SIZE`] agents are labelled with the
+//! focus keyword most of their members share.
+
+use std::collections::HashMap;
+
+use crate::event::AgentId;
+use crate::positioning::Position;
+
+use super::agent::Agent;
+
+/// Agents this close (in normalized field units) are in the same cluster
+pub const LINK_DISTANCE: f32 = 0.1;
+
+/// Fewest agents that make a cluster
+pub const MIN_SIZE: usize = 3;
+
+/// A group of nearby agents
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cluster {
+    /// Mean position of the members
+    pub center: Position,
+    /// Members, sorted by ID
+    pub agents: Vec<AgentId>,
+    /// Most common focus keyword among the members
+    pub label: String,
+}
+
+/// Find the clusters among `agents`, largest first.
+///
+/// Clusters whose members have no focus keywords are left out, as there is
+/// nothing to label them with.
+pub fn detect<'a>(agents: impl IntoIterator<Item = &'a Agent>) -> Vec<Cluster> {
+    let mut agents: Vec<&Agent> = agents.into_iter().collect();
+    agents.sort_by(|a, b| a.id.cmp(&b.id));
+
+    // Flood-fill the groups of linked agents
+    let mut group = vec![usize::MAX; agents.len()];
+    let mut groups = Vec::new();
+    for start in 0..agents.len() {
+        if group[start] != usize::MAX {
+            continue;
+        }
+        let id = groups.len();
+        group[start] = id;
+        let mut members = vec![start];
+        let mut next = 0;
+        while next < members.len() {
+            let current = members[next];
+            next += 1;
+            for other in 0..agents.len() {
+                if group[other] == usize::MAX
+                    && agents[current].position.distance_to(&agents[other].position) <= LINK_DISTANCE
+                {
+                    group[other] = id;
+                    members.push(other);
+                }
+            }
+        }
+        groups.push(members);
+    }
+
+    let mut clusters: Vec<Cluster> = groups
+        .into_iter()
+        .filter(|members| members.len() >= MIN_SIZE)
+        .filter_map(|mut members| {
+            members.sort_unstable();
+            let members: Vec<&Agent> = members.into_iter().map(|i| agents[i]).collect();
+            let label = dominant_keyword(&members)?;
+            let n = members.len() as f32;
+            Some(Cluster {
+                center: Position::new(
+                    members.iter().map(|a| a.position.x).sum::<f32>() / n,
+                    members.iter().map(|a| a.position.y).sum::<f32>() / n,
+                ),
+                agents: members.iter().map(|a| a.id.clone()).collect(),
+                label,
+            })
+        })
+        .collect();
+    clusters.sort_by(|a, b| b.agents.len().cmp(&a.agents.len()).then_with(|| a.label.cmp(&b.label)));
+    clusters
+}
+
+/// Keyword in the most members' focus (alphabetically first on a tie)
+fn dominant_keyword(members: &[&Agent]) -> Option<String> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for agent in members {
+        let mut seen: Vec<&str> = agent.focus.iter().map(|keyword| &**keyword).collect();
+        seen.sort_unstable();
+        seen.dedup();
+        for keyword in seen {
+            *counts.entry(keyword).or_default() += 1;
+        }
+    }
+    counts
+        .into_iter()
+        .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(a.0)))
+        .map(|(keyword, _)| keyword.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn agent(id: &str, x: f32, y: f32, focus: &[&str]) -> Agent {
+        let mut agent = Agent::new(id.into(), 0);
+        agent.position = Position::new(x, y);
+        agent.focus = focus.iter().map(|&f| f.into()).collect();
+        agent
+    }
+
+    #[test]
+    fn test_groups_nearby_agents_and_labels_them() {
+        let agents = [
+            agent("a", 0.20, 0.20, &["auth", "jwt"]),
+            agent("b", 0.25, 0.22, &["auth"]),
+            // Chained through b
+            agent("c", 0.32, 0.24, &["session", "auth"]),
+            agent("d", 0.80, 0.80, &["database"]),
+            agent("e", 0.82, 0.78, &["database", "schema"]),
+            agent("f", 0.78, 0.83, &["schema"]),
+            agent("lone", 0.50, 0.90, &["deploy"]),
+        ];
+        let clusters = detect(&agents);
+
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0].label, "auth");
+        assert_eq!(clusters[0].agents, ["a", "b", "c"].map(AgentId::from));
+        // Tie between database and schema
+        assert_eq!(clusters[1].label, "database");
+    }
+
+    #[test]
+    fn test_unlabelled_or_small_groups_are_skipped() {
+        let agents = [
+            agent("a", 0.2, 0.2, &[]),
+            agent("b", 0.2, 0.25, &[]),
+            agent("c", 0.25, 0.2, &[]),
+            agent("d", 0.8, 0.8, &["api"]),
+            agent("e", 0.8, 0.85, &["api"]),
+        ];
+        assert!(detect(&agents).is_empty());
+    }
+}
@@ -0,0 +1,132 @@
+//! Swarm convergence detection.
+//!
+//! A convergence is a group of agents working hard in the same spot: at least
+//! [`MIN_AGENTS`] agents with an intensity of [`MIN_INTENSITY`] or more, all
+//! within [`RADIUS`] of the group's seed agent.
+
+use std::time::Instant;
+
+use crate::event::AgentId;
+use crate::positioning::Position;
+
+use super::agent::Agent;
+
+/// Distance (in normalized field units) agents must be within
+pub const RADIUS: f32 = 0.12;
+
+/// Fewest agents that count as a swarm
+pub const MIN_AGENTS: usize = 4;
+
+/// Lowest intensity of an agent taking part
+pub const MIN_INTENSITY: f32 = 0.6;
+
+/// A detected group of converging agents
+#[derive(Debug, Clone, PartialEq)]
+pub struct Convergence {
+    /// Mean position of the group
+    pub center: Position,
+    /// Agents in the group, sorted by ID
+    pub agents: Vec<AgentId>,
+    /// When the group was first seen (kept while it stays in place)
+    pub since: Instant,
+}
+
+impl Convergence {
+    /// The largest convergence among `agents`, if there is one.
+    ///
+    /// `previous` is the convergence found last time; when the new group sits
+    /// in the same spot it keeps its start time.
+    pub fn detect<'a>(
+        agents: impl IntoIterator<Item = &'a Agent>,
+        previous: Option<&Convergence>,
+    ) -> Option<Convergence> {
+        let mut busy: Vec<&Agent> = agents
+            .into_iter()
+            .filter(|a| a.intensity >= MIN_INTENSITY)
+            .collect();
+        if busy.len() < MIN_AGENTS {
+            return None;
+        }
+        busy.sort_by(|a, b| a.id.cmp(&b.id));
+
+        // Seed the group at each busy agent and keep the biggest one
+        let (_, group) = busy
+            .iter()
+            .map(|seed| {
+                let group: Vec<&Agent> = busy
+                    .iter()
+                    .copied()
+                    .filter(|a| a.position.distance_to(&seed.position) <= RADIUS)
+                    .collect();
+                (seed, group)
+            })
+            .max_by(|a, b| a.1.len().cmp(&b.1.len()).then_with(|| b.0.id.cmp(&a.0.id)))?;
+        if group.len() < MIN_AGENTS {
+            return None;
+        }
+
+        let n = group.len() as f32;
+        let center = Position::new(
+            group.iter().map(|a| a.position.x).sum::<f32>() / n,
+            group.iter().map(|a| a.position.y).sum::<f32>() / n,
+        );
+        let since = previous
+            .filter(|p| p.center.distance_to(&center) <= RADIUS)
+            .map_or_else(Instant::now, |p| p.since);
+
+        Some(Convergence {
+            center,
+            agents: group.iter().map(|a| a.id.clone()).collect(),
+            since,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn agent(id: &str, x: f32, y: f32, intensity: f32) -> Agent {
+        let mut agent = Agent::new(id.into(), 0);
+        agent.position = Position::new(x, y);
+        agent.intensity = intensity;
+        agent
+    }
+
+    #[test]
+    fn test_detects_busy_group() {
+        let agents = [
+            agent("a", 0.50, 0.50, 0.9),
+            agent("b", 0.52, 0.50, 0.8),
+            agent("c", 0.50, 0.53, 0.7),
+            agent("d", 0.47, 0.49, 0.9),
+            agent("far", 0.10, 0.10, 1.0),
+        ];
+        let found = Convergence::detect(&agents, None).unwrap();
+        assert_eq!(found.agents, ["a", "b", "c", "d"].map(AgentId::from));
+        assert!(found.center.distance_to(&Position::new(0.5, 0.5)) < 0.02);
+
+        // Same spot keeps the start time
+        let again = Convergence::detect(&agents, Some(&found)).unwrap();
+        assert_eq!(again.since, found.since);
+    }
+
+    #[test]
+    fn test_idle_or_spread_agents_do_not_converge() {
+        let idle = [
+            agent("a", 0.50, 0.50, 0.9),
+            agent("b", 0.52, 0.50, 0.8),
+            agent("c", 0.50, 0.53, 0.7),
+            agent("d", 0.47, 0.49, 0.2),
+        ];
+        assert_eq!(Convergence::detect(&idle, None), None);
+
+        let spread = [
+            agent("a", 0.1, 0.1, 0.9),
+            agent("b", 0.9, 0.1, 0.9),
+            agent("c", 0.1, 0.9, 0.9),
+            agent("d", 0.9, 0.9, 0.9),
+        ];
+        assert_eq!(Convergence::detect(&spread, None), None);
+    }
+}
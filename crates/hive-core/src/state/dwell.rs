@@ -0,0 +1,91 @@
+//! Time agents spend near each landmark.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::event::{AgentId, LandmarkId};
+
+/// Accumulated time each agent spent nearest to each landmark
+#[derive(Debug, Clone, Default)]
+pub struct DwellTimes {
+    by_agent: HashMap<AgentId, HashMap<LandmarkId, Duration>>,
+}
+
+impl DwellTimes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `dt` to the time `agent` spent nearest to `landmark`
+    pub fn record(&mut self, agent: &AgentId, landmark: &str, dt: Duration) {
+        // Look up before inserting to avoid allocating keys every frame
+        let zones = match self.by_agent.get_mut(agent) {
+            Some(zones) => zones,
+            None => self.by_agent.entry(agent.clone()).or_default(),
+        };
+        match zones.get_mut(landmark) {
+            Some(time) => *time += dt,
+            None => {
+                zones.insert(landmark.to_string(), dt);
+            }
+        }
+    }
+
+    /// Time one agent spent near each landmark
+    pub fn for_agent(&self, agent: &str) -> Option<&HashMap<LandmarkId, Duration>> {
+        self.by_agent.get(agent)
+    }
+
+    /// Time all agents together spent near each landmark, longest first
+    pub fn zone_totals(&self) -> Vec<(&LandmarkId, Duration)> {
+        let mut totals: HashMap<&LandmarkId, Duration> = HashMap::new();
+        for zones in self.by_agent.values() {
+            for (landmark, time) in zones {
+                *totals.entry(landmark).or_default() += *time;
+            }
+        }
+        let mut totals: Vec<_> = totals.into_iter().collect();
+        totals.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        totals
+    }
+
+    /// Agent that spent the most time near `landmark`
+    pub fn top_agent(&self, landmark: &str) -> Option<(&AgentId, Duration)> {
+        self.by_agent
+            .iter()
+            .filter_map(|(agent, zones)| zones.get(landmark).map(|time| (agent, *time)))
+            .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(a.0)))
+    }
+
+    /// Sum of all recorded time
+    pub fn total(&self) -> Duration {
+        self.by_agent.values().flat_map(|zones| zones.values()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_agent.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zone_totals_and_top_agent() {
+        let mut dwell = DwellTimes::new();
+        let secs = Duration::from_secs;
+        dwell.record(&"atlas".into(), "data", secs(3));
+        dwell.record(&"atlas".into(), "data", secs(2));
+        dwell.record(&"atlas".into(), "auth", secs(1));
+        dwell.record(&"nova".into(), "auth", secs(2));
+
+        let totals = dwell.zone_totals();
+        assert_eq!(totals[0], (&"data".to_string(), secs(5)));
+        assert_eq!(totals[1], (&"auth".to_string(), secs(3)));
+        assert_eq!(dwell.top_agent("auth"), Some((&"nova".into(), secs(2))));
+        assert_eq!(dwell.for_agent("atlas").unwrap()["data"], secs(5));
+        assert_eq!(dwell.total(), secs(8));
+        assert_eq!(dwell.top_agent("ui"), None);
+    }
+}
@@ -0,0 +1,1030 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::event::{AgentId, AgentStatus, Connection, HiveEvent, Keyword, LandmarkId};
+use crate::positioning::{CollisionAvoidance, Position, SemanticPositioner, DEFAULT_MARGIN, MAX_MARGIN};
+
+use super::activity::ActivityWindow;
+use super::agent::Agent;
+use super::clusters::{self, Cluster};
+use super::convergence::Convergence;
+use super::dwell::DwellTimes;
+
+/// How close (in normalized field units) a position must be to a landmark to
+/// count as inside its zone
+const ZONE_RADIUS: f32 = 0.2;
+
+/// Seconds between cluster detection runs
+const CLUSTER_INTERVAL: f32 = 3.0;
+
+/// Seconds between convergence checks
+const CONVERGENCE_CHECK_INTERVAL: f32 = 0.25;
+
+/// Default cap on the number of agents tracked at once
+pub const DEFAULT_MAX_AGENTS: usize = 1000;
+
+/// Evicted agent IDs kept until [`Field::take_evicted`] collects them
+const EVICTED_BACKLOG: usize = 64;
+
+/// An agent evicted to stay under the agent limit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Evicted {
+    pub id: AgentId,
+    /// Whether it was idle; busy agents only go when none is
+    pub idle: bool,
+}
+
+/// Default cap on the number of connections kept on the field
+pub const DEFAULT_MAX_CONNECTIONS: usize = 256;
+
+/// Default cap on the trail points kept across all agents
+pub const DEFAULT_MAX_TRAIL_POINTS: usize = 10_000;
+
+/// How long a connection stays fully visible when its event sets no TTL
+pub const DEFAULT_CONNECTION_HOLD: Duration = Duration::from_secs(3);
+
+/// Active connection between agents with animation state
+#[derive(Debug, Clone)]
+pub struct ActiveConnection {
+    pub from: AgentId,
+    pub to: AgentId,
+    pub label: String,
+    pub created_at: Instant,
+    pub opacity: f32,
+    pub fading_out: bool,
+    /// How long to stay fully visible before fading out
+    pub hold: Duration,
+}
+
+impl ActiveConnection {
+    pub fn new(conn: &Connection) -> Self {
+        Self {
+            from: conn.from.clone(),
+            to: conn.to.clone(),
+            label: conn.label.clone(),
+            created_at: Instant::now(),
+            opacity: 0.0,
+            fading_out: false,
+            hold: conn
+                .ttl_ms
+                .map_or(DEFAULT_CONNECTION_HOLD, Duration::from_millis),
+        }
+    }
+
+    /// Update animation state, returns true if connection should be removed
+    pub fn tick(&mut self, dt: f32) -> bool {
+        let age = self.created_at.elapsed();
+
+        if self.fading_out {
+            self.opacity = (self.opacity - dt * 2.0).max(0.0);
+            return self.opacity <= 0.0;
+        }
+
+        // Hold for the TTL, then start fading (a short TTL cuts the fade-in)
+        if age > self.hold {
+            self.fading_out = true;
+        }
+        // Fade in over 0.3 seconds
+        else if age < Duration::from_millis(300) {
+            self.opacity = (age.as_secs_f32() / 0.3).min(1.0);
+        }
+
+        false
+    }
+}
+
+/// Stored landmark for display
+#[derive(Debug, Clone)]
+pub struct StoredLandmark {
+    pub id: LandmarkId,
+    pub label: String,
+    pub keywords: Vec<String>,
+    pub position: Position,
+    /// Promoted from a frequent focus keyword rather than sent by the emitter
+    pub auto: bool,
+}
+
+/// Settings for promoting frequent focus keywords to landmarks while the
+/// emitter has sent none
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AutoLandmarks {
+    /// Agent updates to count keywords over before promoting them
+    pub warmup_updates: u32,
+    /// Most keywords promoted
+    pub count: usize,
+}
+
+impl Default for AutoLandmarks {
+    fn default() -> Self {
+        Self {
+            warmup_updates: 100,
+            count: 6,
+        }
+    }
+}
+
+/// Running totals for one direction of contact between two agents
+#[derive(Debug, Clone)]
+pub struct ContactStats {
+    /// Connection events seen from one agent to the other
+    pub count: u32,
+    /// When the last of them arrived
+    pub last_contact: Instant,
+    /// Label of the last of them
+    pub last_label: String,
+}
+
+/// An agent another one has been in contact with, either way
+#[derive(Debug, Clone, PartialEq)]
+pub struct Partner {
+    pub id: AgentId,
+    /// Connection events between the two, both ways
+    pub count: u32,
+    pub last_contact: Instant,
+    pub last_label: String,
+}
+
+/// Order agents are listed in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AgentOrder {
+    /// By ID
+    #[default]
+    Id,
+    /// Hardest working first
+    Intensity,
+    /// Most recently updated first
+    LastUpdate,
+    /// Errors first, then active, thinking, waiting and idle agents
+    Status,
+}
+
+impl AgentOrder {
+    /// The order after this one, wrapping around
+    pub fn next(self) -> Self {
+        match self {
+            AgentOrder::Id => AgentOrder::Intensity,
+            AgentOrder::Intensity => AgentOrder::LastUpdate,
+            AgentOrder::LastUpdate => AgentOrder::Status,
+            AgentOrder::Status => AgentOrder::Id,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            AgentOrder::Id => "ID",
+            AgentOrder::Intensity => "intensity",
+            AgentOrder::LastUpdate => "last update",
+            AgentOrder::Status => "status",
+        }
+    }
+}
+
+/// Rank of a status in [`AgentOrder::Status`], most urgent first
+fn status_rank(status: &AgentStatus) -> u8 {
+    match status {
+        AgentStatus::Error => 0,
+        AgentStatus::Active => 1,
+        AgentStatus::Thinking => 2,
+        AgentStatus::Waiting => 3,
+        AgentStatus::Idle => 4,
+    }
+}
+
+/// The field state containing all agents, connections, and landmarks
+pub struct Field {
+    pub agents: HashMap<AgentId, Agent>,
+    pub connections: Vec<ActiveConnection>,
+    pub landmarks: HashMap<LandmarkId, StoredLandmark>,
+    pub positioner: SemanticPositioner,
+
+    /// Contact totals keyed by (from, to), kept after the lines fade
+    pub contacts: HashMap<(AgentId, AgentId), ContactStats>,
+
+    /// Time each agent spent nearest to each landmark
+    pub dwell: DwellTimes,
+
+    /// Intensity-seconds of each agent over the recent past
+    pub activity: ActivityWindow,
+
+    /// Groups of nearby agents outside any landmark zone, labelled with their
+    /// dominant focus keyword
+    pub clusters: Vec<Cluster>,
+
+    /// Time since clusters were last detected
+    cluster_timer: f32,
+
+    /// Group of busy agents gathered in one spot, if any
+    pub convergence: Option<Convergence>,
+
+    /// Time since convergence was last checked
+    convergence_timer: f32,
+
+    /// Counter for assigning colors to new agents
+    agent_color_counter: usize,
+
+    /// Paused state for replay
+    pub paused: bool,
+
+    /// Playback speed multiplier
+    pub playback_speed: f32,
+
+    /// Collision avoidance system using spatial hash
+    collision_avoidance: CollisionAvoidance,
+
+    /// Names of tagged sources; agents whose ID starts with `name/` belong to it
+    sources: Vec<String>,
+
+    /// Most connections kept at once; the least visible are evicted first
+    max_connections: usize,
+
+    /// Most agents tracked at once; idle agents that went quiet longest are
+    /// evicted first
+    max_agents: usize,
+
+    /// Agents evicted to stay under `max_agents`, oldest first
+    evicted: VecDeque<Evicted>,
+
+    /// Most trail points kept across all agents; the oldest go first
+    max_trail_points: usize,
+
+    /// Share of the field kept clear along each edge
+    margin: f32,
+
+    /// Keep agents moving along their recent velocity between updates
+    motion_prediction: bool,
+
+    /// Weight of an agent's previous intensity when a new one arrives
+    /// (0 uses incoming values as they are)
+    intensity_smoothing: f32,
+
+    /// Keyword promotion settings, if enabled
+    auto_landmarks: Option<AutoLandmarks>,
+
+    /// Focus keyword counts during the auto landmark warm-up
+    keyword_counts: HashMap<String, u32>,
+
+    /// Agent updates counted so far; stops once keywords are promoted
+    warmup_seen: u32,
+}
+
+impl Field {
+    pub fn new() -> Self {
+        Self {
+            agents: HashMap::new(),
+            connections: Vec::new(),
+            landmarks: HashMap::new(),
+            positioner: SemanticPositioner::new(),
+            contacts: HashMap::new(),
+            dwell: DwellTimes::new(),
+            activity: ActivityWindow::default(),
+            clusters: Vec::new(),
+            cluster_timer: CLUSTER_INTERVAL,
+            convergence: None,
+            convergence_timer: 0.0,
+            agent_color_counter: 0,
+            paused: false,
+            playback_speed: 1.0,
+            collision_avoidance: CollisionAvoidance::new(),
+            sources: Vec::new(),
+            max_connections: DEFAULT_MAX_CONNECTIONS,
+            max_agents: DEFAULT_MAX_AGENTS,
+            evicted: VecDeque::new(),
+            max_trail_points: DEFAULT_MAX_TRAIL_POINTS,
+            margin: DEFAULT_MARGIN,
+            motion_prediction: false,
+            intensity_smoothing: 0.0,
+            auto_landmarks: None,
+            keyword_counts: HashMap::new(),
+            warmup_seen: 0,
+        }
+    }
+
+    /// Promote the most frequent focus keywords to landmarks after a warm-up,
+    /// unless the emitter sends landmarks of its own
+    pub fn with_auto_landmarks(mut self, settings: AutoLandmarks) -> Self {
+        self.auto_landmarks = Some(settings);
+        self
+    }
+
+    /// Cap the number of connections kept at once (at least 1)
+    pub fn with_max_connections(mut self, max: usize) -> Self {
+        self.max_connections = max.max(1);
+        self
+    }
+
+    /// Rank agents by their work over the last `secs` seconds of field time
+    pub fn with_activity_window(mut self, secs: f32) -> Self {
+        self.activity = ActivityWindow::new(secs);
+        self
+    }
+
+    /// Cap the number of agents tracked at once (at least 1)
+    pub fn with_max_agents(mut self, max: usize) -> Self {
+        self.max_agents = max.max(1);
+        self
+    }
+
+    /// Cap the trail points kept across all agents (0 keeps no trails)
+    pub fn with_max_trail_points(mut self, max: usize) -> Self {
+        self.max_trail_points = max;
+        self
+    }
+
+    /// Keep agents and landmarks `margin` (a share of the field, up to
+    /// [`MAX_MARGIN`]) away from the edges
+    pub fn with_margin(mut self, margin: f32) -> Self {
+        self.margin = margin.clamp(0.0, MAX_MARGIN);
+        self.positioner.set_margin(self.margin);
+        self
+    }
+
+    /// Share of the field kept clear along each edge
+    pub fn margin(&self) -> f32 {
+        self.margin
+    }
+
+    /// Trail points kept across all agents
+    pub fn max_trail_points(&self) -> usize {
+        self.max_trail_points
+    }
+
+    /// Change the trail point cap; points over it go on the next tick
+    pub fn set_max_trail_points(&mut self, max: usize) {
+        self.max_trail_points = max;
+    }
+
+    /// Extrapolate agent movement along the velocity of their last two
+    /// updates, for sources that only report every few seconds
+    pub fn with_motion_prediction(mut self, on: bool) -> Self {
+        self.motion_prediction = on;
+        self
+    }
+
+    /// Smooth incoming intensity values with an exponential moving average:
+    /// each update keeps `smoothing` (clamped to 0.0-0.95) of the previous
+    /// value, so jittery emitters pulse instead of flickering
+    pub fn with_intensity_smoothing(mut self, smoothing: f32) -> Self {
+        self.intensity_smoothing = smoothing.clamp(0.0, 0.95);
+        self
+    }
+
+    /// Agents evicted since the last call, oldest first (the most recent
+    /// few only, if they were not collected)
+    pub fn take_evicted(&mut self) -> Vec<Evicted> {
+        self.evicted.drain(..).collect()
+    }
+
+    /// Recognize agents of the given tagged sources (see [`HiveEvent::tag_source`])
+    pub fn with_sources(mut self, sources: Vec<String>) -> Self {
+        self.sources = sources;
+        self
+    }
+
+    /// Process an incoming event
+    pub fn process_event(&mut self, event: &HiveEvent) {
+        match event {
+            HiveEvent::AgentUpdate(update) => {
+                let is_new = !self.agents.contains_key(&update.agent_id);
+                if is_new {
+                    while self.agents.len() >= self.max_agents && self.evict_agent() {}
+                }
+                let smoothing = self.intensity_smoothing;
+
+                let agent = self.agents.entry(update.agent_id.clone()).or_insert_with(|| {
+                    let color_idx = self.agent_color_counter;
+                    self.agent_color_counter += 1;
+                    let mut agent = Agent::new(update.agent_id.clone(), color_idx);
+                    agent.source = self.sources.iter().position(|source| {
+                        update
+                            .agent_id
+                            .strip_prefix(source.as_str())
+                            .is_some_and(|rest| rest.starts_with('/'))
+                    });
+                    agent
+                });
+
+                // Calculate new target position based on focus
+                let target = self.positioner.calculate_position(&update.focus, &self.landmarks);
+                agent.mark_departure(&target);
+                if self.motion_prediction {
+                    agent.track_velocity(&target);
+                }
+
+                let previous = agent.intensity;
+                agent.apply_update(update);
+                if !is_new {
+                    agent.intensity = previous * smoothing + agent.intensity * (1.0 - smoothing);
+                }
+                agent.set_target(target);
+
+                self.count_keywords(&update.focus);
+            }
+
+            HiveEvent::Connection(conn) => {
+                let now = Instant::now();
+                self.contacts
+                    .entry((conn.from.clone(), conn.to.clone()))
+                    .and_modify(|stats| {
+                        stats.count += 1;
+                        stats.last_contact = now;
+                        stats.last_label.clone_from(&conn.label);
+                    })
+                    .or_insert_with(|| ContactStats {
+                        count: 1,
+                        last_contact: now,
+                        last_label: conn.label.clone(),
+                    });
+
+                // Remove any existing connection between same agents
+                self.connections.retain(|c| {
+                    !((c.from == conn.from && c.to == conn.to)
+                        || (c.from == conn.to && c.to == conn.from))
+                });
+
+                // Make room: fading connections go first, then the oldest
+                while self.connections.len() >= self.max_connections {
+                    let evict = self
+                        .connections
+                        .iter()
+                        .enumerate()
+                        .min_by_key(|(_, c)| (!c.fading_out, c.created_at))
+                        .map(|(idx, _)| idx);
+                    match evict {
+                        Some(idx) => {
+                            self.connections.remove(idx);
+                        }
+                        None => break,
+                    }
+                }
+
+                self.connections.push(ActiveConnection::new(conn));
+            }
+
+            HiveEvent::Landmark(landmark) => {
+                // Real landmarks replace the promoted keywords
+                self.landmarks.retain(|_, l| !l.auto);
+
+                let position = self.positioner.register_landmark(&landmark.keywords);
+
+                self.landmarks.insert(
+                    landmark.id.clone(),
+                    StoredLandmark {
+                        id: landmark.id.clone(),
+                        label: landmark.label.clone(),
+                        keywords: landmark.keywords.clone(),
+                        position,
+                        auto: false,
+                    },
+                );
+            }
+        }
+    }
+
+    /// Drop the idle agent that went longest without an update (or, with no
+    /// idle agents, whichever did) along with its connections. Returns
+    /// whether an agent was evicted.
+    fn evict_agent(&mut self) -> bool {
+        let evict = self
+            .agents
+            .values()
+            .min_by(|a, b| {
+                (a.status != AgentStatus::Idle, a.last_update, &a.id)
+                    .cmp(&(b.status != AgentStatus::Idle, b.last_update, &b.id))
+            })
+            .map(|agent| (agent.id.clone(), agent.status == AgentStatus::Idle));
+        let Some((id, idle)) = evict else {
+            return false;
+        };
+
+        self.agents.remove(&id);
+        self.connections.retain(|c| c.from != id && c.to != id);
+        self.contacts.retain(|(from, to), _| *from != id && *to != id);
+        self.activity.remove(&id);
+        if self.evicted.len() == EVICTED_BACKLOG {
+            self.evicted.pop_front();
+        }
+        self.evicted.push_back(Evicted { id, idle });
+        true
+    }
+
+    /// Count focus keywords during the warm-up, then promote the most
+    /// frequent ones if there are still no landmarks
+    fn count_keywords(&mut self, focus: &[Keyword]) {
+        let Some(settings) = self.auto_landmarks else {
+            return;
+        };
+        if self.warmup_seen >= settings.warmup_updates || !self.landmarks.is_empty() {
+            return;
+        }
+
+        for keyword in focus {
+            *self.keyword_counts.entry(keyword.to_lowercase()).or_default() += 1;
+        }
+        self.warmup_seen += 1;
+        if self.warmup_seen < settings.warmup_updates {
+            return;
+        }
+
+        let mut counts: Vec<(String, u32)> = std::mem::take(&mut self.keyword_counts).into_iter().collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        for (keyword, _) in counts.into_iter().take(settings.count) {
+            let keywords = vec![keyword.clone()];
+            let position = self.positioner.register_landmark(&keywords);
+            let id = format!("auto:{}", keyword);
+            self.landmarks.insert(
+                id.clone(),
+                StoredLandmark {
+                    id,
+                    label: keyword,
+                    keywords,
+                    position,
+                    auto: true,
+                },
+            );
+        }
+    }
+
+    /// Advance the field by one simulation step of `dt` seconds
+    pub fn tick(&mut self, dt: f32) {
+        if self.paused {
+            // Hold agents still rather than drawing them between ticks
+            for agent in self.agents.values_mut() {
+                agent.previous_position = agent.position.clone();
+            }
+            return;
+        }
+
+        let adjusted_dt = dt * self.playback_speed;
+
+        // Update agents
+        for agent in self.agents.values_mut() {
+            agent.tick(adjusted_dt, self.margin);
+        }
+        self.prune_trails();
+
+        // Apply collision avoidance after position updates
+        self.apply_collision_avoidance();
+
+        // Credit the frame to the landmark each agent is closest to
+        let elapsed = Duration::from_secs_f32(adjusted_dt);
+        for agent in self.agents.values() {
+            if let Some(landmark) = closest_landmark(&self.landmarks, &agent.position) {
+                self.dwell.record(&agent.id, &landmark.id, elapsed);
+            }
+        }
+        self.activity.record(self.agents.values(), adjusted_dt);
+
+        // Re-cluster every few seconds; landmarks already label their zones
+        self.cluster_timer += adjusted_dt;
+        if self.cluster_timer >= CLUSTER_INTERVAL {
+            self.cluster_timer = 0.0;
+            let mut found = clusters::detect(self.agents.values());
+            found.retain(|c| self.nearest_landmark(&c.center).is_none());
+            self.clusters = found;
+        }
+
+        // Look for swarms every few frames
+        self.convergence_timer += adjusted_dt;
+        if self.convergence_timer >= CONVERGENCE_CHECK_INTERVAL {
+            self.convergence_timer = 0.0;
+            self.convergence = Convergence::detect(self.agents.values(), self.convergence.as_ref());
+        }
+
+        // Update connections, removing expired ones
+        self.connections.retain_mut(|conn| !conn.tick(adjusted_dt));
+    }
+
+    /// Drop the oldest trail points across all agents until the trails fit
+    /// in `max_trail_points`
+    fn prune_trails(&mut self) {
+        let total: usize = self.agents.values().map(|agent| agent.trail.len()).sum();
+        let mut excess = total.saturating_sub(self.max_trail_points);
+        if excess == 0 {
+            return;
+        }
+
+        // Each trail is oldest first, so the oldest point overall is always
+        // at the front of one of them
+        let mut fronts: BinaryHeap<_> = self
+            .agents
+            .iter()
+            .filter_map(|(id, agent)| agent.trail.front().map(|point| Reverse((point.timestamp, id.clone()))))
+            .collect();
+        while excess > 0 {
+            let Some(Reverse((_, id))) = fronts.pop() else {
+                break;
+            };
+            let trail = &mut self.agents.get_mut(&id).expect("agent with a trail").trail;
+            trail.pop_front();
+            excess -= 1;
+            if let Some(point) = trail.front() {
+                fronts.push(Reverse((point.timestamp, id)));
+            }
+        }
+    }
+
+    /// Apply collision avoidance to prevent agents from overlapping
+    /// Uses spatial hash for O(n) average time complexity
+    fn apply_collision_avoidance(&mut self) {
+        if self.agents.len() < 2 {
+            return;
+        }
+
+        // Collect agent IDs and positions in a stable order
+        let mut agent_ids: Vec<AgentId> = self.agents.keys().cloned().collect();
+        agent_ids.sort();
+
+        let mut positions: Vec<Position> = agent_ids
+            .iter()
+            .map(|id| self.agents.get(id).unwrap().position.clone())
+            .collect();
+
+        // Calculate and apply separation forces using spatial hash
+        let forces = self.collision_avoidance.calculate_separation_forces(&positions);
+
+        // Apply forces to positions
+        for (i, (fx, fy)) in forces.into_iter().enumerate() {
+            positions[i] = Position::new(positions[i].x + fx, positions[i].y + fy).clamp_to(self.margin);
+        }
+
+        // Update agent positions
+        for (i, id) in agent_ids.iter().enumerate() {
+            if let Some(agent) = self.agents.get_mut(id) {
+                agent.position = positions[i].clone();
+            }
+        }
+    }
+
+    /// Get agent position by ID
+    pub fn get_agent_position(&self, id: &str) -> Option<Position> {
+        self.agents.get(id).map(|a| a.position.clone())
+    }
+
+    /// Toggle pause state
+    pub fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+    }
+
+    /// Adjust playback speed
+    pub fn adjust_speed(&mut self, delta: f32) {
+        self.set_speed(self.playback_speed + delta);
+    }
+
+    /// Set the playback speed (0.25x to 4x)
+    pub fn set_speed(&mut self, speed: f32) {
+        self.playback_speed = speed.clamp(0.25, 4.0);
+    }
+
+    /// Landmark whose zone contains `position`, if any (the nearest one wins)
+    pub fn nearest_landmark(&self, position: &Position) -> Option<&StoredLandmark> {
+        self.landmarks
+            .values()
+            .map(|l| (l, l.position.distance_to(position)))
+            .filter(|(_, d)| *d <= ZONE_RADIUS)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(l, _)| l)
+    }
+
+    /// Landmark zone an agent with this focus would move into
+    pub fn zone_for_focus(&mut self, focus: &[impl AsRef<str>]) -> Option<&StoredLandmark> {
+        let target = self.positioner.calculate_position(focus, &self.landmarks);
+        self.nearest_landmark(&target)
+    }
+
+    /// Agents `id` has been in contact with, most recent first
+    pub fn partners(&self, id: &str) -> Vec<Partner> {
+        let mut partners: HashMap<&str, Partner> = HashMap::new();
+        for ((from, to), stats) in &self.contacts {
+            let other = match (&**from == id, &**to == id) {
+                (true, false) => to,
+                (false, true) => from,
+                _ => continue,
+            };
+            partners
+                .entry(other)
+                .and_modify(|partner| {
+                    partner.count += stats.count;
+                    if stats.last_contact > partner.last_contact {
+                        partner.last_contact = stats.last_contact;
+                        partner.last_label.clone_from(&stats.last_label);
+                    }
+                })
+                .or_insert_with(|| Partner {
+                    id: other.clone(),
+                    count: stats.count,
+                    last_contact: stats.last_contact,
+                    last_label: stats.last_label.clone(),
+                });
+        }
+        let mut partners: Vec<_> = partners.into_values().collect();
+        partners.sort_by(|a, b| b.last_contact.cmp(&a.last_contact).then_with(|| a.id.cmp(&b.id)));
+        partners
+    }
+
+    /// Get sorted list of agents for consistent rendering
+    pub fn agents_sorted(&self) -> Vec<&Agent> {
+        let mut agents: Vec<_> = self.agents.values().collect();
+        agents.sort_by(|a, b| a.id.cmp(&b.id));
+        agents
+    }
+
+    /// Agents listed in `order`, ties broken by ID
+    pub fn agents_ordered(&self, order: AgentOrder) -> Vec<&Agent> {
+        let mut agents = self.agents_sorted();
+        match order {
+            AgentOrder::Id => {}
+            AgentOrder::Intensity => agents.sort_by(|a, b| b.intensity.total_cmp(&a.intensity)),
+            AgentOrder::LastUpdate => agents.sort_by_key(|agent| std::cmp::Reverse(agent.last_update)),
+            AgentOrder::Status => agents.sort_by_key(|agent| status_rank(&agent.status)),
+        }
+        agents
+    }
+}
+
+/// Landmark closest to `position`, however far away
+fn closest_landmark<'a>(
+    landmarks: &'a HashMap<LandmarkId, StoredLandmark>,
+    position: &Position,
+) -> Option<&'a StoredLandmark> {
+    landmarks
+        .values()
+        .map(|l| (l, l.position.distance_to(position)))
+        .min_by(|a, b| a.1.total_cmp(&b.1).then_with(|| a.0.id.cmp(&b.0.id)))
+        .map(|(l, _)| l)
+}
+
+impl Default for Field {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn connection(from: &str, to: &str) -> HiveEvent {
+        HiveEvent::Connection(Connection {
+            from: from.into(),
+            to: to.into(),
+            label: String::new(),
+            timestamp: 0,
+            ttl_ms: None,
+        })
+    }
+
+    #[test]
+    fn test_contacts_count_each_direction() {
+        let mut field = Field::new();
+        field.process_event(&connection("a", "b"));
+        field.process_event(&connection("a", "b"));
+        field.process_event(&connection("b", "a"));
+
+        let key = |from: &str, to: &str| (AgentId::from(from), AgentId::from(to));
+        assert_eq!(field.contacts[&key("a", "b")].count, 2);
+        assert_eq!(field.contacts[&key("b", "a")].count, 1);
+        assert!(!field.contacts.contains_key(&key("a", "c")));
+
+        field.process_event(&connection("c", "a"));
+        let partners = field.partners("a");
+        let summary: Vec<_> = partners.iter().map(|p| (&*p.id, p.count)).collect();
+        assert_eq!(summary, [("c", 1), ("b", 3)]);
+    }
+
+    #[test]
+    fn test_margin_keeps_agents_off_the_edges() {
+        let mut field = Field::new().with_margin(0.25);
+        for (id, focus) in [("atlas", "database"), ("nova", "frontend"), ("echo", "zzqx")] {
+            field.process_event(&HiveEvent::AgentUpdate(crate::event::AgentUpdate {
+                agent_id: id.into(),
+                status: AgentStatus::Active,
+                focus: vec![focus.into()],
+                intensity: 0.5,
+                message: String::new(),
+                timestamp: 0,
+            }));
+        }
+        for _ in 0..200 {
+            field.tick(0.05);
+        }
+        for agent in field.agents.values() {
+            for value in [agent.position.x, agent.position.y, agent.target_position.x, agent.target_position.y] {
+                assert!((0.25..=0.75).contains(&value), "{} at {:?}", agent.id, agent.position);
+            }
+        }
+        assert_eq!(Field::new().with_margin(0.9).margin(), MAX_MARGIN);
+    }
+
+    #[test]
+    fn test_agents_ordered() {
+        let mut field = Field::new();
+        for (id, status, intensity) in [
+            ("atlas", AgentStatus::Idle, 0.2),
+            ("echo", AgentStatus::Error, 0.1),
+            ("nova", AgentStatus::Active, 0.9),
+        ] {
+            field.process_event(&HiveEvent::AgentUpdate(crate::event::AgentUpdate {
+                agent_id: id.into(),
+                status,
+                focus: Vec::new(),
+                intensity,
+                message: String::new(),
+                timestamp: 0,
+            }));
+        }
+        let now = Instant::now();
+        field.agents.get_mut("echo").unwrap().last_update = now + Duration::from_secs(2);
+        field.agents.get_mut("atlas").unwrap().last_update = now + Duration::from_secs(1);
+
+        let ids = |order| -> Vec<&str> { field.agents_ordered(order).iter().map(|a| &*a.id).collect() };
+        assert_eq!(ids(AgentOrder::Id), ["atlas", "echo", "nova"]);
+        assert_eq!(ids(AgentOrder::Intensity), ["nova", "atlas", "echo"]);
+        assert_eq!(ids(AgentOrder::Status), ["echo", "nova", "atlas"]);
+        assert_eq!(ids(AgentOrder::LastUpdate), ["echo", "atlas", "nova"]);
+    }
+
+    #[test]
+    fn test_connections_are_capped_fading_first() {
+        let mut field = Field::new().with_max_connections(3);
+        field.process_event(&connection("a", "b"));
+        field.process_event(&connection("b", "c"));
+        field.process_event(&connection("c", "d"));
+        field.connections[1].fading_out = true;
+
+        // The fading b-c goes before the older a-b
+        field.process_event(&connection("d", "e"));
+        let pairs: Vec<_> = field.connections.iter().map(|c| &*c.from).collect();
+        assert_eq!(pairs, ["a", "c", "d"]);
+
+        // Then the oldest
+        field.process_event(&connection("e", "f"));
+        let pairs: Vec<_> = field.connections.iter().map(|c| &*c.from).collect();
+        assert_eq!(pairs, ["c", "d", "e"]);
+    }
+
+    #[test]
+    fn test_tick_records_dwell_near_closest_landmark() {
+        use crate::event::{AgentStatus, AgentUpdate, Landmark};
+
+        let mut field = Field::new();
+        field.process_event(&HiveEvent::Landmark(Landmark {
+            id: "data".to_string(),
+            label: "Database".to_string(),
+            keywords: vec!["database".to_string()],
+            timestamp: 0,
+        }));
+        field.process_event(&HiveEvent::AgentUpdate(AgentUpdate {
+            agent_id: "atlas".into(),
+            status: AgentStatus::Active,
+            focus: vec!["frontend".into()],
+            intensity: 0.5,
+            message: String::new(),
+            timestamp: 0,
+        }));
+
+        // Counted even outside the zone radius, and not while paused
+        field.tick(0.5);
+        field.toggle_pause();
+        field.tick(0.5);
+        assert_eq!(field.dwell.for_agent("atlas").unwrap()["data"], Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_frequent_keywords_become_landmarks_until_real_ones_arrive() {
+        use crate::event::{AgentStatus, AgentUpdate, Landmark};
+
+        let update = |focus: &[&str]| {
+            HiveEvent::AgentUpdate(AgentUpdate {
+                agent_id: "atlas".into(),
+                status: AgentStatus::Active,
+                focus: focus.iter().map(|&f| f.into()).collect(),
+                intensity: 0.5,
+                message: String::new(),
+                timestamp: 0,
+            })
+        };
+        let mut field = Field::new().with_auto_landmarks(AutoLandmarks {
+            warmup_updates: 3,
+            count: 2,
+        });
+
+        field.process_event(&update(&["auth", "jwt"]));
+        field.process_event(&update(&["Auth", "cache"]));
+        assert!(field.landmarks.is_empty());
+        field.process_event(&update(&["cache"]));
+
+        let mut labels: Vec<_> = field.landmarks.values().map(|l| l.label.as_str()).collect();
+        labels.sort();
+        assert_eq!(labels, ["auth", "cache"]);
+        assert!(field.landmarks.values().all(|l| l.auto));
+
+        field.process_event(&HiveEvent::Landmark(Landmark {
+            id: "data".to_string(),
+            label: "Database".to_string(),
+            keywords: vec!["database".to_string()],
+            timestamp: 0,
+        }));
+        assert_eq!(field.landmarks.len(), 1);
+        assert!(!field.landmarks["data"].auto);
+    }
+
+    #[test]
+    fn test_agent_cap_evicts_quietest_idle_agent() {
+        use crate::event::AgentUpdate;
+
+        let update = |id: &str, status: AgentStatus| {
+            HiveEvent::AgentUpdate(AgentUpdate {
+                agent_id: id.into(),
+                status,
+                focus: vec![],
+                intensity: 0.5,
+                message: String::new(),
+                timestamp: 0,
+            })
+        };
+        let mut field = Field::new().with_max_agents(3);
+        field.process_event(&update("busy", AgentStatus::Active));
+        field.process_event(&update("old", AgentStatus::Idle));
+        field.process_event(&update("new", AgentStatus::Idle));
+        field.process_event(&connection("old", "busy"));
+
+        // Updates to known agents never evict
+        field.process_event(&update("busy", AgentStatus::Active));
+        assert!(field.take_evicted().is_empty());
+
+        field.agents.get_mut("busy").unwrap().last_update -= Duration::from_secs(10);
+        field.agents.get_mut("old").unwrap().last_update -= Duration::from_secs(5);
+
+        // The busy agent is older, but not idle
+        field.process_event(&update("fresh", AgentStatus::Active));
+        assert_eq!(field.take_evicted(), [Evicted { id: "old".into(), idle: true }]);
+        assert_eq!(field.agents.len(), 3);
+        assert!(field.connections.is_empty());
+        assert!(field.contacts.is_empty());
+
+        // With no agent idle, the least recently updated goes
+        field.process_event(&update("new", AgentStatus::Active));
+        field.process_event(&update("latest", AgentStatus::Active));
+        assert_eq!(field.take_evicted(), [Evicted { id: "busy".into(), idle: false }]);
+    }
+
+    #[test]
+    fn test_intensity_is_smoothed() {
+        use crate::event::AgentUpdate;
+
+        let update = |intensity: f32| {
+            HiveEvent::AgentUpdate(AgentUpdate {
+                agent_id: "atlas".into(),
+                status: AgentStatus::Active,
+                focus: vec![],
+                intensity,
+                message: String::new(),
+                timestamp: 0,
+            })
+        };
+        let mut field = Field::new().with_intensity_smoothing(0.75);
+
+        // The first value is taken as is, then each one moves a quarter of the way
+        field.process_event(&update(0.2));
+        assert_eq!(field.agents["atlas"].intensity, 0.2);
+        field.process_event(&update(1.0));
+        assert!((field.agents["atlas"].intensity - 0.4).abs() < 1e-6);
+        field.process_event(&update(0.0));
+        assert!((field.agents["atlas"].intensity - 0.3).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_trail_budget_drops_oldest_points_across_agents() {
+        use super::super::agent::TrailPoint;
+
+        let mut field = Field::new().with_max_trail_points(5);
+        field.agents.insert("atlas".into(), Agent::new("atlas".into(), 0));
+        field.agents.insert("nova".into(), Agent::new("nova".into(), 1));
+
+        // Atlas moved long ago, nova just now
+        let start = Instant::now();
+        for (id, offsets) in [("atlas", [0, 1, 2, 3]), ("nova", [10, 11, 12, 13])] {
+            let trail = &mut field.agents.get_mut(id).unwrap().trail;
+            for ms in offsets {
+                trail.push_back(TrailPoint {
+                    position: Position::new(0.5, 0.5),
+                    timestamp: start + Duration::from_millis(ms),
+                    intensity: 0.5,
+                });
+            }
+        }
+
+        field.prune_trails();
+        assert_eq!(field.agents["atlas"].trail.len(), 1);
+        assert_eq!(field.agents["nova"].trail.len(), 4);
+        assert_eq!(field.agents["atlas"].trail[0].timestamp, start + Duration::from_millis(3));
+
+        field.max_trail_points = 0;
+        field.prune_trails();
+        assert!(field.agents.values().all(|agent| agent.trail.is_empty()));
+    }
+}
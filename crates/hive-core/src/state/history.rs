@@ -0,0 +1,375 @@
+use std::time::{Duration, Instant};
+
+use crate::event::{HiveEvent, Keyword, TimestampedEvent};
+
+/// History buffer for replay functionality
+pub struct History {
+    events: Vec<TimestampedEvent>,
+    /// Current playback position (index into events)
+    playback_index: usize,
+    /// Whether we're in replay mode
+    pub replay_mode: bool,
+    /// Start time of the replay
+    replay_start: Option<Instant>,
+    /// Time offset into the recording
+    replay_offset: Duration,
+    /// Position sought to, shown until the replay applies the next event
+    sought: Option<f32>,
+}
+
+impl History {
+    pub fn new() -> Self {
+        Self {
+            events: Vec::new(),
+            playback_index: 0,
+            replay_mode: false,
+            replay_start: None,
+            replay_offset: Duration::ZERO,
+            sought: None,
+        }
+    }
+
+    /// Record a new event
+    pub fn record(&mut self, event: HiveEvent) {
+        self.events.push(TimestampedEvent {
+            event,
+            received_at: Instant::now(),
+        });
+    }
+
+    /// Load events from a list (for replay from file)
+    pub fn load_events(&mut self, events: Vec<HiveEvent>) {
+        let now = Instant::now();
+        self.events.clear();
+
+        let first = events.first().map_or(0, HiveEvent::timestamp);
+        let mut offset = Duration::ZERO;
+        for (i, event) in events.into_iter().enumerate() {
+            // Space events out based on their timestamps, or 100ms apart
+            // without them; an event without one stays with the one before
+            offset = match event.timestamp() {
+                _ if first == 0 => Duration::from_millis(i as u64 * 100),
+                0 => offset,
+                timestamp => Duration::from_secs(timestamp.saturating_sub(first)).max(offset),
+            };
+            self.events.push(TimestampedEvent {
+                event,
+                received_at: now + offset,
+            });
+        }
+    }
+
+    /// Get total duration of recorded history
+    pub fn duration(&self) -> Duration {
+        if self.events.is_empty() {
+            return Duration::ZERO;
+        }
+
+        let first = self.events.first().unwrap().received_at;
+        let last = self.events.last().unwrap().received_at;
+        last.duration_since(first)
+    }
+
+    /// Get number of recorded events
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Check if history is empty
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Enter replay mode
+    pub fn start_replay(&mut self) {
+        self.replay_mode = true;
+        self.playback_index = 0;
+        self.replay_start = Some(Instant::now());
+        self.replay_offset = Duration::ZERO;
+        self.sought = None;
+    }
+
+    /// Exit replay mode
+    pub fn stop_replay(&mut self) {
+        self.replay_mode = false;
+        self.replay_start = None;
+    }
+
+    /// Seek to a specific position (0.0 to 1.0) in time; the events up to
+    /// it count as replayed
+    pub fn seek(&mut self, position: f32) {
+        if self.events.is_empty() {
+            return;
+        }
+
+        let position = position.clamp(0.0, 1.0);
+        let target = position as f64 * self.span();
+        self.playback_index = (0..self.events.len())
+            .filter(|&i| self.time_of(i) <= target)
+            .map(|i| i + 1)
+            .max()
+            .unwrap_or(0);
+        self.resume_at_index();
+        self.sought = Some(position);
+    }
+
+    /// Seek to just after the event at `index`, so it has been replayed
+    pub fn seek_to_index(&mut self, index: usize) {
+        if index >= self.events.len() {
+            return;
+        }
+        self.playback_index = index + 1;
+        self.resume_at_index();
+        self.sought = None;
+    }
+
+    /// Restart the replay clock from the last replayed event
+    fn resume_at_index(&mut self) {
+        self.replay_start = Some(Instant::now());
+        self.replay_offset = match self.playback_index.checked_sub(1) {
+            Some(last) => Duration::from_secs_f64(self.time_of(last)),
+            None => Duration::ZERO,
+        };
+    }
+
+    /// Index of the event whose timestamp is closest to `timestamp`, or
+    /// `None` if no event carries one
+    pub fn index_at_timestamp(&self, timestamp: u64) -> Option<usize> {
+        self.events
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| e.event.timestamp() > 0)
+            .min_by_key(|(_, e)| e.event.timestamp().abs_diff(timestamp))
+            .map(|(index, _)| index)
+    }
+
+    /// Index of the event at `position` (0.0 to 1.0) in time of the
+    /// recording: the first one at or after it
+    pub fn index_at(&self, position: f32) -> usize {
+        let target = position.clamp(0.0, 1.0) as f64 * self.span();
+        (0..self.events.len())
+            .find(|&i| self.time_of(i) >= target)
+            .unwrap_or(self.events.len().saturating_sub(1))
+    }
+
+    /// Seconds from the first event to the event at `index`: by the events'
+    /// timestamps when the recording carries them, otherwise by when they
+    /// were received. Events without a timestamp in a timestamped recording
+    /// are placed by their index.
+    fn time_of(&self, index: usize) -> f64 {
+        match self.timestamp_range() {
+            Some((first, _)) => match self.events[index].event.timestamp() {
+                0 => self.span() * index as f64 / (self.events.len() - 1) as f64,
+                timestamp => timestamp.saturating_sub(first) as f64,
+            },
+            None => self.events[index]
+                .received_at
+                .duration_since(self.events[0].received_at)
+                .as_secs_f64(),
+        }
+    }
+
+    /// Seconds covered by the recording, on the same clock as [`time_of`](Self::time_of)
+    fn span(&self) -> f64 {
+        match self.timestamp_range() {
+            Some((first, last)) => (last - first) as f64,
+            None => self.duration().as_secs_f64(),
+        }
+    }
+
+    /// First and last timestamp, if the first and last events carry
+    /// increasing ones
+    fn timestamp_range(&self) -> Option<(u64, u64)> {
+        let first = self.events.first()?.event.timestamp();
+        let last = self.events.last()?.event.timestamp();
+        (first > 0 && last > first).then_some((first, last))
+    }
+
+    /// All recorded events, oldest first
+    pub fn events(&self) -> &[TimestampedEvent] {
+        &self.events
+    }
+
+    /// The last `limit` focus sets of agent `id`, oldest first, up to the
+    /// replay position in replay mode. A set repeated by consecutive updates
+    /// counts once.
+    pub fn focus_trail(&self, id: &str, limit: usize) -> Vec<Vec<Keyword>> {
+        let end = if self.replay_mode { self.playback_index } else { self.events.len() };
+        let mut trail: Vec<Vec<Keyword>> = Vec::new();
+        for event in self.events[..end].iter().rev() {
+            let HiveEvent::AgentUpdate(ref update) = event.event else {
+                continue;
+            };
+            if &*update.agent_id != id || update.focus.is_empty() || trail.last() == Some(&update.focus) {
+                continue;
+            }
+            trail.push(update.focus.clone());
+            if trail.len() == limit {
+                break;
+            }
+        }
+        trail.reverse();
+        trail
+    }
+
+    /// Timestamp of the last event replayed, if it carries one
+    pub fn current_timestamp(&self) -> Option<u64> {
+        let index = self.playback_index.checked_sub(1)?;
+        let timestamp = self.events.get(index)?.event.timestamp();
+        (timestamp > 0).then_some(timestamp)
+    }
+
+    /// Get current playback position (0.0 to 1.0): the time of the last
+    /// replayed event within the recording, so the playhead moves evenly in
+    /// time however dense the events are
+    pub fn position(&self) -> f32 {
+        if let Some(position) = self.sought {
+            return position;
+        }
+        let Some(last) = self.playback_index.checked_sub(1) else {
+            return 0.0;
+        };
+        let span = self.span();
+        if span <= 0.0 {
+            return self.playback_index as f32 / self.events.len() as f32;
+        }
+        (self.time_of(last) / span).clamp(0.0, 1.0) as f32
+    }
+
+    /// Get events to process for the current frame during replay: those
+    /// whose time in the recording (see [`time_of`](Self::time_of)) the
+    /// replay clock has reached, so playback keeps the recording's pacing
+    pub fn get_replay_events(&mut self, speed: f32) -> Vec<HiveEvent> {
+        if !self.replay_mode || self.events.is_empty() {
+            return Vec::new();
+        }
+
+        let Some(start) = self.replay_start else {
+            return Vec::new();
+        };
+
+        let elapsed = (start.elapsed().mul_f32(speed) + self.replay_offset).as_secs_f64();
+
+        let mut events = Vec::new();
+
+        while self.playback_index < self.events.len() {
+            if self.time_of(self.playback_index) <= elapsed {
+                events.push(self.events[self.playback_index].event.clone());
+                self.playback_index += 1;
+                self.sought = None;
+            } else {
+                break;
+            }
+        }
+
+        // Loop back to beginning if we've reached the end
+        if self.playback_index >= self.events.len() {
+            self.playback_index = 0;
+            self.replay_start = Some(Instant::now());
+            self.replay_offset = Duration::ZERO;
+            self.sought = None;
+        }
+
+        events
+    }
+
+    /// Get all events up to the current playback position
+    pub fn get_events_to_position(&self) -> Vec<HiveEvent> {
+        self.events
+            .iter()
+            .take(self.playback_index)
+            .map(|e| e.event.clone())
+            .collect()
+    }
+}
+
+impl Default for History {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{AgentStatus, AgentUpdate};
+
+    fn update(timestamp: u64) -> HiveEvent {
+        HiveEvent::AgentUpdate(AgentUpdate {
+            agent_id: "atlas".into(),
+            status: AgentStatus::Active,
+            focus: vec![],
+            intensity: 0.5,
+            message: String::new(),
+            timestamp,
+        })
+    }
+
+    #[test]
+    fn test_position_follows_timestamps() {
+        // A burst of events in the first 10 seconds, then one at 100s
+        let mut history = History::new();
+        let mut events: Vec<_> = (0..10).map(|i| update(1000 + i)).collect();
+        events.push(update(1100));
+        history.load_events(events);
+        history.start_replay();
+
+        // Halfway in time lies between the burst and the last event
+        assert_eq!(history.index_at(0.5), 10);
+
+        history.seek_to_index(9);
+        assert!((history.position() - 0.09).abs() < 1e-6);
+        history.seek_to_index(10);
+        assert_eq!(history.position(), 1.0);
+
+        // Seeking keeps the sought position and replays what came before it
+        history.seek(0.5);
+        assert_eq!(history.position(), 0.5);
+        assert_eq!(history.get_events_to_position().len(), 10);
+        history.seek(0.51);
+        assert_eq!(history.position(), 0.51);
+    }
+
+    #[test]
+    fn test_replay_keeps_the_pacing_of_timestamps() {
+        // Recorded as they were read from a file, all at once
+        let mut history = History::new();
+        for i in 0..10 {
+            history.record(update(1000 + i));
+        }
+        history.record(update(1100));
+        history.start_replay();
+        history.seek_to_index(0);
+
+        // 20x speed for 100ms covers about two seconds of the recording: a
+        // few of the burst, never the event 100 seconds in
+        std::thread::sleep(Duration::from_millis(100));
+        let replayed = history.get_replay_events(20.0);
+        assert!((1..10).contains(&replayed.len()), "replayed {}", replayed.len());
+        assert!(history.position() < 0.1);
+
+        // Loaded events are spaced by their timestamps too
+        let mut loaded = History::new();
+        loaded.load_events(vec![update(1000), update(0), update(1100)]);
+        assert_eq!(loaded.duration(), Duration::from_secs(100));
+    }
+
+    #[test]
+    fn test_focus_trail() {
+        let mut history = History::new();
+        for (i, focus) in ["auth", "auth", "database", "tests", "auth"].into_iter().enumerate() {
+            let HiveEvent::AgentUpdate(mut update) = update(i as u64) else {
+                unreachable!();
+            };
+            update.focus = vec![focus.into()];
+            history.record(HiveEvent::AgentUpdate(update));
+        }
+        history.record(update(9));
+
+        let trail = history.focus_trail("atlas", 3);
+        let trail: Vec<Vec<&str>> = trail.iter().map(|focus| focus.iter().map(|k| &**k).collect()).collect();
+        assert_eq!(trail, [vec!["database"], vec!["tests"], vec!["auth"]]);
+        assert_eq!(history.focus_trail("atlas", 10).len(), 4);
+        assert!(history.focus_trail("nova", 3).is_empty());
+    }
+}
@@ -0,0 +1,15 @@
+pub mod activity;
+pub mod agent;
+pub mod clusters;
+pub mod convergence;
+pub mod dwell;
+pub mod field;
+pub mod history;
+
+pub use activity::ActivityWindow;
+pub use agent::{Agent, Waypoint};
+pub use clusters::Cluster;
+pub use convergence::Convergence;
+pub use dwell::DwellTimes;
+pub use field::{AgentOrder, Evicted, Field};
+pub use history::History;
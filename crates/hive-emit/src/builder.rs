@@ -0,0 +1,194 @@
+//! Event builders
+
+use std::io;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use hive_core::event::{AgentId, AgentStatus, AgentUpdate, Connection, HiveEvent, Keyword, Landmark};
+
+use crate::emitter::HiveEmitter;
+
+/// Current Unix timestamp in seconds
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+fn strings<I, S>(items: I) -> Vec<String>
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    items.into_iter().map(Into::into).collect()
+}
+
+/// Builder for an `agent_update` event
+#[derive(Debug, Clone)]
+pub struct AgentUpdateBuilder {
+    update: AgentUpdate,
+}
+
+impl AgentUpdateBuilder {
+    pub fn new(agent_id: impl Into<AgentId>) -> Self {
+        Self {
+            update: AgentUpdate {
+                agent_id: agent_id.into(),
+                status: AgentStatus::default(),
+                focus: Vec::new(),
+                intensity: 0.5,
+                message: String::new(),
+                timestamp: now(),
+            },
+        }
+    }
+
+    pub fn status(mut self, status: AgentStatus) -> Self {
+        self.update.status = status;
+        self
+    }
+
+    /// Keywords describing the current work area
+    pub fn focus<I, S>(mut self, focus: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Keyword>,
+    {
+        self.update.focus = focus.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Work intensity, clamped to 0.0-1.0
+    pub fn intensity(mut self, intensity: f32) -> Self {
+        self.update.intensity = intensity.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.update.message = message.into();
+        self
+    }
+
+    /// Override the timestamp (defaults to now)
+    pub fn timestamp(mut self, timestamp: u64) -> Self {
+        self.update.timestamp = timestamp;
+        self
+    }
+
+    pub fn build(self) -> HiveEvent {
+        HiveEvent::AgentUpdate(self.update)
+    }
+
+    /// Write through the process-wide emitter
+    pub fn send(self) -> io::Result<()> {
+        HiveEmitter::global()?.emit(&self.build())
+    }
+
+    pub fn send_to(self, emitter: &HiveEmitter) -> io::Result<()> {
+        emitter.emit(&self.build())
+    }
+}
+
+/// Builder for a `connection` event
+#[derive(Debug, Clone)]
+pub struct ConnectionBuilder {
+    connection: Connection,
+}
+
+impl ConnectionBuilder {
+    pub fn new(from: impl Into<AgentId>, to: impl Into<AgentId>) -> Self {
+        Self {
+            connection: Connection {
+                from: from.into(),
+                to: to.into(),
+                label: String::new(),
+                timestamp: now(),
+                ttl_ms: None,
+            },
+        }
+    }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.connection.label = label.into();
+        self
+    }
+
+    /// Override the timestamp (defaults to now)
+    pub fn timestamp(mut self, timestamp: u64) -> Self {
+        self.connection.timestamp = timestamp;
+        self
+    }
+
+    /// How long the connection stays visible (defaults to 3 seconds)
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.connection.ttl_ms = Some(ttl.as_millis() as u64);
+        self
+    }
+
+    pub fn build(self) -> HiveEvent {
+        HiveEvent::Connection(self.connection)
+    }
+
+    /// Write through the process-wide emitter
+    pub fn send(self) -> io::Result<()> {
+        HiveEmitter::global()?.emit(&self.build())
+    }
+
+    pub fn send_to(self, emitter: &HiveEmitter) -> io::Result<()> {
+        emitter.emit(&self.build())
+    }
+}
+
+/// Builder for a `landmark` event
+#[derive(Debug, Clone)]
+pub struct LandmarkBuilder {
+    landmark: Landmark,
+}
+
+impl LandmarkBuilder {
+    /// The label defaults to the id
+    pub fn new(id: impl Into<String>) -> Self {
+        let id = id.into();
+        Self {
+            landmark: Landmark {
+                label: id.clone(),
+                id,
+                keywords: Vec::new(),
+                timestamp: now(),
+            },
+        }
+    }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.landmark.label = label.into();
+        self
+    }
+
+    pub fn keywords<I, S>(mut self, keywords: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.landmark.keywords = strings(keywords);
+        self
+    }
+
+    /// Override the timestamp (defaults to now)
+    pub fn timestamp(mut self, timestamp: u64) -> Self {
+        self.landmark.timestamp = timestamp;
+        self
+    }
+
+    pub fn build(self) -> HiveEvent {
+        HiveEvent::Landmark(self.landmark)
+    }
+
+    /// Write through the process-wide emitter
+    pub fn send(self) -> io::Result<()> {
+        HiveEmitter::global()?.emit(&self.build())
+    }
+
+    pub fn send_to(self, emitter: &HiveEmitter) -> io::Result<()> {
+        emitter.emit(&self.build())
+    }
+}
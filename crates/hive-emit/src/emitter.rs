@@ -0,0 +1,193 @@
+//! Event sinks
+
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+use hive_core::event::{AgentId, HiveEvent};
+
+use crate::builder::{AgentUpdateBuilder, ConnectionBuilder, LandmarkBuilder};
+
+/// Environment variable naming the default sink
+pub const EVENTS_ENV: &str = "HIVE_EVENTS";
+
+/// File name used when `HIVE_EVENTS` is not set
+const DEFAULT_FILE: &str = "hive-events.jsonl";
+
+static GLOBAL: OnceLock<HiveEmitter> = OnceLock::new();
+
+/// Writes Hive events as JSON lines to a file or socket.
+///
+/// Each event is serialized and written with a single `write_all`, so
+/// concurrent emitters appending to the same file do not interleave lines.
+pub struct HiveEmitter {
+    sink: Mutex<Box<dyn Write + Send>>,
+}
+
+impl HiveEmitter {
+    /// Append events to a file, creating it if needed.
+    pub fn file(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self::from_writer(file))
+    }
+
+    /// Stream events to a Unix domain socket.
+    #[cfg(unix)]
+    pub fn unix_socket(path: impl AsRef<Path>) -> io::Result<Self> {
+        let stream = std::os::unix::net::UnixStream::connect(path)?;
+        Ok(Self::from_writer(stream))
+    }
+
+    /// Write events to any writer.
+    pub fn from_writer(writer: impl Write + Send + 'static) -> Self {
+        Self {
+            sink: Mutex::new(Box::new(writer)),
+        }
+    }
+
+    /// Open a sink from a target string.
+    ///
+    /// Accepts `unix:///path/to.sock` (Unix only) or a plain file path.
+    pub fn open(target: &str) -> io::Result<Self> {
+        if let Some(path) = target.strip_prefix("unix://") {
+            #[cfg(unix)]
+            return Self::unix_socket(path);
+            #[cfg(not(unix))]
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("unix sockets are not supported on this platform: {}", path),
+            ));
+        }
+        Self::file(target)
+    }
+
+    /// Open the sink named by `HIVE_EVENTS`, or the default events file.
+    pub fn from_env() -> io::Result<Self> {
+        match std::env::var(EVENTS_ENV) {
+            Ok(target) if !target.is_empty() => Self::open(&target),
+            _ => Self::file(default_path()),
+        }
+    }
+
+    /// Make this the process-wide emitter used by `send()`.
+    ///
+    /// Returns the emitter back if one was already installed or opened.
+    pub fn install(self) -> Result<(), Self> {
+        GLOBAL.set(self)
+    }
+
+    /// The process-wide emitter, opened from the environment on first use.
+    pub fn global() -> io::Result<&'static HiveEmitter> {
+        if let Some(emitter) = GLOBAL.get() {
+            return Ok(emitter);
+        }
+        let emitter = Self::from_env()?;
+        // Another thread may have won the race; either emitter is fine
+        let _ = GLOBAL.set(emitter);
+        Ok(GLOBAL.get().expect("global emitter initialized"))
+    }
+
+    /// Write a single event.
+    pub fn emit(&self, event: &HiveEvent) -> io::Result<()> {
+        let mut line = serde_json::to_string(event)?;
+        line.push('\n');
+
+        let mut sink = self
+            .sink
+            .lock()
+            .map_err(|_| io::Error::other("emitter lock poisoned"))?;
+        sink.write_all(line.as_bytes())?;
+        sink.flush()
+    }
+
+    /// Start an agent update.
+    pub fn agent(agent_id: impl Into<AgentId>) -> AgentUpdateBuilder {
+        AgentUpdateBuilder::new(agent_id)
+    }
+
+    /// Start a connection between two agents.
+    pub fn connection(from: impl Into<AgentId>, to: impl Into<AgentId>) -> ConnectionBuilder {
+        ConnectionBuilder::new(from, to)
+    }
+
+    /// Start a landmark definition.
+    pub fn landmark(id: impl Into<String>) -> LandmarkBuilder {
+        LandmarkBuilder::new(id)
+    }
+}
+
+/// Default events file used when `HIVE_EVENTS` is not set
+pub fn default_path() -> PathBuf {
+    std::env::temp_dir().join(DEFAULT_FILE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AgentStatus;
+    use std::sync::Arc;
+
+    /// Writer that shares its buffer with the test
+    #[derive(Clone, Default)]
+    struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_emits_one_parseable_line_per_event() {
+        let buf = SharedBuf::default();
+        let emitter = HiveEmitter::from_writer(buf.clone());
+
+        HiveEmitter::agent("atlas")
+            .status(AgentStatus::Error)
+            .focus(["auth"])
+            .send_to(&emitter)
+            .unwrap();
+        HiveEmitter::connection("atlas", "builder-1")
+            .send_to(&emitter)
+            .unwrap();
+
+        let output = String::from_utf8(buf.0.lock().unwrap().clone()).unwrap();
+        let lines: Vec<_> = output.lines().collect();
+        assert_eq!(lines.len(), 2);
+
+        let event: HiveEvent = serde_json::from_str(lines[0]).unwrap();
+        match event {
+            HiveEvent::AgentUpdate(u) => {
+                assert_eq!(&*u.agent_id, "atlas");
+                assert_eq!(u.status, AgentStatus::Error);
+                assert_eq!(u.focus, vec!["auth".into()]);
+            }
+            _ => panic!("Wrong event type"),
+        }
+        assert!(matches!(
+            serde_json::from_str(lines[1]).unwrap(),
+            HiveEvent::Connection(_)
+        ));
+    }
+
+    #[test]
+    fn test_file_sink_appends() {
+        let path =
+            std::env::temp_dir().join(format!("hive-emit-test-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        for _ in 0..2 {
+            let emitter = HiveEmitter::open(path.to_str().unwrap()).unwrap();
+            HiveEmitter::landmark("db").send_to(&emitter).unwrap();
+        }
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(contents.lines().count(), 2);
+    }
+}
@@ -0,0 +1,36 @@
+//! Emit Hive events from your own agents.
+//!
+//! Hive reads newline-delimited JSON events. This crate builds those events
+//! with a small builder API and writes them to a file or a Unix socket, so an
+//! orchestrator can be instrumented without hand-rolling JSON:
+//!
+//! ```no_run
+//! use hive_emit::{AgentStatus, HiveEmitter};
+//!
+//! # fn main() -> std::io::Result<()> {
+//! HiveEmitter::agent("atlas")
+//!     .status(AgentStatus::Active)
+//!     .focus(["auth", "jwt"])
+//!     .intensity(0.8)
+//!     .message("Reviewing token refresh")
+//!     .send()?;
+//!
+//! HiveEmitter::connection("atlas", "builder-1")
+//!     .label("handing off schema")
+//!     .send()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! `send()` writes to the process-wide emitter. Unless one was installed with
+//! [`HiveEmitter::install`], it is opened on first use from the `HIVE_EVENTS`
+//! environment variable (see [`HiveEmitter::open`] for the accepted forms),
+//! falling back to `hive-events.jsonl` in the system temp directory. Use
+//! `send_to(&emitter)` to write through a specific emitter instead.
+
+pub mod builder;
+pub mod emitter;
+
+pub use builder::{AgentUpdateBuilder, ConnectionBuilder, LandmarkBuilder};
+pub use emitter::HiveEmitter;
+pub use hive_core::event::{AgentStatus, HiveEvent};
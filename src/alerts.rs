@@ -0,0 +1,374 @@
+//! Alert rules evaluated against the live field.
+//!
+//! Rules are edge-triggered: each fires once when its condition becomes true
+//! and re-arms after the condition clears, so a stuck agent does not ring the
+//! bell every frame.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+
+use hive_core::event::{AgentId, AgentStatus};
+use hive_core::state::Agent;
+
+/// `[alerts]` section of the config file
+///
+/// Threshold values of 0 disable the corresponding rule.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AlertConfig {
+    /// Master switch for all rules
+    pub enabled: bool,
+    /// Ring the terminal bell when an alert fires
+    pub bell: bool,
+    /// Also send a desktop notification (notify-send / osascript)
+    pub desktop: bool,
+    /// Alert when an agent stays in the error state this long
+    pub error_after_secs: u64,
+    /// Alert when no events arrive for this long
+    pub silence_after_secs: u64,
+    /// Intensity above which an agent counts as running hot
+    pub intensity_threshold: f32,
+    /// Alert when at least this many agents run hot at once
+    pub intensity_agents: usize,
+    /// POST each alert as JSON to this URL (Slack incoming-webhook compatible)
+    pub webhook_url: Option<String>,
+}
+
+impl Default for AlertConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            bell: true,
+            desktop: false,
+            error_after_secs: 10,
+            silence_after_secs: 60,
+            intensity_threshold: 0.9,
+            intensity_agents: 3,
+            webhook_url: None,
+        }
+    }
+}
+
+/// Which rule produced an alert
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AlertRule {
+    /// Agent has been in the error state too long
+    AgentError(AgentId),
+    /// No events received for a while
+    Silence,
+    /// Too many agents above the intensity threshold
+    HighIntensity,
+}
+
+impl AlertRule {
+    /// Stable identifier used in webhook payloads
+    pub fn name(&self) -> &'static str {
+        match self {
+            AlertRule::AgentError(_) => "agent_error",
+            AlertRule::Silence => "silence",
+            AlertRule::HighIntensity => "high_intensity",
+        }
+    }
+}
+
+/// A fired alert
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alert {
+    pub rule: AlertRule,
+    pub message: String,
+}
+
+/// Evaluates alert rules and remembers which ones are currently firing
+pub struct AlertEngine {
+    config: AlertConfig,
+    /// When each agent was first seen in the error state
+    error_since: HashMap<AgentId, Instant>,
+    /// Rules whose condition currently holds (already alerted)
+    firing: HashSet<AlertRule>,
+    last_event: Instant,
+}
+
+impl AlertEngine {
+    pub fn new(config: AlertConfig) -> Self {
+        Self {
+            config,
+            error_since: HashMap::new(),
+            firing: HashSet::new(),
+            last_event: Instant::now(),
+        }
+    }
+
+    pub fn config(&self) -> &AlertConfig {
+        &self.config
+    }
+
+    /// Note that a live event arrived.
+    pub fn record_event(&mut self, now: Instant) {
+        self.last_event = now;
+    }
+
+    /// Check every rule and return the alerts that just started firing.
+    pub fn check<'a>(
+        &mut self,
+        agents: impl IntoIterator<Item = &'a Agent>,
+        now: Instant,
+    ) -> Vec<Alert> {
+        if !self.config.enabled {
+            return Vec::new();
+        }
+
+        let mut holding = Vec::new();
+        let mut hot_agents = 0;
+
+        let mut erroring = HashSet::new();
+        for agent in agents {
+            if agent.status == AgentStatus::Error {
+                erroring.insert(agent.id.clone());
+                let since = *self.error_since.entry(agent.id.clone()).or_insert(now);
+                let limit = self.config.error_after_secs;
+                if limit > 0 && now.duration_since(since) >= Duration::from_secs(limit) {
+                    holding.push(Alert {
+                        rule: AlertRule::AgentError(agent.id.clone()),
+                        message: format!("{} in error for {}s", agent.id, limit),
+                    });
+                }
+            }
+            if agent.intensity > self.config.intensity_threshold {
+                hot_agents += 1;
+            }
+        }
+        self.error_since.retain(|id, _| erroring.contains(id));
+
+        let silence = self.config.silence_after_secs;
+        if silence > 0 && now.duration_since(self.last_event) >= Duration::from_secs(silence) {
+            holding.push(Alert {
+                rule: AlertRule::Silence,
+                message: format!("No events for {}s", silence),
+            });
+        }
+
+        let min_hot = self.config.intensity_agents;
+        if min_hot > 0 && hot_agents >= min_hot {
+            holding.push(Alert {
+                rule: AlertRule::HighIntensity,
+                message: format!(
+                    "{} agents above {:.0}% intensity",
+                    hot_agents,
+                    self.config.intensity_threshold * 100.0
+                ),
+            });
+        }
+
+        // Re-arm rules that stopped holding, report the ones that just started
+        let now_holding: HashSet<_> = holding.iter().map(|a| a.rule.clone()).collect();
+        self.firing.retain(|rule| now_holding.contains(rule));
+        holding
+            .into_iter()
+            .filter(|alert| self.firing.insert(alert.rule.clone()))
+            .collect()
+    }
+}
+
+/// Show a desktop notification using the platform's notifier.
+///
+/// Best effort: failures (no notifier installed, no session bus) are ignored.
+pub fn send_desktop_notification(title: &str, body: &str) {
+    use std::process::{Command, Stdio};
+
+    let mut command = if cfg!(target_os = "macos") {
+        let script = format!("display notification {:?} with title {:?}", body, title);
+        let mut command = Command::new("osascript");
+        command.arg("-e").arg(script);
+        command
+    } else {
+        let mut command = Command::new("notify-send");
+        command.arg("--app-name=hive").arg(title).arg(body);
+        command
+    };
+
+    let spawned = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+    // Reaped off the UI thread so it does not linger as a zombie
+    if let Ok(mut child) = spawned {
+        std::thread::spawn(move || {
+            let _ = child.wait();
+        });
+    }
+}
+
+/// Posts alerts to a webhook in the background
+pub struct Webhook {
+    url: String,
+    client: reqwest::Client,
+    errors_tx: mpsc::Sender<String>,
+    errors_rx: mpsc::Receiver<String>,
+}
+
+impl Webhook {
+    pub fn new(url: String) -> Self {
+        let (errors_tx, errors_rx) = mpsc::channel();
+        Self {
+            url,
+            client: reqwest::Client::new(),
+            errors_tx,
+            errors_rx,
+        }
+    }
+
+    /// Send an alert without blocking the render loop.
+    ///
+    /// Must be called from within a tokio runtime. Delivery failures are
+    /// reported through [`take_errors`](Self::take_errors).
+    pub fn send(&self, alert: &Alert) {
+        let request = self
+            .client
+            .post(&self.url)
+            .timeout(Duration::from_secs(10))
+            .json(&webhook_payload(alert));
+        let errors = self.errors_tx.clone();
+
+        tokio::spawn(async move {
+            let result = request.send().await.and_then(|r| r.error_for_status());
+            if let Err(e) = result {
+                let _ = errors.send(format!("webhook failed: {}", e));
+            }
+        });
+    }
+
+    /// Delivery errors since the last call
+    pub fn take_errors(&self) -> Vec<String> {
+        self.errors_rx.try_iter().collect()
+    }
+}
+
+/// JSON body for an alert webhook.
+///
+/// `text` is what Slack (and Slack-compatible receivers such as Mattermost or
+/// Discord's `/slack` endpoint) display; the remaining keys are for custom
+/// receivers.
+pub fn webhook_payload(alert: &Alert) -> serde_json::Value {
+    let mut payload = serde_json::json!({
+        "text": format!(":rotating_light: Hive alert: {}", alert.message),
+        "rule": alert.rule.name(),
+        "message": alert.message,
+        "timestamp": std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs(),
+    });
+    if let AlertRule::AgentError(ref agent_id) = alert.rule {
+        payload["agent_id"] = agent_id[..].into();
+    }
+    payload
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn agent(id: &str, status: AgentStatus, intensity: f32) -> Agent {
+        let mut agent = Agent::new(id.into(), 0);
+        agent.status = status;
+        agent.intensity = intensity;
+        agent
+    }
+
+    #[test]
+    fn test_error_rule_fires_once_after_threshold() {
+        let mut engine = AlertEngine::new(AlertConfig::default());
+        let start = Instant::now();
+        engine.record_event(start);
+        let agents = [agent("atlas", AgentStatus::Error, 0.5)];
+
+        assert!(engine.check(&agents, start).is_empty());
+        assert!(engine
+            .check(&agents, start + Duration::from_secs(5))
+            .is_empty());
+
+        let fired = engine.check(&agents, start + Duration::from_secs(10));
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].rule, AlertRule::AgentError("atlas".into()));
+
+        // Still erroring: no repeat
+        assert!(engine
+            .check(&agents, start + Duration::from_secs(11))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_error_rule_rearms_after_recovery() {
+        let mut engine = AlertEngine::new(AlertConfig::default());
+        let start = Instant::now();
+        engine.record_event(start);
+        let erroring = [agent("atlas", AgentStatus::Error, 0.5)];
+        let recovered = [agent("atlas", AgentStatus::Active, 0.5)];
+
+        engine.check(&erroring, start);
+        assert_eq!(
+            engine
+                .check(&erroring, start + Duration::from_secs(10))
+                .len(),
+            1
+        );
+
+        engine.record_event(start + Duration::from_secs(12));
+        engine.check(&recovered, start + Duration::from_secs(12));
+        engine.check(&erroring, start + Duration::from_secs(13));
+        assert_eq!(
+            engine
+                .check(&erroring, start + Duration::from_secs(23))
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_silence_and_intensity_rules() {
+        let mut engine = AlertEngine::new(AlertConfig::default());
+        let start = Instant::now();
+        engine.record_event(start);
+
+        let hot: Vec<_> = (0..3)
+            .map(|i| agent(&format!("a{}", i), AgentStatus::Active, 0.95))
+            .collect();
+        let fired = engine.check(&hot, start);
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].rule, AlertRule::HighIntensity);
+
+        let fired = engine.check(&hot, start + Duration::from_secs(60));
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].rule, AlertRule::Silence);
+    }
+
+    #[test]
+    fn test_zero_threshold_disables_rule() {
+        let config = AlertConfig {
+            silence_after_secs: 0,
+            ..AlertConfig::default()
+        };
+        let mut engine = AlertEngine::new(config);
+        let start = Instant::now();
+        engine.record_event(start);
+        assert!(engine
+            .check([], start + Duration::from_secs(3600))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_webhook_payload_is_slack_compatible() {
+        let alert = Alert {
+            rule: AlertRule::AgentError("atlas".into()),
+            message: "atlas in error for 10s".to_string(),
+        };
+        let payload = webhook_payload(&alert);
+        assert!(payload["text"].as_str().unwrap().contains("atlas in error for 10s"));
+        assert_eq!(payload["rule"], "agent_error");
+        assert_eq!(payload["agent_id"], "atlas");
+    }
+}
@@ -0,0 +1,84 @@
+//! Friendly display names for agents.
+//!
+//! Machine-generated agent IDs (`worker-7f3a9c`) are hard to follow on the
+//! field. `[[aliases]]` entries in the config file map IDs matching a regex to
+//! a display name; the first matching rule wins and `$1`-style references pull
+//! in capture groups. Events and selection keep using the real ID.
+//!
+//! ```toml
+//! [[aliases]]
+//! pattern = "^worker-([0-9a-f]{6})$"
+//! name = "Worker $1"
+//! ```
+
+use regex::Regex;
+use serde::Deserialize;
+
+/// One `[[aliases]]` entry of the config file
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AliasRule {
+    /// Regex matched against the agent ID
+    pub pattern: String,
+    /// Display name; may reference capture groups as `$1` or `${name}`
+    pub name: String,
+}
+
+/// Compiled alias rules
+#[derive(Debug, Clone, Default)]
+pub struct AliasMap {
+    rules: Vec<(Regex, String)>,
+}
+
+impl AliasMap {
+    /// Compile `rules`, failing on the first invalid pattern.
+    pub fn new(rules: &[AliasRule]) -> Result<Self, regex::Error> {
+        let rules = rules
+            .iter()
+            .map(|rule| Ok((Regex::new(&rule.pattern)?, rule.name.clone())))
+            .collect::<Result<_, regex::Error>>()?;
+        Ok(Self { rules })
+    }
+
+    /// Display name for `agent_id`, if a rule matches it.
+    pub fn resolve(&self, agent_id: &str) -> Option<String> {
+        self.rules.iter().find_map(|(pattern, name)| {
+            let captures = pattern.captures(agent_id)?;
+            let mut resolved = String::new();
+            captures.expand(name, &mut resolved);
+            Some(resolved)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(pattern: &str, name: &str) -> AliasRule {
+        AliasRule {
+            pattern: pattern.to_string(),
+            name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_first_matching_rule_wins() {
+        let aliases = AliasMap::new(&[
+            rule("^worker-([0-9a-f]+)$", "Worker $1"),
+            rule("^worker-", "Some worker"),
+            rule("^planner$", "Planner"),
+        ])
+        .unwrap();
+
+        assert_eq!(aliases.resolve("worker-7f3a9c").as_deref(), Some("Worker 7f3a9c"));
+        assert_eq!(aliases.resolve("worker-xyz").as_deref(), Some("Some worker"));
+        assert_eq!(aliases.resolve("planner").as_deref(), Some("Planner"));
+        assert_eq!(aliases.resolve("tester"), None);
+    }
+
+    #[test]
+    fn test_invalid_pattern_is_rejected() {
+        assert!(AliasMap::new(&[rule("worker-(", "Worker")]).is_err());
+    }
+}
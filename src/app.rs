@@ -1,5 +1,7 @@
+use std::collections::VecDeque;
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 use crossterm::{
     event::{DisableMouseCapture, EnableMouseCapture},
@@ -14,33 +16,400 @@ use ratatui::{
     Terminal,
 };
 
-use crate::animation::AnimationLoop;
-use crate::event::{create_event_queue, EventReceiver, FileWatcher, HiveEvent};
-use crate::input::{InputEvent, InputHandler};
+use crate::alerts::{
+    send_desktop_notification, Alert, AlertConfig, AlertEngine, AlertRule, Webhook,
+};
+use crate::alias::AliasMap;
+use crate::audio::{AudioConfig, Cue};
+use crate::backfill::Since;
+use crate::collisions::{Collision, CollisionPolicy, IdCollisions};
+use crate::config::RecentFiles;
+use crate::demo::{DemoCommand, IncidentKind};
+use crate::filter::AgentFilter;
+use crate::input::{Hover, HoverConfig, InputEvent, InputHandler, Pane};
+use crate::landmarks::LandmarkEdits;
+use crate::remap::Remapper;
 use crate::render::{
-    ActivityLog, ActivityLogWidget, DisplayMode, EmptyStateType, EmptyStateWidget,
-    HeatMap, LayerRenderer, LayerVisibility, RenderLayer, RenderState,
+    detect_unicode, find_agents, session_summary, ActivityLog, ActivityLogWidget, AgentSearch,
+    AgentSearchWidget, ColorMode, ColorVision, CommandLine, ConnectionMatrix, CustomDisplayMode,
+    DisplayMode, EditorAction, EmptyStateType, EmptyStateWidget, FieldConfig, FieldTransform,
+    FileBrowser, FileBrowserWidget, FrameProfile, GhostsWidget, HeatAccumulation, HeatMap,
+    HeatRange, HeatSource, HeatmapConfig, HitBuffers, HitTarget, Intro, IntroWidget,
+    LandmarkEditor, LandmarkEditorWidget, LayerRenderer, LayerVisibility, Leaderboard,
+    LeaderboardConfig, LoadingWidget, LogSearch, Panel, ProfileWidget, RenderLayer, RenderState,
+    SearchAction, StatsOverlay, StatusBarConfig, TimestampStyle, ToastWidget, Toasts,
+    TooSmallWidget, ZOrderConfig,
+};
+use crate::report::StatsReport;
+use crate::sampling::{Sampler, SamplingRules};
+use crate::saved_state::SavedState;
+use crate::seek::SeekTarget;
+use crate::telemetry::EventMeter;
+use crate::terminal::{self, TerminalCaps, TerminalConfig, TerminalStatus};
+use crate::watchdog::{Degradation, Watchdog, WatchdogConfig};
+use crate::workspace::{Workspace, Workspaces};
+use hive_core::animation::{AnimationLoop, TARGET_FPS, TICK_STEP};
+#[cfg(feature = "http")]
+use hive_core::event::EventStream;
+#[cfg(feature = "grpc")]
+use hive_core::event::GrpcServer;
+#[cfg(feature = "kafka")]
+use hive_core::event::KafkaConsumer;
+#[cfg(feature = "nats")]
+use hive_core::event::NatsSubscriber;
+#[cfg(feature = "redis")]
+use hive_core::event::RedisSubscriber;
+#[cfg(unix)]
+use hive_core::event::SocketListener;
+#[cfg(feature = "ws")]
+use hive_core::event::WebSocketServer;
+use hive_core::event::{
+    create_event_queue, AgentId, AgentStatus, EventFormat, EventReceiver, FileWatcher, HiveEvent,
+    InitialLoad,
 };
-use crate::state::{Field, History};
+use hive_core::positioning::Position;
+use hive_core::state::field::{
+    AutoLandmarks, StoredLandmark, DEFAULT_MAX_AGENTS, DEFAULT_MAX_CONNECTIONS,
+    DEFAULT_MAX_TRAIL_POINTS,
+};
+use hive_core::state::{AgentOrder, Evicted, Field, History};
+
+/// One event source feeding the field
+struct Source {
+    /// Prefix for the agent IDs of this source, when sources are tagged
+    tag: Option<String>,
+    events: EventReceiver,
+    /// Keeps the file watched; `None` for the demo generator
+    watcher: Option<FileWatcher>,
+    /// Keeps the endpoint open, for the `--ws`, `--socket`, `--grpc`, `--url`,
+    /// `--redis`, `--nats` and `--kafka` sources
+    endpoint: Option<Endpoint>,
+    /// File name, for messages about it
+    name: String,
+    /// Unparseable lines reported so far while following the file
+    skipped: usize,
+    /// Lost connections reported so far, for `--url`, `--redis`, `--nats` and
+    /// `--kafka`
+    dropped: usize,
+    /// Still reading the backlog of a `--kafka --from-beginning` topic
+    replaying: bool,
+}
+
+impl Source {
+    /// Unparseable lines seen so far while following the source
+    fn skipped_lines(&self) -> usize {
+        let watched = self.watcher.as_ref().map_or(0, FileWatcher::skipped_lines);
+        watched + self.endpoint.as_ref().map_or(0, Endpoint::skipped_lines)
+    }
+}
+
+/// Listener that agents push events to, or a stream Hive follows, kept open
+/// while files are switched
+enum Endpoint {
+    #[cfg(feature = "ws")]
+    WebSocket(WebSocketServer),
+    #[cfg(feature = "http")]
+    Stream(EventStream),
+    #[cfg(feature = "redis")]
+    Redis(RedisSubscriber),
+    #[cfg(unix)]
+    Socket(SocketListener),
+    #[cfg(feature = "grpc")]
+    Grpc(GrpcServer),
+    #[cfg(feature = "nats")]
+    Nats(NatsSubscriber),
+    #[cfg(feature = "kafka")]
+    Kafka(KafkaConsumer),
+}
+
+impl Endpoint {
+    fn skipped_lines(&self) -> usize {
+        match self {
+            #[cfg(feature = "ws")]
+            Endpoint::WebSocket(server) => server.skipped_lines(),
+            #[cfg(feature = "http")]
+            Endpoint::Stream(stream) => stream.skipped_lines(),
+            #[cfg(feature = "redis")]
+            Endpoint::Redis(subscriber) => subscriber.skipped_lines(),
+            #[cfg(unix)]
+            Endpoint::Socket(listener) => listener.skipped_lines(),
+            #[cfg(feature = "grpc")]
+            Endpoint::Grpc(server) => server.skipped_lines(),
+            #[cfg(feature = "nats")]
+            Endpoint::Nats(subscriber) => subscriber.skipped_lines(),
+            #[cfg(feature = "kafka")]
+            Endpoint::Kafka(consumer) => consumer.skipped_lines(),
+        }
+    }
+
+    /// Times the connection was lost; listeners never lose theirs
+    fn drops(&self) -> usize {
+        match self {
+            #[cfg(feature = "http")]
+            Endpoint::Stream(stream) => stream.drops(),
+            #[cfg(feature = "redis")]
+            Endpoint::Redis(subscriber) => subscriber.drops(),
+            #[cfg(feature = "nats")]
+            Endpoint::Nats(subscriber) => subscriber.drops(),
+            #[cfg(feature = "kafka")]
+            Endpoint::Kafka(consumer) => consumer.drops(),
+            _ => 0,
+        }
+    }
+
+    /// Whether the events sent are still from a backlog rather than live
+    fn replaying(&self) -> bool {
+        match self {
+            #[cfg(feature = "kafka")]
+            Endpoint::Kafka(consumer) => consumer.replaying(),
+            _ => false,
+        }
+    }
+}
+
+/// Existing events of the open files, read in the background and then
+/// applied a chunk per frame
+struct Loading {
+    /// Reads still running, by source index
+    reads: Vec<(usize, InitialLoad)>,
+    /// Events of the finished reads, by source index
+    read: Vec<(usize, HiveEvent)>,
+    /// Bytes of the finished reads, and of all reads
+    bytes_done: u64,
+    bytes_total: u64,
+    /// Events sorted by timestamp, left to apply once all reads are done
+    pending: VecDeque<(usize, HiveEvent)>,
+    /// Events applied so far
+    applied: usize,
+    /// Events older than `--since`
+    stale: usize,
+    /// Oldest event timestamp kept, with `--since`
+    cutoff: Option<u64>,
+}
+
+impl Loading {
+    /// What is going on and how far along it is
+    fn progress(&self) -> (String, f32) {
+        if self.reads.is_empty() {
+            let total = self.applied + self.pending.len();
+            let share = if total == 0 { 1.0 } else { self.applied as f32 / total as f32 };
+            return (format!("Applying {} of {} events", self.applied, total), share);
+        }
+        let done = self.bytes_done + self.reads.iter().map(|(_, read)| read.bytes().0).sum::<u64>();
+        let share = if self.bytes_total == 0 { 1.0 } else { done as f32 / self.bytes_total as f32 };
+        let mb = |bytes: u64| bytes as f64 / 1_000_000.0;
+        (format!("Reading {:.1} of {:.1} MB", mb(done), mb(self.bytes_total)), share)
+    }
+}
+
+/// Existing events applied to the field per frame while loading
+const LOAD_CHUNK: usize = 20_000;
+
+/// Tag names for `paths`: each file's stem, numbered when two files share one
+fn source_names(paths: &[PathBuf]) -> Vec<String> {
+    let mut names: Vec<String> = Vec::with_capacity(paths.len());
+    for path in paths {
+        let stem = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "source".to_string());
+        let mut name = stem.clone();
+        let mut n = 2;
+        while names.contains(&name) {
+            name = format!("{}-{}", stem, n);
+            n += 1;
+        }
+        names.push(name);
+    }
+    names
+}
+
+/// How long the events files must stay quiet after their last event before
+/// `exit_on_eof` ends the run, so the final moves can play out
+const EOF_IDLE: Duration = Duration::from_secs(2);
+
+/// Terminals at least this wide get a wider activity log that wraps long
+/// messages instead of truncating them
+const WIDE_TERMINAL: u16 = 140;
+
+/// Widest the activity log grows on wide terminals
+const MAX_ACTIVITY_LOG_WIDTH: u16 = 90;
+
+/// Focus sets in the pinned detail panel's breadcrumb
+const FOCUS_TRAIL_LEN: usize = 4;
+
+/// Activity log entries scrolled per mouse wheel step
+const LOG_SCROLL_STEP: usize = 3;
+
+/// Fewest trail points the watchdog cuts trails down to
+const MIN_TRAIL_POINTS: usize = 500;
+
+/// Lowest frame rate the watchdog drops to
+const MIN_FPS: u32 = 10;
+
+/// Most live events held back for the history while replaying; the oldest
+/// go first (the live field has applied them all)
+const MAX_LIVE_BACKLOG: usize = 100_000;
+
+/// Kafka topic to read, from `--kafka`, `--topic` and `--from-beginning`
+#[derive(Debug, Clone)]
+pub struct KafkaOptions {
+    /// Comma-separated `host:port` bootstrap brokers
+    pub brokers: String,
+    pub topic: String,
+    /// Replay the retained messages into the history before going live
+    pub from_beginning: bool,
+}
 
 /// Application configuration
 #[derive(Debug, Clone)]
 pub struct AppConfig {
-    pub file_path: Option<PathBuf>,
+    /// Events files to watch; events from several files are merged
+    pub files: Vec<PathBuf>,
+    /// Prefix agent IDs with their file's name and tint each file's agents
+    pub tag_sources: bool,
+    /// Address to accept events over WebSocket on, alongside the other sources
+    pub ws: Option<String>,
+    /// Unix socket to accept events on, alongside the other sources
+    pub socket: Option<PathBuf>,
+    /// Address to serve the gRPC event service on, alongside the other sources
+    pub grpc: Option<String>,
+    /// SSE or JSON lines stream to follow, alongside the other sources
+    pub url: Option<String>,
+    /// Redis channel to subscribe to (`redis://host/channel`), alongside the
+    /// other sources (requires the `redis` feature)
+    pub redis: Option<String>,
+    /// NATS server and subject (wildcards allowed) to subscribe to, alongside
+    /// the other sources (requires the `nats` feature)
+    pub nats: Option<(String, String)>,
+    /// Kafka topic to read, alongside the other sources (requires the `kafka`
+    /// feature)
+    pub kafka: Option<KafkaOptions>,
+    /// Format of the events from files, streams and sockets
+    pub format: EventFormat,
+    /// Oldest existing events to load when opening files
+    pub since: Option<Since>,
+    /// Save the field when closing the files and start from it next time
+    pub persist_state: bool,
+    /// What to do when untagged files send the same agent ID
+    pub id_collisions: CollisionPolicy,
     pub demo_mode: bool,
+    /// Display mode to start in, when given on the command line (kept over
+    /// the one saved in the source's workspace)
+    pub display_mode: Option<DisplayMode>,
+    /// Optional layers to start with instead of the display mode's
+    pub layers: Option<Vec<RenderLayer>>,
     pub show_heatmap: bool,
     pub show_trails: bool,
     pub show_landmarks: bool,
+    /// Draw agents as filled, outlined blocks with status badges
+    pub high_contrast: bool,
+    /// Show the intro splash at startup
+    pub intro: bool,
+    /// Frames drawn per second
+    pub fps: u32,
+    /// Rhai script with an `on_event` hook (requires the `scripting` feature)
+    pub script_path: Option<PathBuf>,
+    /// Scenario and seed for demo mode
+    pub demo: crate::demo::DemoOptions,
+    /// Alert rules from the config file
+    pub alerts: AlertConfig,
+    /// Display names for agent IDs
+    pub aliases: AliasMap,
+    /// Cleanup rules applied to events as they arrive
+    pub remap: Remapper,
+    /// How live updates of chatty agents are thinned out
+    pub sampling: SamplingRules,
+    /// How the activity log shows entry times
+    pub timestamps: TimestampStyle,
+    /// Most agents tracked at once
+    pub max_agents: usize,
+    /// Share of the previous intensity kept when an agent reports a new one
+    pub intensity_smoothing: f32,
+    /// Most trail points kept across all agents
+    pub max_trail_points: usize,
+    /// Extrapolate agent movement between infrequent updates
+    pub motion_prediction: bool,
+    /// Most connection lines kept on the field at once
+    pub max_connections: usize,
+    /// Promotion of frequent focus keywords to landmarks, if enabled
+    pub auto_landmarks: Option<AutoLandmarks>,
+    /// Heat map settings
+    pub heatmap: HeatmapConfig,
+    /// Margin kept clear around the field and how its edge is drawn
+    pub field: FieldConfig,
+    /// Window and length of the busiest agents panel
+    pub leaderboard: LeaderboardConfig,
+    /// Status bar readouts per display mode
+    pub status_bar: StatusBarConfig,
+    /// Window title and OSC 9 notifications
+    pub terminal: TerminalConfig,
+    /// Shedding work when frames keep running over budget
+    pub watchdog: WatchdogConfig,
+    /// Tones on agent errors, convergence and new agents
+    pub audio: AudioConfig,
+    /// Floating panels drawn above the help overlay
+    pub z_order: ZOrderConfig,
+    /// Display modes from the config file, selected with the number keys 4-9
+    pub display_modes: Vec<CustomDisplayMode>,
+    /// Quit after running this long
+    pub duration: Option<Duration>,
+    /// Quit once the events files have been read and stopped growing
+    pub exit_on_eof: bool,
+    /// Hover delay and how long the detail panel outlasts the cursor
+    pub hover: HoverConfig,
 }
 
 impl Default for AppConfig {
     fn default() -> Self {
         Self {
-            file_path: None,
+            files: Vec::new(),
+            tag_sources: false,
+            ws: None,
+            socket: None,
+            grpc: None,
+            url: None,
+            redis: None,
+            nats: None,
+            kafka: None,
+            format: EventFormat::default(),
+            since: None,
+            id_collisions: CollisionPolicy::default(),
+            persist_state: false,
             demo_mode: false,
+            display_mode: None,
+            layers: None,
             show_heatmap: true,
             show_trails: true,
             show_landmarks: true,
+            high_contrast: false,
+            intro: false,
+            fps: TARGET_FPS,
+            script_path: None,
+            demo: crate::demo::DemoOptions::default(),
+            alerts: AlertConfig::default(),
+            aliases: AliasMap::default(),
+            remap: Remapper::default(),
+            sampling: SamplingRules::default(),
+            timestamps: TimestampStyle::default(),
+            max_agents: DEFAULT_MAX_AGENTS,
+            intensity_smoothing: 0.0,
+            max_trail_points: DEFAULT_MAX_TRAIL_POINTS,
+            motion_prediction: false,
+            max_connections: DEFAULT_MAX_CONNECTIONS,
+            auto_landmarks: Some(AutoLandmarks::default()),
+            heatmap: HeatmapConfig::default(),
+            field: FieldConfig::default(),
+            leaderboard: LeaderboardConfig::default(),
+            status_bar: StatusBarConfig::default(),
+            terminal: TerminalConfig::default(),
+            watchdog: WatchdogConfig::default(),
+            audio: AudioConfig::default(),
+            z_order: ZOrderConfig::default(),
+            display_modes: Vec::new(),
+            duration: None,
+            exit_on_eof: false,
+            hover: HoverConfig::default(),
         }
     }
 }
@@ -57,227 +426,559 @@ pub struct App {
     // Display mode (replaces individual toggles)
     display_mode: DisplayMode,
 
+    // Active custom display mode (index into config.display_modes); it
+    // overrides display_mode's layers and panels
+    custom_mode: Option<usize>,
+
     // Layer-based rendering (derived from display_mode)
     layer_visibility: LayerVisibility,
+    // Layers turned off with --no-heatmap/--no-trails/--no-landmarks; they
+    // stay off across mode switches until toggled back on
+    hidden_layers: Vec<RenderLayer>,
 
     // Help overlay toggle
     show_help: bool,
 
+    // Connection matrix overlay toggle
+    show_matrix: bool,
+
+    // Session stats overlay toggle
+    show_stats: bool,
+    show_leaderboard: bool,
+
+    // Live state kept up to date while replaying, drawn as ghosts when enabled
+    live_field: Option<Field>,
+    live_backlog: VecDeque<HiveEvent>,
+    // Live events left out of the history because the backlog was full
+    live_backlog_dropped: usize,
+    show_ghosts: bool,
+
+    // Recording range the heat map is computed from instead of live activity
+    heat_range: Option<HeatRange>,
+    // Set once the watchdog has turned the heat map off
+    heat_shed: bool,
+
+    // Slow frame counter deciding when to shed work
+    watchdog: Watchdog,
+
+    // Per-agent state deciding which live updates are applied
+    sampler: Sampler,
+
+    // Incoming event rate for the status bar
+    event_meter: EventMeter,
+    // Rate replayed events are applied at
+    replay_meter: EventMeter,
+
     // Mouse state
     mouse_position: Option<(u16, u16)>,
-    selected_agent: Option<String>,
+    selected_agent: Option<AgentId>,
+    /// Highlighted row of the selected agent's partner list
+    partner_cursor: usize,
 
-    // Hovered agent (for mouse hover detection)
-    hovered_agent: Option<String>,
+    // Hovered agent (for mouse hover detection), debounced
+    hover: Hover,
 
-    // Last known field area for hit detection
-    last_field_area: Option<Rect>,
+    // What each cell showed in the last frame, for mouse hit detection
+    hits: HitBuffers,
+    // Size of the last frame, for placing mouse wheel input
+    last_area: Rect,
+    // Where frames spend their time, shown in Debug mode
+    profile: FrameProfile,
 
     // Activity log for tracking recent agent events
     activity_log: ActivityLog,
+    log_search: LogSearch,
+    // Activity log entries scrolled back from the newest
+    log_scroll: usize,
+
+    // Pane with keyboard focus
+    focus: Pane,
 
     // Filter state
-    filter_text: String,
+    filter: AgentFilter,
+    agent_order: AgentOrder,
     filter_mode: bool,
 
+    // Alert rules and the toasts they raise
+    alerts: AlertEngine,
+    webhook: Option<Webhook>,
+    // Window title and OSC 9 notifications
+    terminal_status: TerminalStatus,
+    toasts: Toasts,
+
+    // Event sources feeding the field
+    sources: Vec<Source>,
+    collisions: IdCollisions,
+    loading: Option<Loading>,
+
+    // File browser overlay and `:` command line
+    file_browser: Option<FileBrowser>,
+    command_text: Option<String>,
+    recent_files: RecentFiles,
+
+    // Landmark changes made in the editor, saved next to the events file
+    landmark_edits: LandmarkEdits,
+    landmark_editor: Option<LandmarkEditor>,
+    // Agent search overlay (f)
+    agent_search: Option<AgentSearch>,
+    // Color blindness the frame is previewed with (`:cvd`)
+    color_vision: Option<ColorVision>,
+    // Filled, outlined agents with status badges (C)
+    high_contrast: bool,
+    // Color depth of the terminal, once probed
+    color_mode: ColorMode,
+    // Startup splash (`--intro`), until a key or the first event
+    intro: Option<Intro>,
+    // View settings remembered per event source
+    workspaces: Workspaces,
+
+    // Chaos injection into the running demo
+    demo_commands: Option<tokio::sync::mpsc::UnboundedSender<DemoCommand>>,
+    chaos_injected: usize,
+
+    // Event scripting hooks
+    #[cfg(feature = "scripting")]
+    script: Option<crate::script::ScriptHooks>,
+
+    // Audio cues, when enabled in the config
+    #[cfg(feature = "audio")]
+    audio: Option<crate::audio::AudioCues>,
+    // Whether the swarm had converged at the last frame
+    converged: bool,
+
     // Running state
     running: bool,
+    /// When the last event came in, for `exit_on_eof`
+    last_event: Instant,
 }
 
 impl App {
     pub fn new(config: AppConfig) -> Self {
-        // Start in Standard mode (default)
-        let display_mode = DisplayMode::default();
-        let layer_visibility = display_mode.layer_visibility();
+        let display_mode = config.display_mode.unwrap_or_default();
+        let mut layer_visibility = match config.layers {
+            Some(ref layers) => DisplayMode::with_layers(layers),
+            None => display_mode.layer_visibility(),
+        };
+        // Landmarks are drawn with the zones
+        let hidden_layers: Vec<_> = [
+            (RenderLayer::Heatmap, config.show_heatmap),
+            (RenderLayer::Trails, config.show_trails),
+            (RenderLayer::Zones, config.show_landmarks),
+        ]
+        .into_iter()
+        .filter(|&(_, shown)| !shown)
+        .map(|(layer, _)| layer)
+        .collect();
+        for layer in &hidden_layers {
+            layer_visibility.set_visible(*layer, false);
+        }
+        let mut profile = FrameProfile::default();
+        profile.set_enabled(display_mode == DisplayMode::Debug);
+
+        let alerts = AlertEngine::new(config.alerts.clone());
+        let webhook = config.alerts.webhook_url.clone().map(Webhook::new);
+        let terminal_status = TerminalStatus::new(config.terminal);
+        let high_contrast = config.high_contrast;
+        let mut field = Field::new()
+            .with_max_agents(config.max_agents)
+            .with_intensity_smoothing(config.intensity_smoothing)
+            .with_max_trail_points(config.max_trail_points)
+            .with_motion_prediction(config.motion_prediction)
+            .with_max_connections(config.max_connections)
+            .with_activity_window(config.leaderboard.window_secs as f32)
+            .with_margin(config.field.margin);
+        if let Some(settings) = config.auto_landmarks {
+            field = field.with_auto_landmarks(settings);
+        }
+        let heatmap = HeatMap::with_config(80, 24, config.heatmap.clone());
+        let hover = Hover::new(&config.hover);
+        let animation_loop = AnimationLoop::new().with_fps(config.fps);
+        let watchdog = Watchdog::new(&config.watchdog);
+        let sampler = Sampler::new(config.sampling.clone());
 
         Self {
             config,
-            field: Field::new(),
+            field,
             history: History::new(),
-            heatmap: HeatMap::new(80, 24),
-            animation_loop: AnimationLoop::new(),
+            heatmap,
+            animation_loop,
             input_handler: InputHandler::new(),
             display_mode,
+            custom_mode: None,
             layer_visibility,
+            hidden_layers,
             show_help: false,
+            show_matrix: false,
+            show_stats: false,
+            show_leaderboard: false,
+            live_field: None,
+            live_backlog: VecDeque::new(),
+            live_backlog_dropped: 0,
+            show_ghosts: false,
+            heat_range: None,
+            heat_shed: false,
+            watchdog,
+            sampler,
+            event_meter: EventMeter::new(),
+            replay_meter: EventMeter::new(),
             mouse_position: None,
             selected_agent: None,
-            hovered_agent: None,
-            last_field_area: None,
+            partner_cursor: 0,
+            hover,
+            hits: HitBuffers::default(),
+            profile,
+            last_area: Rect::default(),
             activity_log: ActivityLog::new(100), // Keep last 100 activity entries
-            filter_text: String::new(),
+            focus: Pane::Field,
+            log_search: LogSearch::default(),
+            log_scroll: 0,
+            filter: AgentFilter::default(),
+            agent_order: AgentOrder::default(),
             filter_mode: false,
+            alerts,
+            webhook,
+            terminal_status,
+            toasts: Toasts::new(),
+            sources: Vec::new(),
+            collisions: IdCollisions::default(),
+            loading: None,
+            file_browser: None,
+            command_text: None,
+            recent_files: RecentFiles::load(),
+            landmark_edits: LandmarkEdits::default(),
+            landmark_editor: None,
+            agent_search: None,
+            color_vision: None,
+            high_contrast,
+            color_mode: ColorMode::default(),
+            intro: None,
+            workspaces: Workspaces::load(),
+            demo_commands: None,
+            chaos_injected: 0,
+            #[cfg(feature = "scripting")]
+            script: None,
+            #[cfg(feature = "audio")]
+            audio: None,
+            converged: false,
             running: true,
+            last_event: Instant::now(),
         }
     }
 
     /// Set the display mode and update layer visibility accordingly.
     fn set_display_mode(&mut self, mode: DisplayMode) {
         self.display_mode = mode;
-        self.layer_visibility = mode.layer_visibility();
-    }
+        self.custom_mode = None;
+        self.layer_visibility = mode.layer_visibility().with_opacity_of(&self.layer_visibility);
+        self.hide_flagged_layers();
+        self.profile.set_enabled(mode == DisplayMode::Debug);
 
-    /// Cycle to the next display mode.
-    fn cycle_display_mode(&mut self) {
-        self.set_display_mode(self.display_mode.cycle());
+        // The focused pane may have been hidden
+        self.set_focus(self.focus);
     }
 
-    /// Find an agent at the given screen position.
-    ///
-    /// Uses a 3x2 character hit target around each agent for easier selection.
-    /// Returns the agent ID if found, None otherwise.
-    fn find_agent_at_position(&self, x: u16, y: u16) -> Option<String> {
-        let field_area = self.last_field_area?;
+    /// Switch to the custom display mode bound to number `key`
+    fn set_custom_mode(&mut self, key: u8) {
+        let Some(idx) = self.config.display_modes.iter().position(|m| m.key == key) else {
+            self.toasts.push(
+                format!("No display mode on key {}", key),
+                ratatui::style::Color::Rgb(240, 190, 90),
+            );
+            return;
+        };
+        self.custom_mode = Some(idx);
+        self.layer_visibility = self.config.display_modes[idx].layer_visibility();
+        self.hide_flagged_layers();
+        self.profile.set_enabled(false);
 
-        // Check if position is within field bounds
-        if x < field_area.x + 1 || x >= field_area.x + field_area.width - 1 {
-            return None;
-        }
-        if y < field_area.y + 1 || y >= field_area.y + field_area.height - 1 {
-            return None;
+        // The focused pane may have been hidden
+        self.set_focus(self.focus);
+    }
+
+    /// Keep the layers turned off on the command line hidden
+    fn hide_flagged_layers(&mut self) {
+        for layer in &self.hidden_layers {
+            self.layer_visibility.set_visible(*layer, false);
         }
+    }
 
-        // Calculate inner dimensions (excluding border)
-        let inner_width = field_area.width.saturating_sub(2);
-        let inner_height = field_area.height.saturating_sub(2);
+    /// Show or hide `layer` by hand, which also lifts its command line flag
+    fn toggle_layer(&mut self, layer: RenderLayer) {
+        self.hidden_layers.retain(|&hidden| hidden != layer);
+        self.layer_visibility.toggle(layer);
+    }
 
-        if inner_width == 0 || inner_height == 0 {
-            return None;
+    /// Panes currently on screen, in Tab order
+    fn visible_panes(&self) -> Vec<Pane> {
+        Pane::ALL
+            .into_iter()
+            .filter(|pane| match pane {
+                Pane::Field => true,
+                Pane::ActivityLog => self.show_activity_log(),
+                Pane::Timeline => self.history.replay_mode,
+            })
+            .collect()
+    }
+
+    /// Give `pane` keyboard focus, falling back to the field if it is hidden
+    fn set_focus(&mut self, pane: Pane) {
+        let pane = if self.visible_panes().contains(&pane) {
+            pane
+        } else {
+            Pane::Field
+        };
+        if pane != Pane::ActivityLog {
+            self.log_search = LogSearch::default();
         }
+        self.focus = pane;
+        self.input_handler.set_focus(pane);
+    }
 
-        // Hit target size: 3 characters wide, 2 characters tall
-        const HIT_WIDTH: u16 = 3;
-        const HIT_HEIGHT: u16 = 2;
+    /// The active custom display mode, if any
+    fn custom_mode(&self) -> Option<&CustomDisplayMode> {
+        self.custom_mode.map(|idx| &self.config.display_modes[idx])
+    }
 
-        // Check each agent
-        for agent in self.field.agents.values() {
-            // Convert agent's normalized position to screen coordinates
-            let (agent_x, agent_y) = agent.position.to_terminal(inner_width, inner_height);
-            let draw_x = field_area.x + 1 + agent_x;
-            let draw_y = field_area.y + 1 + agent_y;
+    /// Whether the activity log panel is shown (Standard and Debug modes, or
+    /// as the custom mode says)
+    fn show_activity_log(&self) -> bool {
+        match self.custom_mode() {
+            Some(mode) => mode.activity_log,
+            None => matches!(self.display_mode, DisplayMode::Standard | DisplayMode::Debug),
+        }
+    }
 
-            // Check if click is within hit target (centered on agent)
-            let left = draw_x.saturating_sub(HIT_WIDTH / 2);
-            let right = draw_x + HIT_WIDTH / 2;
-            let top = draw_y.saturating_sub(HIT_HEIGHT / 2);
-            let bottom = draw_y + HIT_HEIGHT / 2;
+    /// Cycle to the next display mode.
+    fn cycle_display_mode(&mut self) {
+        self.set_display_mode(self.display_mode.cycle());
+    }
 
-            if x >= left && x <= right && y >= top && y <= bottom {
-                return Some(agent.id.clone());
-            }
+    /// Find the agent drawn at the given screen position in the last frame
+    /// (its glyph, the cells either side or its label).
+    /// Returns the agent ID if found, None otherwise.
+    fn find_agent_at_position(&self, x: u16, y: u16) -> Option<AgentId> {
+        match self.hits.target_at(x, y)? {
+            HitTarget::Agent(id) => Some(id.clone()),
+            _ => None,
         }
-
-        None
     }
 
-    /// Get agents filtered by current filter text.
-    fn get_filtered_agents(&self) -> Vec<&crate::state::Agent> {
-        let agents = self.field.agents_sorted();
+    /// Get agents passing the filter text and status filter.
+    fn get_filtered_agents(&self) -> Vec<&hive_core::state::Agent> {
+        let agents = self.field.agents_ordered(self.agent_order);
 
-        if self.filter_text.is_empty() {
+        if !self.filter.is_active() {
             return agents;
         }
 
-        let filter_lower = self.filter_text.to_lowercase();
-        agents
-            .into_iter()
-            .filter(|agent| agent.id.to_lowercase().contains(&filter_lower))
-            .collect()
+        agents.into_iter().filter(|agent| self.filter.matches(agent)).collect()
+    }
+
+    /// Where the swarm spent its time, for printing after the session
+    pub fn session_summary(&self) -> Option<String> {
+        session_summary(&self.field)
+    }
+
+    /// Show the intro splash with what was detected about the terminal
+    fn open_intro(&mut self, caps: &TerminalCaps) {
+        let mut names: Vec<_> = if self.config.demo_mode {
+            vec![format!("demo ({})", self.config.demo.scenario.name)]
+        } else {
+            self.config
+                .files
+                .iter()
+                .map(|path| path.file_name().map_or_else(|| path.display().to_string(), |n| n.to_string_lossy().into_owned()))
+                .collect()
+        };
+        names.extend(self.config.ws.as_ref().map(|addr| format!("ws://{}", addr)));
+        names.extend(self.config.socket.as_ref().map(|path| format!("unix://{}", path.display())));
+        names.extend(self.config.grpc.as_ref().map(|addr| format!("grpc://{}", addr)));
+        names.extend(self.config.url.clone());
+        names.extend(self.config.redis.clone());
+        names.extend(self.config.nats.as_ref().map(|(url, subject)| format!("{} {}", url, subject)));
+        names.extend(self.config.kafka.as_ref().map(|kafka| format!("{} {}", kafka.brokers, kafka.topic)));
+        let source = if names.is_empty() {
+            "none yet (o opens a file)".to_string()
+        } else {
+            names.join(", ")
+        };
+        self.intro = Some(Intro::new(source).capabilities(caps.color_mode, detect_unicode(), caps.mouse));
+        self.input_handler.set_intro_visible(true);
+    }
+
+    /// Take the intro splash down
+    fn close_intro(&mut self) {
+        self.intro = None;
+        self.input_handler.set_intro_visible(false);
     }
 
     /// Run the application
     pub async fn run(&mut self) -> io::Result<()> {
-        // Setup terminal
+        self.load_script()?;
+        self.load_audio();
+
+        // Setup terminal, carrying on without the mouse if it can't be captured
+        let mut caps = TerminalCaps::probe();
         enable_raw_mode()?;
         let mut stdout = io::stdout();
-        execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+        execute!(stdout, EnterAlternateScreen)?;
+        caps.mouse = execute!(stdout, EnableMouseCapture).is_ok();
+        self.terminal_status.set_caps(&caps);
+        self.color_mode = caps.color_mode;
+        for warning in caps.warnings() {
+            self.activity_log.add("hive".to_string(), warning, ratatui::style::Color::Rgb(150, 150, 160));
+        }
+        if self.config.intro {
+            self.open_intro(&caps);
+        }
         let backend = CrosstermBackend::new(stdout);
         let mut terminal = Terminal::new(backend)?;
 
-        // Create event channel
-        let (event_tx, mut event_rx) = create_event_queue();
-
-        // Start file watcher or demo mode
-        let _watcher = if self.config.demo_mode {
-            // Start demo event generator
-            let tx = event_tx.inner();
-            tokio::spawn(crate::demo::generate_demo_events(tx));
-            None
-        } else if let Some(ref path) = self.config.file_path {
-            // Load existing events
-            let watcher = FileWatcher::new(path, event_tx.inner())
-                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
-
-            let existing_events = watcher.read_all_events();
-            for event in existing_events {
-                self.process_event(event.clone());
-                self.history.record(event);
-            }
-
-            Some(watcher)
-        } else {
-            None
-        };
+        // Start file watcher or demo mode, and the endpoints agents push to
+        let mut opened = Ok(());
+        if self.config.demo_mode {
+            self.start_demo();
+        } else if !self.config.files.is_empty() {
+            let files = self.config.files.clone();
+            opened = self.open_files(&files);
+        }
+        if let (Ok(()), Some(addr)) = (&opened, self.config.ws.clone()) {
+            opened = self.start_websocket(&addr);
+        }
+        if let (Ok(()), Some(path)) = (&opened, self.config.socket.clone()) {
+            opened = self.start_socket(&path);
+        }
+        if let (Ok(()), Some(addr)) = (&opened, self.config.grpc.clone()) {
+            opened = self.start_grpc(&addr);
+        }
+        if let (Ok(()), Some(url)) = (&opened, self.config.url.clone()) {
+            opened = self.start_stream(&url);
+        }
+        if let (Ok(()), Some(url)) = (&opened, self.config.redis.clone()) {
+            opened = self.start_redis(&url);
+        }
+        if let (Ok(()), Some((url, subject))) = (&opened, self.config.nats.clone()) {
+            opened = self.start_nats(&url, &subject);
+        }
+        if let (Ok(()), Some(kafka)) = (&opened, self.config.kafka.clone()) {
+            opened = self.start_kafka(&kafka);
+        }
+        if let Err(e) = opened {
+            // Leave the alternate screen before reporting the error
+            self.terminal_status.restore();
+            disable_raw_mode()?;
+            execute!(terminal.backend_mut(), LeaveAlternateScreen, DisableMouseCapture)?;
+            return Err(e);
+        }
 
         // Main loop
+        let started = Instant::now();
+        self.last_event = started;
         while self.running {
             // Handle input
             self.handle_input();
 
-            // Process new events
-            self.process_incoming_events(&mut event_rx);
+            // Load the existing events, then process new ones
+            self.advance_loading();
+            self.process_incoming_events();
 
             // Handle replay mode
             if self.history.replay_mode {
                 let replay_events = self.history.get_replay_events(self.field.playback_speed);
+                let now = Instant::now();
+                for _ in &replay_events {
+                    self.replay_meter.record(now);
+                }
+                let timer = self.profile.timer();
+                let count = replay_events.len();
                 for event in replay_events {
-                    self.field.process_event(&event);
+                    self.apply_to_field(&event);
                 }
+                self.profile.record_events(timer, count);
             }
 
             // Update animations
             if self.animation_loop.should_render() {
-                let dt = self.animation_loop.delta_time();
+                let frame_started = Instant::now();
+
+                // Advance the field in fixed steps, whatever the frame rate.
+                // Heat builds up with them (also while hidden unless
+                // configured otherwise), as it decays by a share per step.
+                let heat_frozen = self.heat_range.is_some_and(|range| range.end.is_some());
+                let heat_wanted = (self.heatmap.config().accumulate == HeatAccumulation::Always && !self.heat_shed)
+                    || self.layer_visibility.is_visible(RenderLayer::Heatmap);
+                for _ in 0..self.animation_loop.fixed_steps() {
+                    self.field.tick(TICK_STEP);
+                    if let Some(ref mut live) = self.live_field {
+                        live.tick(TICK_STEP);
+                    }
+                    if heat_wanted && !heat_frozen {
+                        self.heatmap.accumulate(&self.field);
+                    }
+                }
 
-                // Update field state
-                self.field.tick(dt);
+                // Cue the swarm converging, on live data only
+                let converged = self.field.convergence.is_some();
+                if converged && !self.converged && !self.history.replay_mode {
+                    self.play_cue(Cue::Convergence);
+                }
+                self.converged = converged;
 
-                // Update heat map (always update to maintain state, visibility controlled at render)
-                if self.layer_visibility.is_visible(RenderLayer::Heatmap) {
-                    for agent in self.field.agents.values() {
-                        self.heatmap.add_heat(&agent.position, agent.intensity);
+                // Alert rules only watch live data
+                let now = Instant::now();
+                if !self.history.replay_mode && !self.field.paused {
+                    for alert in self.alerts.check(self.field.agents.values(), now) {
+                        self.raise_alert(alert);
                     }
-                    self.heatmap.decay();
                 }
+                self.report_webhook_errors();
+                self.toasts.expire(now);
+
+                // Hover the agent under the (possibly resting) cursor
+                let under = self.mouse_position.and_then(|(x, y)| self.find_agent_at_position(x, y));
+                self.hover.update(under, Instant::now());
+
+                // Window title and load progress
+                let active = self.field.agents.values().filter(|a| a.status == AgentStatus::Active).count();
+                self.terminal_status.update_title(self.field.agents.len(), active);
+                self.terminal_status.progress(self.loading.as_ref().map(|loading| loading.progress().1));
 
                 // Render
                 terminal.draw(|frame| {
                     let area = frame.area();
-                    // Store field area for hit detection (calculate same as in render)
-                    let show_activity_log = matches!(
-                        self.display_mode,
-                        DisplayMode::Standard | DisplayMode::Debug
-                    );
-                    let activity_log_width = if show_activity_log { 30u16 } else { 0u16 };
-                    let field_height = if self.history.replay_mode {
-                        area.height.saturating_sub(2)
-                    } else {
-                        area.height.saturating_sub(1)
-                    };
-                    let field_width = area.width.saturating_sub(activity_log_width);
-                    self.last_field_area = Some(Rect::new(area.x, area.y, field_width, field_height));
-
+                    if !terminal::fits(area.width, area.height) {
+                        self.hits.clear();
+                        TooSmallWidget::new((terminal::MIN_WIDTH, terminal::MIN_HEIGHT))
+                            .render(area, frame.buffer_mut());
+                        return;
+                    }
+                    self.fit_heat_grid(area);
                     self.render(area, frame.buffer_mut());
+                    // Mouse input now hits what this frame drew
+                    self.hits.swap();
+                    self.last_area = area;
                 })?;
+                self.profile.finish_frame();
 
                 self.animation_loop.frame_rendered();
+                if self.watchdog.record(frame_started.elapsed()) {
+                    self.shed_work();
+                }
+            }
+
+            if self.should_exit(started) {
+                self.running = false;
             }
 
             // Small sleep to prevent busy loop
             tokio::time::sleep(self.animation_loop.time_until_next_frame()).await;
         }
 
+        self.save_workspace();
+        self.save_state();
+
         // Cleanup terminal
+        self.terminal_status.restore();
         disable_raw_mode()?;
         execute!(
             terminal.backend_mut(),
@@ -289,13 +990,181 @@ impl App {
         Ok(())
     }
 
+    /// Whether `--duration` is up, or with `--exit-on-eof` the files have
+    /// been replayed and gone quiet
+    fn should_exit(&self, started: Instant) -> bool {
+        if self.config.duration.is_some_and(|duration| started.elapsed() >= duration) {
+            return true;
+        }
+        self.config.exit_on_eof
+            && !self.config.demo_mode
+            && self.loading.is_none()
+            && !self.history.replay_mode
+            && self.last_event.elapsed() >= EOF_IDLE
+    }
+
+    /// Set up audio cues if the config enables them
+    #[cfg(feature = "audio")]
+    fn load_audio(&mut self) {
+        if self.config.audio.enabled {
+            self.audio = Some(crate::audio::AudioCues::new(self.config.audio.clone()));
+        }
+    }
+
+    #[cfg(not(feature = "audio"))]
+    fn load_audio(&mut self) {
+        if self.config.audio.enabled {
+            self.activity_log.add(
+                "hive".to_string(),
+                "audio cues require hive to be built with the `audio` feature".to_string(),
+                ratatui::style::Color::Rgb(150, 150, 160),
+            );
+        }
+    }
+
+    /// Play `cue`, if audio cues are on
+    #[cfg(feature = "audio")]
+    fn play_cue(&mut self, cue: Cue) {
+        if let Some(ref mut audio) = self.audio {
+            audio.play(cue);
+        }
+    }
+
+    #[cfg(not(feature = "audio"))]
+    fn play_cue(&mut self, _cue: Cue) {}
+
+    /// Toggle the audio cues' master mute (`:mute`)
+    fn toggle_mute(&mut self) {
+        #[cfg(feature = "audio")]
+        if let Some(ref mut audio) = self.audio {
+            let state = if audio.toggle_mute() { "muted" } else { "unmuted" };
+            self.toasts.push(format!("Audio cues {}", state), ratatui::style::Color::Rgb(150, 200, 255));
+            return;
+        }
+        self.toasts.push("Audio cues are off".to_string(), ratatui::style::Color::Rgb(240, 190, 90));
+    }
+
+    /// The cue `event` calls for: a new agent joining or an agent going
+    /// into error
+    fn cue_for(&self, event: &HiveEvent) -> Option<Cue> {
+        let HiveEvent::AgentUpdate(update) = event else {
+            return None;
+        };
+        let previous = self.field.agents.get(&update.agent_id).map(|agent| &agent.status);
+        if update.status == AgentStatus::Error && previous != Some(&AgentStatus::Error) {
+            Some(Cue::AgentError)
+        } else if previous.is_none() {
+            Some(Cue::AgentJoined)
+        } else {
+            None
+        }
+    }
+
+    /// Compile the configured event script, if any
+    #[cfg(feature = "scripting")]
+    fn load_script(&mut self) -> io::Result<()> {
+        if let Some(ref path) = self.config.script_path {
+            self.script = Some(crate::script::ScriptHooks::load(path)?);
+        }
+        Ok(())
+    }
+
+    #[cfg(not(feature = "scripting"))]
+    fn load_script(&mut self) -> io::Result<()> {
+        if self.config.script_path.is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "--script requires hive to be built with the `scripting` feature",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Run the event through the script hook. Called before the event is
+    /// recorded, so replays show what the script made of it; the actions it
+    /// requests are applied with the event.
+    ///
+    /// Returns `None` if the script dropped the event.
+    #[cfg(feature = "scripting")]
+    fn run_script(&mut self, event: HiveEvent) -> Option<HiveEvent> {
+        let Some(script) = self.script.as_mut() else {
+            return Some(event);
+        };
+
+        let zone = match event {
+            HiveEvent::AgentUpdate(ref update) => self
+                .field
+                .zone_for_focus(&update.focus)
+                .map(|landmark| landmark.label.clone()),
+            _ => None,
+        };
+
+        let (event, error) = script.on_event(event, zone.as_deref());
+        if let Some(error) = error {
+            self.activity_log.add(
+                "script".to_string(),
+                format!("error: {}", error),
+                ratatui::style::Color::Rgb(255, 100, 100),
+            );
+        }
+        event
+    }
+
+    #[cfg(not(feature = "scripting"))]
+    fn run_script(&mut self, event: HiveEvent) -> Option<HiveEvent> {
+        Some(event)
+    }
+
+    /// Apply side effects requested by the script for the last event
+    #[cfg(feature = "scripting")]
+    fn apply_script_actions(&mut self) {
+        use crate::script::ScriptAction;
+
+        let Some(ref script) = self.script else {
+            return;
+        };
+        // During a replay the script only sees live events, so its actions
+        // belong to the live state
+        let field = match self.live_field {
+            Some(ref mut live) if self.history.replay_mode => live,
+            _ => &mut self.field,
+        };
+
+        for action in script.take_actions() {
+            match action {
+                ScriptAction::Notify(message) => {
+                    self.activity_log.add(
+                        "script".to_string(),
+                        message,
+                        ratatui::style::Color::Rgb(220, 180, 90),
+                    );
+                }
+                ScriptAction::Beep => ring_bell(),
+                ScriptAction::Flash { agent_id, seconds } => {
+                    if let Some(agent) = field.agents.get_mut(agent_id.as_str()) {
+                        agent.start_flash(seconds);
+                    }
+                }
+                ScriptAction::SetMeta { agent_id, key, value } => {
+                    if let Some(agent) = field.agents.get_mut(agent_id.as_str()) {
+                        agent.metadata.insert(key, value);
+                    }
+                }
+            }
+        }
+    }
+
     /// Process a single event
     fn process_event(&mut self, event: HiveEvent) {
+        if self.intro.as_ref().is_some_and(|intro| intro.dismissed_by_event(Instant::now())) {
+            self.close_intro();
+        }
+
         // Add to activity log for AgentUpdate events
         if let HiveEvent::AgentUpdate(ref update) = event {
             // Get the agent's color for the activity log entry
             let color = self.field.agents.get(&update.agent_id)
-                .map(|a| crate::render::get_agent_color(a.color_index))
+                .map(crate::render::color_for_agent)
                 .unwrap_or(ratatui::style::Color::Rgb(150, 150, 150));
 
             // Create a descriptive message for the activity log
@@ -305,22 +1174,1112 @@ impl App {
                 update.message.clone()
             };
 
-            self.activity_log.add(update.agent_id.clone(), message, color);
+            let name = self
+                .config
+                .aliases
+                .resolve(&update.agent_id)
+                .map(AgentId::from)
+                .unwrap_or_else(|| update.agent_id.clone());
+            self.activity_log.add(name, message, color);
+        }
+
+        self.apply_to_field(&event);
+
+        #[cfg(feature = "scripting")]
+        self.apply_script_actions();
+    }
+
+    /// Fire the next chaos incident into the running demo
+    fn inject_chaos(&mut self) {
+        const CHAOS: [(IncidentKind, &str); 3] = [
+            (IncidentKind::Cascade, "error cascade"),
+            (IncidentKind::Swarm, "swarm convergence"),
+            (IncidentKind::Death, "agent death"),
+        ];
+
+        let Some(ref commands) = self.demo_commands else {
+            return;
+        };
+
+        let (kind, label) = CHAOS[self.chaos_injected % CHAOS.len()];
+        if commands.send(DemoCommand::Inject(kind)).is_ok() {
+            self.chaos_injected += 1;
+            self.activity_log.add(
+                "chaos".to_string(),
+                format!("Injected {}", label),
+                ratatui::style::Color::Rgb(255, 140, 60),
+            );
+        }
+    }
+
+    /// Surface a fired alert as a toast, log entry, bell and desktop and
+    /// terminal notifications
+    fn raise_alert(&mut self, alert: Alert) {
+        let color = match alert.rule {
+            AlertRule::AgentError(_) => ratatui::style::Color::Rgb(255, 110, 110),
+            AlertRule::Silence | AlertRule::HighIntensity => ratatui::style::Color::Rgb(240, 190, 90),
+        };
+
+        self.toasts.push(alert.message.clone(), color);
+        self.activity_log
+            .add("alert".to_string(), alert.message.clone(), color);
+
+        if self.alerts.config().bell {
+            ring_bell();
+        }
+        if self.alerts.config().desktop {
+            send_desktop_notification("Hive alert", &alert.message);
+        }
+        self.terminal_status.notify(&alert.message);
+        if let Some(ref webhook) = self.webhook {
+            webhook.send(&alert);
+        }
+    }
+
+    /// Move webhook delivery failures into the activity log
+    fn report_webhook_errors(&mut self) {
+        let Some(ref webhook) = self.webhook else {
+            return;
+        };
+        for error in webhook.take_errors() {
+            self.activity_log
+                .add("alert".to_string(), error, ratatui::style::Color::Rgb(255, 100, 100));
+        }
+    }
+
+    /// Start the demo event generator as the event source
+    fn start_demo(&mut self) {
+        let (event_tx, event_rx) = create_event_queue();
+        let (commands_tx, commands_rx) = tokio::sync::mpsc::unbounded_channel();
+        tokio::spawn(crate::demo::generate_demo_events_with_commands(
+            event_tx.inner(),
+            commands_rx,
+            self.config.demo.clone(),
+        ));
+        self.demo_commands = Some(commands_tx);
+        self.collisions = IdCollisions::default();
+        self.sources = vec![Source {
+            tag: None,
+            events: event_rx,
+            watcher: None,
+            endpoint: None,
+            name: "demo".to_string(),
+            skipped: 0,
+            dropped: 0,
+            replaying: false,
+        }];
+    }
+
+    /// Accept events pushed over WebSocket at `addr`, as one more source
+    #[cfg(feature = "ws")]
+    fn start_websocket(&mut self, addr: &str) -> io::Result<()> {
+        let (event_tx, event_rx) = create_event_queue();
+        let server = WebSocketServer::with_format(addr, event_tx.inner(), self.config.format)
+            .map_err(|e| io::Error::new(e.kind(), format!("cannot listen on {}: {}", addr, e)))?;
+        let name = format!("ws://{}", server.local_addr());
+        self.add_endpoint(name, Endpoint::WebSocket(server), event_rx);
+        Ok(())
+    }
+
+    #[cfg(not(feature = "ws"))]
+    fn start_websocket(&mut self, _addr: &str) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "--ws requires hive to be built with the `ws` feature",
+        ))
+    }
+
+    /// Accept events written to a Unix socket at `path`, as one more source
+    #[cfg(unix)]
+    fn start_socket(&mut self, path: &Path) -> io::Result<()> {
+        let (event_tx, event_rx) = create_event_queue();
+        let listener = SocketListener::with_format(path, event_tx.inner(), self.config.format)
+            .map_err(|e| io::Error::new(e.kind(), format!("cannot listen on {}: {}", path.display(), e)))?;
+        let name = format!("unix://{}", listener.path().display());
+        self.add_endpoint(name, Endpoint::Socket(listener), event_rx);
+        Ok(())
+    }
+
+    #[cfg(not(unix))]
+    fn start_socket(&mut self, path: &Path) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("cannot listen on {}: Unix sockets are not supported on this platform", path.display()),
+        ))
+    }
+
+    /// Serve the gRPC event service at `addr`, as one more source
+    #[cfg(feature = "grpc")]
+    fn start_grpc(&mut self, addr: &str) -> io::Result<()> {
+        let (event_tx, event_rx) = create_event_queue();
+        let server = GrpcServer::bind(addr, event_tx.inner())
+            .map_err(|e| io::Error::new(e.kind(), format!("cannot listen on {}: {}", addr, e)))?;
+        let name = format!("grpc://{}", server.local_addr());
+        self.add_endpoint(name, Endpoint::Grpc(server), event_rx);
+        Ok(())
+    }
+
+    #[cfg(not(feature = "grpc"))]
+    fn start_grpc(&mut self, _addr: &str) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "--grpc requires hive to be built with the `grpc` feature",
+        ))
+    }
+
+    /// Follow the event stream at `url`, as one more source
+    #[cfg(feature = "http")]
+    fn start_stream(&mut self, url: &str) -> io::Result<()> {
+        let (event_tx, event_rx) = create_event_queue();
+        let stream = EventStream::connect(url, event_tx.inner(), self.config.format);
+        self.add_endpoint(url.to_string(), Endpoint::Stream(stream), event_rx);
+        Ok(())
+    }
+
+    #[cfg(not(feature = "http"))]
+    fn start_stream(&mut self, _url: &str) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "--url requires hive to be built with the `http` feature",
+        ))
+    }
+
+    /// Subscribe to the Redis channel in `url`, as one more source
+    #[cfg(feature = "redis")]
+    fn start_redis(&mut self, url: &str) -> io::Result<()> {
+        let (event_tx, event_rx) = create_event_queue();
+        let subscriber = RedisSubscriber::connect(url, event_tx.inner())
+            .map_err(|e| io::Error::new(e.kind(), format!("cannot subscribe to {}: {}", url, e)))?;
+        self.add_endpoint(url.to_string(), Endpoint::Redis(subscriber), event_rx);
+        Ok(())
+    }
+
+    #[cfg(not(feature = "redis"))]
+    fn start_redis(&mut self, _url: &str) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "--redis requires hive to be built with the `redis` feature",
+        ))
+    }
+
+    /// Subscribe to `subject` on the NATS server at `url`, as one more source
+    #[cfg(feature = "nats")]
+    fn start_nats(&mut self, url: &str, subject: &str) -> io::Result<()> {
+        let (event_tx, event_rx) = create_event_queue();
+        let subscriber = NatsSubscriber::connect(url, subject, event_tx.inner())
+            .map_err(|e| io::Error::new(e.kind(), format!("cannot subscribe to {}: {}", url, e)))?;
+        let name = format!("{} {}", url, subscriber.subject());
+        self.add_endpoint(name, Endpoint::Nats(subscriber), event_rx);
+        Ok(())
+    }
+
+    #[cfg(not(feature = "nats"))]
+    fn start_nats(&mut self, _url: &str, _subject: &str) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "--nats requires hive to be built with the `nats` feature",
+        ))
+    }
+
+    /// Read the Kafka topic in `kafka`, as one more source
+    #[cfg(feature = "kafka")]
+    fn start_kafka(&mut self, kafka: &KafkaOptions) -> io::Result<()> {
+        let (event_tx, event_rx) = create_event_queue();
+        let consumer = KafkaConsumer::connect(&kafka.brokers, &kafka.topic, kafka.from_beginning, event_tx.inner())
+            .map_err(|e| io::Error::new(e.kind(), format!("cannot read {}: {}", kafka.brokers, e)))?;
+        let name = format!("{} {}", kafka.brokers, consumer.topic());
+        self.add_endpoint(name, Endpoint::Kafka(consumer), event_rx);
+        Ok(())
+    }
+
+    #[cfg(not(feature = "kafka"))]
+    fn start_kafka(&mut self, _kafka: &KafkaOptions) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "--kafka requires hive to be built with the `kafka` feature",
+        ))
+    }
+
+    fn add_endpoint(&mut self, name: String, endpoint: Endpoint, events: EventReceiver) {
+        let action = match endpoint {
+            #[cfg(feature = "http")]
+            Endpoint::Stream(_) => "following events from",
+            #[cfg(feature = "redis")]
+            Endpoint::Redis(_) => "following events from",
+            #[cfg(feature = "nats")]
+            Endpoint::Nats(_) => "following events from",
+            #[cfg(feature = "kafka")]
+            Endpoint::Kafka(ref consumer) if consumer.replaying() => "replaying, then following, events from",
+            #[cfg(feature = "kafka")]
+            Endpoint::Kafka(_) => "following events from",
+            _ => "listening for events on",
+        };
+        self.activity_log.add(
+            "hive".to_string(),
+            format!("{} {}", action, name),
+            ratatui::style::Color::Rgb(150, 150, 160),
+        );
+        self.sources.push(Source {
+            tag: None,
+            events,
+            watcher: None,
+            replaying: endpoint.replaying(),
+            endpoint: Some(endpoint),
+            name,
+            skipped: 0,
+            dropped: 0,
+        });
+    }
+
+    /// Switch the event sources to `paths`: load their events and watch them
+    /// for new ones, replacing whatever was shown before.
+    fn open_files(&mut self, paths: &[PathBuf]) -> io::Result<()> {
+        self.save_workspace();
+        self.save_state();
+
+        let tags = if self.config.tag_sources {
+            source_names(paths)
+        } else {
+            Vec::new()
+        };
+
+        let mut sources = Vec::with_capacity(paths.len());
+        let mut reads = Vec::with_capacity(paths.len());
+        for (idx, path) in paths.iter().enumerate() {
+            let (event_tx, event_rx) = create_event_queue();
+            let watcher =
+                FileWatcher::with_format(path, event_tx.inner(), self.config.format).map_err(io::Error::other)?;
+            let tag = tags.get(idx).cloned();
+            let name = path.file_name().map_or_else(|| path.display().to_string(), |n| n.to_string_lossy().into_owned());
+            reads.push((idx, watcher.load_existing()));
+            sources.push(Source {
+                tag,
+                events: event_rx,
+                watcher: Some(watcher),
+                endpoint: None,
+                name,
+                skipped: 0,
+                dropped: 0,
+                replaying: false,
+            });
+        }
+
+        // Dropping the old receivers stops the previous demo or watchers; the
+        // endpoints stay open, after the files
+        sources.extend(self.sources.drain(..).filter(|source| source.endpoint.is_some()));
+        let mut names = source_names(paths);
+        names.extend(sources[paths.len()..].iter().map(|source| source.name.clone()));
+        self.sources = sources;
+        self.collisions = IdCollisions::new(self.config.id_collisions, names);
+        self.demo_commands = None;
+        self.config.demo_mode = false;
+        self.config.files = paths.to_vec();
+
+        self.landmark_edits = match paths.first().map(|path| LandmarkEdits::load_for(path)) {
+            Some(Ok(edits)) => edits,
+            Some(Err(e)) => {
+                self.toasts.push(
+                    format!("Cannot load landmark edits: {}", e),
+                    ratatui::style::Color::Rgb(255, 110, 110),
+                );
+                LandmarkEdits::default()
+            }
+            None => LandmarkEdits::default(),
+        };
+        self.landmark_editor = None;
+        self.input_handler.set_editor_open(false);
+        self.close_agent_search();
+
+        self.field = self.new_field();
+        self.history = History::new();
+        self.live_field = None;
+        self.live_backlog.clear();
+        self.live_backlog_dropped = 0;
+        self.heat_range = None;
+        self.heatmap.clear();
+        self.selected_agent = None;
+        self.hover.clear();
+        self.alerts = AlertEngine::new(self.config.alerts.clone());
+
+        // Start from the saved state, if any, and only load the events after it
+        let mut cutoff = self.config.since.map(Since::cutoff_now);
+        if let Some(saved) = self.load_state() {
+            cutoff = Some(cutoff.map_or(saved.timestamp, |since| since.max(saved.timestamp)));
+        }
+
+        // Existing events come in over the next frames, new ones wait for them
+        self.loading = Some(Loading {
+            bytes_total: reads.iter().map(|(_, read)| read.bytes().1).sum(),
+            reads,
+            read: Vec::new(),
+            bytes_done: 0,
+            pending: VecDeque::new(),
+            applied: 0,
+            stale: 0,
+            cutoff,
+        });
+        self.input_handler.set_loading(true);
+
+        for path in paths {
+            self.recent_files.add(path);
+        }
+        if let Err(e) = self.recent_files.save() {
+            self.activity_log.add(
+                "hive".to_string(),
+                format!("could not save recent files: {}", e),
+                ratatui::style::Color::Rgb(255, 100, 100),
+            );
+        }
+        Ok(())
+    }
+
+    /// Collect the finished reads of the existing events and apply the next
+    /// chunk of them, finishing the load once all are in
+    fn advance_loading(&mut self) {
+        let Some(mut loading) = self.loading.take() else {
+            return;
+        };
+
+        let mut skipped = Vec::new();
+        let mut failed = Vec::new();
+        let mut reads = std::mem::take(&mut loading.reads);
+        reads.retain(|(idx, read)| {
+            let Some(result) = read.try_finish() else {
+                return true;
+            };
+            let source = &self.sources[*idx];
+            loading.bytes_done += read.bytes().1;
+            match result {
+                Ok(events) => {
+                    if events.skipped > 0 {
+                        let first =
+                            events.first_skipped.map_or(String::new(), |line| format!(", first at line {}", line));
+                        skipped.push(format!("skipped {} unreadable lines in {}{}", events.skipped, source.name, first));
+                    }
+                    for event in events.events {
+                        if loading.cutoff.is_some_and(|cutoff| !Since::keeps(cutoff, &event)) {
+                            loading.stale += 1;
+                            continue;
+                        }
+                        let Some(mut event) = self.config.remap.apply(event) else {
+                            continue;
+                        };
+                        if let Some(ref tag) = source.tag {
+                            event.tag_source(tag);
+                        }
+                        loading.read.push((*idx, event));
+                    }
+                }
+                Err(e) => failed.push(format!("could not load {}: {}", source.name, e)),
+            }
+            false
+        });
+        loading.reads = reads;
+        for message in skipped {
+            self.report_skipped(message);
+        }
+        for message in failed {
+            self.toasts.push(message.clone(), ratatui::style::Color::Rgb(255, 110, 110));
+            self.activity_log.add("hive".to_string(), message, ratatui::style::Color::Rgb(255, 100, 100));
+        }
+        if !loading.reads.is_empty() {
+            self.loading = Some(loading);
+            return;
+        }
+
+        // Interleave merged files by timestamp
+        if !loading.read.is_empty() {
+            let mut read = std::mem::take(&mut loading.read);
+            read.sort_by_key(|(_, event)| event.timestamp());
+            loading.pending = read.into();
+        }
+
+        for _ in 0..LOAD_CHUNK {
+            let Some((source, mut event)) = loading.pending.pop_front() else {
+                break;
+            };
+            let timer = self.profile.timer();
+            let found = self.collisions.resolve(source, &mut event);
+            self.profile.record_collisions(timer);
+            self.report_collisions(found);
+
+            let timer = self.profile.timer();
+            if let Some(event) = self.run_script(event) {
+                self.history.record(event.clone());
+                self.process_event(event);
+            }
+            self.profile.record_events(timer, 1);
+            loading.applied += 1;
+        }
+        if !loading.pending.is_empty() {
+            self.loading = Some(loading);
+            return;
+        }
+
+        if loading.stale > 0 {
+            self.activity_log.add(
+                "hive".to_string(),
+                format!("skipped {} events older than --since", loading.stale),
+                ratatui::style::Color::Rgb(150, 150, 160),
+            );
+        }
+        self.finish_loading();
+    }
+
+    /// Apply the state saved for the open files, with `persist_state`
+    fn load_state(&mut self) -> Option<SavedState> {
+        if !self.config.persist_state {
+            return None;
+        }
+        let path = SavedState::sidecar_path(self.config.files.first()?);
+        let saved = match SavedState::load(&path) {
+            Ok(saved) => saved?,
+            Err(e) => {
+                self.toasts.push(
+                    format!("Cannot load saved state: {}", e),
+                    ratatui::style::Color::Rgb(255, 110, 110),
+                );
+                return None;
+            }
+        };
+        for event in saved.events() {
+            self.apply_to_field(&event);
+            self.history.record(event);
+        }
+        self.heatmap.set_source(saved.heat_source);
+        self.heatmap.load_cells(&saved.heat);
+        self.activity_log.add(
+            "hive".to_string(),
+            format!("restored {} agents from {}", saved.agents.len(), path.display()),
+            ratatui::style::Color::Rgb(150, 150, 160),
+        );
+        Some(saved)
+    }
+
+    /// Save the field next to the open files, with `persist_state` (not in
+    /// demo mode, nor while the files are still loading)
+    fn save_state(&mut self) {
+        if !self.config.persist_state || self.config.demo_mode || self.loading.is_some() {
+            return;
+        }
+        let Some(path) = self.config.files.first().map(|file| SavedState::sidecar_path(file)) else {
+            return;
+        };
+        let Some(timestamp) = self.history.events().iter().map(|e| e.event.timestamp()).max() else {
+            return;
+        };
+        // The live field, also when quitting in the middle of a replay
+        let field = self.live_field.as_ref().unwrap_or(&self.field);
+        if let Err(e) = SavedState::capture(field, &self.heatmap, timestamp).save(&path) {
+            self.activity_log.add(
+                "hive".to_string(),
+                format!("could not save state: {}", e),
+                ratatui::style::Color::Rgb(255, 100, 100),
+            );
+        }
+    }
+
+    /// Stop loading the existing events and only follow the files from now on
+    fn skip_loading(&mut self) {
+        let Some(loading) = self.loading.take() else {
+            return;
+        };
+        for (_, read) in &loading.reads {
+            read.cancel();
+        }
+        self.field = self.new_field();
+        self.history = History::new();
+        self.heatmap.clear();
+        self.activity_log.add(
+            "hive".to_string(),
+            "skipped the existing events, following new ones".to_string(),
+            ratatui::style::Color::Rgb(150, 150, 160),
+        );
+        self.load_state();
+        self.finish_loading();
+    }
+
+    /// Back to normal once the existing events are in (or skipped)
+    fn finish_loading(&mut self) {
+        self.input_handler.set_loading(false);
+        self.last_event = Instant::now();
+        if let Some(workspace) = self.workspaces.get(&Workspaces::key(&self.config.files)).cloned() {
+            self.restore_workspace(&workspace);
+        }
+    }
+
+    /// The current view settings, to be saved for the open files
+    fn workspace(&self) -> Workspace {
+        let mode = match self.custom_mode() {
+            Some(mode) => mode.name.clone(),
+            None => self.display_mode.name().to_string(),
+        };
+        Workspace {
+            mode,
+            layers: RenderLayer::all()
+                .into_iter()
+                .filter(|&layer| self.layer_visibility.is_visible(layer))
+                .collect(),
+            opacity: RenderLayer::all()
+                .into_iter()
+                .filter(|&layer| self.layer_visibility.opacity(layer) < 1.0)
+                .map(|layer| (layer, (self.layer_visibility.opacity(layer) * 100.0).round() as u8))
+                .collect(),
+            filter: self.filter.text.clone(),
+            status_filter: self.filter.status,
+            order: self.agent_order,
+            selected_agent: self.selected_agent.clone(),
+            show_matrix: self.show_matrix,
+            show_stats: self.show_stats,
+            show_leaderboard: self.show_leaderboard,
+            show_ghosts: self.show_ghosts,
+        }
+    }
+
+    /// Go back to view settings saved for the open files
+    fn restore_workspace(&mut self, workspace: &Workspace) {
+        // A mode given on the command line wins over the saved one, and
+        // layers given there (or brought by that mode) over the saved layers
+        let restore_mode = self.config.display_mode.is_none();
+        let restore_layers = restore_mode && self.config.layers.is_none();
+        if restore_mode {
+            let custom = self.config.display_modes.iter().find(|m| m.name == workspace.mode).map(|m| m.key);
+            match custom {
+                Some(key) => self.set_custom_mode(key),
+                None => {
+                    let mode = [DisplayMode::Minimal, DisplayMode::Standard, DisplayMode::Debug]
+                        .into_iter()
+                        .find(|m| m.name() == workspace.mode)
+                        .unwrap_or_default();
+                    self.set_display_mode(mode);
+                }
+            }
+            if let Some(ref layers) = self.config.layers {
+                self.layer_visibility = DisplayMode::with_layers(layers);
+            }
+        }
+
+        for layer in RenderLayer::all() {
+            if restore_layers {
+                self.layer_visibility.set_visible(layer, workspace.layers.contains(&layer));
+            }
+            let percent = workspace.opacity.get(&layer).copied().unwrap_or(100);
+            self.layer_visibility.set_opacity(layer, percent as f32 / 100.0);
+        }
+        self.hide_flagged_layers();
+        self.filter = AgentFilter {
+            text: workspace.filter.clone(),
+            status: workspace.status_filter,
+        };
+        self.agent_order = workspace.order;
+        self.selected_agent = workspace
+            .selected_agent
+            .clone()
+            .filter(|id| self.field.agents.contains_key(id));
+        self.show_matrix = workspace.show_matrix;
+        self.show_stats = workspace.show_stats;
+        self.show_leaderboard = workspace.show_leaderboard;
+        self.show_ghosts = workspace.show_ghosts;
+        self.set_focus(self.focus);
+    }
+
+    /// Remember the view settings of the open files (nothing to do in demo
+    /// mode, or before any file is open)
+    fn save_workspace(&mut self) {
+        if self.config.demo_mode || self.config.files.is_empty() || self.sources.is_empty() {
+            return;
+        }
+        let key = Workspaces::key(&self.config.files);
+        self.workspaces.set(key, self.workspace());
+        if let Err(e) = self.workspaces.save() {
+            self.activity_log.add(
+                "hive".to_string(),
+                format!("could not save workspace: {}", e),
+                ratatui::style::Color::Rgb(255, 100, 100),
+            );
+        }
+    }
+
+    /// An empty field that knows the tagged sources
+    fn new_field(&self) -> Field {
+        let field = Field::new()
+            .with_max_agents(self.config.max_agents)
+            .with_intensity_smoothing(self.config.intensity_smoothing)
+            .with_max_trail_points(self.config.max_trail_points)
+            .with_motion_prediction(self.config.motion_prediction)
+            .with_max_connections(self.config.max_connections)
+            .with_activity_window(self.config.leaderboard.window_secs as f32)
+            .with_margin(self.config.field.margin)
+            .with_sources(self.sources.iter().filter_map(|s| s.tag.clone()).collect());
+        let mut field = match self.config.auto_landmarks {
+            Some(settings) => field.with_auto_landmarks(settings),
+            None => field,
+        };
+        self.landmark_edits.apply(&mut field);
+        field
+    }
+
+    /// Open a file from the browser or command line, reporting failures as a toast
+    fn open_file_reporting(&mut self, path: &Path) {
+        match self.open_files(&[path.to_path_buf()]) {
+            Ok(()) => self.activity_log.add(
+                "hive".to_string(),
+                format!("Watching {}", path.display()),
+                ratatui::style::Color::Rgb(100, 200, 150),
+            ),
+            Err(e) => self.toasts.push(
+                format!("Cannot open {}: {}", path.display(), e),
+                ratatui::style::Color::Rgb(255, 110, 110),
+            ),
+        }
+    }
+
+    /// Save the heat grid to `path` (a timestamped CSV by default), reporting
+    /// the outcome as a toast
+    fn export_heatmap(&mut self, path: Option<PathBuf>) {
+        let path = path.unwrap_or_else(|| {
+            PathBuf::from(format!("hive-heat-{}.csv", chrono::Local::now().format("%Y%m%d-%H%M%S")))
+        });
+        match self.heatmap.export(&path) {
+            Ok(()) => self.toasts.push(
+                format!("Heat map saved to {}", path.display()),
+                ratatui::style::Color::Rgb(100, 200, 150),
+            ),
+            Err(e) => self.toasts.push(
+                format!("Cannot export heat map: {}", e),
+                ratatui::style::Color::Rgb(255, 110, 110),
+            ),
+        }
+    }
+
+    /// Save the aggregated metrics to `path` as JSON (a timestamped file by
+    /// default), reporting the outcome as a toast
+    fn export_stats(&mut self, path: Option<PathBuf>) {
+        let path = path.unwrap_or_else(|| {
+            PathBuf::from(format!("hive-stats-{}.json", chrono::Local::now().format("%Y%m%d-%H%M%S")))
+        });
+        match StatsReport::capture(&self.field).save(&path) {
+            Ok(()) => self.toasts.push(
+                format!("Stats saved to {}", path.display()),
+                ratatui::style::Color::Rgb(100, 200, 150),
+            ),
+            Err(e) => self.toasts.push(
+                format!("Cannot export stats: {}", e),
+                ratatui::style::Color::Rgb(255, 110, 110),
+            ),
+        }
+    }
+
+    /// Landmarks as listed in the editor: by label, then ID
+    fn landmark_rows(&self) -> Vec<&StoredLandmark> {
+        let mut rows: Vec<_> = self.field.landmarks.values().collect();
+        rows.sort_by(|a, b| a.label.cmp(&b.label).then_with(|| a.id.cmp(&b.id)));
+        rows
+    }
+
+    fn open_landmark_editor(&mut self) {
+        self.landmark_editor = Some(LandmarkEditor::new());
+        self.input_handler.set_editor_open(true);
+    }
+
+    fn close_landmark_editor(&mut self) {
+        self.landmark_editor = None;
+        self.input_handler.set_editor_open(false);
+    }
+
+    /// Pass a key to the landmark editor and record the change it asks for
+    fn handle_editor_key(&mut self, code: crossterm::event::KeyCode) {
+        let rows: Vec<_> = self.landmark_rows().iter().map(|l| l.id.clone()).collect();
+        let Some(ref mut editor) = self.landmark_editor else {
+            return;
+        };
+
+        match editor.handle_key(code, &rows) {
+            EditorAction::None => return,
+            EditorAction::Close => {
+                self.close_landmark_editor();
+                return;
+            }
+            EditorAction::Add { label } => {
+                let id = self.landmark_edits.add(&label, &Position::new(0.5, 0.5));
+                self.landmark_edits.apply(&mut self.field);
+                let rows: Vec<_> = self.landmark_rows().iter().map(|l| l.id.clone()).collect();
+                if let Some(ref mut editor) = self.landmark_editor {
+                    editor.select(&id, &rows);
+                }
+            }
+            EditorAction::Rename { id, label } => self.landmark_edits.rename(&id, &label),
+            EditorAction::Nudge { id, dx, dy } => {
+                if let Some(landmark) = self.field.landmarks.get(&id) {
+                    let moved = Position::new(landmark.position.x + dx, landmark.position.y + dy).clamp_to(self.field.margin());
+                    self.landmark_edits.move_to(&id, &moved);
+                }
+            }
+            EditorAction::Delete { id } => {
+                self.landmark_edits.delete(&id);
+                self.field.landmarks.remove(&id);
+            }
+        }
+
+        self.landmark_edits.apply(&mut self.field);
+        if let Err(e) = self.landmark_edits.save() {
+            self.toasts.push(
+                format!("Cannot save landmark edits: {}", e),
+                ratatui::style::Color::Rgb(255, 110, 110),
+            );
+        }
+    }
+
+    fn open_agent_search(&mut self) {
+        self.agent_search = Some(AgentSearch::new());
+        self.input_handler.set_search_open(true);
+    }
+
+    fn close_agent_search(&mut self) {
+        self.agent_search = None;
+        self.input_handler.set_search_open(false);
+    }
+
+    /// Pass a key to the agent search, selecting the agent picked
+    fn handle_search_key(&mut self, code: crossterm::event::KeyCode) {
+        let Some(ref mut search) = self.agent_search else {
+            return;
+        };
+        let matches = find_agents(&self.field, &self.history, search.query());
+
+        match search.handle_key(code, &matches) {
+            SearchAction::None => {}
+            SearchAction::Close => self.close_agent_search(),
+            SearchAction::Jump(id) => {
+                if let Some(agent) = self.field.agents.get_mut(&id) {
+                    agent.start_flash(1.5);
+                }
+                self.selected_agent = Some(id);
+                self.partner_cursor = 0;
+                self.close_agent_search();
+            }
+        }
+    }
+
+    /// Show the file browser, starting next to the current file
+    fn open_file_browser(&mut self) {
+        let start = self
+            .config
+            .files
+            .first()
+            .and_then(|path| path.parent())
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .map(Path::to_path_buf)
+            .or_else(|| std::env::current_dir().ok())
+            .unwrap_or_else(|| PathBuf::from("."));
+
+        match FileBrowser::open(&start) {
+            Ok(browser) => {
+                self.file_browser = Some(browser.with_recent(&self.recent_files.files));
+                self.input_handler.set_browser_open(true);
+            }
+            Err(e) => self.toasts.push(
+                format!("Cannot browse {}: {}", start.display(), e),
+                ratatui::style::Color::Rgb(255, 110, 110),
+            ),
+        }
+    }
+
+    fn close_file_browser(&mut self) {
+        self.file_browser = None;
+        self.input_handler.set_browser_open(false);
+    }
+
+    /// `:opacity <layer> <percent>` dims a layer; without arguments every
+    /// layer goes back to full brightness.
+    fn set_layer_opacity(&mut self, layer: Option<&str>, percent: Option<&str>) {
+        let warning = ratatui::style::Color::Rgb(240, 190, 90);
+        let Some(name) = layer else {
+            for layer in RenderLayer::all() {
+                self.layer_visibility.set_opacity(layer, 1.0);
+            }
+            self.toasts.push("All layers at full brightness", ratatui::style::Color::Rgb(100, 200, 150));
+            return;
+        };
+        let Some(layer) = RenderLayer::from_name(name) else {
+            self.toasts.push(format!("Unknown layer: {}", name), warning);
+            return;
+        };
+        let Some(percent) = percent.and_then(|p| p.trim_end_matches('%').parse::<u8>().ok()) else {
+            self.toasts.push("Usage: :opacity <layer> <percent>", warning);
+            return;
+        };
+
+        let percent = percent.min(100);
+        self.layer_visibility.set_opacity(layer, percent as f32 / 100.0);
+        self.toasts.push(
+            format!("{} at {}%", layer.name(), percent),
+            ratatui::style::Color::Rgb(100, 200, 150),
+        );
+    }
+
+    /// `:cvd <kind>` previews the frame with a color vision deficiency,
+    /// `:cvd off` goes back to normal colors
+    fn preview_color_vision(&mut self, kind: Option<&str>) {
+        match kind {
+            None | Some("off") => {
+                self.color_vision = None;
+                self.toasts.push("Color vision preview off", ratatui::style::Color::Rgb(100, 200, 150));
+            }
+            Some(kind) => match kind.parse::<ColorVision>() {
+                Ok(vision) => {
+                    self.color_vision = Some(vision);
+                    self.toasts.push(
+                        format!("Previewing {} (:cvd off to stop)", vision.name()),
+                        ratatui::style::Color::Rgb(100, 200, 150),
+                    );
+                }
+                Err(e) => self.toasts.push(e, ratatui::style::Color::Rgb(240, 190, 90)),
+            },
+        }
+    }
+
+    /// `:seek` to a time of day or by an offset, going by event timestamps
+    fn seek_to(&mut self, target: Option<&str>) {
+        let warning = ratatui::style::Color::Rgb(240, 190, 90);
+        let Some(target) = target.and_then(SeekTarget::parse) else {
+            self.toasts.push("Usage: :seek HH:MM[:SS] | +2m | -30s", warning);
+            return;
+        };
+        if !self.history.replay_mode {
+            self.toasts.push("Seeking works in replay (press r)", warning);
+            return;
+        }
+
+        let first = self.history.events().iter().map(|e| e.event.timestamp()).find(|&t| t > 0);
+        let index = self
+            .history
+            .current_timestamp()
+            .or(first)
+            .and_then(|reference| target.resolve(reference))
+            .and_then(|timestamp| self.history.index_at_timestamp(timestamp));
+        let Some(index) = index else {
+            self.toasts.push("The recording has no timestamps to seek by", warning);
+            return;
+        };
+        self.history.seek_to_index(index);
+        self.rebuild_state_to_position();
+    }
+
+    /// Run a `:` command
+    fn run_command(&mut self, command: &str) {
+        let mut words = command.split_whitespace();
+        match words.next() {
+            None => {}
+            Some("open" | "o") => match words.next() {
+                Some(path) => self.open_file_reporting(Path::new(path)),
+                None => self.open_file_browser(),
+            },
+            Some("export") => self.export_heatmap(words.next().map(PathBuf::from)),
+            Some("stats") => self.export_stats(words.next().map(PathBuf::from)),
+            Some("opacity" | "dim") => self.set_layer_opacity(words.next(), words.next()),
+            Some("seek") => self.seek_to(words.next()),
+            Some("cvd") => self.preview_color_vision(words.next()),
+            Some("mute") => self.toggle_mute(),
+            Some(other) => self.toasts.push(
+                format!("Unknown command: {}", other),
+                ratatui::style::Color::Rgb(240, 190, 90),
+            ),
+        }
+    }
+
+    /// Process incoming events from the queue
+    fn process_incoming_events(&mut self) {
+        // New events wait in the queues until the existing ones are in
+        if self.loading.is_some() {
+            return;
+        }
+        let mut incoming = Vec::new();
+        let mut replayed = Vec::new();
+        let mut collisions = Vec::new();
+        for (idx, source) in self.sources.iter_mut().enumerate() {
+            let replaying = source.endpoint.as_ref().is_some_and(Endpoint::replaying);
+            while let Ok(event) = source.events.try_recv() {
+                let Some(mut event) = self.config.remap.apply(event) else {
+                    continue;
+                };
+                if let Some(ref tag) = source.tag {
+                    event.tag_source(tag);
+                }
+                let timer = self.profile.timer();
+                collisions.extend(self.collisions.resolve(idx, &mut event));
+                self.profile.record_collisions(timer);
+                if replaying {
+                    replayed.push(event);
+                    continue;
+                }
+                if let HiveEvent::AgentUpdate(ref update) = event {
+                    if !self.sampler.keep(update, Instant::now()) {
+                        continue;
+                    }
+                }
+                incoming.push(event);
+            }
+        }
+        incoming.extend(self.sampler.due(Instant::now()).into_iter().map(HiveEvent::AgentUpdate));
+        self.report_collisions(collisions);
+
+        let mut warnings = Vec::new();
+        let mut caught_up = Vec::new();
+        for source in &mut self.sources {
+            let total = source.skipped_lines();
+            if total > source.skipped {
+                warnings.push(format!("skipped {} unreadable lines in {}", total - source.skipped, source.name));
+                source.skipped = total;
+            }
+            let dropped = source.endpoint.as_ref().map_or(0, Endpoint::drops);
+            if dropped > source.dropped {
+                warnings.push(format!("lost the connection to {}, reconnecting", source.name));
+                source.dropped = dropped;
+            }
+            let replaying = source.endpoint.as_ref().is_some_and(Endpoint::replaying);
+            if source.replaying && !replaying {
+                caught_up.push(format!("replayed the backlog of {}, now following it live", source.name));
+            }
+            source.replaying = replaying;
+        }
+        for message in warnings {
+            self.report_skipped(message);
+        }
+        for message in caught_up {
+            self.activity_log.add("hive".to_string(), message, ratatui::style::Color::Rgb(150, 150, 160));
         }
 
-        self.field.process_event(&event);
-    }
+        if !incoming.is_empty() || !replayed.is_empty() {
+            self.last_event = Instant::now();
+        }
 
-    /// Process incoming events from the queue
-    fn process_incoming_events(&mut self, rx: &mut EventReceiver) {
-        // Don't process new events in replay mode
+        // Hold live events back until replay ends, keeping the live state
+        // current for the ghost view
         if self.history.replay_mode {
+            let replayed: Vec<HiveEvent> = replayed.into_iter().filter_map(|event| self.run_script(event)).collect();
+            let incoming: Vec<HiveEvent> = incoming.into_iter().filter_map(|event| self.run_script(event)).collect();
+            if let Some(ref mut live) = self.live_field {
+                for event in replayed.iter().chain(&incoming) {
+                    live.process_event(event);
+                }
+            }
+            #[cfg(feature = "scripting")]
+            self.apply_script_actions();
+            self.live_backlog.extend(replayed);
+            self.live_backlog.extend(incoming);
+            let excess = self.live_backlog.len().saturating_sub(MAX_LIVE_BACKLOG);
+            self.live_backlog.drain(..excess);
+            self.live_backlog_dropped += excess;
             return;
         }
 
-        while let Ok(event) = rx.try_recv() {
+        let timer = self.profile.timer();
+        let count = replayed.len() + incoming.len();
+        // A topic's backlog goes into the history like the existing events of
+        // a file, without alerts or cues
+        for event in replayed {
+            let Some(event) = self.run_script(event) else {
+                continue;
+            };
+            self.history.record(event.clone());
+            self.process_event(event);
+        }
+        for event in incoming {
+            self.event_meter.record(Instant::now());
+            self.alerts.record_event(Instant::now());
+            let Some(event) = self.run_script(event) else {
+                continue;
+            };
             self.history.record(event.clone());
+            let cue = self.cue_for(&event);
             self.process_event(event);
+            if let Some(cue) = cue {
+                self.play_cue(cue);
+            }
+        }
+        self.profile.record_events(timer, count);
+    }
+
+    /// Frames keep running over budget: take the first step of shedding work
+    /// that still changes something, and say so
+    fn shed_work(&mut self) {
+        let Some(budget) = self.watchdog.budget() else {
+            return;
+        };
+        let shed = Degradation::ALL.into_iter().find_map(|step| match step {
+            Degradation::HideHeatmap => {
+                let accumulating = self.heatmap.config().accumulate == HeatAccumulation::Always && !self.heat_shed;
+                if !accumulating && !self.layer_visibility.is_visible(RenderLayer::Heatmap) {
+                    return None;
+                }
+                self.layer_visibility.set_visible(RenderLayer::Heatmap, false);
+                self.heat_shed = true;
+                Some("heat map hidden".to_string())
+            }
+            Degradation::ShortenTrails => {
+                let points = self.field.max_trail_points();
+                if points <= MIN_TRAIL_POINTS {
+                    return None;
+                }
+                let points = (points / 2).max(MIN_TRAIL_POINTS);
+                self.field.set_max_trail_points(points);
+                if let Some(ref mut live) = self.live_field {
+                    live.set_max_trail_points(points);
+                }
+                self.config.max_trail_points = points;
+                Some(format!("trails cut to {} points", points))
+            }
+            Degradation::LowerFrameRate => {
+                let fps = self.animation_loop.target_fps();
+                if fps <= MIN_FPS {
+                    return None;
+                }
+                let fps = (fps / 2).max(MIN_FPS);
+                self.animation_loop.set_fps(fps);
+                self.config.fps = fps;
+                Some(format!("frame rate lowered to {} fps", fps))
+            }
+        });
+
+        if let Some(shed) = shed {
+            let message = format!("Frames over {}ms: {}", budget.as_millis(), shed);
+            self.activity_log.add(
+                "hive".to_string(),
+                message.clone(),
+                ratatui::style::Color::Rgb(240, 190, 90),
+            );
+            self.toasts.push(message, ratatui::style::Color::Rgb(240, 190, 90));
+        }
+    }
+
+    /// Tell about lines of an events file that could not be parsed
+    fn report_skipped(&mut self, message: String) {
+        self.activity_log.add(
+            "hive".to_string(),
+            message.clone(),
+            ratatui::style::Color::Rgb(240, 190, 90),
+        );
+        self.toasts.push(message, ratatui::style::Color::Rgb(240, 190, 90));
+    }
+
+    /// Tell the user about agent IDs sent by two sources
+    fn report_collisions(&mut self, collisions: Vec<Collision>) {
+        for collision in collisions {
+            let outcome = match self.config.id_collisions {
+                CollisionPolicy::Merge => "merged".to_string(),
+                CollisionPolicy::Namespace => format!("renamed to {}/{}", collision.other, collision.id),
+            };
+            let message = format!(
+                "agent ID {} is used by both {} and {} ({})",
+                collision.id, collision.owner, collision.other, outcome
+            );
+            self.activity_log.add(
+                "hive".to_string(),
+                message.clone(),
+                ratatui::style::Color::Rgb(240, 190, 90),
+            );
+            self.toasts.push(message, ratatui::style::Color::Rgb(240, 190, 90));
         }
     }
 
@@ -332,52 +2291,95 @@ impl App {
             match event {
                 InputEvent::Quit => self.running = false,
 
+                InputEvent::SkipLoad => self.skip_loading(),
+
+                InputEvent::PartnerPrev => self.partner_cursor = self.partner_cursor.saturating_sub(1),
+
+                InputEvent::PartnerNext => {
+                    let count = self.selected_agent.as_ref().map_or(0, |id| self.field.partners(id).len());
+                    self.partner_cursor = (self.partner_cursor + 1).min(count.saturating_sub(1));
+                }
+
+                InputEvent::JumpToPartner => {
+                    let partners = self.selected_agent.as_ref().map(|id| self.field.partners(id));
+                    if let Some(partner) = partners.and_then(|partners| partners.into_iter().nth(self.partner_cursor)) {
+                        if self.field.agents.contains_key(&partner.id) {
+                            self.selected_agent = Some(partner.id);
+                            self.partner_cursor = 0;
+                        }
+                    }
+                }
+
                 InputEvent::TogglePause => self.field.toggle_pause(),
 
                 InputEvent::SpeedUp => self.field.adjust_speed(0.25),
 
                 InputEvent::SpeedDown => self.field.adjust_speed(-0.25),
 
+                InputEvent::SetSpeed(speed) => self.field.set_speed(speed),
+
                 InputEvent::ToggleReplay => {
                     if self.history.replay_mode {
                         self.history.stop_replay();
+                        // The live field already applied the events that
+                        // arrived meanwhile; they only go into the history
+                        self.field = self.live_field.take().unwrap_or_else(|| self.new_field());
+                        if self.heat_range.take().is_some() {
+                            self.heatmap.clear();
+                        }
+                        for event in std::mem::take(&mut self.live_backlog) {
+                            self.history.record(event);
+                        }
+                        let dropped = std::mem::take(&mut self.live_backlog_dropped);
+                        if dropped > 0 {
+                            self.report_skipped(format!(
+                                "left {} live events from during the replay out of the history",
+                                dropped
+                            ));
+                        }
                     } else {
                         self.history.start_replay();
-                        // Reset field state for replay
-                        self.field = Field::new();
-                    }
-                }
-
-                InputEvent::SeekBackward => {
-                    if self.history.replay_mode {
-                        let pos = (self.history.position() - 0.05).max(0.0);
-                        self.history.seek(pos);
-                        self.rebuild_state_to_position();
+                        // Reset field state for replay, keeping the live one for ghosts
+                        let replay = self.new_field();
+                        self.live_field = Some(std::mem::replace(&mut self.field, replay));
                     }
+                    // The timeline only exists in replay mode
+                    self.set_focus(self.focus);
                 }
 
-                InputEvent::SeekForward => {
+                InputEvent::Seek(delta) => {
                     if self.history.replay_mode {
-                        let pos = (self.history.position() + 0.05).min(1.0);
+                        let pos = (self.history.position() + delta).clamp(0.0, 1.0);
                         self.history.seek(pos);
                         self.rebuild_state_to_position();
                     }
                 }
 
                 // Legacy individual toggles - still work for fine-grained control
-                InputEvent::ToggleHeatMap => {
-                    self.layer_visibility.toggle(RenderLayer::Heatmap);
-                }
+                InputEvent::ToggleHeatMap => self.toggle_layer(RenderLayer::Heatmap),
 
-                InputEvent::ToggleTrails => {
-                    self.layer_visibility.toggle(RenderLayer::Trails);
-                }
+                InputEvent::ToggleTrails => self.toggle_layer(RenderLayer::Trails),
+
+                InputEvent::ToggleLandmarks => self.toggle_layer(RenderLayer::Zones),
+
+                InputEvent::ExportHeatMap => self.export_heatmap(None),
+
+                InputEvent::ExportStats => self.export_stats(None),
 
-                InputEvent::ToggleLandmarks => {
-                    self.layer_visibility.toggle(RenderLayer::Zones);
+                InputEvent::CycleHeatSource => {
+                    self.heatmap.set_source(self.heatmap.source().next());
+                    // A range is computed again for the new source
+                    self.load_range_heat();
+                    self.toasts.push(
+                        format!("Heat map shows {}", self.heatmap.source().name()),
+                        ratatui::style::Color::Rgb(150, 200, 255),
+                    );
                 }
 
-                InputEvent::ClearHeatMap => self.heatmap.clear(),
+                InputEvent::ClearHeatMap => {
+                    self.heat_range = None;
+                    self.heatmap.clear();
+                }
 
                 // Display mode controls
                 InputEvent::CycleDisplayMode => self.cycle_display_mode(),
@@ -388,22 +2390,50 @@ impl App {
 
                 InputEvent::SetModeDebug => self.set_display_mode(DisplayMode::Debug),
 
+                InputEvent::SetModeCustom(key) => self.set_custom_mode(key),
+
                 InputEvent::ToggleHelp => {
                     self.show_help = !self.show_help;
                     self.input_handler.set_help_visible(self.show_help);
                 }
 
+                InputEvent::ToggleMatrix => self.show_matrix = !self.show_matrix,
+
+                InputEvent::ToggleStats => self.show_stats = !self.show_stats,
+
+                InputEvent::ToggleLeaderboard => self.show_leaderboard = !self.show_leaderboard,
+
+                InputEvent::ToggleGhosts => self.show_ghosts = !self.show_ghosts,
+
+                InputEvent::CycleAgentOrder => {
+                    self.agent_order = self.agent_order.next();
+                    self.toasts.push(
+                        format!("Agents ordered by {}", self.agent_order.name()),
+                        ratatui::style::Color::Rgb(150, 200, 255),
+                    );
+                }
+
+                InputEvent::ToggleHighContrast => {
+                    self.high_contrast = !self.high_contrast;
+                    let state = if self.high_contrast { "on" } else { "off" };
+                    self.toasts.push(format!("High contrast {}", state), ratatui::style::Color::Rgb(150, 200, 255));
+                }
+
+                InputEvent::DismissIntro => self.close_intro(),
+
                 InputEvent::CloseHelp => {
                     self.show_help = false;
                     self.input_handler.set_help_visible(false);
                 }
 
                 InputEvent::MouseHover { x, y } => {
+                    // The hovered agent follows once the frame loop sees
+                    // the mouse resting on it
                     self.mouse_position = Some((x, y));
-                    // Update hovered agent based on mouse position
-                    self.hovered_agent = self.find_agent_at_position(x, y);
                 }
 
+                InputEvent::MouseScroll { x, y, up } => self.scroll_at(x, y, up),
+
                 InputEvent::MouseClick { x, y } => {
                     // Select agent on click
                     if let Some(agent_id) = self.find_agent_at_position(x, y) {
@@ -412,10 +2442,12 @@ impl App {
                         // Clear selection when clicking empty area
                         self.selected_agent = None;
                     }
+                    self.partner_cursor = 0;
                 }
 
                 InputEvent::Resize { width, height } => {
-                    self.heatmap.resize(width, height);
+                    self.fit_heat_grid(Rect::new(0, 0, width, height));
+                    self.load_range_heat();
                 }
 
                 // Filter mode controls
@@ -424,11 +2456,22 @@ impl App {
                     self.input_handler.set_filter_mode(true);
                 }
 
+                InputEvent::ExitFilterMode if self.log_search.editing => {
+                    self.log_search = LogSearch::default();
+                    self.input_handler.set_filter_mode(false);
+                }
+
                 InputEvent::ExitFilterMode => {
                     self.filter_mode = false;
                     self.input_handler.set_filter_mode(false);
                 }
 
+                InputEvent::ApplyFilter if self.log_search.editing => {
+                    self.log_search.editing = false;
+                    self.log_search.jump_to_latest(&self.activity_log);
+                    self.input_handler.set_filter_mode(false);
+                }
+
                 InputEvent::ApplyFilter => {
                     // Apply filter and exit filter mode
                     self.filter_mode = false;
@@ -436,22 +2479,170 @@ impl App {
                 }
 
                 InputEvent::CharInput(c) => {
-                    if self.filter_mode {
+                    if self.log_search.editing {
+                        if c == '\x08' {
+                            self.log_search.query.pop();
+                        } else {
+                            self.log_search.query.push(c);
+                        }
+                    } else if self.filter_mode {
                         if c == '\x08' {
                             // Backspace
-                            self.filter_text.pop();
+                            self.filter.text.pop();
                         } else {
-                            self.filter_text.push(c);
+                            self.filter.text.push(c);
                         }
                     }
                 }
 
                 InputEvent::ClearFilter => {
-                    self.filter_text.clear();
+                    self.filter = AgentFilter::default();
                     self.filter_mode = false;
                     self.input_handler.set_filter_mode(false);
                 }
 
+                InputEvent::ToggleStatusFilter(status) => {
+                    self.filter.status = self.filter.status.toggle(status);
+                }
+
+                InputEvent::FocusNext => {
+                    let next = self.focus.next(&self.visible_panes());
+                    self.set_focus(next);
+                }
+
+                InputEvent::FocusPrev => {
+                    let prev = self.focus.prev(&self.visible_panes());
+                    self.set_focus(prev);
+                }
+
+                InputEvent::FocusField => self.set_focus(Pane::Field),
+
+                InputEvent::SeekStart => {
+                    if self.history.replay_mode {
+                        self.history.seek(0.0);
+                        self.rebuild_state_to_position();
+                    }
+                }
+
+                InputEvent::SeekEnd => {
+                    if self.history.replay_mode {
+                        self.history.seek(1.0);
+                        self.rebuild_state_to_position();
+                    }
+                }
+
+                InputEvent::MarkHeatStart => {
+                    if self.history.replay_mode {
+                        self.heat_range = Some(HeatRange {
+                            start: self.history.position(),
+                            end: None,
+                        });
+                    }
+                }
+
+                InputEvent::MarkHeatEnd => {
+                    if let Some(ref mut range) = self.heat_range {
+                        let here = self.history.position();
+                        range.end = Some(here.max(range.start));
+                        range.start = range.start.min(here);
+                        self.load_range_heat();
+                    }
+                }
+
+                InputEvent::ClearHeatRange => {
+                    if self.heat_range.take().is_some() {
+                        self.heatmap.clear();
+                    }
+                }
+
+                InputEvent::EnterLogSearch => {
+                    self.log_search = LogSearch {
+                        editing: true,
+                        ..LogSearch::default()
+                    };
+                    self.input_handler.set_filter_mode(true);
+                }
+
+                InputEvent::NextLogMatch => self.log_search.jump(&self.activity_log, true),
+
+                InputEvent::PrevLogMatch => self.log_search.jump(&self.activity_log, false),
+
+                InputEvent::InjectChaos => self.inject_chaos(),
+
+                InputEvent::OpenFileBrowser => self.open_file_browser(),
+
+                InputEvent::BrowserUp => {
+                    if let Some(ref mut browser) = self.file_browser {
+                        browser.move_up();
+                    }
+                }
+
+                InputEvent::BrowserDown => {
+                    if let Some(ref mut browser) = self.file_browser {
+                        browser.move_down();
+                    }
+                }
+
+                InputEvent::BrowserParent => {
+                    if let Some(ref mut browser) = self.file_browser {
+                        if let Err(e) = browser.parent() {
+                            self.toasts.push(e.to_string(), ratatui::style::Color::Rgb(255, 110, 110));
+                        }
+                    }
+                }
+
+                InputEvent::BrowserEnter => {
+                    let result = match self.file_browser {
+                        Some(ref mut browser) => browser.enter(),
+                        None => Ok(None),
+                    };
+                    match result {
+                        Ok(Some(path)) => {
+                            self.close_file_browser();
+                            self.open_file_reporting(&path);
+                        }
+                        Ok(None) => {}
+                        Err(e) => self.toasts.push(e.to_string(), ratatui::style::Color::Rgb(255, 110, 110)),
+                    }
+                }
+
+                InputEvent::CloseBrowser => self.close_file_browser(),
+
+                InputEvent::OpenLandmarkEditor => self.open_landmark_editor(),
+
+                InputEvent::EditorKey(code) => self.handle_editor_key(code),
+
+                InputEvent::OpenSearch => self.open_agent_search(),
+
+                InputEvent::SearchKey(code) => self.handle_search_key(code),
+
+                InputEvent::EnterCommandMode => {
+                    self.command_text = Some(String::new());
+                    self.input_handler.set_command_mode(true);
+                }
+
+                InputEvent::CommandInput(c) => {
+                    if let Some(ref mut text) = self.command_text {
+                        if c == '\x08' {
+                            text.pop();
+                        } else {
+                            text.push(c);
+                        }
+                    }
+                }
+
+                InputEvent::RunCommand => {
+                    self.input_handler.set_command_mode(false);
+                    if let Some(command) = self.command_text.take() {
+                        self.run_command(&command);
+                    }
+                }
+
+                InputEvent::ExitCommandMode => {
+                    self.command_text = None;
+                    self.input_handler.set_command_mode(false);
+                }
+
                 InputEvent::None => {}
             }
         }
@@ -459,10 +2650,167 @@ impl App {
 
     /// Rebuild field state to current history position
     fn rebuild_state_to_position(&mut self) {
-        self.field = Field::new();
+        // Keep dwell time across seeks so it covers the whole session
+        let dwell = std::mem::take(&mut self.field.dwell);
+        self.field = self.new_field();
+        self.field.dwell = dwell;
         let events = self.history.get_events_to_position();
         for event in events {
-            self.field.process_event(&event);
+            self.apply_to_field(&event);
+        }
+    }
+
+    /// Fill the heat map from the selected range of the recording.
+    ///
+    /// Each agent adds heat at the position its focus put it, weighted by how
+    /// long it stayed until the next event and, for the intensity heat, by its
+    /// intensity. For the connections heat, both ends of each connection made
+    /// in the range add the same heat instead.
+    fn load_range_heat(&mut self) {
+        let Some(HeatRange { start, end: Some(end) }) = self.heat_range else {
+            return;
+        };
+        let events = self.history.events();
+        let (first, last) = (self.history.index_at(start), self.history.index_at(end));
+
+        let mut field = self.new_field();
+        let mut samples = Vec::new();
+        for (index, recorded) in events.iter().enumerate().take(last + 1) {
+            field.process_event(&recorded.event);
+            if index < first {
+                continue;
+            }
+
+            // A small floor so bursts of simultaneous events still count
+            let until_next = events.get(index + 1).map_or(Duration::ZERO, |next| {
+                next.received_at.saturating_duration_since(recorded.received_at)
+            });
+            let weight = until_next.as_secs_f32().max(0.001);
+            match self.heatmap.source() {
+                HeatSource::Intensity => samples.extend(
+                    field
+                        .agents
+                        .values()
+                        .map(|agent| (agent.target_position.clone(), agent.intensity * weight)),
+                ),
+                HeatSource::Presence => {
+                    samples.extend(field.agents.values().map(|agent| (agent.target_position.clone(), weight)))
+                }
+                HeatSource::Connections => {
+                    if let HiveEvent::Connection(ref connection) = recorded.event {
+                        samples.extend(
+                            [&connection.from, &connection.to]
+                                .into_iter()
+                                .filter_map(|end| field.agents.get(end))
+                                .map(|agent| (agent.target_position.clone(), 1.0)),
+                        );
+                    }
+                }
+            }
+        }
+        self.heatmap.load_samples(samples);
+    }
+
+    /// Width of the activity log panel in a terminal `total_width` columns
+    /// wide: wider when entries carry timestamps, and growing with very wide
+    /// terminals
+    fn activity_log_width(&self, total_width: u16) -> u16 {
+        let base = match self.config.timestamps {
+            TimestampStyle::Off => 30,
+            _ => 39,
+        };
+        if total_width < WIDE_TERMINAL {
+            return base;
+        }
+        (total_width * 3 / 10).clamp(base, MAX_ACTIVITY_LOG_WIDTH)
+    }
+
+    /// Size the heat grid to the field of a frame covering `area`, which
+    /// changes with the terminal, the activity log and the replay timeline
+    fn fit_heat_grid(&mut self, area: Rect) {
+        let (field_area, _) = self.layout(area);
+        let inner = FieldTransform::new(field_area).inner();
+        self.heatmap.resize(inner.width, inner.height);
+    }
+
+    /// Field area and, if shown, activity log area of a frame covering
+    /// `area` (leaving room for the status bar and the replay timeline)
+    fn layout(&self, area: Rect) -> (Rect, Option<Rect>) {
+        let activity_log_width = if self.show_activity_log() { self.activity_log_width(area.width) } else { 0 };
+        let field_height = if self.history.replay_mode {
+            area.height.saturating_sub(2)
+        } else {
+            area.height.saturating_sub(1)
+        };
+        let field_width = area.width.saturating_sub(activity_log_width);
+        let field_area = Rect::new(area.x, area.y, field_width, field_height);
+        let activity_area = (activity_log_width > 0)
+            .then(|| Rect::new(area.x + field_width, area.y, activity_log_width, field_height));
+        (field_area, activity_area)
+    }
+
+    /// Mouse wheel at (x, y): over the status bar it changes the playback
+    /// speed, over the activity log it scrolls back through older entries.
+    /// The field has no zoomable viewport, so the wheel does nothing there.
+    fn scroll_at(&mut self, x: u16, y: u16, up: bool) {
+        let area = self.last_area;
+        if area.height == 0 {
+            return;
+        }
+        let (_, activity_area) = self.layout(area);
+
+        if y == area.bottom() - 1 {
+            self.field.adjust_speed(if up { 0.25 } else { -0.25 });
+        } else if activity_area.is_some_and(|log| log.contains((x, y).into())) {
+            self.log_scroll = if up {
+                (self.log_scroll + LOG_SCROLL_STEP).min(self.activity_log.len().saturating_sub(1))
+            } else {
+                self.log_scroll.saturating_sub(LOG_SCROLL_STEP)
+            };
+        }
+    }
+
+    /// Apply `event` to the field, naming any agent it creates from the alias map
+    fn apply_to_field(&mut self, event: &HiveEvent) {
+        let new_agent = match event {
+            HiveEvent::AgentUpdate(update) if !self.field.agents.contains_key(&update.agent_id) => {
+                Some(&*update.agent_id)
+            }
+            _ => None,
+        };
+
+        self.field.process_event(event);
+
+        // Edits made in the landmark editor win over the emitter's landmarks
+        if matches!(event, HiveEvent::Landmark(_)) {
+            self.landmark_edits.apply(&mut self.field);
+        }
+
+        if let Some(agent) = new_agent.and_then(|id| self.field.agents.get_mut(id)) {
+            agent.alias = self.config.aliases.resolve(&agent.id);
+        }
+
+        // Rebuilding the field while seeking evicts the same agents again
+        let replaying = self.history.replay_mode;
+        for Evicted { id, idle } in self.field.take_evicted() {
+            if self.selected_agent.as_ref() == Some(&id) {
+                self.selected_agent = None;
+            }
+            if self.hover.current() == Some(&*id) {
+                self.hover.clear();
+            }
+            if !replaying {
+                self.activity_log.add(
+                    "hive".to_string(),
+                    format!(
+                        "evicted {} agent {} (limit of {} agents)",
+                        if idle { "idle" } else { "least recently updated" },
+                        id,
+                        self.config.max_agents
+                    ),
+                    ratatui::style::Color::Rgb(240, 190, 90),
+                );
+            }
         }
     }
 
@@ -475,41 +2823,33 @@ impl App {
     /// 4. Heatmap (activity visualization)
     /// 5. Trails (agent movement history)
     /// 6. Connections (lines between agents)
-    /// 7. Flashes (event indicators, not yet implemented)
+    /// 7. Flashes (event indicators)
     /// 8. Agents (primary content)
     /// 9. Labels (agent names, rendered with agents)
     /// 10. StatusIndicators (status symbols, rendered with agents)
     /// 11. UI (status bar, timeline)
-    /// 12. Overlays (help panel)
-    /// 13. Activity log (in Standard and Debug modes)
+    /// 12. Panels (ghosts, activity log, and the floating panels)
+    /// 13. Overlays (help panel)
+    /// 14. PinnedPanels (floating panels configured to stay above the help)
+    ///
+    /// The file browser and command line are drawn last.
     fn render(&self, area: Rect, buf: &mut Buffer) {
-        // Determine if we should show activity log (Standard and Debug modes)
-        let show_activity_log = matches!(
-            self.display_mode,
-            DisplayMode::Standard | DisplayMode::Debug
-        );
-
-        // Calculate activity log width (right side panel)
-        let activity_log_width = if show_activity_log { 30u16 } else { 0u16 };
-
-        // Calculate field area (leave room for status bar, optional timeline, and activity log)
-        let field_height = if self.history.replay_mode {
-            area.height.saturating_sub(2)
-        } else {
-            area.height.saturating_sub(1)
-        };
-        let field_width = area.width.saturating_sub(activity_log_width);
-        let field_area = Rect::new(area.x, area.y, field_width, field_height);
+        // Field on the left, activity log (Standard and Debug modes) on the
+        // right, status bar and optional timeline below
+        let (field_area, activity_area) = self.layout(area);
 
         // Prepare filtered agent list
         let agents: Vec<_> = self.get_filtered_agents();
 
         // Render empty state if no agents
-        if agents.is_empty() {
-            if self.filter_text.is_empty() {
-                EmptyStateWidget::new(EmptyStateType::NoAgents).render(field_area, buf);
-            }
-            // If filter is active but no matches, we still show the field
+        // (if a filter is active but nothing matches, we still show the field)
+        if let Some(ref loading) = self.loading {
+            let names: Vec<_> = self.sources.iter().map(|source| source.name.as_str()).collect();
+            let title = format!("Loading {}", names.join(", "));
+            let (detail, progress) = loading.progress();
+            LoadingWidget::new(&title, &detail, progress).render(field_area, buf);
+        } else if agents.is_empty() && !self.filter.is_active() {
+            EmptyStateWidget::new(EmptyStateType::NoAgents).render(field_area, buf);
         }
 
         // Prepare landmarks based on layer visibility
@@ -528,61 +2868,268 @@ impl App {
         };
 
         // Create the render state with all data needed for layer rendering
-        let get_agent_position = |id: &str| self.field.get_agent_position(id);
+        let alpha = self.animation_loop.interpolation();
+        let get_agent_position = |id: &str| self.field.agents.get(id).map(|agent| agent.drawn_position(alpha));
+        let filter_label = self.filter.describe();
         let render_state = RenderState {
             agents: &agents,
             selected_agent: self.selected_agent.as_deref(),
-            hovered_agent: self.hovered_agent.as_deref(),
+            hovered_agent: self.hover.current(),
             heatmap: heatmap_ref,
             connections: &self.field.connections,
             get_agent_position: &get_agent_position,
             landmarks,
+            clusters: &self.field.clusters,
             history: &self.history,
             paused: self.field.paused,
             playback_speed: self.field.playback_speed,
             show_help: self.show_help,
             fps: self.animation_loop.fps(),
             display_mode: self.display_mode,
-            filter_text: if self.filter_mode || !self.filter_text.is_empty() {
-                Some(self.filter_text.as_str())
+            filter_text: if self.filter_mode || self.filter.is_active() {
+                Some(filter_label.as_str())
             } else {
                 None
             },
             filter_mode: self.filter_mode,
+            field_focused: self.focus == Pane::Field && self.visible_panes().len() > 1,
+            field_border: self.config.field.border,
+            timeline_focused: self.focus == Pane::Timeline,
+            heat_range: self.heat_range,
+            status_items: match self.custom_mode() {
+                Some(CustomDisplayMode { status_bar: Some(items), .. }) => items,
+                Some(_) => self.config.status_bar.items(DisplayMode::Standard),
+                None => self.config.status_bar.items(self.display_mode),
+            },
+            mode_name: self.custom_mode().map(|mode| mode.name.as_str()),
+            event_rate: self.event_meter.rate(Instant::now()),
+            last_event_age: self.event_meter.last_event_age(Instant::now()),
+            sampled: self.sampler.dropped(),
+            replay_rate: self.replay_meter.rate(Instant::now()),
+            convergence: self.field.convergence.as_ref(),
+            time: self.animation_loop.time(),
+            hits: Some(self.hits.begin(area)),
+            high_contrast: self.high_contrast,
+            heat_braille: self.heatmap.config().style.braille(self.color_mode),
+            interpolation: self.animation_loop.interpolation(),
         };
 
-        // Create layer renderer and render all layers in z-order
-        let layer_renderer = LayerRenderer::new(area, field_area, &self.layer_visibility);
-        layer_renderer.render_all(buf, &render_state);
+        // Create layer renderer and render all layers in z-order, with the
+        // floating panels in the user slots
+        let layer_renderer = LayerRenderer::new(area, field_area, &self.layer_visibility).profile(&self.profile);
+        layer_renderer.render_all_with(buf, &render_state, |slot, buf| {
+            if slot == RenderLayer::Panels {
+                // Live agents faintly over the replay
+                if self.show_ghosts && self.history.replay_mode {
+                    if let Some(ref live) = self.live_field {
+                        GhostsWidget::new(live.agents_sorted(), self.field.agents_sorted())
+                            .render(field_area, buf);
+                    }
+                }
+
+                // Render activity log in Standard and Debug modes
+                if let Some(activity_area) = activity_area {
+                    ActivityLogWidget::new(&self.activity_log)
+                        .timestamps(self.config.timestamps)
+                        .wrap(area.width >= WIDE_TERMINAL)
+                        .focused(self.focus == Pane::ActivityLog)
+                        .search((self.focus == Pane::ActivityLog).then_some(&self.log_search))
+                        .scroll(self.log_scroll)
+                        .render(activity_area, buf);
+                }
+            }
+
+            for panel in Panel::ALL {
+                if self.config.z_order.slot(panel) == slot {
+                    self.render_panel(panel, field_area, buf);
+                }
+            }
+        });
+
+        // Timings of the last frame in the bottom-left of the field
+        if self.profile.enabled() {
+            ProfileWidget::new(self.profile.last()).render(field_area, buf);
+        }
+
+        // File browser overlay
+        if let Some(ref browser) = self.file_browser {
+            let width = area.width.saturating_sub(4).min(70);
+            let height = area.height.saturating_sub(4).min(20);
+            let browser_area = Rect::new(
+                area.x + (area.width - width) / 2,
+                area.y + (area.height - height) / 2,
+                width,
+                height,
+            );
+            FileBrowserWidget::new(browser).render(browser_area, buf);
+            self.hits.back().clear_area(browser_area);
+        }
+
+        // Landmark editor on the right of the field, marking the selected
+        // landmark
+        if let Some(ref editor) = self.landmark_editor {
+            let rows = self.landmark_rows();
+            if let Some(landmark) = rows.get(editor.selected()) {
+                if let Some((x, y)) = FieldTransform::new(field_area).cell(&landmark.position) {
+                    let style = ratatui::style::Style::default()
+                        .fg(ratatui::style::Color::Rgb(255, 220, 120))
+                        .add_modifier(ratatui::style::Modifier::BOLD);
+                    buf[(x, y)].set_char('✛').set_style(style);
+                }
+            }
+
+            let width = field_area.width.saturating_sub(2).min(44);
+            let editor_area = Rect::new(
+                field_area.x + field_area.width.saturating_sub(width + 1),
+                field_area.y + 1,
+                width,
+                field_area.height.saturating_sub(2).min(20),
+            );
+            let saved_to = self.landmark_edits.path().map(|p| p.display().to_string());
+            LandmarkEditorWidget::new(editor, &rows)
+                .saved_to(saved_to.as_deref())
+                .render(editor_area, buf);
+            self.hits.back().clear_area(editor_area);
+        }
 
-        // Render activity log in Standard and Debug modes
-        if show_activity_log && activity_log_width > 0 {
-            let activity_area = Rect::new(
-                area.x + field_width,
-                area.y,
-                activity_log_width,
-                field_height,
+        // Agent search, centered like the file browser
+        if let Some(ref search) = self.agent_search {
+            let matches = find_agents(&self.field, &self.history, search.query());
+            let width = area.width.saturating_sub(4).min(80);
+            let height = area.height.saturating_sub(4).min(16);
+            let search_area = Rect::new(
+                area.x + (area.width - width) / 2,
+                area.y + (area.height - height) / 2,
+                width,
+                height,
             );
-            ActivityLogWidget::new(&self.activity_log).render(activity_area, buf);
+            AgentSearchWidget::new(search, &matches).render(search_area, buf);
+            self.hits.back().clear_area(search_area);
         }
 
-        // Render agent hover panel if an agent is hovered
-        if let Some(ref hovered_id) = self.hovered_agent {
-            if let Some(agent) = self.field.agents.get(hovered_id) {
-                // Calculate agent's screen position
-                let inner_width = field_area.width.saturating_sub(2);
-                let inner_height = field_area.height.saturating_sub(2);
-                let (agent_x, agent_y) = agent.position.to_terminal(inner_width, inner_height);
-                let draw_x = field_area.x + 1 + agent_x;
-                let draw_y = field_area.y + 1 + agent_y;
+        // Command line over the status bar
+        if let Some(ref command) = self.command_text {
+            let line = Rect::new(area.x, area.y + area.height.saturating_sub(1), area.width, 1.min(area.height));
+            CommandLine::new(command).render(line, buf);
+        }
+
+        // Intro splash over everything else
+        if let Some(ref intro) = self.intro {
+            IntroWidget::new(intro).render(area, buf);
+            self.hits.back().clear_area(area);
+        }
 
-                // Calculate panel position
-                let (panel_x, panel_y) = crate::render::AgentPanel::calculate_position(draw_x, draw_y, field_area);
-                let (panel_width, panel_height) = crate::render::AgentPanel::dimensions();
+        // Color vision preview over the whole frame
+        if let Some(vision) = self.color_vision {
+            vision.apply(area, buf);
+        }
+    }
 
-                let panel_area = Rect::new(panel_x, panel_y, panel_width, panel_height);
-                crate::render::AgentPanel::new(agent).render(panel_area, buf);
+    /// Draw one of the floating panels over the field, if it is shown
+    fn render_panel(&self, panel: Panel, field_area: Rect, buf: &mut Buffer) {
+        match panel {
+            Panel::Matrix if self.show_matrix => {
+                ConnectionMatrix::new(&self.field).order(self.agent_order).render(field_area, buf);
+            }
+            Panel::Stats if self.show_stats => {
+                StatsOverlay::new(&self.field).render(field_area, buf);
+            }
+            // Busiest agents in the top-left of the field
+            Panel::Leaderboard if self.show_leaderboard => {
+                Leaderboard::new(&self.field).rows(self.config.leaderboard.rows).render(field_area, buf);
+            }
+            // Alert toasts in the top-right of the field
+            Panel::Toasts if !self.toasts.is_empty() => {
+                ToastWidget::new(&self.toasts).render(field_area, buf);
             }
+            Panel::AgentDetail => self.render_agent_detail(field_area, buf),
+            _ => {}
         }
     }
+
+    /// Detail panel next to the hovered agent
+    fn render_agent_detail(&self, field_area: Rect, buf: &mut Buffer) {
+        // The hovered agent, or else the panel pinned to the selected one
+        let hovered = self.hover.current().filter(|id| self.selected_agent.as_deref() != Some(id));
+        let Some(agent) = hovered.or(self.selected_agent.as_deref()).and_then(|id| self.field.agents.get(id)) else {
+            return;
+        };
+        let (trail, partners) = if hovered.is_none() {
+            (self.history.focus_trail(&agent.id, FOCUS_TRAIL_LEN), self.field.partners(&agent.id))
+        } else {
+            (Vec::new(), Vec::new())
+        };
+        let panel = crate::render::AgentPanel::new(agent)
+            .trail(&trail)
+            .partners(&partners, self.partner_cursor.min(partners.len().saturating_sub(1)));
+
+        // Calculate agent's screen position
+        let Some((draw_x, draw_y)) = FieldTransform::new(field_area).cell(&agent.drawn_position(self.animation_loop.interpolation())) else {
+            return;
+        };
+
+        // Calculate panel position
+        let panel_height = panel.height();
+        let (panel_x, panel_y) =
+            crate::render::AgentPanel::calculate_position_for(draw_x, draw_y, field_area, panel_height);
+        let (panel_width, _) = crate::render::AgentPanel::dimensions();
+
+        let panel_area = Rect::new(panel_x, panel_y, panel_width, panel_height);
+        panel.render(panel_area, buf);
+    }
+}
+
+/// Ring the terminal bell
+fn ring_bell() {
+    use std::io::Write;
+
+    let mut stdout = io::stdout();
+    let _ = stdout.write_all(b"\x07");
+    let _ = stdout.flush();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_line_view_wins_over_workspace() {
+        let workspace = Workspace {
+            mode: DisplayMode::Debug.name().to_string(),
+            layers: RenderLayer::all().to_vec(),
+            ..Workspace::default()
+        };
+
+        let mut app = App::new(AppConfig {
+            display_mode: Some(DisplayMode::Minimal),
+            show_trails: false,
+            ..AppConfig::default()
+        });
+        let layers = app.layer_visibility.clone();
+        app.restore_workspace(&workspace);
+        assert_eq!(app.display_mode, DisplayMode::Minimal);
+        assert!(RenderLayer::all().into_iter().all(|layer| app.layer_visibility.is_visible(layer) == layers.is_visible(layer)));
+        assert!(!app.layer_visibility.is_visible(RenderLayer::Trails));
+
+        // Without --mode the saved view comes back, minus the flagged layers
+        let mut app = App::new(AppConfig {
+            show_trails: false,
+            ..AppConfig::default()
+        });
+        app.restore_workspace(&workspace);
+        assert_eq!(app.display_mode, DisplayMode::Debug);
+        assert!(app.layer_visibility.is_visible(RenderLayer::Heatmap));
+        assert!(!app.layer_visibility.is_visible(RenderLayer::Trails));
+
+        // Nor does it replace a --layers list
+        let mut app = App::new(AppConfig {
+            layers: Some(vec![RenderLayer::Heatmap]),
+            ..AppConfig::default()
+        });
+        let layers = app.layer_visibility.clone();
+        app.restore_workspace(&workspace);
+        assert_eq!(app.display_mode, DisplayMode::Debug);
+        assert!(RenderLayer::all().into_iter().all(|layer| app.layer_visibility.is_visible(layer) == layers.is_visible(layer)));
+        assert!(!app.layer_visibility.is_visible(RenderLayer::Trails));
+    }
 }
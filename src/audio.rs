@@ -0,0 +1,262 @@
+//! Audio cues for ambient monitoring.
+//!
+//! With the `audio` feature, Hive plays a short tone when an agent goes into
+//! error, when the swarm converges on one spot and when a new agent joins, so
+//! a session can be followed without watching the screen. The tones are
+//! synthesized into WAV files and played with the platform's player (`afplay`
+//! on macOS, `paplay` or `aplay` elsewhere), so no audio libraries are
+//! linked in.
+//!
+//! Cues are off unless enabled in the config file, and `:mute` silences them
+//! for the session:
+//!
+//! ```toml
+//! [audio]
+//! enabled = true
+//! volume = 0.8
+//! error_volume = 1.0
+//! convergence_volume = 0.6
+//! join_volume = 0.3
+//! ```
+
+use serde::Deserialize;
+
+/// Samples per second of the synthesized tones
+const SAMPLE_RATE: u32 = 22_050;
+
+/// Silence between the notes of a cue, in seconds
+const NOTE_GAP: f32 = 0.02;
+
+/// `[audio]` section of the config file
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AudioConfig {
+    /// Play cues at all (needs the `audio` feature)
+    pub enabled: bool,
+    /// Start muted (`:mute` toggles)
+    pub muted: bool,
+    /// Master volume, 0.0 to 1.0
+    pub volume: f32,
+    /// Volume of the agent error cue
+    pub error_volume: f32,
+    /// Volume of the swarm convergence cue
+    pub convergence_volume: f32,
+    /// Volume of the new agent cue
+    pub join_volume: f32,
+}
+
+impl Default for AudioConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            muted: false,
+            volume: 0.8,
+            error_volume: 1.0,
+            convergence_volume: 0.6,
+            join_volume: 0.3,
+        }
+    }
+}
+
+impl AudioConfig {
+    /// Volume `cue` plays at, master volume included
+    pub fn volume_for(&self, cue: Cue) -> f32 {
+        let volume = match cue {
+            Cue::AgentError => self.error_volume,
+            Cue::Convergence => self.convergence_volume,
+            Cue::AgentJoined => self.join_volume,
+        };
+        (self.volume * volume).clamp(0.0, 1.0)
+    }
+}
+
+/// Something worth a sound
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Cue {
+    /// An agent went into error: two falling notes
+    AgentError,
+    /// The swarm converged: a rising triad
+    Convergence,
+    /// A new agent showed up: one short high blip
+    AgentJoined,
+}
+
+impl Cue {
+    pub fn name(self) -> &'static str {
+        match self {
+            Cue::AgentError => "error",
+            Cue::Convergence => "convergence",
+            Cue::AgentJoined => "join",
+        }
+    }
+
+    /// Notes of the cue as (frequency in Hz, seconds)
+    fn notes(self) -> &'static [(f32, f32)] {
+        match self {
+            Cue::AgentError => &[(440.0, 0.12), (330.0, 0.18)],
+            Cue::Convergence => &[(523.25, 0.09), (659.25, 0.09), (783.99, 0.14)],
+            Cue::AgentJoined => &[(880.0, 0.07)],
+        }
+    }
+}
+
+/// 16-bit mono samples of `cue` at `volume`
+pub fn synthesize(cue: Cue, volume: f32) -> Vec<i16> {
+    let rate = SAMPLE_RATE as f32;
+    let amplitude = volume.clamp(0.0, 1.0) * i16::MAX as f32;
+    let mut samples = Vec::new();
+    for (idx, &(frequency, secs)) in cue.notes().iter().enumerate() {
+        if idx > 0 {
+            samples.extend(std::iter::repeat_n(0, (NOTE_GAP * rate) as usize));
+        }
+        let len = (secs * rate) as usize;
+        for n in 0..len {
+            let t = n as f32 / rate;
+            // 5ms attack, then a linear fade so notes do not click
+            let envelope = (t / 0.005).min(1.0) * (1.0 - n as f32 / len as f32);
+            let value = (std::f32::consts::TAU * frequency * t).sin() * envelope * amplitude;
+            samples.push(value as i16);
+        }
+    }
+    samples
+}
+
+/// `samples` as a WAV file
+pub fn wav_bytes(samples: &[i16]) -> Vec<u8> {
+    let data_len = (samples.len() * 2) as u32;
+    let mut bytes = Vec::with_capacity(44 + data_len as usize);
+    bytes.extend_from_slice(b"RIFF");
+    bytes.extend_from_slice(&(36 + data_len).to_le_bytes());
+    bytes.extend_from_slice(b"WAVEfmt ");
+    bytes.extend_from_slice(&16u32.to_le_bytes());
+    bytes.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    bytes.extend_from_slice(&1u16.to_le_bytes()); // mono
+    bytes.extend_from_slice(&SAMPLE_RATE.to_le_bytes());
+    bytes.extend_from_slice(&(SAMPLE_RATE * 2).to_le_bytes());
+    bytes.extend_from_slice(&2u16.to_le_bytes());
+    bytes.extend_from_slice(&16u16.to_le_bytes());
+    bytes.extend_from_slice(b"data");
+    bytes.extend_from_slice(&data_len.to_le_bytes());
+    for sample in samples {
+        bytes.extend_from_slice(&sample.to_le_bytes());
+    }
+    bytes
+}
+
+/// Plays cues through the platform's audio player
+#[cfg(feature = "audio")]
+pub struct AudioCues {
+    config: AudioConfig,
+    muted: bool,
+    /// WAV files written so far, by cue
+    files: std::collections::HashMap<Cue, std::path::PathBuf>,
+    /// When each cue last played, so a cascade of errors plays one cue
+    last_played: std::collections::HashMap<Cue, std::time::Instant>,
+}
+
+#[cfg(feature = "audio")]
+impl AudioCues {
+    /// Least time between two plays of the same cue
+    const REPEAT_AFTER: std::time::Duration = std::time::Duration::from_millis(300);
+
+    pub fn new(config: AudioConfig) -> Self {
+        Self {
+            muted: config.muted,
+            config,
+            files: std::collections::HashMap::new(),
+            last_played: std::collections::HashMap::new(),
+        }
+    }
+
+    /// Toggle the master mute, returning whether cues are now muted
+    pub fn toggle_mute(&mut self) -> bool {
+        self.muted = !self.muted;
+        self.muted
+    }
+
+    /// Play `cue` in the background. Best effort: a missing player or a
+    /// failed write is ignored, like desktop notifications.
+    pub fn play(&mut self, cue: Cue) {
+        use std::process::{Command, Stdio};
+        use std::time::Instant;
+
+        let volume = self.config.volume_for(cue);
+        if self.muted || volume <= 0.0 {
+            return;
+        }
+        let now = Instant::now();
+        if self.last_played.get(&cue).is_some_and(|last| now.duration_since(*last) < Self::REPEAT_AFTER) {
+            return;
+        }
+        self.last_played.insert(cue, now);
+
+        let Some(path) = self.file_for(cue, volume) else {
+            return;
+        };
+        let players: &[&str] = if cfg!(target_os = "macos") { &["afplay"] } else { &["paplay", "aplay"] };
+        for player in players {
+            let mut command = Command::new(player);
+            if *player == "aplay" {
+                command.arg("-q");
+            }
+            let spawned = command
+                .arg(&path)
+                .stdin(Stdio::null())
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .spawn();
+            if spawned.is_ok() {
+                break;
+            }
+        }
+    }
+
+    /// The WAV file of `cue`, written on first use
+    fn file_for(&mut self, cue: Cue, volume: f32) -> Option<std::path::PathBuf> {
+        if let Some(path) = self.files.get(&cue) {
+            return Some(path.clone());
+        }
+        let path = std::env::temp_dir().join(format!("hive-{}-{}.wav", std::process::id(), cue.name()));
+        std::fs::write(&path, wav_bytes(&synthesize(cue, volume))).ok()?;
+        self.files.insert(cue, path.clone());
+        Some(path)
+    }
+}
+
+#[cfg(feature = "audio")]
+impl Drop for AudioCues {
+    fn drop(&mut self) {
+        for path in self.files.values() {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cues_are_distinct_and_scaled_by_volume() {
+        let config = AudioConfig {
+            volume: 0.5,
+            ..AudioConfig::default()
+        };
+        assert_eq!(config.volume_for(Cue::AgentError), 0.5);
+        assert_eq!(config.volume_for(Cue::AgentJoined), 0.15);
+
+        let peak = |samples: &[i16]| samples.iter().map(|s| s.unsigned_abs()).max().unwrap_or(0);
+        let loud = synthesize(Cue::AgentError, 1.0);
+        let quiet = synthesize(Cue::AgentError, 0.25);
+        assert_eq!(loud.len(), quiet.len());
+        assert!(peak(&quiet) * 3 < peak(&loud));
+        assert!(synthesize(Cue::AgentJoined, 1.0).len() < loud.len());
+        assert_eq!(peak(&synthesize(Cue::Convergence, 0.0)), 0);
+
+        let wav = wav_bytes(&loud);
+        assert_eq!(&wav[..4], b"RIFF");
+        assert_eq!(&wav[8..16], b"WAVEfmt ");
+        assert_eq!(wav.len(), 44 + loud.len() * 2);
+        assert_eq!(u32::from_le_bytes(wav[40..44].try_into().unwrap()) as usize, loud.len() * 2);
+    }
+}
@@ -0,0 +1,89 @@
+//! Cutoff for the events already in a file when it is opened.
+//!
+//! Opening a file replays everything in it to rebuild the field. For long
+//! recordings most of that is stale, so `--since 10m` (or `--since-timestamp`)
+//! only loads events from the cutoff on. Landmarks are kept whatever their
+//! age, as they define the field's regions rather than its state.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use hive_core::event::HiveEvent;
+
+/// Oldest events to load
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Since {
+    /// Events at most this many seconds old when the file is opened
+    Ago(u64),
+    /// Events at or after this Unix timestamp
+    Timestamp(u64),
+}
+
+impl Since {
+    /// Parse a `--since` duration such as `90s`, `10m` or `2h`
+    pub fn parse_ago(text: &str) -> Result<Self, String> {
+        parse_duration(text)
+            .map(Self::Ago)
+            .ok_or_else(|| format!("invalid duration {:?} (expected e.g. 90s, 10m, 2h or 1d)", text))
+    }
+
+    /// Unix timestamp of the cutoff, for a file opened at `now`
+    pub fn cutoff(self, now: u64) -> u64 {
+        match self {
+            Self::Ago(secs) => now.saturating_sub(secs),
+            Self::Timestamp(timestamp) => timestamp,
+        }
+    }
+
+    /// Unix timestamp of the cutoff for a file opened now
+    pub fn cutoff_now(self) -> u64 {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        self.cutoff(now)
+    }
+
+    /// Whether `event` should be loaded given the `cutoff` timestamp
+    pub fn keeps(cutoff: u64, event: &HiveEvent) -> bool {
+        matches!(event, HiveEvent::Landmark(_)) || event.timestamp() >= cutoff
+    }
+}
+
+/// Parse `N` seconds, or `N` followed by `s`, `m`, `h` or `d`
+pub fn parse_duration(text: &str) -> Option<u64> {
+    let (number, unit) = match text.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+        Some((i, _)) => text.split_at(i),
+        None => (text, "s"),
+    };
+    let scale = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 3600,
+        "d" => 86400,
+        _ => return None,
+    };
+    number.parse::<u64>().ok()?.checked_mul(scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_since_cutoff() {
+        assert_eq!(Since::parse_ago("10m"), Ok(Since::Ago(600)));
+        assert_eq!(Since::parse_ago("45"), Ok(Since::Ago(45)));
+        assert!(Since::parse_ago("10 minutes").is_err());
+        assert!(Since::parse_ago("m").is_err());
+
+        assert_eq!(Since::Ago(600).cutoff(10_000), 9_400);
+        assert_eq!(Since::Ago(600).cutoff(100), 0);
+        assert_eq!(Since::Timestamp(5_000).cutoff(10_000), 5_000);
+
+        let event = |json: &str| serde_json::from_str::<HiveEvent>(json).unwrap();
+        let old_update = event(
+            r#"{"type": "agent_update", "agent_id": "a", "status": "idle", "focus": [], "intensity": 0, "message": "", "timestamp": 10}"#,
+        );
+        let old_landmark = event(r#"{"type": "landmark", "id": "l", "label": "L", "keywords": [], "timestamp": 10}"#);
+        assert!(!Since::keeps(20, &old_update));
+        assert!(Since::keeps(10, &old_update));
+        assert!(Since::keeps(20, &old_landmark));
+    }
+}
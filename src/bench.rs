@@ -0,0 +1,277 @@
+//! Synthetic load benchmark (`hive bench`).
+//!
+//! Floods the event pipeline with generated JSON lines at a target rate and
+//! times each stage separately: parsing lines into [`HiveEvent`]s, applying
+//! them to the field, and rendering the field into an off-screen buffer at the
+//! normal frame rate. A frame counts as dropped when its work takes longer than
+//! the frame budget.
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use ratatui::{buffer::Buffer, layout::Rect, widgets::StatefulWidget};
+
+use hive_core::animation::FRAME_DURATION;
+use hive_core::event::{AgentStatus, AgentUpdate, Connection, HiveEvent, Landmark};
+
+use crate::widget::{HiveState, HiveWidget};
+
+/// Size of the off-screen buffer frames are rendered into
+const RENDER_AREA: Rect = Rect {
+    x: 0,
+    y: 0,
+    width: 160,
+    height: 48,
+};
+
+/// Focus keywords the synthetic agents pick from
+const KEYWORDS: &[&str] = &[
+    "auth", "jwt", "session", "database", "schema", "query", "frontend", "react", "api",
+    "endpoint", "test", "mock", "deploy", "docker", "cache", "logging",
+];
+
+/// Benchmark settings
+#[derive(Debug, Clone)]
+pub struct BenchOptions {
+    /// Number of distinct agents the events are spread over
+    pub agents: usize,
+    /// Target events per second
+    pub eps: u32,
+    /// How long to run
+    pub duration: Duration,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            agents: 1000,
+            eps: 5000,
+            duration: Duration::from_secs(10),
+        }
+    }
+}
+
+/// Results of a benchmark run
+#[derive(Debug, Clone)]
+pub struct BenchReport {
+    pub options: BenchOptions,
+    pub elapsed: Duration,
+    pub events: u64,
+    /// Lines that failed to parse (should always be 0)
+    pub parse_errors: u64,
+    pub parse_time: Duration,
+    pub apply_time: Duration,
+    pub render_time: Duration,
+    /// Work time of every frame (parse + apply + tick + render)
+    pub frame_times: Vec<Duration>,
+    /// Frames whose work exceeded the frame budget
+    pub dropped_frames: u64,
+}
+
+impl BenchReport {
+    /// Frame work time at percentile `p` (0-100)
+    pub fn frame_percentile(&self, p: f64) -> Duration {
+        let mut times = self.frame_times.clone();
+        times.sort();
+        match times.len() {
+            0 => Duration::ZERO,
+            n => times[((n - 1) as f64 * p / 100.0).round() as usize],
+        }
+    }
+}
+
+impl fmt::Display for BenchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let events = self.events.max(1) as f64;
+        let frames = self.frame_times.len().max(1) as f64;
+        let secs = self.elapsed.as_secs_f64().max(f64::EPSILON);
+        let per_event = |total: Duration| total.as_secs_f64() * 1e6 / events;
+        let rate = |total: Duration| events / total.as_secs_f64().max(f64::EPSILON);
+
+        writeln!(
+            f,
+            "Hive benchmark: {} agents, {} events/s target, {:.1}s",
+            self.options.agents,
+            self.options.eps,
+            self.elapsed.as_secs_f64()
+        )?;
+        writeln!(f)?;
+        writeln!(
+            f,
+            "events   {:>10}  ({:.0}/s achieved, {} parse errors)",
+            self.events,
+            self.events as f64 / secs,
+            self.parse_errors
+        )?;
+        writeln!(
+            f,
+            "parse    {:>8.2}µs  per event ({:.0} events/s)",
+            per_event(self.parse_time),
+            rate(self.parse_time)
+        )?;
+        writeln!(
+            f,
+            "apply    {:>8.2}µs  per event ({:.0} events/s)",
+            per_event(self.apply_time),
+            rate(self.apply_time)
+        )?;
+        writeln!(
+            f,
+            "render   {:>8.2}ms  per frame",
+            self.render_time.as_secs_f64() * 1e3 / frames
+        )?;
+        writeln!(
+            f,
+            "frames   {:>10}  (p50 {:.2}ms, p99 {:.2}ms, budget {}ms)",
+            self.frame_times.len(),
+            self.frame_percentile(50.0).as_secs_f64() * 1e3,
+            self.frame_percentile(99.0).as_secs_f64() * 1e3,
+            FRAME_DURATION.as_millis()
+        )?;
+        write!(
+            f,
+            "dropped  {:>10}  ({:.1}%)",
+            self.dropped_frames,
+            self.dropped_frames as f64 * 100.0 / frames
+        )
+    }
+}
+
+/// Run the benchmark, blocking for `options.duration`.
+pub fn run(options: BenchOptions) -> BenchReport {
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut state = HiveState::new();
+    let widget = HiveWidget::new();
+    let mut buf = Buffer::empty(RENDER_AREA);
+
+    let mut report = BenchReport {
+        options: options.clone(),
+        elapsed: Duration::ZERO,
+        events: 0,
+        parse_errors: 0,
+        parse_time: Duration::ZERO,
+        apply_time: Duration::ZERO,
+        render_time: Duration::ZERO,
+        frame_times: Vec::new(),
+        dropped_frames: 0,
+    };
+
+    let mut lines = landmark_lines();
+    let start = Instant::now();
+
+    while start.elapsed() < options.duration {
+        // Events due since the start, so a slow frame is followed by a bigger batch
+        let due = (start.elapsed().as_secs_f64() * options.eps as f64) as u64;
+        while report.events + (lines.len() as u64) < due {
+            lines.push(synthetic_line(&mut rng, options.agents));
+        }
+
+        let frame_start = Instant::now();
+
+        let mut events = Vec::with_capacity(lines.len());
+        for line in lines.drain(..) {
+            match serde_json::from_str::<HiveEvent>(&line) {
+                Ok(event) => events.push(event),
+                Err(_) => report.parse_errors += 1,
+            }
+        }
+        let parsed = Instant::now();
+        report.parse_time += parsed - frame_start;
+
+        for event in &events {
+            state.process_event(event);
+        }
+        report.events += events.len() as u64;
+        let applied = Instant::now();
+        report.apply_time += applied - parsed;
+
+        state.tick();
+        buf.reset();
+        widget.clone().render(RENDER_AREA, &mut buf, &mut state);
+        let rendered = Instant::now();
+        report.render_time += rendered - applied;
+
+        let frame_time = rendered - frame_start;
+        report.frame_times.push(frame_time);
+        if frame_time > FRAME_DURATION {
+            report.dropped_frames += 1;
+        }
+
+        std::thread::sleep(FRAME_DURATION.saturating_sub(frame_time));
+    }
+
+    report.elapsed = start.elapsed();
+    report
+}
+
+/// Landmarks sent once at the start so positioning has zones to work with
+fn landmark_lines() -> Vec<String> {
+    KEYWORDS
+        .chunks(4)
+        .enumerate()
+        .map(|(i, keywords)| {
+            let event = HiveEvent::Landmark(Landmark {
+                id: format!("zone-{}", i),
+                label: keywords[0].to_string(),
+                keywords: keywords.iter().map(|k| k.to_string()).collect(),
+                timestamp: 0,
+            });
+            serde_json::to_string(&event).expect("events serialize")
+        })
+        .collect()
+}
+
+/// One random event as a JSON line: mostly agent updates, some connections
+fn synthetic_line(rng: &mut StdRng, agents: usize) -> String {
+    let agent = |rng: &mut StdRng| format!("agent-{}", rng.gen_range(0..agents.max(1))).into();
+
+    let event = if rng.gen_bool(0.1) {
+        HiveEvent::Connection(Connection {
+            from: agent(rng),
+            to: agent(rng),
+            label: "sync".to_string(),
+            timestamp: 0,
+            ttl_ms: None,
+        })
+    } else {
+        let status = match rng.gen_range(0..10) {
+            0 => AgentStatus::Error,
+            1 | 2 => AgentStatus::Idle,
+            3 | 4 => AgentStatus::Thinking,
+            _ => AgentStatus::Active,
+        };
+        HiveEvent::AgentUpdate(AgentUpdate {
+            agent_id: agent(rng),
+            status,
+            focus: (0..2)
+                .map(|_| KEYWORDS[rng.gen_range(0..KEYWORDS.len())].into())
+                .collect(),
+            intensity: rng.gen_range(0.0..1.0),
+            message: "Working".to_string(),
+            timestamp: 0,
+        })
+    };
+    serde_json::to_string(&event).expect("events serialize")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_run_reports_throughput() {
+        let report = run(BenchOptions {
+            agents: 50,
+            eps: 2000,
+            duration: Duration::from_millis(300),
+        });
+
+        assert!(report.events > 0);
+        assert_eq!(report.parse_errors, 0);
+        assert!(!report.frame_times.is_empty());
+        assert!(report.frame_percentile(99.0) >= report.frame_percentile(50.0));
+        assert!(report.to_string().contains("dropped"));
+    }
+}
@@ -0,0 +1,182 @@
+//! Agent ID collisions between event sources.
+//!
+//! When several files are merged without `--tag-sources`, two emitters can
+//! use the same agent ID for unrelated agents. The first source to send an
+//! update for an ID owns it; when another source uses the ID too, the
+//! collision is reported once and, depending on the [`CollisionPolicy`],
+//! the agents are either merged into one or the later source's agent is
+//! renamed `source/id`.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::Deserialize;
+
+use hive_core::event::{intern, AgentId, HiveEvent};
+
+/// What to do when two sources send the same agent ID
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CollisionPolicy {
+    /// Treat them as one agent (reported, but otherwise unchanged)
+    #[default]
+    Merge,
+    /// Rename the later source's agent to `source/id`
+    Namespace,
+}
+
+/// `[sources]` section of the config file
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SourcesConfig {
+    pub id_collisions: CollisionPolicy,
+    /// Save the field next to the events file and start from it next time
+    pub persist_state: bool,
+}
+
+/// An agent ID used by two sources
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Collision {
+    pub id: AgentId,
+    /// Source that used the ID first
+    pub owner: String,
+    /// Source that used it again
+    pub other: String,
+}
+
+/// Tracks which source owns each agent ID
+#[derive(Debug, Clone, Default)]
+pub struct IdCollisions {
+    policy: CollisionPolicy,
+    /// Source names, by source index
+    names: Vec<String>,
+    owners: HashMap<AgentId, usize>,
+    /// (ID, source) pairs already reported
+    reported: HashSet<(AgentId, usize)>,
+}
+
+impl IdCollisions {
+    pub fn new(policy: CollisionPolicy, names: Vec<String>) -> Self {
+        Self {
+            policy,
+            names,
+            ..Self::default()
+        }
+    }
+
+    /// Check the agent IDs in `event` from source number `source`, renaming
+    /// them if the policy says so. Returns the collisions not reported yet.
+    pub fn resolve(&mut self, source: usize, event: &mut HiveEvent) -> Vec<Collision> {
+        let mut found = Vec::new();
+        match event {
+            HiveEvent::AgentUpdate(update) => {
+                self.owners.entry(update.agent_id.clone()).or_insert(source);
+                self.resolve_id(source, &mut update.agent_id, &mut found);
+            }
+            HiveEvent::Connection(conn) => {
+                self.resolve_id(source, &mut conn.from, &mut found);
+                self.resolve_id(source, &mut conn.to, &mut found);
+            }
+            HiveEvent::Landmark(_) => {}
+        }
+        found
+    }
+
+    fn resolve_id(&mut self, source: usize, id: &mut AgentId, found: &mut Vec<Collision>) {
+        let Some(&owner) = self.owners.get(&**id) else {
+            return;
+        };
+        if owner == source {
+            return;
+        }
+
+        if self.reported.insert((id.clone(), source)) {
+            found.push(Collision {
+                id: id.clone(),
+                owner: self.name(owner),
+                other: self.name(source),
+            });
+        }
+        if self.policy == CollisionPolicy::Namespace {
+            *id = intern(&format!("{}/{}", self.name(source), id));
+        }
+    }
+
+    fn name(&self, source: usize) -> String {
+        self.names
+            .get(source)
+            .cloned()
+            .unwrap_or_else(|| format!("source-{}", source + 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use hive_core::event::{AgentStatus, AgentUpdate, Connection};
+
+    fn update(id: &str) -> HiveEvent {
+        HiveEvent::AgentUpdate(AgentUpdate {
+            agent_id: id.into(),
+            status: AgentStatus::Active,
+            focus: vec![],
+            intensity: 0.5,
+            message: String::new(),
+            timestamp: 0,
+        })
+    }
+
+    fn agent_id(event: &HiveEvent) -> &str {
+        match event {
+            HiveEvent::AgentUpdate(update) => &update.agent_id,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_namespace_renames_the_later_source() {
+        let names = vec!["ci".to_string(), "nightly".to_string()];
+        let mut collisions = IdCollisions::new(CollisionPolicy::Namespace, names);
+
+        let mut first = update("atlas");
+        assert!(collisions.resolve(0, &mut first).is_empty());
+        assert_eq!(agent_id(&first), "atlas");
+
+        let mut second = update("atlas");
+        let found = collisions.resolve(1, &mut second);
+        assert_eq!(
+            found,
+            [Collision {
+                id: "atlas".into(),
+                owner: "ci".to_string(),
+                other: "nightly".to_string(),
+            }]
+        );
+        assert_eq!(agent_id(&second), "nightly/atlas");
+
+        // Reported once; connections follow the rename
+        let mut conn = HiveEvent::Connection(Connection {
+            from: "atlas".into(),
+            to: "nova".into(),
+            label: String::new(),
+            timestamp: 0,
+            ttl_ms: None,
+        });
+        assert!(collisions.resolve(1, &mut conn).is_empty());
+        let HiveEvent::Connection(conn) = conn else { unreachable!() };
+        assert_eq!(&*conn.from, "nightly/atlas");
+        assert_eq!(&*conn.to, "nova");
+    }
+
+    #[test]
+    fn test_merge_reports_but_keeps_ids() {
+        let mut collisions = IdCollisions::new(CollisionPolicy::Merge, vec![]);
+        collisions.resolve(0, &mut update("atlas"));
+
+        let mut again = update("atlas");
+        let found = collisions.resolve(1, &mut again);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].other, "source-2");
+        assert_eq!(agent_id(&again), "atlas");
+    }
+}
@@ -0,0 +1,431 @@
+//! User configuration file.
+//!
+//! Hive reads an optional TOML file, either the one passed with `--config` or
+//! `hive/config.toml` under the user's config directory (`$XDG_CONFIG_HOME`, or
+//! `~/.config`). Every section is optional and falls back to its defaults.
+//!
+//! ```toml
+//! [alerts]
+//! bell = true
+//! desktop = true
+//! error_after_secs = 10
+//!
+//! [activity_log]
+//! timestamps = "absolute"
+//!
+//! [audio]
+//! enabled = true
+//! join_volume = 0.2
+//!
+//! [agents]
+//! max = 2000
+//! intensity_smoothing = 0.6
+//! max_trail_points = 20000
+//! motion_prediction = true
+//!
+//! [connections]
+//! max = 500
+//!
+//! [sources]
+//! id_collisions = "namespace"
+//! persist_state = true
+//!
+//! [auto_landmarks]
+//! warmup_updates = 200
+//! count = 4
+//!
+//! [heatmap]
+//! accumulate = "visible"
+//! style = "braille"
+//! source = "presence"
+//!
+//! [field]
+//! margin = 0.02
+//! border = "rounded"
+//!
+//! [leaderboard]
+//! window_secs = 600
+//! rows = 5
+//!
+//! [status_bar]
+//! standard = ["agents", "event_rate", "last_event"]
+//!
+//! [terminal]
+//! title = false
+//! osc9 = false
+//!
+//! [sampling]
+//! min_interval_ms = 100
+//!
+//! [[sampling.agents]]
+//! pattern = "^indexer-"
+//! every = 10
+//!
+//! [watchdog]
+//! frame_budget_ms = 80
+//!
+//! [z_order]
+//! above_overlays = ["agent_detail"]
+//!
+//! [[display_modes]]
+//! name = "Heat"
+//! key = 4
+//! layers = ["heatmap", "zones"]
+//!
+//! [[aliases]]
+//! pattern = "^worker-([0-9a-f]+)$"
+//! name = "Worker $1"
+//!
+//! [remap]
+//! drop = ["landmark"]
+//!
+//! [[remap.agents]]
+//! pattern = "^(worker-[0-9]+)-attempt-[0-9]+$"
+//! replace = "$1"
+//! ```
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use hive_core::state::field::{AutoLandmarks, DEFAULT_MAX_AGENTS, DEFAULT_MAX_CONNECTIONS, DEFAULT_MAX_TRAIL_POINTS};
+
+use crate::alerts::AlertConfig;
+use crate::audio::AudioConfig;
+use crate::alias::AliasRule;
+use crate::remap::RemapConfig;
+use crate::sampling::SamplingConfig;
+use crate::terminal::TerminalConfig;
+use crate::watchdog::WatchdogConfig;
+use crate::collisions::SourcesConfig;
+use crate::input::HoverConfig;
+use crate::render::{
+    ActivityLogConfig, CustomDisplayMode, FieldConfig, HeatmapConfig, LeaderboardConfig, StatusBarConfig, ZOrderConfig,
+};
+
+/// How many recently opened files are remembered
+const MAX_RECENT_FILES: usize = 10;
+
+/// Parsed configuration file
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub alerts: AlertConfig,
+    pub activity_log: ActivityLogConfig,
+    pub audio: AudioConfig,
+    pub agents: AgentsConfig,
+    pub connections: ConnectionsConfig,
+    pub sources: SourcesConfig,
+    pub hover: HoverConfig,
+    pub auto_landmarks: AutoLandmarksConfig,
+    pub heatmap: HeatmapConfig,
+    pub field: FieldConfig,
+    pub leaderboard: LeaderboardConfig,
+    pub status_bar: StatusBarConfig,
+    pub sampling: SamplingConfig,
+    pub terminal: TerminalConfig,
+    pub watchdog: WatchdogConfig,
+    pub z_order: ZOrderConfig,
+    /// Extra display modes selected with the number keys 4-9
+    pub display_modes: Vec<CustomDisplayMode>,
+    /// Display names for agent IDs, tried in order
+    pub aliases: Vec<AliasRule>,
+    /// Cleanup rules applied to incoming events
+    pub remap: RemapConfig,
+}
+
+impl Config {
+    /// Load configuration from `path`, or from the default location.
+    ///
+    /// An explicit path must exist; a missing default file yields the default
+    /// configuration.
+    pub fn load(path: Option<&Path>) -> io::Result<Self> {
+        let path = match path {
+            Some(path) => path.to_path_buf(),
+            None => match default_path() {
+                Some(path) if path.exists() => path,
+                _ => return Ok(Self::default()),
+            },
+        };
+
+        let contents = std::fs::read_to_string(&path)?;
+        Self::parse(&contents).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {}", path.display(), e),
+            )
+        })
+    }
+
+    /// Parse configuration from TOML text.
+    pub fn parse(contents: &str) -> Result<Self, toml::de::Error> {
+        let config: Self = toml::from_str(contents)?;
+
+        for (idx, mode) in config.display_modes.iter().enumerate() {
+            if !CustomDisplayMode::KEYS.contains(&mode.key) {
+                return Err(serde::de::Error::custom(format!(
+                    "display mode \"{}\": key must be 4-9, got {}",
+                    mode.name, mode.key
+                )));
+            }
+            if config.display_modes[..idx].iter().any(|other| other.key == mode.key) {
+                return Err(serde::de::Error::custom(format!(
+                    "display mode \"{}\": key {} is already used",
+                    mode.name, mode.key
+                )));
+            }
+        }
+        Ok(config)
+    }
+}
+
+/// `[agents]` section of the config file
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AgentsConfig {
+    /// Most agents tracked at once; beyond it the idle agents that went quiet
+    /// longest are dropped
+    pub max: usize,
+    /// Share of an agent's previous intensity kept when a new value arrives
+    /// (0 shows values as sent, 0.9 is very smooth)
+    pub intensity_smoothing: f32,
+    /// Most trail points kept across all agents; the oldest go first
+    pub max_trail_points: usize,
+    /// Keep agents moving along their recent velocity between updates
+    pub motion_prediction: bool,
+}
+
+impl Default for AgentsConfig {
+    fn default() -> Self {
+        Self {
+            max: DEFAULT_MAX_AGENTS,
+            intensity_smoothing: 0.0,
+            max_trail_points: DEFAULT_MAX_TRAIL_POINTS,
+            motion_prediction: false,
+        }
+    }
+}
+
+/// `[connections]` section of the config file
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConnectionsConfig {
+    /// Most connection lines kept on the field at once
+    pub max: usize,
+}
+
+impl Default for ConnectionsConfig {
+    fn default() -> Self {
+        Self {
+            max: DEFAULT_MAX_CONNECTIONS,
+        }
+    }
+}
+
+/// `[auto_landmarks]` section of the config file: promoting frequent focus
+/// keywords to landmarks when the emitter sends none
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AutoLandmarksConfig {
+    pub enabled: bool,
+    /// Agent updates to count keywords over first
+    pub warmup_updates: u32,
+    /// Most keywords promoted
+    pub count: usize,
+}
+
+impl Default for AutoLandmarksConfig {
+    fn default() -> Self {
+        let defaults = AutoLandmarks::default();
+        Self {
+            enabled: true,
+            warmup_updates: defaults.warmup_updates,
+            count: defaults.count,
+        }
+    }
+}
+
+impl AutoLandmarksConfig {
+    /// Settings for the field, `None` when disabled
+    pub fn settings(&self) -> Option<AutoLandmarks> {
+        self.enabled.then_some(AutoLandmarks {
+            warmup_updates: self.warmup_updates,
+            count: self.count,
+        })
+    }
+}
+
+/// Recently opened event files, newest first.
+///
+/// Stored in `recent.toml` next to the config file rather than in it, because
+/// Hive rewrites this file whenever a file is opened.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RecentFiles {
+    pub files: Vec<PathBuf>,
+    #[serde(skip)]
+    path: Option<PathBuf>,
+}
+
+impl RecentFiles {
+    /// Load the list from its default location; a missing or unreadable file
+    /// gives an empty list.
+    pub fn load() -> Self {
+        match default_path().and_then(|config| Some(config.parent()?.join("recent.toml"))) {
+            Some(path) => Self::load_from(path),
+            None => Self::default(),
+        }
+    }
+
+    /// Load the list from `path`, saving back to the same file.
+    pub fn load_from(path: PathBuf) -> Self {
+        let mut recent: Self = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|contents| toml::from_str(&contents).ok())
+            .unwrap_or_default();
+        recent.path = Some(path);
+        recent
+    }
+
+    /// Move `file` to the front of the list.
+    pub fn add(&mut self, file: &Path) {
+        let file = file.canonicalize().unwrap_or_else(|_| file.to_path_buf());
+        self.files.retain(|f| *f != file);
+        self.files.insert(0, file);
+        self.files.truncate(MAX_RECENT_FILES);
+    }
+
+    /// Write the list back to disk.
+    pub fn save(&self) -> io::Result<()> {
+        let Some(ref path) = self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let contents = toml::to_string(self).map_err(io::Error::other)?;
+        std::fs::write(path, contents)
+    }
+}
+
+/// Default config file location
+pub fn default_path() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(base.join("hive").join("config.toml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_config_uses_defaults() {
+        let config = Config::parse("").unwrap();
+        assert_eq!(
+            config.alerts.error_after_secs,
+            AlertConfig::default().error_after_secs
+        );
+    }
+
+    #[test]
+    fn test_partial_section() {
+        let config = Config::parse("[alerts]\ndesktop = true\n").unwrap();
+        assert!(config.alerts.desktop);
+        assert!(config.alerts.bell);
+    }
+
+    #[test]
+    fn test_aliases_keep_their_order() {
+        let config = Config::parse(
+            "[[aliases]]\npattern = \"^worker-\"\nname = \"Worker\"\n\n\
+             [[aliases]]\npattern = \".*\"\nname = \"Agent\"\n",
+        )
+        .unwrap();
+        let names: Vec<_> = config.aliases.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, ["Worker", "Agent"]);
+    }
+
+    #[test]
+    fn test_heatmap_accumulation() {
+        use crate::render::HeatAccumulation;
+
+        let config = Config::parse("").unwrap();
+        assert_eq!(config.heatmap.accumulate, HeatAccumulation::Always);
+        let config = Config::parse("[heatmap]\naccumulate = \"visible\"\n").unwrap();
+        assert_eq!(config.heatmap.accumulate, HeatAccumulation::Visible);
+        // Decay is not configurable
+        assert!(Config::parse("[heatmap]\ndecay_rate = 0.9\n").is_err());
+    }
+
+    #[test]
+    fn test_status_bar_items_per_mode() {
+        use crate::render::{DisplayMode, StatusItem};
+
+        let config = Config::parse("[status_bar]\nstandard = [\"fps\", \"event_rate\"]\n").unwrap();
+        let items = config.status_bar.items(DisplayMode::Standard);
+        assert_eq!(items, [StatusItem::Fps, StatusItem::EventRate]);
+        // FPS only shows in Debug mode by default
+        assert!(config.status_bar.items(DisplayMode::Debug).contains(&StatusItem::Fps));
+        assert!(!config.status_bar.items(DisplayMode::Minimal).contains(&StatusItem::Fps));
+    }
+
+    #[test]
+    fn test_display_modes_need_free_keys() {
+        let mode = |name: &str, key: u8| {
+            format!("[[display_modes]]\nname = \"{}\"\nkey = {}\nlayers = [\"heatmap\"]\n", name, key)
+        };
+
+        let config = Config::parse(&[mode("Heat", 4), mode("Quiet", 9)].concat()).unwrap();
+        assert_eq!(config.display_modes.len(), 2);
+        assert_eq!(config.display_modes[0].layers, [crate::render::RenderLayer::Heatmap]);
+
+        assert!(Config::parse(&mode("Clash", 2)).is_err());
+        assert!(Config::parse(&[mode("Heat", 4), mode("Again", 4)].concat()).is_err());
+    }
+
+    #[test]
+    fn test_z_order_pins_panels() {
+        use crate::render::{Panel, RenderLayer};
+
+        let config = Config::parse("[z_order]\nabove_overlays = [\"agent_detail\"]\n").unwrap();
+        assert_eq!(config.z_order.slot(Panel::AgentDetail), RenderLayer::PinnedPanels);
+        assert_eq!(config.z_order.slot(Panel::Toasts), RenderLayer::Panels);
+        assert!(Config::parse("[z_order]\nabove_overlays = [\"minimap\"]\n").is_err());
+    }
+
+    #[test]
+    fn test_auto_landmarks_can_be_disabled() {
+        assert!(Config::default().auto_landmarks.settings().is_some());
+        let config = Config::parse("[auto_landmarks]\nenabled = false\n").unwrap();
+        assert_eq!(config.auto_landmarks.settings(), None);
+    }
+
+    #[test]
+    fn test_unknown_section_is_rejected() {
+        assert!(Config::parse("[alertz]\n").is_err());
+    }
+
+    #[test]
+    fn test_recent_files_round_trip() {
+        let path = std::env::temp_dir().join(format!("hive-recent-{}.toml", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let mut recent = RecentFiles::load_from(path.clone());
+        assert!(recent.files.is_empty());
+        recent.add(Path::new("/tmp/a.jsonl"));
+        recent.add(Path::new("/tmp/b.jsonl"));
+        recent.add(Path::new("/tmp/a.jsonl"));
+        recent.save().unwrap();
+
+        let loaded = RecentFiles::load_from(path.clone());
+        assert_eq!(
+            loaded.files,
+            vec![PathBuf::from("/tmp/a.jsonl"), PathBuf::from("/tmp/b.jsonl")]
+        );
+        let _ = std::fs::remove_file(&path);
+    }
+}
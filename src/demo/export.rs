@@ -0,0 +1,72 @@
+//! Headless demo export.
+//!
+//! Runs the demo generator without a terminal, with demo time advancing as
+//! fast as events can be written, and saves the events as JSON lines.
+
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use tokio::sync::mpsc;
+
+use super::{generate_demo_events, DemoOptions};
+
+/// Write `duration` worth of demo events to `path`.
+///
+/// Returns the number of events written.
+pub async fn export_demo_events(
+    options: DemoOptions,
+    duration: Duration,
+    path: &Path,
+) -> io::Result<usize> {
+    let mut out = BufWriter::new(std::fs::File::create(path)?);
+
+    let options = DemoOptions {
+        duration: Some(duration),
+        realtime: false,
+        ..options
+    };
+
+    let (tx, mut rx) = mpsc::channel(1000);
+    let generator = tokio::spawn(generate_demo_events(tx, options));
+
+    let mut count = 0;
+    while let Some(event) = rx.recv().await {
+        serde_json::to_writer(&mut out, &event)?;
+        out.write_all(b"\n")?;
+        count += 1;
+    }
+    out.flush()?;
+
+    generator.await.map_err(io::Error::other)?;
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hive_core::event::HiveEvent;
+
+    #[tokio::test]
+    async fn test_export_writes_parseable_lines() {
+        let path =
+            std::env::temp_dir().join(format!("hive-demo-export-{}.jsonl", std::process::id()));
+        let options = DemoOptions {
+            seed: Some(3),
+            ..DemoOptions::default()
+        };
+
+        let count = export_demo_events(options, Duration::from_secs(30), &path)
+            .await
+            .unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert!(count > 0);
+        assert_eq!(contents.lines().count(), count);
+        for line in contents.lines() {
+            serde_json::from_str::<HiveEvent>(line).unwrap();
+        }
+    }
+}
@@ -0,0 +1,436 @@
+//! Demo scenario files.
+//!
+//! A scenario replaces the built-in cast of the demo: which zones exist, which
+//! agents work in them, how the narrative phases are paced and which incidents
+//! happen when. Scenarios are TOML:
+//!
+//! ```toml
+//! name = "Auth outage walkthrough"
+//!
+//! [[landmarks]]
+//! id = "auth-zone"
+//! label = "Authentication"
+//! keywords = ["auth", "jwt", "session"]
+//!
+//! [[agents]]
+//! name = "Cipher"
+//! role = "Security Specialist"
+//! preferred_areas = ["auth", "jwt"]
+//! style = "steady"
+//! collaboration = 0.2
+//! base_intensity = 0.45
+//! messages = ["Auditing access controls"]
+//!
+//! [[phases]]
+//! phase = "exploration"
+//! secs = 10
+//!
+//! [[incidents]]
+//! at_secs = 30
+//! kind = "error"
+//! agents = ["Cipher"]
+//! message = "Token signing key rotated"
+//! duration_secs = 12
+//! ```
+//!
+//! Every section is optional; omitted sections fall back to the built-in demo.
+
+use std::io;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use hive_core::event::Keyword;
+
+use super::{ActivityStyle, AgentPersonality, NarrativePhase};
+
+/// A landmark created at the start of the demo
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LandmarkSpec {
+    pub id: String,
+    pub label: String,
+    pub keywords: Vec<String>,
+}
+
+/// One step of the narrative cycle with a fixed duration
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PhaseSpec {
+    pub phase: NarrativePhase,
+    pub secs: f32,
+}
+
+/// What happens during a scripted incident
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IncidentKind {
+    /// The listed agents (or a random one) go into the error state
+    Error,
+    /// Every agent converges on the incident's focus area
+    Swarm,
+    /// An error in the listed agents (or a random one) that spreads to a few
+    /// more agents, one after another
+    Cascade,
+    /// The listed agents (or a random one) fail for good and stop reporting
+    Death,
+}
+
+/// An incident fired at a fixed time into the demo
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct IncidentSpec {
+    /// Seconds after the agents have started up
+    pub at_secs: f32,
+    pub kind: IncidentKind,
+    /// Agents involved (defaults to a random agent for `error`)
+    #[serde(default)]
+    pub agents: Vec<String>,
+    /// Focus keywords the incident happens in
+    #[serde(default)]
+    pub focus: Vec<Keyword>,
+    #[serde(default)]
+    pub message: Option<String>,
+    /// How long affected agents stay in the error state
+    #[serde(default = "default_incident_duration")]
+    pub duration_secs: f32,
+}
+
+fn default_incident_duration() -> f32 {
+    10.0
+}
+
+impl IncidentSpec {
+    /// An incident with default settings, fired as soon as possible
+    pub fn on_demand(kind: IncidentKind) -> Self {
+        Self {
+            at_secs: 0.0,
+            kind,
+            agents: Vec::new(),
+            focus: Vec::new(),
+            message: None,
+            duration_secs: default_incident_duration(),
+        }
+    }
+}
+
+/// A complete demo definition
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Scenario {
+    pub name: String,
+    pub landmarks: Vec<LandmarkSpec>,
+    pub agents: Vec<AgentPersonality>,
+    /// Focus keyword pairs agents wander between
+    pub focus_areas: Vec<Vec<Keyword>>,
+    /// Fixed narrative cycle; empty means the built-in randomized pacing
+    pub phases: Vec<PhaseSpec>,
+    pub incidents: Vec<IncidentSpec>,
+}
+
+impl Default for Scenario {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl Scenario {
+    /// The built-in six-agent demo
+    pub fn builtin() -> Self {
+        Self {
+            name: "Hive demo".to_string(),
+            landmarks: builtin_landmarks(),
+            agents: builtin_personalities(),
+            focus_areas: builtin_focus_areas(),
+            phases: Vec::new(),
+            incidents: Vec::new(),
+        }
+    }
+
+    /// Load and validate a scenario file.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let contents = std::fs::read_to_string(path)?;
+        Self::parse(&contents).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {}", path.display(), e),
+            )
+        })
+    }
+
+    /// Parse and validate scenario TOML.
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let scenario: Scenario = toml::from_str(contents).map_err(|e| e.to_string())?;
+        scenario.validate()?;
+        Ok(scenario)
+    }
+
+    /// Grow or shrink the cast to `count` agents.
+    ///
+    /// Extra agents are copies of the existing personalities, numbered after
+    /// the original (`Atlas-2`, `Nova-2`, ..., `Atlas-3`, ...).
+    pub fn set_agent_count(&mut self, count: usize) {
+        let originals = self.agents.len();
+        if originals == 0 || count <= originals {
+            self.agents.truncate(count.max(1));
+            return;
+        }
+
+        for i in originals..count {
+            let mut agent = self.agents[i % originals].clone();
+            agent.name = format!("{}-{}", agent.name, i / originals + 1).into();
+            self.agents.push(agent);
+        }
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.agents.is_empty() {
+            return Err("scenario needs at least one agent".to_string());
+        }
+        if self.focus_areas.iter().all(|area| area.is_empty()) {
+            return Err("scenario needs at least one focus area".to_string());
+        }
+
+        for agent in &self.agents {
+            if agent.name.is_empty() {
+                return Err("agent names must not be empty".to_string());
+            }
+            if !(0.0..=1.0).contains(&agent.collaboration_tendency) {
+                return Err(format!("{}: collaboration must be between 0 and 1", agent.name));
+            }
+            if !(0.0..=1.0).contains(&agent.base_intensity) {
+                return Err(format!("{}: base_intensity must be between 0 and 1", agent.name));
+            }
+        }
+
+        for phase in &self.phases {
+            if phase.secs <= 0.0 {
+                return Err(format!("phase {:?} must last longer than 0s", phase.phase));
+            }
+        }
+
+        for incident in &self.incidents {
+            if let Some(unknown) = incident
+                .agents
+                .iter()
+                .find(|name| !self.agents.iter().any(|a| *a.name == **name))
+            {
+                return Err(format!("incident at {}s names unknown agent {}", incident.at_secs, unknown));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn strings(items: &[&str]) -> Vec<String> {
+    items.iter().map(|s| s.to_string()).collect()
+}
+
+fn builtin_landmarks() -> Vec<LandmarkSpec> {
+    [
+        ("auth-zone", "Authentication", ["auth", "jwt", "session", "login"]),
+        ("data-zone", "Database", ["database", "schema", "query", "model"]),
+        ("ui-zone", "Frontend", ["frontend", "react", "component", "ui"]),
+        ("api-zone", "API Layer", ["api", "endpoint", "rest", "handler"]),
+        ("test-zone", "Testing", ["test", "unit", "integration", "mock"]),
+        ("ops-zone", "DevOps", ["deploy", "docker", "ci", "kubernetes"]),
+    ]
+    .into_iter()
+    .map(|(id, label, keywords)| LandmarkSpec {
+        id: id.to_string(),
+        label: label.to_string(),
+        keywords: strings(&keywords),
+    })
+    .collect()
+}
+
+fn builtin_focus_areas() -> Vec<Vec<Keyword>> {
+    [
+        ["authentication", "jwt"],
+        ["database", "schema"],
+        ["frontend", "react"],
+        ["api", "endpoints"],
+        ["testing", "unit"],
+        ["deploy", "docker"],
+        ["cache", "redis"],
+        ["logging", "errors"],
+    ]
+    .iter()
+    .map(|area| area.iter().map(|&keyword| keyword.into()).collect())
+    .collect()
+}
+
+/// The six demo agents with distinct personalities
+fn builtin_personalities() -> Vec<AgentPersonality> {
+    vec![
+        AgentPersonality {
+            name: "Atlas".into(),
+            role: "Backend Specialist".to_string(),
+            preferred_areas: strings(&["api", "database", "schema", "query", "model", "endpoint"]),
+            activity_style: ActivityStyle::Steady,
+            collaboration_tendency: 0.3,
+            base_intensity: 0.5,
+            messages: strings(&[
+                "Optimizing query performance",
+                "Schema migration in progress",
+                "Refactoring data access layer",
+                "Indexing database tables",
+                "Reviewing API contracts",
+                "Tuning connection pool",
+            ]),
+        },
+        AgentPersonality {
+            name: "Nova".into(),
+            role: "Frontend Explorer".to_string(),
+            preferred_areas: strings(&["frontend", "react", "component", "ui", "style", "layout"]),
+            activity_style: ActivityStyle::Fast,
+            collaboration_tendency: 0.8,
+            base_intensity: 0.7,
+            messages: strings(&[
+                "Building new component",
+                "Styling user interface",
+                "Optimizing render cycle",
+                "Testing responsiveness",
+                "Exploring design patterns",
+                "Refining user experience",
+            ]),
+        },
+        AgentPersonality {
+            name: "Echo".into(),
+            role: "Quality Tester".to_string(),
+            preferred_areas: strings(&["test", "unit", "integration", "mock", "coverage", "debug"]),
+            activity_style: ActivityStyle::Bursty,
+            collaboration_tendency: 0.4,
+            base_intensity: 0.4,
+            messages: strings(&[
+                "Running test suite",
+                "Analyzing test coverage",
+                "Found edge case issue",
+                "Validating error handling",
+                "Checking regression tests",
+                "Investigating flaky test",
+            ]),
+        },
+        AgentPersonality {
+            name: "Cipher".into(),
+            role: "Security Specialist".to_string(),
+            preferred_areas: strings(&["auth", "jwt", "session", "login", "permission", "security"]),
+            activity_style: ActivityStyle::Steady,
+            collaboration_tendency: 0.2,
+            base_intensity: 0.45,
+            messages: strings(&[
+                "Auditing access controls",
+                "Validating JWT tokens",
+                "Reviewing auth flow",
+                "Checking permission matrix",
+                "Scanning for vulnerabilities",
+                "Hardening session management",
+            ]),
+        },
+        AgentPersonality {
+            name: "Flux".into(),
+            role: "DevOps Engineer".to_string(),
+            preferred_areas: strings(&["deploy", "docker", "ci", "kubernetes", "pipeline", "infra"]),
+            activity_style: ActivityStyle::Fast,
+            collaboration_tendency: 0.6,
+            base_intensity: 0.6,
+            messages: strings(&[
+                "Configuring deployment",
+                "Building container image",
+                "Updating CI pipeline",
+                "Scaling infrastructure",
+                "Monitoring health checks",
+                "Optimizing build times",
+            ]),
+        },
+        AgentPersonality {
+            name: "Sage".into(),
+            role: "Architecture Planner".to_string(),
+            preferred_areas: strings(&["architecture", "design", "pattern", "planning", "review"]),
+            activity_style: ActivityStyle::Bursty,
+            collaboration_tendency: 0.5,
+            base_intensity: 0.3,
+            messages: strings(&[
+                "Reviewing system design",
+                "Planning module structure",
+                "Analyzing dependencies",
+                "Documenting architecture",
+                "Evaluating trade-offs",
+                "Proposing improvements",
+            ]),
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_is_valid() {
+        let scenario = Scenario::builtin();
+        assert!(scenario.validate().is_ok());
+        assert_eq!(scenario.agents.len(), 6);
+    }
+
+    #[test]
+    fn test_parse_scenario() {
+        let scenario = Scenario::parse(
+            r#"
+            name = "Outage"
+
+            [[agents]]
+            name = "Atlas"
+            role = "Backend Specialist"
+            preferred_areas = ["database"]
+            style = "fast"
+            messages = ["Draining connections"]
+
+            [[phases]]
+            phase = "collaboration"
+            secs = 5
+
+            [[incidents]]
+            at_secs = 3
+            kind = "error"
+            agents = ["Atlas"]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(scenario.name, "Outage");
+        assert_eq!(scenario.agents[0].activity_style, ActivityStyle::Fast);
+        // Omitted sections fall back to the built-in demo
+        assert_eq!(scenario.landmarks.len(), 6);
+        assert_eq!(scenario.phases[0].phase, NarrativePhase::Collaboration);
+        assert_eq!(scenario.incidents[0].duration_secs, 10.0);
+    }
+
+    #[test]
+    fn test_set_agent_count() {
+        let mut scenario = Scenario::builtin();
+        scenario.set_agent_count(14);
+        assert_eq!(scenario.agents.len(), 14);
+        assert_eq!(&*scenario.agents[6].name, "Atlas-2");
+        assert_eq!(&*scenario.agents[13].name, "Nova-3");
+
+        scenario.set_agent_count(2);
+        let names: Vec<_> = scenario.agents.iter().map(|a| &*a.name).collect();
+        assert_eq!(names, ["Atlas", "Nova"]);
+    }
+
+    #[test]
+    fn test_unknown_incident_agent_is_rejected() {
+        let err = Scenario::parse(
+            r#"
+            [[incidents]]
+            at_secs = 3
+            kind = "error"
+            agents = ["Nobody"]
+            "#,
+        )
+        .unwrap_err();
+        assert!(err.contains("Nobody"));
+    }
+}
@@ -0,0 +1,125 @@
+//! Which agents are shown on the field.
+//!
+//! The `/` filter text narrows the agents down by ID or alias, and the quick
+//! status filters (`A`, `E`, `I`) by what they are doing. Both apply at once.
+
+use serde::{Deserialize, Serialize};
+
+use hive_core::event::AgentStatus;
+use hive_core::state::Agent;
+
+/// Quick filter on the agents' status
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatusFilter {
+    /// Every status
+    #[default]
+    All,
+    /// Only active agents
+    OnlyActive,
+    /// Only agents in error
+    OnlyError,
+    /// Everything but idle agents
+    HideIdle,
+}
+
+impl StatusFilter {
+    /// Switch to `filter`, or back to all agents if it is already on
+    pub fn toggle(self, filter: StatusFilter) -> Self {
+        if self == filter {
+            StatusFilter::All
+        } else {
+            filter
+        }
+    }
+
+    /// Whether agents with `status` pass the filter
+    pub fn matches(self, status: &AgentStatus) -> bool {
+        match self {
+            StatusFilter::All => true,
+            StatusFilter::OnlyActive => *status == AgentStatus::Active,
+            StatusFilter::OnlyError => *status == AgentStatus::Error,
+            StatusFilter::HideIdle => *status != AgentStatus::Idle,
+        }
+    }
+
+    /// Label shown in the status bar
+    pub fn label(self) -> Option<&'static str> {
+        match self {
+            StatusFilter::All => None,
+            StatusFilter::OnlyActive => Some("active only"),
+            StatusFilter::OnlyError => Some("errors only"),
+            StatusFilter::HideIdle => Some("no idle"),
+        }
+    }
+}
+
+/// Filter on the agents shown
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AgentFilter {
+    /// Text the agent's ID or alias must contain (case-insensitive)
+    pub text: String,
+    pub status: StatusFilter,
+}
+
+impl AgentFilter {
+    /// Whether any agent can be filtered out
+    pub fn is_active(&self) -> bool {
+        !self.text.is_empty() || self.status != StatusFilter::All
+    }
+
+    /// Whether `agent` is shown
+    pub fn matches(&self, agent: &Agent) -> bool {
+        if !self.status.matches(&agent.status) {
+            return false;
+        }
+        if self.text.is_empty() {
+            return true;
+        }
+        let text = self.text.to_lowercase();
+        agent.id.to_lowercase().contains(&text) || agent.display_name().to_lowercase().contains(&text)
+    }
+
+    /// Status bar description: the text and the status filter's label
+    pub fn describe(&self) -> String {
+        match (self.text.as_str(), self.status.label()) {
+            (text, None) => text.to_string(),
+            ("", Some(label)) => label.to_string(),
+            (text, Some(label)) => format!("{} · {}", text, label),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn agent(id: &str, status: AgentStatus) -> Agent {
+        let mut agent = Agent::new(id.into(), 0);
+        agent.status = status;
+        agent
+    }
+
+    #[test]
+    fn test_status_and_text_filters_combine() {
+        let atlas = agent("atlas", AgentStatus::Active);
+        let nova = agent("nova", AgentStatus::Idle);
+        let orion = agent("orion", AgentStatus::Error);
+
+        let mut filter = AgentFilter::default();
+        filter.status = filter.status.toggle(StatusFilter::HideIdle);
+        assert!(filter.matches(&atlas) && filter.matches(&orion) && !filter.matches(&nova));
+
+        filter.status = filter.status.toggle(StatusFilter::OnlyError);
+        assert!(filter.matches(&orion) && !filter.matches(&atlas));
+
+        filter.text = "ATL".to_string();
+        assert!(!filter.matches(&orion));
+        assert_eq!(filter.describe(), "ATL · errors only");
+
+        // Toggling the same quick filter again turns it off
+        filter.status = filter.status.toggle(StatusFilter::OnlyError);
+        assert!(filter.matches(&atlas));
+        assert_eq!(filter.describe(), "ATL");
+    }
+}
@@ -0,0 +1,270 @@
+//! Hook adapter for coding agent CLIs (`hive hook`).
+//!
+//! Coding agents that run a command on lifecycle hooks pass it a JSON payload
+//! on stdin naming the hook (`hook_event_name`) and, for tool hooks, the tool
+//! and its input. `hive hook` turns each payload into an agent update and
+//! appends it to the events file, so the CLI shows up on the field without a
+//! translation script. Each session is one agent, named after the project
+//! directory and the session ID. Its focus is the project, the tool and what
+//! the tool works on (file names, the program run, the host fetched), so
+//! agents gather around the parts of the code they touch.
+
+use std::io::{self, Read};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use hive_core::event::{intern, AgentStatus, AgentUpdate, HiveEvent, Keyword};
+use hive_emit::HiveEmitter;
+
+/// Characters of a command or prompt kept in the message
+const MESSAGE_PREVIEW: usize = 60;
+
+/// Characters of the session ID kept in the agent ID
+const SESSION_PREFIX: usize = 6;
+
+/// Fields of a hook payload that are used; others are ignored
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct HookPayload {
+    hook_event_name: String,
+    session_id: String,
+    cwd: String,
+    tool_name: String,
+    tool_input: Value,
+    tool_response: Value,
+    prompt: String,
+    message: String,
+}
+
+/// Settings for `hive hook`
+#[derive(Debug, Clone, Default)]
+pub struct HookOptions {
+    /// Agent ID to report as, instead of one derived from the session
+    pub agent: Option<String>,
+}
+
+/// Translate the payloads on `input` and write their events to `emitter`,
+/// returning how many were written. Payloads of hooks Hive has no use for
+/// are skipped.
+pub fn run(mut input: impl Read, emitter: &HiveEmitter, options: &HookOptions) -> io::Result<usize> {
+    let mut text = String::new();
+    input.read_to_string(&mut text)?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs());
+
+    let mut written = 0;
+    for payload in serde_json::Deserializer::from_str(&text).into_iter::<HookPayload>() {
+        let payload = payload.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("invalid hook payload: {}", e)))?;
+        if let Some(event) = translate(&payload, options, now) {
+            emitter.emit(&event)?;
+            written += 1;
+        }
+    }
+    Ok(written)
+}
+
+/// The agent update a hook payload stands for
+fn translate(payload: &HookPayload, options: &HookOptions, now: u64) -> Option<HiveEvent> {
+    let tool = tool_label(&payload.tool_name);
+    let (status, intensity, message) = match payload.hook_event_name.as_str() {
+        "SessionStart" => (AgentStatus::Idle, 0.2, "Session started".to_string()),
+        "UserPromptSubmit" => (AgentStatus::Thinking, 0.5, preview(&payload.prompt)),
+        "PreToolUse" => {
+            let message = match tool_detail(payload) {
+                Some(detail) => format!("{}: {}", tool, detail),
+                None => tool.clone(),
+            };
+            (AgentStatus::Active, 0.8, message)
+        }
+        "PostToolUse" if tool_failed(&payload.tool_response) => {
+            (AgentStatus::Error, 0.6, format!("{} failed", tool))
+        }
+        "PostToolUse" => (AgentStatus::Thinking, 0.5, format!("{} done", tool)),
+        "Notification" => (AgentStatus::Waiting, 0.3, preview(&payload.message)),
+        "SubagentStop" => (AgentStatus::Thinking, 0.4, "Subagent finished".to_string()),
+        "PreCompact" => (AgentStatus::Thinking, 0.3, "Compacting context".to_string()),
+        "Stop" => (AgentStatus::Idle, 0.1, "Finished".to_string()),
+        "SessionEnd" => (AgentStatus::Idle, 0.0, "Session ended".to_string()),
+        _ => return None,
+    };
+
+    Some(HiveEvent::AgentUpdate(AgentUpdate {
+        agent_id: intern(&agent_id(payload, options)),
+        status,
+        focus: focus(payload),
+        intensity,
+        message,
+        timestamp: now,
+    }))
+}
+
+/// `--agent`, or the project directory's name and the start of the session ID
+fn agent_id(payload: &HookPayload, options: &HookOptions) -> String {
+    if let Some(ref agent) = options.agent {
+        return agent.clone();
+    }
+    let project = project(payload).unwrap_or_else(|| "agent".to_string());
+    let session: String = payload.session_id.chars().take(SESSION_PREFIX).collect();
+    if session.is_empty() {
+        project
+    } else {
+        format!("{}-{}", project, session)
+    }
+}
+
+fn project(payload: &HookPayload) -> Option<String> {
+    let name = Path::new(&payload.cwd).file_name()?.to_str()?;
+    Some(name.to_lowercase())
+}
+
+/// Keywords placing the agent: the project, the tool, and what it works on
+fn focus(payload: &HookPayload) -> Vec<Keyword> {
+    let mut keywords: Vec<String> = project(payload).into_iter().collect();
+    if !payload.tool_name.is_empty() {
+        keywords.push(tool_label(&payload.tool_name).to_lowercase());
+    }
+    let input = &payload.tool_input;
+    let path = ["file_path", "notebook_path", "path"].iter().find_map(|key| input.get(key)?.as_str());
+    if let Some(path) = path {
+        let path = Path::new(path);
+        if let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) {
+            keywords.push(stem.to_lowercase());
+        }
+        if let Some(dir) = path.parent().and_then(Path::file_name).and_then(|dir| dir.to_str()) {
+            keywords.push(dir.to_lowercase());
+        }
+    }
+    if let Some(command) = input.get("command").and_then(Value::as_str) {
+        let program = command.split_whitespace().next().unwrap_or_default();
+        if let Some(program) = Path::new(program).file_name().and_then(|name| name.to_str()) {
+            keywords.push(program.to_lowercase());
+        }
+    }
+    if let Some(url) = input.get("url").and_then(Value::as_str) {
+        let host = url.split("://").nth(1).unwrap_or(url).split(['/', ':']).next().unwrap_or_default();
+        keywords.push(host.to_lowercase());
+    }
+
+    let mut focus: Vec<Keyword> = Vec::new();
+    for keyword in keywords {
+        let keyword = intern(&keyword);
+        if !keyword.is_empty() && !focus.contains(&keyword) {
+            focus.push(keyword);
+        }
+    }
+    focus
+}
+
+/// Tool name as shown; MCP tools (`mcp__server__tool`) go by their server
+fn tool_label(tool_name: &str) -> String {
+    match tool_name.strip_prefix("mcp__") {
+        Some(rest) => rest.split("__").next().unwrap_or(rest).to_string(),
+        None => tool_name.to_string(),
+    }
+}
+
+/// What a tool call is about, for the message
+fn tool_detail(payload: &HookPayload) -> Option<String> {
+    let input = &payload.tool_input;
+    ["file_path", "notebook_path", "path", "command", "pattern", "url", "query", "description"]
+        .iter()
+        .find_map(|key| input.get(key)?.as_str())
+        .map(|detail| {
+            // Files inside the project are shown relative to it
+            let relative = Path::new(detail).strip_prefix(&payload.cwd).ok().and_then(Path::to_str);
+            preview(relative.unwrap_or(detail))
+        })
+}
+
+/// Whether a tool's response reports that it failed
+fn tool_failed(response: &Value) -> bool {
+    response.get("is_error").and_then(Value::as_bool) == Some(true)
+        || response.get("success").and_then(Value::as_bool) == Some(false)
+        || response.get("error").is_some_and(|error| !error.is_null())
+}
+
+/// First line of `text`, cut short
+fn preview(text: &str) -> String {
+    let line = text.lines().next().unwrap_or_default().trim();
+    if line.chars().count() > MESSAGE_PREVIEW {
+        let cut: String = line.chars().take(MESSAGE_PREVIEW - 1).collect();
+        format!("{}…", cut)
+    } else {
+        line.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    /// Writer whose contents can be read after the emitter took it
+    #[derive(Clone, Default)]
+    struct Shared(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_payloads_become_agent_updates() {
+        let input = r#"
+            {"hook_event_name": "PreToolUse", "session_id": "3f9c2a77-1e", "cwd": "/home/dev/Shop",
+             "tool_name": "Edit", "tool_input": {"file_path": "/home/dev/Shop/src/auth/token.rs", "old_string": "a"}}
+            {"hook_event_name": "PostToolUse", "session_id": "3f9c2a77-1e", "cwd": "/home/dev/Shop",
+             "tool_name": "Bash", "tool_input": {"command": "/usr/bin/cargo test"}, "tool_response": {"error": "exit 101"}}
+            {"hook_event_name": "Notification", "session_id": "3f9c2a77-1e", "cwd": "/home/dev/Shop",
+             "message": "Needs your permission to use Bash"}
+            {"hook_event_name": "SomethingNew", "session_id": "3f9c2a77-1e"}
+        "#;
+        let out = Shared::default();
+        let emitter = HiveEmitter::from_writer(out.clone());
+        let written = run(input.as_bytes(), &emitter, &HookOptions::default()).unwrap();
+        assert_eq!(written, 3);
+
+        let out = String::from_utf8(out.0.lock().unwrap().clone()).unwrap();
+        let updates: Vec<AgentUpdate> = out
+            .lines()
+            .map(|line| match serde_json::from_str(line).unwrap() {
+                HiveEvent::AgentUpdate(update) => update,
+                other => panic!("unexpected event {:?}", other),
+            })
+            .collect();
+
+        assert_eq!(&*updates[0].agent_id, "shop-3f9c2a");
+        assert_eq!(updates[0].status, AgentStatus::Active);
+        assert_eq!(updates[0].message, "Edit: src/auth/token.rs");
+        let focus: Vec<&str> = updates[0].focus.iter().map(|keyword| &**keyword).collect();
+        assert_eq!(focus, ["shop", "edit", "token", "auth"]);
+
+        assert_eq!(updates[1].status, AgentStatus::Error);
+        assert_eq!(updates[1].message, "Bash failed");
+        assert!(updates[1].focus.iter().any(|keyword| &**keyword == "cargo"));
+
+        assert_eq!(updates[2].status, AgentStatus::Waiting);
+
+        let options = HookOptions {
+            agent: Some("reviewer".to_string()),
+        };
+        let payload: HookPayload = serde_json::from_str(r#"{"hook_event_name": "Stop"}"#).unwrap();
+        match translate(&payload, &options, 1) {
+            Some(HiveEvent::AgentUpdate(update)) => assert_eq!(&*update.agent_id, "reviewer"),
+            other => panic!("unexpected event {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_invalid_payload_is_an_error() {
+        let emitter = HiveEmitter::from_writer(io::sink());
+        assert!(run("{\"hook_event_name\": ".as_bytes(), &emitter, &HookOptions::default()).is_err());
+    }
+}
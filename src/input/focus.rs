@@ -0,0 +1,58 @@
+//! Keyboard focus between the panes of the app.
+//!
+//! Tab and Shift+Tab cycle through the panes currently on screen. The focused
+//! pane receives the navigation keys and draws a highlighted border; keys it
+//! does not use fall through to the global bindings.
+
+/// A pane that can hold keyboard focus
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Pane {
+    /// The swarm field
+    #[default]
+    Field,
+    /// The activity log on the right
+    ActivityLog,
+    /// The replay timeline
+    Timeline,
+}
+
+impl Pane {
+    /// Every pane, in Tab order
+    pub const ALL: [Pane; 3] = [Pane::Field, Pane::ActivityLog, Pane::Timeline];
+
+    /// The pane after this one among `visible`, wrapping around
+    pub fn next(self, visible: &[Pane]) -> Pane {
+        self.step(visible, 1)
+    }
+
+    /// The pane before this one among `visible`, wrapping around
+    pub fn prev(self, visible: &[Pane]) -> Pane {
+        self.step(visible, visible.len().saturating_sub(1))
+    }
+
+    fn step(self, visible: &[Pane], by: usize) -> Pane {
+        match visible.iter().position(|&p| p == self) {
+            Some(idx) => visible[(idx + by) % visible.len()],
+            None => visible.first().copied().unwrap_or_default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cycles_through_visible_panes() {
+        let visible = [Pane::Field, Pane::ActivityLog, Pane::Timeline];
+        assert_eq!(Pane::Field.next(&visible), Pane::ActivityLog);
+        assert_eq!(Pane::Timeline.next(&visible), Pane::Field);
+        assert_eq!(Pane::Field.prev(&visible), Pane::Timeline);
+
+        // Hidden panes are skipped, and focus on one falls back to the first
+        let visible = [Pane::Field, Pane::Timeline];
+        assert_eq!(Pane::Field.next(&visible), Pane::Timeline);
+        assert_eq!(Pane::ActivityLog.next(&visible), Pane::Field);
+        assert_eq!(Pane::Field.next(&[Pane::Field]), Pane::Field);
+    }
+}
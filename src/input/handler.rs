@@ -1,6 +1,21 @@
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 use std::time::Duration;
 
+/// Share of the recording the arrow keys seek by
+const SEEK_STEP: f32 = 0.05;
+
+/// Seek step with Shift held
+const SEEK_FINE: f32 = 0.01;
+
+/// Seek step of Page Up / Page Down
+const SEEK_COARSE: f32 = 0.25;
+
+/// Playback speeds of the keys 1-4 while the timeline has focus
+const SPEED_PRESETS: [f32; 4] = [0.5, 1.0, 2.0, 4.0];
+
+use super::Pane;
+use crate::filter::StatusFilter;
+
 /// Processed input events for the application
 #[derive(Debug, Clone)]
 pub enum InputEvent {
@@ -12,12 +27,12 @@ pub enum InputEvent {
     SpeedUp,
     /// Speed down playback
     SpeedDown,
+    /// Set the playback speed to a preset (1-4 on the timeline)
+    SetSpeed(f32),
     /// Toggle replay mode
     ToggleReplay,
-    /// Seek backward in replay
-    SeekBackward,
-    /// Seek forward in replay
-    SeekForward,
+    /// Seek in replay by this share of the recording (negative is backward)
+    Seek(f32),
     /// Toggle heat map display
     ToggleHeatMap,
     /// Toggle trails display
@@ -28,6 +43,24 @@ pub enum InputEvent {
     ClearHeatMap,
     /// Toggle help overlay
     ToggleHelp,
+    /// Toggle the connection matrix overlay
+    ToggleMatrix,
+    /// Toggle the session stats overlay
+    ToggleStats,
+    /// Toggle live ghost agents over a replay
+    ToggleGhosts,
+    /// Toggle the leaderboard of the busiest agents
+    ToggleLeaderboard,
+    /// Cycle the order agents are listed in (O)
+    CycleAgentOrder,
+    /// Cycle what the heat map shows (H)
+    CycleHeatSource,
+    /// Toggle filled, outlined agents with status badges (C)
+    ToggleHighContrast,
+    /// Save the heat grid to a file
+    ExportHeatMap,
+    /// Save the aggregated metrics to a JSON file
+    ExportStats,
     /// Cycle through display modes (Minimal -> Standard -> Debug)
     CycleDisplayMode,
     /// Set display mode to Minimal
@@ -36,14 +69,20 @@ pub enum InputEvent {
     SetModeStandard,
     /// Set display mode to Debug
     SetModeDebug,
+    /// Switch to the custom display mode bound to this number key (4-9)
+    SetModeCustom(u8),
     /// Mouse hover at position
     MouseHover { x: u16, y: u16 },
     /// Mouse click at position
     MouseClick { x: u16, y: u16 },
+    /// Mouse wheel turned at position (`up` away from the user)
+    MouseScroll { x: u16, y: u16, up: bool },
     /// Terminal resize
     Resize { width: u16, height: u16 },
     /// Close help (any key when help is shown)
     CloseHelp,
+    /// Take down the intro splash (any key while it is shown)
+    DismissIntro,
     /// Enter filter mode (/)
     EnterFilterMode,
     /// Character input for filter text
@@ -54,6 +93,69 @@ pub enum InputEvent {
     ClearFilter,
     /// Exit filter mode (Esc when in filter mode)
     ExitFilterMode,
+    /// Turn a quick status filter on or off (A, E, I)
+    ToggleStatusFilter(StatusFilter),
+    /// Inject the next chaos incident (demo mode)
+    InjectChaos,
+    /// Open the file browser (o)
+    OpenFileBrowser,
+    /// Move the file browser selection up
+    BrowserUp,
+    /// Move the file browser selection down
+    BrowserDown,
+    /// Open the selected directory or file
+    BrowserEnter,
+    /// Go to the parent directory
+    BrowserParent,
+    /// Close the file browser
+    CloseBrowser,
+    /// Open the landmark editor (L)
+    OpenLandmarkEditor,
+    /// Key press while the landmark editor is open
+    EditorKey(KeyCode),
+    /// Open the agent search (f)
+    OpenSearch,
+    /// Key press while the agent search is open
+    SearchKey(KeyCode),
+    /// Enter command mode (:)
+    EnterCommandMode,
+    /// Character input for the command line
+    CommandInput(char),
+    /// Run the typed command (Enter in command mode)
+    RunCommand,
+    /// Exit command mode (Esc in command mode)
+    ExitCommandMode,
+    /// Focus the next pane (Tab)
+    FocusNext,
+    /// Focus the previous pane (Shift+Tab)
+    FocusPrev,
+    /// Return focus to the field (Esc in another pane)
+    FocusField,
+    /// Jump to the start of the recording (Home)
+    SeekStart,
+    /// Jump to the end of the recording (End)
+    SeekEnd,
+    /// Mark the start of the heat map range at the playhead ([ on the timeline)
+    MarkHeatStart,
+    /// Mark the end of the heat map range and compute it (] on the timeline)
+    MarkHeatEnd,
+    /// Go back to live heat (Backspace on the timeline)
+    ClearHeatRange,
+    /// Start typing a search in the focused activity log (/)
+    EnterLogSearch,
+    /// Jump to the next older search match (n)
+    NextLogMatch,
+    /// Jump to the next newer search match (N)
+    PrevLogMatch,
+    /// Move the highlight up the pinned agent's partner list (Up)
+    PartnerPrev,
+    /// Move the highlight down the pinned agent's partner list (Down)
+    PartnerNext,
+    /// Select the highlighted partner (Enter)
+    JumpToPartner,
+    /// Stop loading the existing events and only follow new ones (Esc
+    /// while loading)
+    SkipLoad,
     /// No event
     None,
 }
@@ -61,14 +163,28 @@ pub enum InputEvent {
 /// Input handler for processing terminal events
 pub struct InputHandler {
     help_visible: bool,
+    intro_visible: bool,
     filter_mode: bool,
+    command_mode: bool,
+    browser_open: bool,
+    editor_open: bool,
+    search_open: bool,
+    loading: bool,
+    focus: Pane,
 }
 
 impl InputHandler {
     pub fn new() -> Self {
         Self {
             help_visible: false,
+            intro_visible: false,
             filter_mode: false,
+            command_mode: false,
+            browser_open: false,
+            editor_open: false,
+            search_open: false,
+            loading: false,
+            focus: Pane::Field,
         }
     }
 
@@ -77,6 +193,11 @@ impl InputHandler {
         self.help_visible = visible;
     }
 
+    /// Set whether the intro splash is shown
+    pub fn set_intro_visible(&mut self, visible: bool) {
+        self.intro_visible = visible;
+    }
+
     /// Set filter mode state
     pub fn set_filter_mode(&mut self, active: bool) {
         self.filter_mode = active;
@@ -87,6 +208,36 @@ impl InputHandler {
         self.filter_mode
     }
 
+    /// Set command mode state
+    pub fn set_command_mode(&mut self, active: bool) {
+        self.command_mode = active;
+    }
+
+    /// Set whether the existing events are still being loaded
+    pub fn set_loading(&mut self, loading: bool) {
+        self.loading = loading;
+    }
+
+    /// Set the pane with keyboard focus
+    pub fn set_focus(&mut self, pane: Pane) {
+        self.focus = pane;
+    }
+
+    /// Set whether the file browser is open
+    pub fn set_browser_open(&mut self, open: bool) {
+        self.browser_open = open;
+    }
+
+    /// Set whether the landmark editor is open
+    pub fn set_editor_open(&mut self, open: bool) {
+        self.editor_open = open;
+    }
+
+    /// Set whether the agent search is open
+    pub fn set_search_open(&mut self, open: bool) {
+        self.search_open = open;
+    }
+
     /// Poll for input events with timeout
     pub fn poll(&mut self, timeout: Duration) -> Option<InputEvent> {
         if event::poll(timeout).ok()? {
@@ -103,16 +254,68 @@ impl InputHandler {
 
     /// Handle keyboard input
     fn handle_key(&self, event: KeyEvent) -> InputEvent {
+        // Any key starts past the intro splash
+        if self.intro_visible {
+            return InputEvent::DismissIntro;
+        }
+
         // If help is visible, any key closes it
         if self.help_visible {
             return InputEvent::CloseHelp;
         }
 
+        // Only quitting and skipping the load until the events are in
+        if self.loading {
+            return match event.code {
+                KeyCode::Esc => InputEvent::SkipLoad,
+                KeyCode::Char('q') => InputEvent::Quit,
+                KeyCode::Char('c') if event.modifiers.contains(KeyModifiers::CONTROL) => InputEvent::Quit,
+                _ => InputEvent::None,
+            };
+        }
+
         // If filter mode is active, handle filter-specific input
         if self.filter_mode {
             return self.handle_filter_key(event);
         }
 
+        if self.command_mode {
+            return self.handle_command_key(event);
+        }
+
+        if self.browser_open {
+            return self.handle_browser_key(event);
+        }
+
+        if self.editor_open {
+            return InputEvent::EditorKey(event.code);
+        }
+
+        if self.search_open {
+            return InputEvent::SearchKey(event.code);
+        }
+
+        // Navigation keys of the focused pane
+        let pane_event = match (self.focus, event.code) {
+            (Pane::ActivityLog | Pane::Timeline, KeyCode::Esc) => InputEvent::FocusField,
+            (Pane::ActivityLog, KeyCode::Char('/')) => InputEvent::EnterLogSearch,
+            (Pane::ActivityLog, KeyCode::Char('n')) => InputEvent::NextLogMatch,
+            (Pane::ActivityLog, KeyCode::Char('N')) => InputEvent::PrevLogMatch,
+            (Pane::Field, KeyCode::Up) => InputEvent::PartnerPrev,
+            (Pane::Field, KeyCode::Down) => InputEvent::PartnerNext,
+            (Pane::Field, KeyCode::Enter) => InputEvent::JumpToPartner,
+            (Pane::Timeline, KeyCode::Char('[')) => InputEvent::MarkHeatStart,
+            (Pane::Timeline, KeyCode::Char(']')) => InputEvent::MarkHeatEnd,
+            (Pane::Timeline, KeyCode::Backspace) => InputEvent::ClearHeatRange,
+            (Pane::Timeline, KeyCode::Char(c @ '1'..='4')) => {
+                InputEvent::SetSpeed(SPEED_PRESETS[(c as u8 - b'1') as usize])
+            }
+            _ => InputEvent::None,
+        };
+        if !matches!(pane_event, InputEvent::None) {
+            return pane_event;
+        }
+
         match event.code {
             // Quit
             KeyCode::Char('q') | KeyCode::Esc => InputEvent::Quit,
@@ -131,27 +334,59 @@ impl InputHandler {
 
             // Replay
             KeyCode::Char('r') => InputEvent::ToggleReplay,
-            KeyCode::Left => InputEvent::SeekBackward,
-            KeyCode::Right => InputEvent::SeekForward,
+            KeyCode::Left | KeyCode::Right => {
+                let step = if event.modifiers.contains(KeyModifiers::SHIFT) { SEEK_FINE } else { SEEK_STEP };
+                InputEvent::Seek(if event.code == KeyCode::Left { -step } else { step })
+            }
+            KeyCode::PageUp => InputEvent::Seek(-SEEK_COARSE),
+            KeyCode::PageDown => InputEvent::Seek(SEEK_COARSE),
+            KeyCode::Home => InputEvent::SeekStart,
+            KeyCode::End => InputEvent::SeekEnd,
 
             // Display toggles (legacy - still work for fine-grained control)
             KeyCode::Char('h') => InputEvent::ToggleHeatMap,
+            KeyCode::Char('H') => InputEvent::CycleHeatSource,
             KeyCode::Char('t') => InputEvent::ToggleTrails,
             KeyCode::Char('l') => InputEvent::ToggleLandmarks,
             KeyCode::Char('c') => InputEvent::ClearHeatMap,
+            KeyCode::Char('e') => InputEvent::ExportHeatMap,
+            KeyCode::Char('S') => InputEvent::ExportStats,
+            KeyCode::Char('g') => InputEvent::ToggleMatrix,
+            KeyCode::Char('s') => InputEvent::ToggleStats,
+            KeyCode::Char('v') => InputEvent::ToggleGhosts,
+            KeyCode::Char('b') => InputEvent::ToggleLeaderboard,
+            KeyCode::Char('C') => InputEvent::ToggleHighContrast,
+            KeyCode::Char('O') => InputEvent::CycleAgentOrder,
 
             // Display mode controls
             KeyCode::Char('m') => InputEvent::CycleDisplayMode,
             KeyCode::Char('1') => InputEvent::SetModeMinimal,
             KeyCode::Char('2') => InputEvent::SetModeStandard,
             KeyCode::Char('3') => InputEvent::SetModeDebug,
+            KeyCode::Char(c @ '4'..='9') => InputEvent::SetModeCustom(c as u8 - b'0'),
 
             // Help
             KeyCode::Char('?') => InputEvent::ToggleHelp,
 
+            // Pane focus
+            KeyCode::Tab => InputEvent::FocusNext,
+            KeyCode::BackTab => InputEvent::FocusPrev,
+
             // Filter mode
             KeyCode::Char('/') => InputEvent::EnterFilterMode,
             KeyCode::Char('0') => InputEvent::ClearFilter,
+            KeyCode::Char('A') => InputEvent::ToggleStatusFilter(StatusFilter::OnlyActive),
+            KeyCode::Char('E') => InputEvent::ToggleStatusFilter(StatusFilter::OnlyError),
+            KeyCode::Char('I') => InputEvent::ToggleStatusFilter(StatusFilter::HideIdle),
+            KeyCode::Char('f') => InputEvent::OpenSearch,
+
+            // Demo chaos
+            KeyCode::Char('x') => InputEvent::InjectChaos,
+
+            // Sources
+            KeyCode::Char('o') => InputEvent::OpenFileBrowser,
+            KeyCode::Char('L') => InputEvent::OpenLandmarkEditor,
+            KeyCode::Char(':') => InputEvent::EnterCommandMode,
 
             _ => InputEvent::None,
         }
@@ -176,6 +411,29 @@ impl InputHandler {
         }
     }
 
+    /// Handle keyboard input when in command mode
+    fn handle_command_key(&self, event: KeyEvent) -> InputEvent {
+        match event.code {
+            KeyCode::Esc => InputEvent::ExitCommandMode,
+            KeyCode::Enter => InputEvent::RunCommand,
+            KeyCode::Char(c) => InputEvent::CommandInput(c),
+            KeyCode::Backspace => InputEvent::CommandInput('\x08'),
+            _ => InputEvent::None,
+        }
+    }
+
+    /// Handle keyboard input while the file browser is open
+    fn handle_browser_key(&self, event: KeyEvent) -> InputEvent {
+        match event.code {
+            KeyCode::Esc | KeyCode::Char('q') => InputEvent::CloseBrowser,
+            KeyCode::Up | KeyCode::Char('k') => InputEvent::BrowserUp,
+            KeyCode::Down | KeyCode::Char('j') => InputEvent::BrowserDown,
+            KeyCode::Enter | KeyCode::Right | KeyCode::Char('l') => InputEvent::BrowserEnter,
+            KeyCode::Left | KeyCode::Backspace | KeyCode::Char('h') => InputEvent::BrowserParent,
+            _ => InputEvent::None,
+        }
+    }
+
     /// Handle mouse input
     fn handle_mouse(&self, event: MouseEvent) -> InputEvent {
         match event.kind {
@@ -187,6 +445,11 @@ impl InputHandler {
                 x: event.column,
                 y: event.row,
             },
+            MouseEventKind::ScrollUp | MouseEventKind::ScrollDown => InputEvent::MouseScroll {
+                x: event.column,
+                y: event.row,
+                up: event.kind == MouseEventKind::ScrollUp,
+            },
             _ => InputEvent::None,
         }
     }
@@ -0,0 +1,121 @@
+//! Which agent the mouse is hovering.
+//!
+//! The agent under the cursor only counts as hovered once it has stayed there
+//! for a short delay, so agents drifting under a resting cursor do not flash
+//! the detail panel. Once shown, the panel stays for a while after the mouse
+//! leaves the agent ("sticky"), unless another agent is hovered meanwhile.
+
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+
+use hive_core::event::AgentId;
+
+/// `[hover]` section of the config file
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HoverConfig {
+    /// Milliseconds the mouse has to rest on an agent before it is hovered
+    pub delay_ms: u64,
+    /// Milliseconds the detail panel stays after the mouse leaves the agent
+    /// (0 hides it at once)
+    pub sticky_ms: u64,
+}
+
+impl Default for HoverConfig {
+    fn default() -> Self {
+        Self {
+            delay_ms: 80,
+            sticky_ms: 1000,
+        }
+    }
+}
+
+/// Debounced hover state
+#[derive(Debug, Clone)]
+pub struct Hover {
+    delay: Duration,
+    sticky: Duration,
+    /// Agent counted as hovered
+    current: Option<AgentId>,
+    /// Agent under the cursor, and since when
+    under: Option<AgentId>,
+    since: Instant,
+}
+
+impl Hover {
+    pub fn new(config: &HoverConfig) -> Self {
+        Self {
+            delay: Duration::from_millis(config.delay_ms),
+            sticky: Duration::from_millis(config.sticky_ms),
+            current: None,
+            under: None,
+            since: Instant::now(),
+        }
+    }
+
+    /// Note the agent under the cursor at `now` (called every frame, since
+    /// agents move under a resting cursor) and return the hovered agent.
+    pub fn update(&mut self, under: Option<AgentId>, now: Instant) -> Option<&str> {
+        if under != self.under {
+            self.under = under;
+            self.since = now;
+        }
+
+        let settled = now.saturating_duration_since(self.since);
+        match self.under {
+            Some(ref id) if settled >= self.delay => self.current = Some(id.clone()),
+            None if settled >= self.sticky => self.current = None,
+            _ => {}
+        }
+        self.current.as_deref()
+    }
+
+    /// The hovered agent
+    pub fn current(&self) -> Option<&str> {
+        self.current.as_deref()
+    }
+
+    /// Stop hovering at once (e.g. the agent is gone)
+    pub fn clear(&mut self) {
+        self.current = None;
+        self.under = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debounce_and_sticky() {
+        let mut hover = Hover::new(&HoverConfig {
+            delay_ms: 100,
+            sticky_ms: 1000,
+        });
+        let start = Instant::now();
+        let at = |ms| start + Duration::from_millis(ms);
+        let atlas = || Some("atlas".into());
+
+        // Passing over an agent briefly does nothing
+        assert_eq!(hover.update(atlas(), at(0)), None);
+        assert_eq!(hover.update(None, at(50)), None);
+
+        // Resting on it does
+        hover.update(atlas(), at(100));
+        assert_eq!(hover.update(atlas(), at(200)), Some("atlas"));
+
+        // The panel outlasts the cursor leaving...
+        assert_eq!(hover.update(None, at(300)), Some("atlas"));
+        assert_eq!(hover.update(None, at(1200)), Some("atlas"));
+        // ...for the sticky time
+        assert_eq!(hover.update(None, at(1300)), None);
+
+        // Another agent takes over once settled
+        hover.update(atlas(), at(1400));
+        hover.update(atlas(), at(1500));
+        hover.update(Some("nova".into()), at(1600));
+        assert_eq!(hover.update(Some("nova".into()), at(1650)), Some("atlas"));
+        assert_eq!(hover.update(Some("nova".into()), at(1700)), Some("nova"));
+    }
+}
@@ -1,3 +1,7 @@
+pub mod focus;
 pub mod handler;
+pub mod hover;
 
+pub use focus::Pane;
 pub use handler::{InputHandler, InputEvent};
+pub use hover::{Hover, HoverConfig};
@@ -0,0 +1,203 @@
+//! Landmark edits made in the app.
+//!
+//! Landmarks added, renamed, moved or deleted in the landmark editor are kept
+//! as edits on top of the ones the emitter sends, and saved to a sidecar file
+//! next to the events file (`events.jsonl` → `events.landmarks.toml`). The
+//! edits are applied again whenever a Landmark event arrives, so they win
+//! over the emitter's version of the same landmark.
+
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use hive_core::event::LandmarkId;
+use hive_core::positioning::Position;
+use hive_core::state::field::StoredLandmark;
+use hive_core::state::Field;
+
+/// Changes to one landmark
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LandmarkEdit {
+    /// New label
+    pub label: Option<String>,
+    /// Keywords of a landmark added in the editor
+    pub keywords: Option<Vec<String>>,
+    /// Position set by moving the landmark, as `[x, y]`
+    pub position: Option<[f32; 2]>,
+    /// Hidden, even when the emitter sends it again
+    pub deleted: bool,
+}
+
+/// All landmark edits for one event source, saved to its sidecar file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LandmarkEdits {
+    pub landmarks: BTreeMap<LandmarkId, LandmarkEdit>,
+    #[serde(skip)]
+    path: Option<PathBuf>,
+}
+
+impl LandmarkEdits {
+    /// Sidecar file holding the edits for `events`
+    pub fn sidecar_path(events: &Path) -> PathBuf {
+        events.with_extension("landmarks.toml")
+    }
+
+    /// Load the edits for `events`, saving back to its sidecar file.
+    ///
+    /// A missing sidecar file means no edits yet.
+    pub fn load_for(events: &Path) -> io::Result<Self> {
+        let path = Self::sidecar_path(events);
+        let mut edits: Self = match std::fs::read_to_string(&path) {
+            Ok(contents) => toml::from_str(&contents).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {}", path.display(), e),
+                )
+            })?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Self::default(),
+            Err(e) => return Err(e),
+        };
+        edits.path = Some(path);
+        Ok(edits)
+    }
+
+    /// Write the edits to the sidecar file (nothing to do without one, as in
+    /// demo mode).
+    pub fn save(&self) -> io::Result<()> {
+        let Some(ref path) = self.path else {
+            return Ok(());
+        };
+        let contents = toml::to_string(self).map_err(io::Error::other)?;
+        std::fs::write(path, contents)
+    }
+
+    /// Where the edits are saved, if anywhere
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Add a landmark labelled `label` at `position`; its keywords are the
+    /// words of the label. Returns the new landmark's ID.
+    pub fn add(&mut self, label: &str, position: &Position) -> LandmarkId {
+        let base = format!("user:{}", label.to_lowercase().split_whitespace().collect::<Vec<_>>().join("-"));
+        let mut id = base.clone();
+        let mut n = 1;
+        while self.landmarks.contains_key(&id) {
+            n += 1;
+            id = format!("{}-{}", base, n);
+        }
+
+        self.landmarks.insert(
+            id.clone(),
+            LandmarkEdit {
+                label: Some(label.to_string()),
+                keywords: Some(label.split_whitespace().map(str::to_lowercase).collect()),
+                position: Some([position.x, position.y]),
+                deleted: false,
+            },
+        );
+        id
+    }
+
+    pub fn rename(&mut self, id: &str, label: &str) {
+        self.landmarks.entry(id.to_string()).or_default().label = Some(label.to_string());
+    }
+
+    pub fn move_to(&mut self, id: &str, position: &Position) {
+        self.landmarks.entry(id.to_string()).or_default().position = Some([position.x, position.y]);
+    }
+
+    pub fn delete(&mut self, id: &str) {
+        // Landmarks added here have nothing to hide once removed
+        match self.landmarks.get(id) {
+            Some(edit) if edit.keywords.is_some() => {
+                self.landmarks.remove(id);
+            }
+            _ => self.landmarks.entry(id.to_string()).or_default().deleted = true,
+        }
+    }
+
+    /// Apply the edits to the landmarks of `field`.
+    pub fn apply(&self, field: &mut Field) {
+        for (id, edit) in &self.landmarks {
+            if edit.deleted {
+                field.landmarks.remove(id);
+                continue;
+            }
+
+            if let Some(ref keywords) = edit.keywords {
+                if !field.landmarks.contains_key(id) {
+                    let position = field.positioner.register_landmark(keywords);
+                    field.landmarks.insert(
+                        id.clone(),
+                        StoredLandmark {
+                            id: id.clone(),
+                            label: edit.label.clone().unwrap_or_else(|| id.clone()),
+                            keywords: keywords.clone(),
+                            position,
+                            auto: false,
+                        },
+                    );
+                }
+            }
+
+            let Some(landmark) = field.landmarks.get_mut(id) else {
+                continue;
+            };
+            if let Some(ref label) = edit.label {
+                landmark.label = label.clone();
+            }
+            if let Some([x, y]) = edit.position {
+                landmark.position = Position::new(x, y);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use hive_core::event::{HiveEvent, Landmark};
+
+    fn landmark(id: &str, label: &str) -> HiveEvent {
+        HiveEvent::Landmark(Landmark {
+            id: id.to_string(),
+            label: label.to_string(),
+            keywords: vec![label.to_lowercase()],
+            timestamp: 0,
+        })
+    }
+
+    #[test]
+    fn test_edits_override_incoming_landmarks_and_round_trip() {
+        let dir = std::env::temp_dir().join(format!("hive-landmarks-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let events = dir.join("events.jsonl");
+
+        let mut edits = LandmarkEdits::load_for(&events).unwrap();
+        edits.rename("auth", "Login");
+        edits.move_to("auth", &Position::new(0.1, 0.2));
+        edits.delete("cache");
+        let added = edits.add("Build Tools", &Position::new(0.8, 0.8));
+        edits.save().unwrap();
+        assert_eq!(added, "user:build-tools");
+
+        let edits = LandmarkEdits::load_for(&events).unwrap();
+        let mut field = Field::new();
+        field.process_event(&landmark("auth", "Auth"));
+        field.process_event(&landmark("cache", "Cache"));
+        edits.apply(&mut field);
+
+        assert_eq!(field.landmarks["auth"].label, "Login");
+        assert_eq!(field.landmarks["auth"].position, Position::new(0.1, 0.2));
+        assert!(!field.landmarks.contains_key("cache"));
+        assert_eq!(field.landmarks[&added].keywords, ["build", "tools"]);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
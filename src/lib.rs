@@ -0,0 +1,41 @@
+//! Terminal frontend for Hive.
+//!
+//! The swarm model (events, field state, positioning, animation) lives in the
+//! [`hive_core`] crate. This crate layers the ratatui renderer, input handling,
+//! the demo event generator and the [`App`](app::App) main loop on top of it;
+//! the `hive` binary is a thin command-line wrapper around [`app::App`].
+//!
+//! Other ratatui applications can embed the swarm view through
+//! [`widget::HiveWidget`].
+
+pub mod alerts;
+pub mod alias;
+pub mod app;
+pub mod audio;
+pub mod backfill;
+pub mod bench;
+pub mod collisions;
+pub mod config;
+pub mod demo;
+pub mod filter;
+pub mod hook;
+pub mod input;
+pub mod landmarks;
+pub mod remap;
+pub mod render;
+pub mod report;
+pub mod sampling;
+pub mod saved_state;
+#[cfg(feature = "scripting")]
+pub mod script;
+pub mod seek;
+pub mod sources;
+pub mod tail;
+pub mod telemetry;
+pub mod terminal;
+pub mod watchdog;
+pub mod widget;
+pub mod wizard;
+pub mod workspace;
+
+pub use widget::{HiveState, HiveWidget};
@@ -1,17 +1,24 @@
-mod app;
-mod animation;
-mod demo;
-mod event;
-mod input;
-mod positioning;
-mod render;
-mod state;
-
 use std::path::PathBuf;
+use std::time::Duration;
 
-use clap::Parser;
+use clap::{Args, Parser, Subcommand};
 
-use app::{App, AppConfig};
+use hive::alias::AliasMap;
+use hive::app::{App, AppConfig, KafkaOptions};
+use hive::backfill::Since;
+use hive::bench::BenchOptions;
+use hive::config::Config;
+use hive::demo::{export_demo_events, DemoOptions, Scenario};
+use hive::hook::HookOptions;
+use hive::remap::Remapper;
+use hive::render::{DisplayMode, RenderLayer};
+use hive::sampling::SamplingRules;
+use hive::sources::expand_file_patterns;
+use hive::tail::TailOptions;
+use hive::wizard::{run_setup_wizard, WizardChoice};
+use hive_core::animation::TARGET_FPS;
+use hive_core::event::{EventFormat, HiveEvent};
+use hive_emit::HiveEmitter;
 
 /// Hive: Real-time AI Agent Visualization
 ///
@@ -21,15 +28,101 @@ use app::{App, AppConfig};
 #[derive(Parser, Debug)]
 #[command(name = "hive")]
 #[command(author, version, about, long_about = None)]
+#[command(args_conflicts_with_subcommands = true)]
 struct Cli {
-    /// Path to the events file to watch (JSON lines format)
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Events file to watch (JSON lines format), or a pattern such as 'runs/*.jsonl'; repeat to merge several
     #[arg(short, long, value_name = "FILE")]
-    file: Option<PathBuf>,
+    file: Vec<PathBuf>,
+
+    /// Prefix agent IDs with their file's name and tint each file's agents
+    #[arg(long, requires = "file")]
+    tag_sources: bool,
+
+    /// Accept events pushed over WebSocket at this address (e.g. 127.0.0.1:9000)
+    #[arg(long, value_name = "ADDR")]
+    ws: Option<String>,
+
+    /// Accept events written as JSON lines to a Unix socket at this path (e.g. /tmp/hive.sock)
+    #[arg(long, value_name = "PATH")]
+    socket: Option<PathBuf>,
+
+    /// Serve the gRPC `StreamEvents` call at this address (e.g. 127.0.0.1:50051; needs the `grpc` feature)
+    #[arg(long, value_name = "ADDR")]
+    grpc: Option<String>,
+
+    /// Follow a Server-Sent Events or JSON lines stream at this URL (e.g. https://host/stream)
+    #[arg(long, value_name = "URL")]
+    url: Option<String>,
+
+    /// Subscribe to events published on a Redis channel (e.g. redis://host/channel; needs the `redis` feature)
+    #[arg(long, value_name = "URL")]
+    redis: Option<String>,
+
+    /// Subscribe to events published on a NATS server (e.g. nats://host; needs the `nats` feature)
+    #[arg(long, value_name = "URL")]
+    nats: Option<String>,
+
+    /// NATS subject to subscribe to; wildcards like hive.events.* and hive.events.> work
+    #[arg(long, value_name = "SUBJECT", requires = "nats", default_value = "hive.events")]
+    subject: String,
+
+    /// Read events from a Kafka topic on these brokers (e.g. host:9092; needs the `kafka` feature)
+    #[arg(long, value_name = "BROKERS")]
+    kafka: Option<String>,
+
+    /// Kafka topic to read
+    #[arg(long, value_name = "TOPIC", requires = "kafka", default_value = "hive.events")]
+    topic: String,
+
+    /// Replay the topic's retained events into the history before following it live
+    #[arg(long, requires = "kafka")]
+    from_beginning: bool,
+
+    /// Format of the events: json, csv with
+    /// `timestamp,agent_id,status,intensity,focus,message` agent updates, or
+    /// length-prefixed msgpack for --socket and --ws
+    #[arg(long, value_name = "FORMAT", default_value_t = EventFormat::Json)]
+    format: EventFormat,
+
+    /// Only load existing events from this long ago on (e.g. 10m, 2h)
+    #[arg(long, value_name = "DURATION", requires = "file", value_parser = Since::parse_ago)]
+    since: Option<Since>,
+
+    /// Only load existing events from this Unix timestamp on
+    #[arg(long, value_name = "T", requires = "file", conflicts_with = "since")]
+    since_timestamp: Option<u64>,
 
     /// Run in demo mode with simulated agents
     #[arg(long)]
     demo: bool,
 
+    /// Scenario file for demo mode (agents, phases, incidents)
+    #[arg(long, value_name = "FILE", requires = "demo")]
+    scenario: Option<PathBuf>,
+
+    /// Seed for a reproducible demo run
+    #[arg(long, value_name = "N", requires = "demo")]
+    demo_seed: Option<u64>,
+
+    /// Number of simulated agents (copies of the scenario's cast)
+    #[arg(long, value_name = "N", requires = "demo")]
+    demo_agents: Option<usize>,
+
+    /// Demo pacing multiplier (0.5 = half speed, 4 = four times as fast)
+    #[arg(long, value_name = "X", requires = "demo", default_value_t = 1.0)]
+    demo_speed: f32,
+
+    /// Display mode to start in: minimal, standard (default) or debug
+    #[arg(long, value_name = "MODE")]
+    mode: Option<DisplayMode>,
+
+    /// Optional layers to start with instead of the mode's, e.g. heatmap,trails
+    #[arg(long, value_name = "LAYERS", value_delimiter = ',', value_parser = parse_layer)]
+    layers: Option<Vec<RenderLayer>>,
+
     /// Disable heat map display
     #[arg(long)]
     no_heatmap: bool,
@@ -41,30 +134,318 @@ struct Cli {
     /// Disable landmark display
     #[arg(long)]
     no_landmarks: bool,
+
+    /// Draw agents as filled, outlined blocks with status badges (toggle with C)
+    #[arg(long)]
+    high_contrast: bool,
+
+    /// Show a banner with the logo, terminal capabilities and event source at startup
+    #[arg(long)]
+    intro: bool,
+
+    /// Frames drawn per second; the field advances at the same pace whatever it is
+    #[arg(long, value_name = "N", default_value_t = TARGET_FPS, value_parser = clap::value_parser!(u32).range(1..=240))]
+    fps: u32,
+
+    /// Config file (defaults to ~/.config/hive/config.toml)
+    #[arg(short, long, value_name = "FILE")]
+    config: Option<PathBuf>,
+
+    /// Quit after this many seconds (for scripted runs and recordings)
+    #[arg(long, value_name = "SECS")]
+    duration: Option<u64>,
+
+    /// Quit once the events files have been replayed and stay quiet for 2 seconds
+    #[arg(long, requires = "file", conflicts_with_all = ["ws", "socket", "grpc", "url", "redis", "nats", "kafka"])]
+    exit_on_eof: bool,
+
+    /// Rhai script whose `on_event` hook sees every event (needs the `scripting` feature)
+    #[arg(long, value_name = "FILE")]
+    script: Option<PathBuf>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run the demo generator headlessly and write its events to a file
+    Demo(DemoArgs),
+    /// Flood the pipeline with synthetic events and report throughput
+    Bench(BenchArgs),
+    /// Print the JSON Schema of the events file lines
+    Schema,
+    /// Print the events of a file as they are written, with notes on likely mistakes
+    Tail(TailArgs),
+    /// Append the events for agent CLI hook payloads read on stdin
+    Hook(HookArgs),
+}
+
+#[derive(Args, Debug)]
+struct HookArgs {
+    /// Where to write the events: a file or unix:///path
+    /// (default: $HIVE_EVENTS, or hive-events.jsonl in the temp directory)
+    #[arg(long, value_name = "TARGET")]
+    emit: Option<String>,
+
+    /// Agent ID to report as (default: the project directory and session ID)
+    #[arg(long, value_name = "ID")]
+    agent: Option<String>,
+}
+
+#[derive(Args, Debug)]
+struct TailArgs {
+    /// Events file to print
+    #[arg(value_name = "FILE")]
+    file: PathBuf,
+
+    /// Line format of the file: json or csv
+    #[arg(long, value_name = "FORMAT", default_value_t = EventFormat::Json)]
+    format: EventFormat,
+
+    /// Existing lines to print before following, from the end of the file
+    #[arg(short = 'n', long, value_name = "N", default_value_t = 10)]
+    lines: usize,
+
+    /// Print the existing lines and exit instead of following the file
+    #[arg(long)]
+    no_follow: bool,
+}
+
+#[derive(Args, Debug)]
+struct DemoArgs {
+    /// File to write the events to (JSON lines format)
+    #[arg(long, value_name = "FILE")]
+    emit: PathBuf,
+
+    /// Seconds of demo time to generate
+    #[arg(long, value_name = "SECS", default_value_t = 120)]
+    duration: u64,
+
+    /// Scenario file (agents, phases, incidents)
+    #[arg(long, value_name = "FILE")]
+    scenario: Option<PathBuf>,
+
+    /// Seed for a reproducible run
+    #[arg(long, value_name = "N")]
+    seed: Option<u64>,
+
+    /// Number of simulated agents (copies of the scenario's cast)
+    #[arg(long, value_name = "N")]
+    agents: Option<usize>,
+}
+
+#[derive(Args, Debug)]
+struct BenchArgs {
+    /// Number of distinct agents
+    #[arg(long, value_name = "N", default_value_t = 1000)]
+    agents: usize,
+
+    /// Target events per second
+    #[arg(long, value_name = "N", default_value_t = 5000)]
+    eps: u32,
+
+    /// Seconds to run
+    #[arg(long, value_name = "SECS", default_value_t = 10)]
+    duration: u64,
 }
 
 #[tokio::main]
 async fn main() -> std::io::Result<()> {
-    let cli = Cli::parse();
+    let mut cli = Cli::parse();
+
+    match cli.command {
+        Some(Command::Demo(args)) => {
+            export_demo(args).await;
+            return Ok(());
+        }
+        Some(Command::Bench(args)) => {
+            let report = hive::bench::run(BenchOptions {
+                agents: args.agents,
+                eps: args.eps,
+                duration: Duration::from_secs(args.duration),
+            });
+            println!("{}", report);
+            return Ok(());
+        }
+        Some(Command::Tail(args)) => {
+            let options = TailOptions {
+                path: args.file,
+                format: args.format,
+                lines: args.lines,
+                follow: !args.no_follow,
+            };
+            if let Err(e) = hive::tail::run(&options) {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Some(Command::Hook(args)) => {
+            let emitter = match args.emit {
+                Some(target) => HiveEmitter::open(&target),
+                None => HiveEmitter::from_env(),
+            };
+            let options = HookOptions { agent: args.agent };
+            // Nothing goes to stdout, which some CLIs feed back to the agent
+            if let Err(e) = emitter.and_then(|emitter| hive::hook::run(std::io::stdin().lock(), &emitter, &options)) {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Some(Command::Schema) => {
+            let schema = HiveEvent::schema();
+            println!("{}", serde_json::to_string_pretty(&schema).map_err(std::io::Error::other)?);
+            return Ok(());
+        }
+        None => {}
+    }
+
+    // Without a source, first-time users get the setup wizard
+    let has_config = cli.config.is_some() || hive::config::default_path().is_some_and(|p| p.exists());
+    let listening = cli.ws.is_some() || cli.socket.is_some() || cli.grpc.is_some() || cli.url.is_some() || cli.redis.is_some() || cli.nats.is_some() || cli.kafka.is_some();
+    if !cli.demo && cli.file.is_empty() && !listening && !has_config {
+        match run_setup_wizard()? {
+            Some(WizardChoice::Demo) => cli.demo = true,
+            Some(WizardChoice::File(path)) => cli.file = vec![path],
+            Some(WizardChoice::Socket(path)) => cli.socket = Some(path),
+            Some(WizardChoice::Url(url)) => cli.url = Some(url),
+            None => return Ok(()),
+        }
+    }
 
     // Validate arguments
-    if !cli.demo && cli.file.is_none() {
-        eprintln!("Error: Either --file or --demo must be specified");
+    if !cli.demo && cli.file.is_empty() && !listening {
+        eprintln!("Error: One of --file, --demo, --ws, --socket, --grpc, --url, --redis, --nats or --kafka must be specified");
         eprintln!();
         eprintln!("Usage:");
-        eprintln!("  hive --file events.jsonl   Watch a file for agent events");
-        eprintln!("  hive --demo                Run demo mode with simulated agents");
+        eprintln!("  hive --file events.jsonl       Watch a file for agent events");
+        eprintln!("  hive --demo                    Run demo mode with simulated agents");
+        eprintln!("  hive --ws 127.0.0.1:9000       Accept events pushed over WebSocket");
+        eprintln!("  hive --socket /tmp/hive.sock   Accept events written to a Unix socket");
+        eprintln!("  hive --grpc 127.0.0.1:50051    Accept events streamed over gRPC");
+        eprintln!("  hive --url https://host/stream Follow a remote SSE or JSON lines stream");
+        eprintln!("  hive --redis redis://host/ch   Subscribe to events published on a Redis channel");
+        eprintln!("  hive --nats nats://host        Subscribe to events published on NATS");
+        eprintln!("  hive --kafka host:9092         Read events from a Kafka topic");
         eprintln!();
         eprintln!("Run 'hive --help' for more options");
         std::process::exit(1);
     }
 
+    let files = match expand_file_patterns(&cli.file) {
+        Ok(files) => files,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let settings = match Config::load(cli.config.as_deref()) {
+        Ok(settings) => settings,
+        Err(e) => {
+            eprintln!("Error: failed to load config: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    if cli.format.is_framed() && (!files.is_empty() || cli.url.is_some()) {
+        eprintln!("Error: --format {} only applies to --socket and --ws; files and streams are read as lines", cli.format);
+        std::process::exit(1);
+    }
+
+    let brokered = cli.grpc.is_some() || cli.redis.is_some() || cli.nats.is_some() || cli.kafka.is_some();
+    if cli.format != EventFormat::Json && brokered {
+        eprintln!("Error: --format {} does not apply to --grpc, --redis, --nats or --kafka; they only carry JSON events", cli.format);
+        std::process::exit(1);
+    }
+
+    if !(cli.demo_speed > 0.0 && cli.demo_speed.is_finite()) {
+        eprintln!("Error: --demo-speed must be a positive number");
+        std::process::exit(1);
+    }
+
+    let aliases = match AliasMap::new(&settings.aliases) {
+        Ok(aliases) => aliases,
+        Err(e) => {
+            eprintln!("Error: invalid alias pattern: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let remap = match Remapper::new(&settings.remap) {
+        Ok(remap) => remap,
+        Err(e) => {
+            eprintln!("Error: invalid remap pattern: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let sampling = match SamplingRules::new(&settings.sampling) {
+        Ok(sampling) => sampling,
+        Err(e) => {
+            eprintln!("Error: invalid sampling pattern: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let scenario = load_scenario(cli.scenario.as_deref(), cli.demo_agents);
+
     let config = AppConfig {
-        file_path: cli.file,
+        files,
+        tag_sources: cli.tag_sources,
+        ws: cli.ws,
+        socket: cli.socket,
+        grpc: cli.grpc,
+        url: cli.url,
+        redis: cli.redis,
+        nats: cli.nats.map(|url| (url, cli.subject)),
+        kafka: cli.kafka.map(|brokers| KafkaOptions {
+            brokers,
+            topic: cli.topic,
+            from_beginning: cli.from_beginning,
+        }),
+        format: cli.format,
+        since: cli.since.or(cli.since_timestamp.map(Since::Timestamp)),
+        id_collisions: settings.sources.id_collisions,
+        persist_state: settings.sources.persist_state,
         demo_mode: cli.demo,
+        display_mode: cli.mode,
+        layers: cli.layers,
         show_heatmap: !cli.no_heatmap,
         show_trails: !cli.no_trails,
         show_landmarks: !cli.no_landmarks,
+        high_contrast: cli.high_contrast,
+        intro: cli.intro,
+        fps: cli.fps,
+        script_path: cli.script,
+        demo: DemoOptions {
+            scenario,
+            seed: cli.demo_seed,
+            speed: cli.demo_speed,
+            ..DemoOptions::default()
+        },
+        alerts: settings.alerts,
+        aliases,
+        remap,
+        sampling,
+        timestamps: settings.activity_log.timestamps,
+        max_agents: settings.agents.max,
+        intensity_smoothing: settings.agents.intensity_smoothing,
+        max_trail_points: settings.agents.max_trail_points,
+        motion_prediction: settings.agents.motion_prediction,
+        max_connections: settings.connections.max,
+        auto_landmarks: settings.auto_landmarks.settings(),
+        heatmap: settings.heatmap,
+        field: settings.field,
+        leaderboard: settings.leaderboard,
+        status_bar: settings.status_bar,
+        terminal: settings.terminal,
+        watchdog: settings.watchdog,
+        audio: settings.audio,
+        z_order: settings.z_order,
+        display_modes: settings.display_modes,
+        duration: cli.duration.map(Duration::from_secs),
+        exit_on_eof: cli.exit_on_eof,
+        hover: settings.hover,
     };
 
     let mut app = App::new(config);
@@ -75,5 +456,63 @@ async fn main() -> std::io::Result<()> {
         std::process::exit(1);
     }
 
+    if let Some(summary) = app.session_summary() {
+        print!("{}", summary);
+    }
+
     Ok(())
 }
+
+/// One name of `--layers`
+fn parse_layer(name: &str) -> Result<RenderLayer, String> {
+    RenderLayer::from_name(name.trim()).ok_or_else(|| {
+        format!("unknown layer {:?} (expected zones, grid, heatmap, trails, connections or flashes)", name)
+    })
+}
+
+/// Load a scenario file (or the built-in demo when none is given), resized to
+/// `agents` agents if requested
+fn load_scenario(path: Option<&std::path::Path>, agents: Option<usize>) -> Scenario {
+    if agents == Some(0) {
+        eprintln!("Error: the demo needs at least one agent");
+        std::process::exit(1);
+    }
+
+    let mut scenario = match path {
+        Some(path) => match Scenario::load(path) {
+            Ok(scenario) => scenario,
+            Err(e) => {
+                eprintln!("Error: failed to load scenario: {}", e);
+                std::process::exit(1);
+            }
+        },
+        None => Scenario::builtin(),
+    };
+    if let Some(count) = agents {
+        scenario.set_agent_count(count);
+    }
+    scenario
+}
+
+/// `hive demo --emit FILE`
+async fn export_demo(args: DemoArgs) {
+    let options = DemoOptions {
+        scenario: load_scenario(args.scenario.as_deref(), args.agents),
+        seed: args.seed,
+        ..DemoOptions::default()
+    };
+    let duration = Duration::from_secs(args.duration);
+
+    match export_demo_events(options, duration, &args.emit).await {
+        Ok(count) => eprintln!(
+            "Wrote {} events ({}s of demo time) to {}",
+            count,
+            args.duration,
+            args.emit.display()
+        ),
+        Err(e) => {
+            eprintln!("Error: failed to export demo events: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,236 @@
+//! Cleanup rules for incoming events.
+//!
+//! The `[remap]` config section tidies up noisy emitters without touching
+//! them: agent IDs and focus keywords can be rewritten by regex, whole kinds
+//! of events dropped, and intensities clamped to a narrower range. Rules run
+//! on every event as it arrives, before source tags and the field see it, so
+//! the history and saved recordings hold the cleaned events.
+//!
+//! ```toml
+//! [remap]
+//! drop = ["landmark"]
+//! max_intensity = 0.8
+//!
+//! [[remap.agents]]
+//! pattern = "^(worker-[0-9]+)-attempt-[0-9]+$"
+//! replace = "$1"
+//!
+//! [[remap.focus]]
+//! pattern = "(?i)^(authn?|login)$"
+//! replace = "auth"
+//! ```
+
+use regex::Regex;
+use serde::Deserialize;
+
+use hive_core::event::{intern, AgentId, HiveEvent, Keyword};
+
+/// Kind of event, as named by its `type` field
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventKind {
+    AgentUpdate,
+    Connection,
+    Landmark,
+}
+
+impl EventKind {
+    fn of(event: &HiveEvent) -> Self {
+        match event {
+            HiveEvent::AgentUpdate(_) => EventKind::AgentUpdate,
+            HiveEvent::Connection(_) => EventKind::Connection,
+            HiveEvent::Landmark(_) => EventKind::Landmark,
+        }
+    }
+}
+
+/// One `[[remap.agents]]` or `[[remap.focus]]` entry of the config file
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RewriteRule {
+    /// Regex matched against the agent ID or keyword
+    pub pattern: String,
+    /// Replacement for the matched part; may reference capture groups as `$1`
+    /// or `${name}`. A keyword rewritten to nothing is removed.
+    pub replace: String,
+}
+
+/// `[remap]` section of the config file
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RemapConfig {
+    /// Event kinds thrown away on arrival
+    pub drop: Vec<EventKind>,
+    /// Intensities below this are raised to it
+    pub min_intensity: Option<f32>,
+    /// Intensities above this are lowered to it
+    pub max_intensity: Option<f32>,
+    /// Agent ID rewrites, applied to updates and both ends of connections
+    pub agents: Vec<RewriteRule>,
+    /// Focus keyword rewrites
+    pub focus: Vec<RewriteRule>,
+}
+
+/// Compiled remap rules
+#[derive(Debug, Clone, Default)]
+pub struct Remapper {
+    drop: Vec<EventKind>,
+    intensity: Option<(f32, f32)>,
+    agents: Vec<(Regex, String)>,
+    focus: Vec<(Regex, String)>,
+}
+
+impl Remapper {
+    /// Compile `config`, failing on the first invalid pattern.
+    pub fn new(config: &RemapConfig) -> Result<Self, regex::Error> {
+        let compile = |rules: &[RewriteRule]| {
+            rules
+                .iter()
+                .map(|rule| Ok((Regex::new(&rule.pattern)?, rule.replace.clone())))
+                .collect::<Result<Vec<_>, regex::Error>>()
+        };
+        let intensity = match (config.min_intensity, config.max_intensity) {
+            (None, None) => None,
+            (min, max) => Some((min.unwrap_or(f32::MIN), max.unwrap_or(f32::MAX))),
+        };
+        Ok(Self {
+            drop: config.drop.clone(),
+            intensity,
+            agents: compile(&config.agents)?,
+            focus: compile(&config.focus)?,
+        })
+    }
+
+    /// Whether there are no rules, so events pass through untouched
+    pub fn is_empty(&self) -> bool {
+        self.drop.is_empty() && self.intensity.is_none() && self.agents.is_empty() && self.focus.is_empty()
+    }
+
+    /// `event` with the rules applied, or `None` if its kind is dropped
+    pub fn apply(&self, mut event: HiveEvent) -> Option<HiveEvent> {
+        if self.drop.contains(&EventKind::of(&event)) {
+            return None;
+        }
+        match event {
+            HiveEvent::AgentUpdate(ref mut update) => {
+                update.agent_id = self.rename(&update.agent_id);
+                if !self.focus.is_empty() {
+                    update.focus = self.rewrite_focus(&update.focus);
+                }
+                if let Some((min, max)) = self.intensity {
+                    update.intensity = update.intensity.max(min).min(max);
+                }
+            }
+            HiveEvent::Connection(ref mut connection) => {
+                connection.from = self.rename(&connection.from);
+                connection.to = self.rename(&connection.to);
+            }
+            HiveEvent::Landmark(_) => {}
+        }
+        Some(event)
+    }
+
+    /// `agent_id` rewritten by the first matching rule
+    fn rename(&self, agent_id: &AgentId) -> AgentId {
+        match rewrite(&self.agents, agent_id) {
+            Some(renamed) => intern(&renamed),
+            None => agent_id.clone(),
+        }
+    }
+
+    /// `focus` with each keyword rewritten by its first matching rule,
+    /// leaving out emptied and repeated keywords
+    fn rewrite_focus(&self, focus: &[Keyword]) -> Vec<Keyword> {
+        let mut rewritten: Vec<Keyword> = Vec::with_capacity(focus.len());
+        for keyword in focus {
+            let keyword = match rewrite(&self.focus, keyword) {
+                Some(replaced) if replaced.is_empty() => continue,
+                Some(replaced) => intern(&replaced),
+                None => keyword.clone(),
+            };
+            if !rewritten.contains(&keyword) {
+                rewritten.push(keyword);
+            }
+        }
+        rewritten
+    }
+}
+
+/// `text` with the first rule whose pattern matches it applied
+fn rewrite(rules: &[(Regex, String)], text: &str) -> Option<String> {
+    rules.iter().find_map(|(pattern, replace)| {
+        pattern.is_match(text).then(|| pattern.replace(text, replace.as_str()).into_owned())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(pattern: &str, replace: &str) -> RewriteRule {
+        RewriteRule {
+            pattern: pattern.to_string(),
+            replace: replace.to_string(),
+        }
+    }
+
+    fn event(json: &str) -> HiveEvent {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_rules_clean_up_events() {
+        let remapper = Remapper::new(&RemapConfig {
+            drop: vec![EventKind::Landmark],
+            min_intensity: None,
+            max_intensity: Some(0.8),
+            agents: vec![rule("^(worker-[0-9]+)-attempt-[0-9]+$", "$1")],
+            focus: vec![rule("(?i)^(authn?|login)$", "auth"), rule("^tmp-.*", "")],
+        })
+        .unwrap();
+
+        let update = event(
+            r#"{"type":"agent_update","agent_id":"worker-3-attempt-2","status":"active","focus":["Login","authn","tmp-12","db"],"intensity":1.5,"message":"","timestamp":1}"#,
+        );
+        match remapper.apply(update) {
+            Some(HiveEvent::AgentUpdate(update)) => {
+                assert_eq!(&*update.agent_id, "worker-3");
+                let focus: Vec<&str> = update.focus.iter().map(|keyword| &**keyword).collect();
+                assert_eq!(focus, ["auth", "db"]);
+                assert_eq!(update.intensity, 0.8);
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+
+        let connection = event(r#"{"type":"connection","from":"worker-3-attempt-2","to":"planner","label":"","timestamp":1}"#);
+        match remapper.apply(connection) {
+            Some(HiveEvent::Connection(connection)) => {
+                assert_eq!((&*connection.from, &*connection.to), ("worker-3", "planner"));
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+
+        let landmark = event(r#"{"type":"landmark","id":"auth","label":"Auth","keywords":["auth"],"timestamp":1}"#);
+        assert!(remapper.apply(landmark).is_none());
+    }
+
+    #[test]
+    fn test_config_section_parses() {
+        let config: RemapConfig = toml::from_str(
+            r#"
+            drop = ["connection"]
+            min_intensity = 0.1
+
+            [[agents]]
+            pattern = "^w-"
+            replace = "worker-"
+            "#,
+        )
+        .unwrap();
+        let remapper = Remapper::new(&config).unwrap();
+        assert!(!remapper.is_empty());
+        assert_eq!(remapper.intensity, Some((0.1, f32::MAX)));
+        assert!(Remapper::new(&RemapConfig::default()).unwrap().is_empty());
+        assert!(Remapper::new(&RemapConfig { focus: vec![rule("(", "")], ..RemapConfig::default() }).is_err());
+    }
+}
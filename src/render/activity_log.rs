@@ -2,39 +2,78 @@
 //!
 //! The activity log shows a chronological list of recent agent events,
 //! with the newest entries at the bottom. Entries fade based on age
-//! to provide visual indication of recency.
+//! to provide visual indication of recency. An agent repeating its last
+//! message does not add a new line: the earlier entry moves to the bottom with
+//! a repeat counter ("×12"). Each entry shows when it happened, either as
+//! its age ("3s ago") or as the time of day.
+//!
+//! A [`LogSearch`] highlights matching entries and moves a cursor between
+//! them; the widget scrolls to keep the cursor in view.
 
 use std::collections::VecDeque;
 use std::time::Instant;
 
+use chrono::{DateTime, Local};
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     widgets::Widget,
 };
+use serde::Deserialize;
+
+use hive_core::event::AgentId;
+
+/// How entry times are shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimestampStyle {
+    /// Age of the entry, e.g. "3s ago"
+    #[default]
+    Relative,
+    /// Time of day, e.g. "14:03:22"
+    Absolute,
+    /// No timestamps
+    Off,
+}
+
+/// `[activity_log]` section of the config file
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ActivityLogConfig {
+    pub timestamps: TimestampStyle,
+}
 
 /// A single entry in the activity log.
 #[derive(Debug, Clone)]
 pub struct ActivityEntry {
+    /// Identifier assigned by the log, stable while the entry is kept
+    pub id: u64,
     /// When this entry was created
     pub timestamp: Instant,
+    /// Wall-clock time of creation, for absolute timestamps
+    pub time: DateTime<Local>,
     /// The agent that generated this activity
-    pub agent_id: String,
+    pub agent_id: AgentId,
     /// The activity message
     pub message: String,
     /// Color associated with this agent
     pub color: Color,
+    /// How many times in a row the agent reported this message
+    pub count: u32,
 }
 
 impl ActivityEntry {
     /// Create a new activity entry.
-    pub fn new(agent_id: String, message: String, color: Color) -> Self {
+    pub fn new(agent_id: impl Into<AgentId>, message: String, color: Color) -> Self {
         Self {
+            id: 0,
             timestamp: Instant::now(),
-            agent_id,
+            time: Local::now(),
+            agent_id: agent_id.into(),
             message,
             color,
+            count: 1,
         }
     }
 
@@ -42,6 +81,24 @@ impl ActivityEntry {
     pub fn age_seconds(&self) -> f32 {
         self.timestamp.elapsed().as_secs_f32()
     }
+
+    /// Format the entry's time in `style` (empty for [`TimestampStyle::Off`]).
+    pub fn format_time(&self, style: TimestampStyle) -> String {
+        match style {
+            TimestampStyle::Relative => format_age(self.age_seconds() as u64),
+            TimestampStyle::Absolute => self.time.format("%H:%M:%S").to_string(),
+            TimestampStyle::Off => String::new(),
+        }
+    }
+}
+
+/// Short age such as "3s ago", "2m ago" or "1h ago"
+pub(crate) fn format_age(secs: u64) -> String {
+    match secs {
+        0..=59 => format!("{}s ago", secs),
+        60..=3599 => format!("{}m ago", secs / 60),
+        _ => format!("{}h ago", secs / 3600),
+    }
 }
 
 /// Activity log that tracks recent agent events.
@@ -49,6 +106,7 @@ impl ActivityEntry {
 pub struct ActivityLog {
     entries: VecDeque<ActivityEntry>,
     max_entries: usize,
+    next_id: u64,
 }
 
 impl ActivityLog {
@@ -57,21 +115,40 @@ impl ActivityLog {
         Self {
             entries: VecDeque::with_capacity(max_entries),
             max_entries,
+            next_id: 0,
         }
     }
 
     /// Add a new entry to the activity log.
     ///
-    /// If the log is at capacity, the oldest entry will be removed.
-    pub fn add(&mut self, agent_id: String, message: String, color: Color) {
+    /// If the agent's latest entry has the same message, that entry is moved
+    /// to the bottom and its repeat count bumped instead. If the log is at
+    /// capacity, the oldest entry will be removed.
+    pub fn add(&mut self, agent_id: impl Into<AgentId>, message: String, color: Color) {
+        let agent_id = agent_id.into();
+        let previous = self.entries.iter().rposition(|e| e.agent_id == agent_id);
+        if let Some(idx) = previous.filter(|&idx| self.entries[idx].message == message) {
+            if let Some(mut entry) = self.entries.remove(idx) {
+                entry.count += 1;
+                entry.timestamp = Instant::now();
+                entry.time = Local::now();
+                entry.color = color;
+                self.entries.push_back(entry);
+            }
+            return;
+        }
+
         if self.entries.len() >= self.max_entries {
             self.entries.pop_front();
         }
-        self.entries.push_back(ActivityEntry::new(agent_id, message, color));
+        let mut entry = ActivityEntry::new(agent_id, message, color);
+        entry.id = self.next_id;
+        self.next_id += 1;
+        self.entries.push_back(entry);
     }
 
     /// Get an iterator over the entries (oldest first).
-    pub fn entries(&self) -> impl Iterator<Item = &ActivityEntry> {
+    pub fn entries(&self) -> impl DoubleEndedIterator<Item = &ActivityEntry> {
         self.entries.iter()
     }
 
@@ -91,6 +168,63 @@ impl ActivityLog {
     }
 }
 
+/// Search within the activity log
+#[derive(Debug, Clone, Default)]
+pub struct LogSearch {
+    /// Case-insensitive text matched against agent names and messages
+    pub query: String,
+    /// Whether the query is still being typed
+    pub editing: bool,
+    /// ID of the match the cursor is on
+    pub current: Option<u64>,
+}
+
+impl LogSearch {
+    /// Whether `entry` matches the query (an empty query matches nothing)
+    pub fn matches(&self, entry: &ActivityEntry) -> bool {
+        if self.query.is_empty() {
+            return false;
+        }
+        let query = self.query.to_lowercase();
+        entry.agent_id.to_lowercase().contains(&query) || entry.message.to_lowercase().contains(&query)
+    }
+
+    /// Put the cursor on the newest match.
+    pub fn jump_to_latest(&mut self, log: &ActivityLog) {
+        self.current = log.entries().rev().find(|e| self.matches(e)).map(|e| e.id);
+    }
+
+    /// Move the cursor to the next older (`older == true`) or newer match,
+    /// wrapping around at either end.
+    pub fn jump(&mut self, log: &ActivityLog, older: bool) {
+        let matches: Vec<u64> = log.entries().filter(|e| self.matches(e)).map(|e| e.id).collect();
+        if matches.is_empty() {
+            self.current = None;
+            return;
+        }
+
+        let position = self.current.and_then(|id| matches.iter().position(|&m| m == id));
+        let next = match (position, older) {
+            (None, _) => matches.len() - 1,
+            (Some(0), true) => matches.len() - 1,
+            (Some(i), true) => i - 1,
+            (Some(i), false) => (i + 1) % matches.len(),
+        };
+        self.current = Some(matches[next]);
+    }
+
+    /// Position of the cursor among the matches and the number of matches,
+    /// e.g. `(2, 5)` for "2/5"
+    pub fn progress(&self, log: &ActivityLog) -> (usize, usize) {
+        let matches: Vec<u64> = log.entries().filter(|e| self.matches(e)).map(|e| e.id).collect();
+        let position = self
+            .current
+            .and_then(|id| matches.iter().position(|&m| m == id))
+            .map_or(0, |i| i + 1);
+        (position, matches.len())
+    }
+}
+
 impl Default for ActivityLog {
     fn default() -> Self {
         Self::new(50) // Default to 50 entries
@@ -107,6 +241,16 @@ pub struct ActivityLogWidget<'a> {
     max_age: f32,
     /// Title to display above the log
     title: Option<&'a str>,
+    /// How entry times are shown
+    timestamps: TimestampStyle,
+    /// Whether the log has keyboard focus
+    focused: bool,
+    /// Active search, if any
+    search: Option<&'a LogSearch>,
+    /// Wrap long messages instead of truncating them
+    wrap: bool,
+    /// Number of newest entries scrolled out of view below
+    scroll: usize,
 }
 
 impl<'a> ActivityLogWidget<'a> {
@@ -116,21 +260,84 @@ impl<'a> ActivityLogWidget<'a> {
             log,
             max_age: 30.0, // Entries fade over 30 seconds
             title: Some("Activity"),
+            timestamps: TimestampStyle::default(),
+            focused: false,
+            search: None,
+            wrap: false,
+            scroll: 0,
         }
     }
 
+    /// Mark the log as having keyboard focus.
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Highlight the matches of `search`.
+    pub fn search(mut self, search: Option<&'a LogSearch>) -> Self {
+        self.search = search;
+        self
+    }
+
+    /// Set how entry times are shown.
+    pub fn timestamps(mut self, timestamps: TimestampStyle) -> Self {
+        self.timestamps = timestamps;
+        self
+    }
+
     /// Set the maximum age for fading (in seconds).
     pub fn max_age(mut self, max_age: f32) -> Self {
         self.max_age = max_age;
         self
     }
 
+    /// Wrap long messages onto extra lines instead of truncating them.
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Scroll back by `entries` from the newest entry.
+    pub fn scroll(mut self, entries: usize) -> Self {
+        self.scroll = entries;
+        self
+    }
+
     /// Set the title for the log widget.
     pub fn title(mut self, title: Option<&'a str>) -> Self {
         self.title = title;
         self
     }
 
+    /// Agent ID as shown between the brackets, shortened unless wrapping
+    fn agent_display(&self, entry: &ActivityEntry) -> String {
+        let max_id_len = 12;
+        if !self.wrap && entry.agent_id.chars().count() > max_id_len {
+            let head: String = entry.agent_id.chars().take(max_id_len - 3).collect();
+            format!("{}...", head)
+        } else {
+            entry.agent_id.to_string()
+        }
+    }
+
+    /// Lines `entry` takes up in a log `width` columns wide (after the border)
+    fn entry_height(&self, entry: &ActivityEntry, width: u16) -> usize {
+        if !self.wrap {
+            return 1;
+        }
+        let time = entry.format_time(self.timestamps);
+        let prefix = if time.is_empty() { 0 } else { time.chars().count().max(8) + 1 }
+            + self.agent_display(entry).chars().count()
+            + 3;
+        let counter = if entry.count > 1 {
+            format!(" ×{}", entry.count).chars().count()
+        } else {
+            0
+        };
+        wrap_message(&entry.message, (width as usize).saturating_sub(prefix), counter).len()
+    }
+
     /// Calculate the opacity for an entry based on its age.
     fn opacity_for_age(&self, age_seconds: f32) -> f32 {
         // Start fading after 5 seconds, fully faded at max_age
@@ -158,17 +365,42 @@ impl<'a> ActivityLogWidget<'a> {
 
 impl Widget for ActivityLogWidget<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        if area.height == 0 || area.width == 0 {
+        if area.height == 0 || area.width < 2 {
             return;
         }
 
+        // Left border, highlighted while the log has focus
+        let border_style = if self.focused {
+            Style::default().fg(Color::Rgb(70, 130, 105))
+        } else {
+            Style::default().fg(Color::Rgb(40, 40, 50))
+        };
+        for y in area.y..area.y + area.height {
+            buf[(area.x, y)].set_char('│').set_style(border_style);
+        }
+        let area = Rect::new(area.x + 1, area.y, area.width - 1, area.height);
+
         let mut y = area.y;
 
         // Render title if present
         if let Some(title) = self.title {
             if y < area.y + area.height {
-                let title_style = Style::default().fg(Color::Rgb(100, 200, 150));
-                let title_text = format!(" {} ", title);
+                let mut title_style = Style::default().fg(Color::Rgb(100, 200, 150));
+                let mut title_text = format!(" {} ", title);
+                if self.focused {
+                    title_style = title_style.add_modifier(Modifier::BOLD);
+                }
+                if let Some(search) = self.search {
+                    if search.editing {
+                        title_text.push_str(&format!(" /{}█", search.query));
+                    } else if !search.query.is_empty() {
+                        let (position, total) = search.progress(self.log);
+                        title_text.push_str(&format!(" /{} {}/{}", search.query, position, total));
+                    }
+                }
+                if self.scroll > 0 {
+                    title_text.push_str(&format!(" ↓{}", self.scroll));
+                }
                 for (i, ch) in title_text.chars().enumerate() {
                     if area.x + i as u16 >= area.x + area.width {
                         break;
@@ -187,13 +419,27 @@ impl Widget for ActivityLogWidget<'_> {
             return;
         }
 
-        // Get the last N entries that fit
+        // Get the entries that fit above the scroll position, scrolled up to
+        // the search cursor
         let entries: Vec<_> = self.log.entries().collect();
-        let start_idx = entries.len().saturating_sub(available_height);
-        let visible_entries = &entries[start_idx..];
+        let heights: Vec<usize> = entries
+            .iter()
+            .map(|entry| self.entry_height(entry, area.width))
+            .collect();
+        let end_idx = entries.len().saturating_sub(self.scroll).max(1).min(entries.len());
+        let mut start_idx = end_idx;
+        let mut used = 0;
+        while start_idx > 0 && used + heights[start_idx - 1] <= available_height {
+            start_idx -= 1;
+            used += heights[start_idx];
+        }
+        let current = self.search.and_then(|s| s.current);
+        if let Some(idx) = current.and_then(|id| entries.iter().position(|e| e.id == id)) {
+            start_idx = start_idx.min(idx);
+        }
 
         // Render entries (newest at bottom)
-        for entry in visible_entries {
+        for entry in &entries[start_idx..] {
             if y >= area.y + area.height {
                 break;
             }
@@ -201,6 +447,21 @@ impl Widget for ActivityLogWidget<'_> {
             let age = entry.age_seconds();
             let opacity = self.opacity_for_age(age);
 
+            // Highlight search matches, the cursor's match strongest
+            let highlight = match self.search {
+                Some(_) if current == Some(entry.id) => Some(Color::Rgb(90, 80, 30)),
+                Some(search) if search.matches(entry) => Some(Color::Rgb(50, 50, 35)),
+                _ => None,
+            };
+            if let Some(bg) = highlight {
+                let lines = self.entry_height(entry, area.width) as u16;
+                for line_y in y..(y + lines).min(area.y + area.height) {
+                    for x in area.x..area.x + area.width {
+                        buf[(x, line_y)].set_bg(bg);
+                    }
+                }
+            }
+
             // Format: "[agent_id] message"
             let agent_style = Style::default().fg(Self::apply_opacity(entry.color, opacity));
             let msg_style =
@@ -208,19 +469,29 @@ impl Widget for ActivityLogWidget<'_> {
 
             let mut x = area.x;
 
+            // Entry time, right-aligned so the brackets line up
+            let time = entry.format_time(self.timestamps);
+            if !time.is_empty() {
+                let time_style =
+                    Style::default().fg(Self::apply_opacity(Color::Rgb(110, 110, 125), opacity));
+                for ch in format!("{:>8} ", time).chars() {
+                    if x >= area.x + area.width {
+                        break;
+                    }
+                    buf[(x, y)].set_char(ch).set_style(time_style);
+                    x += 1;
+                }
+            }
+            if x + 2 >= area.x + area.width {
+                y += 1;
+                continue;
+            }
+
             // Render agent ID in brackets
             buf[(x, y)].set_char('[').set_style(msg_style);
             x += 1;
 
-            // Truncate agent ID if needed
-            let max_id_len = 12;
-            let agent_display: String = if entry.agent_id.len() > max_id_len {
-                format!("{}...", &entry.agent_id[..max_id_len - 3])
-            } else {
-                entry.agent_id.clone()
-            };
-
-            for ch in agent_display.chars() {
+            for ch in self.agent_display(entry).chars() {
                 if x >= area.x + area.width - 1 {
                     break;
                 }
@@ -237,23 +508,54 @@ impl Widget for ActivityLogWidget<'_> {
                 x += 1;
             }
 
-            // Render message (truncate if needed)
-            let remaining_width = (area.x + area.width).saturating_sub(x) as usize;
-            let message_display: String = if entry.message.len() > remaining_width {
-                if remaining_width > 3 {
-                    format!("{}...", &entry.message[..remaining_width - 3])
+            // Repeat counter goes after the message and is never truncated away
+            let counter = if entry.count > 1 {
+                format!(" ×{}", entry.count)
+            } else {
+                String::new()
+            };
+            let counter_width = counter.chars().count();
+            let message_x = x;
+            let message_width = (area.x + area.width).saturating_sub(message_x) as usize;
+
+            // Message lines: wrapped under the message column, or truncated
+            let lines: Vec<String> = if self.wrap {
+                wrap_message(&entry.message, message_width, counter_width)
+            } else {
+                let remaining_width = message_width.saturating_sub(counter_width);
+                let length = entry.message.chars().count();
+                if length > remaining_width && remaining_width > 3 {
+                    let head: String = entry.message.chars().take(remaining_width - 3).collect();
+                    vec![format!("{}...", head)]
                 } else {
-                    entry.message.chars().take(remaining_width).collect()
+                    vec![entry.message.chars().take(remaining_width).collect()]
                 }
-            } else {
-                entry.message.clone()
             };
 
-            for ch in message_display.chars() {
-                if x >= area.x + area.width {
+            let last = lines.len() - 1;
+            for (idx, line) in lines.iter().enumerate() {
+                if y >= area.y + area.height {
                     break;
                 }
-                buf[(x, y)].set_char(ch).set_style(msg_style);
+                x = message_x;
+                for ch in line.chars() {
+                    if x >= area.x + area.width {
+                        break;
+                    }
+                    buf[(x, y)].set_char(ch).set_style(msg_style);
+                    x += 1;
+                }
+                if idx < last {
+                    y += 1;
+                }
+            }
+
+            let counter_style = agent_style.add_modifier(Modifier::BOLD);
+            for ch in counter.chars() {
+                if x >= area.x + area.width || y >= area.y + area.height {
+                    break;
+                }
+                buf[(x, y)].set_char(ch).set_style(counter_style);
                 x += 1;
             }
 
@@ -262,6 +564,38 @@ impl Widget for ActivityLogWidget<'_> {
     }
 }
 
+/// Split `message` into lines of at most `width` characters, breaking at
+/// spaces where possible and leaving room for `reserved` characters (the
+/// repeat counter) on the last line
+fn wrap_message(message: &str, width: usize, reserved: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut rest: Vec<char> = message.trim_end().chars().collect();
+    while rest.len() + reserved > width {
+        if rest.len() <= width {
+            // Fits, but the counter doesn't: move the counter to a line of
+            // its own rather than splitting the last word
+            lines.push(rest.iter().collect());
+            rest.clear();
+            break;
+        }
+        let split = rest[..width]
+            .iter()
+            .rposition(|&c| c == ' ')
+            .filter(|&at| at > 0)
+            .unwrap_or(width);
+        lines.push(rest[..split].iter().collect());
+        let skip = if rest.get(split) == Some(&' ') { split + 1 } else { split };
+        rest.drain(..skip);
+    }
+    if !rest.is_empty() || lines.is_empty() {
+        lines.push(rest.into_iter().collect());
+    } else {
+        lines.push(String::new());
+    }
+    lines
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -301,8 +635,126 @@ mod tests {
 
         // First entry should be "agent-2" (oldest remaining)
         let entries: Vec<_> = log.entries().collect();
-        assert_eq!(entries[0].agent_id, "agent-2");
-        assert_eq!(entries[2].agent_id, "agent-4");
+        assert_eq!(&*entries[0].agent_id, "agent-2");
+        assert_eq!(&*entries[2].agent_id, "agent-4");
+    }
+
+    #[test]
+    fn test_repeated_message_is_coalesced() {
+        let mut log = ActivityLog::new(10);
+        log.add("agent-1".to_string(), "Working".to_string(), Color::Blue);
+        log.add("agent-2".to_string(), "Idle".to_string(), Color::Green);
+        log.add("agent-1".to_string(), "Working".to_string(), Color::Blue);
+        log.add("agent-1".to_string(), "Working".to_string(), Color::Blue);
+
+        // agent-1's entry moved to the bottom with a counter
+        let entries: Vec<_> = log.entries().collect();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(&*entries[1].agent_id, "agent-1");
+        assert_eq!(entries[1].count, 3);
+
+        // A different message starts a new entry
+        log.add("agent-1".to_string(), "Done".to_string(), Color::Blue);
+        assert_eq!(log.len(), 3);
+        assert_eq!(log.entries().last().unwrap().count, 1);
+
+        let area = Rect::new(0, 0, 40, 4);
+        let mut buf = Buffer::empty(area);
+        ActivityLogWidget::new(&log).render(area, &mut buf);
+        let row: String = (0..40).map(|x| buf[(x, 2)].symbol()).collect();
+        assert!(row.contains("Working ×3"), "{}", row);
+    }
+
+    #[test]
+    fn test_search_jumps_between_matches() {
+        let mut log = ActivityLog::new(10);
+        log.add("atlas".to_string(), "Schema migration".to_string(), Color::Blue);
+        log.add("nova".to_string(), "Reviewing auth".to_string(), Color::Green);
+        log.add("echo".to_string(), "schema tests pass".to_string(), Color::Red);
+
+        let mut search = LogSearch {
+            query: "SCHEMA".to_string(),
+            ..LogSearch::default()
+        };
+        search.jump_to_latest(&log);
+        assert_eq!(search.progress(&log), (2, 2));
+
+        search.jump(&log, true);
+        assert_eq!(search.progress(&log), (1, 2));
+        search.jump(&log, true);
+        assert_eq!(search.progress(&log), (2, 2));
+        search.jump(&log, false);
+        assert_eq!(search.progress(&log), (1, 2));
+
+        // The widget scrolls up to the cursor
+        let area = Rect::new(0, 0, 40, 2);
+        let mut buf = Buffer::empty(area);
+        ActivityLogWidget::new(&log)
+            .timestamps(TimestampStyle::Off)
+            .search(Some(&search))
+            .render(area, &mut buf);
+        let row: String = (0..40).map(|x| buf[(x, 1)].symbol()).collect();
+        assert!(row.contains("Schema migration"), "{}", row);
+    }
+
+    #[test]
+    fn test_scroll_shows_older_entries() {
+        let mut log = ActivityLog::new(10);
+        for i in 0..5 {
+            log.add(format!("agent-{}", i), format!("step {}", i), Color::Blue);
+        }
+
+        let area = Rect::new(0, 0, 40, 3);
+        let mut buf = Buffer::empty(area);
+        ActivityLogWidget::new(&log)
+            .timestamps(TimestampStyle::Off)
+            .scroll(2)
+            .render(area, &mut buf);
+        let rows: Vec<String> = (0..3).map(|y| (0..40).map(|x| buf[(x, y)].symbol()).collect()).collect();
+        assert!(rows[0].contains("↓2"), "{}", rows[0]);
+        assert!(rows[1].contains("step 1"), "{}", rows[1]);
+        assert!(rows[2].contains("step 2"), "{}", rows[2]);
+    }
+
+    #[test]
+    fn test_wrapped_messages_are_shown_in_full() {
+        let mut log = ActivityLog::new(10);
+        log.add("atlas".to_string(), "first".to_string(), Color::Blue);
+        log.add(
+            "nova".to_string(),
+            "Refactoring the session token refresh path".to_string(),
+            Color::Green,
+        );
+
+        // "[nova] " leaves 20 columns for the message after the border
+        let area = Rect::new(0, 0, 28, 3);
+        let mut buf = Buffer::empty(area);
+        ActivityLogWidget::new(&log)
+            .title(None)
+            .timestamps(TimestampStyle::Off)
+            .wrap(true)
+            .render(area, &mut buf);
+        let rows: Vec<String> = (0..3)
+            .map(|y| (0..28).map(|x| buf[(x, y)].symbol()).collect::<String>().trim_end().to_string())
+            .collect();
+        assert_eq!(rows[0], "│[nova] Refactoring the");
+        assert_eq!(rows[1], "│       session token");
+        assert_eq!(rows[2], "│       refresh path");
+
+        assert_eq!(wrap_message("abc def", 6, 3), ["abc", "def"]);
+        assert_eq!(wrap_message("abc", 4, 3), ["abc", ""]);
+    }
+
+    #[test]
+    fn test_format_age() {
+        assert_eq!(format_age(3), "3s ago");
+        assert_eq!(format_age(150), "2m ago");
+        assert_eq!(format_age(7300), "2h ago");
+
+        let entry = ActivityEntry::new("a".to_string(), "m".to_string(), Color::Blue);
+        assert_eq!(entry.format_time(TimestampStyle::Relative), "0s ago");
+        assert_eq!(entry.format_time(TimestampStyle::Absolute).len(), 8);
+        assert_eq!(entry.format_time(TimestampStyle::Off), "");
     }
 
     #[test]
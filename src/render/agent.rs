@@ -5,15 +5,32 @@ use ratatui::{
     widgets::Widget,
 };
 
-use crate::state::Agent;
+use hive_core::event::AgentStatus;
+use hive_core::positioning::{ease_in_out_cubic, ease_out_cubic, Position};
+use hive_core::state::agent::StatusTransition;
+use hive_core::state::Agent;
 
-use super::colors::{dim_color, get_agent_color};
+use std::cell::RefCell;
+
+use super::colors::{color_for_agent, dim_color, STATUS_COLORS};
+use super::hit_map::{HitMap, HitTarget};
+use super::lerp_color;
+use super::transform::FieldTransform;
+
+/// Share of a status transition spent on the opening flash
+const TRANSITION_FLASH: f32 = 0.15;
 
 /// Widget for rendering all agents
 pub struct AgentsWidget<'a> {
     agents: Vec<&'a Agent>,
     selected_agent: Option<&'a str>,
     hovered_agent: Option<&'a str>,
+    /// Animation clock driving the status animations
+    time: f32,
+    hits: Option<&'a RefCell<HitMap>>,
+    high_contrast: bool,
+    /// How far between their last two positions agents are drawn
+    interpolation: f32,
 }
 
 impl<'a> AgentsWidget<'a> {
@@ -22,9 +39,33 @@ impl<'a> AgentsWidget<'a> {
             agents,
             selected_agent: None,
             hovered_agent: None,
+            time: 0.0,
+            hits: None,
+            high_contrast: false,
+            interpolation: 1.0,
         }
     }
 
+    /// Draw agents `alpha` (0 to 1) of the way from their position before the
+    /// last simulation step to their current one (1, the current, by default)
+    pub fn interpolation(mut self, alpha: f32) -> Self {
+        self.interpolation = alpha;
+        self
+    }
+
+    /// Draw agents as filled blocks with bold outlines, full-color labels
+    /// and a status badge above them, for low vision and far-away displays
+    pub fn high_contrast(mut self, on: bool) -> Self {
+        self.high_contrast = on;
+        self
+    }
+
+    /// Animation time in seconds (see [`AnimationLoop::time`](hive_core::animation::AnimationLoop::time))
+    pub fn time(mut self, time: f32) -> Self {
+        self.time = time;
+        self
+    }
+
     pub fn selected(mut self, agent_id: Option<&'a str>) -> Self {
         self.selected_agent = agent_id;
         self
@@ -34,50 +75,94 @@ impl<'a> AgentsWidget<'a> {
         self.hovered_agent = agent_id;
         self
     }
+
+    /// Record each agent's glyph, the cells either side of it and its label
+    /// in a hit-test map
+    pub fn hits(mut self, hits: Option<&'a RefCell<HitMap>>) -> Self {
+        self.hits = hits;
+        self
+    }
 }
 
 impl Widget for AgentsWidget<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let inner_width = area.width.saturating_sub(2);
-        let inner_height = area.height.saturating_sub(2);
+        let transform = FieldTransform::new(area);
+        let inner = transform.inner();
+
+        let mut hits = self.hits.map(|hits| hits.borrow_mut());
+        let mut glyphs = Vec::new();
 
         for agent in &self.agents {
-            render_single_agent(agent, area, inner_width, inner_height, buf, self.selected_agent, self.hovered_agent);
+            let drawn = render_single_agent(
+                agent,
+                &agent.drawn_position(self.interpolation),
+                &transform,
+                buf,
+                self.selected_agent,
+                self.hovered_agent,
+                self.time,
+            );
+            if self.high_contrast {
+                if let Some((x, y)) = drawn {
+                    outline_agent(agent, x, y, inner, buf, self.selected_agent == Some(&*agent.id));
+                }
+            }
+            let (Some((x, y)), Some(hits)) = (drawn, hits.as_mut()) else {
+                continue;
+            };
+
+            let handle = hits.add(HitTarget::Agent(agent.id.clone()));
+            let label = agent.short_name();
+            let label_start = x.saturating_sub(label.len() as u16 / 2);
+            let around = [(x.saturating_sub(1), y), (x + 1, y)]
+                .into_iter()
+                .chain((0..label.chars().count() as u16).map(|i| (label_start + i, y + 1)));
+            for (cx, cy) in around {
+                if inner.contains((cx, cy).into()) {
+                    hits.mark(cx, cy, handle);
+                }
+            }
+            glyphs.push((x, y, handle));
+        }
+
+        // Glyphs last, so another agent's label or glow never covers one
+        if let Some(hits) = hits.as_mut() {
+            for (x, y, handle) in glyphs {
+                hits.mark(x, y, handle);
+            }
         }
     }
 }
 
+/// Draw one agent, returning where its glyph went (None if the field has
+/// no room)
 fn render_single_agent(
     agent: &Agent,
-    area: Rect,
-    inner_width: u16,
-    inner_height: u16,
+    position: &Position,
+    transform: &FieldTransform,
     buf: &mut Buffer,
     selected: Option<&str>,
     hovered: Option<&str>,
-) {
-    let (x, y) = agent.position.to_terminal(inner_width, inner_height);
-    let draw_x = area.x + 1 + x;
-    let draw_y = area.y + 1 + y;
-
-    // Skip if outside bounds
-    if draw_x <= area.x || draw_x >= area.x + area.width - 1 {
-        return;
-    }
-    if draw_y <= area.y || draw_y >= area.y + area.height - 1 {
-        return;
-    }
+    time: f32,
+) -> Option<(u16, u16)> {
+    let (draw_x, draw_y) = transform.cell(position)?;
+    let inner = transform.inner();
 
-    let base_color = get_agent_color(agent.color_index);
-    let brightness = agent.pulse_brightness();
+    let base_color = color_for_agent(agent);
+    let brightness = agent.animated_brightness(time);
     let color = if brightness > 0.8 {
         base_color
     } else {
         dim_color(base_color, brightness)
     };
 
-    let is_selected = selected.is_some_and(|id| id == agent.id);
-    let is_hovered = hovered.is_some_and(|id| id == agent.id);
+    let color = match agent.transition {
+        Some(ref transition) => transition_color(color, transition, &agent.status),
+        None => color,
+    };
+
+    let is_selected = selected.is_some_and(|id| id == &*agent.id);
+    let is_hovered = hovered.is_some_and(|id| id == &*agent.id);
 
     let mut style = Style::default().fg(color);
     if is_selected {
@@ -90,7 +175,7 @@ fn render_single_agent(
     }
 
     // Draw the agent symbol
-    let symbol = agent.symbol();
+    let symbol = agent.animated_symbol(time);
     buf[(draw_x, draw_y)].set_symbol(symbol).set_style(style);
 
     // Draw glow effect for high intensity agents
@@ -99,13 +184,13 @@ fn render_single_agent(
         let glow_style = Style::default().fg(glow_color);
 
         // Horizontal glow
-        if draw_x > area.x + 1 {
+        if draw_x > inner.x {
             let cell = &mut buf[(draw_x - 1, draw_y)];
             if cell.symbol() == " " {
                 cell.set_symbol("·").set_style(glow_style);
             }
         }
-        if draw_x < area.x + area.width - 2 {
+        if draw_x + 1 < inner.right() {
             let cell = &mut buf[(draw_x + 1, draw_y)];
             if cell.symbol() == " " {
                 cell.set_symbol("·").set_style(glow_style);
@@ -117,13 +202,13 @@ fn render_single_agent(
     let label = agent.short_name();
     let label_y = draw_y + 1;
 
-    if label_y < area.y + area.height - 1 {
+    if label_y < inner.bottom() {
         let label_style = Style::default().fg(dim_color(base_color, 0.6));
         let label_start = draw_x.saturating_sub(label.len() as u16 / 2);
 
         for (i, ch) in label.chars().enumerate() {
             let cx = label_start + i as u16;
-            if cx > area.x && cx < area.x + area.width - 1 {
+            if cx >= inner.x && cx < inner.right() {
                 let cell = &mut buf[(cx, label_y)];
                 // Only draw if cell is empty
                 if cell.symbol() == " " {
@@ -132,6 +217,72 @@ fn render_single_agent(
             }
         }
     }
+
+    Some((draw_x, draw_y))
+}
+
+/// Redraw an agent drawn at (x, y) in high contrast: its glyph on a block of
+/// its color framed by half blocks, its label bold in full color and a
+/// three-letter status badge above it
+fn outline_agent(agent: &Agent, x: u16, y: u16, inner: Rect, buf: &mut Buffer, selected: bool) {
+    let base_color = color_for_agent(agent);
+    let block = if selected { Color::Rgb(255, 255, 255) } else { base_color };
+
+    let glyph = Style::default().fg(Color::Rgb(0, 0, 0)).bg(block).add_modifier(Modifier::BOLD);
+    buf[(x, y)].set_style(glyph);
+    let frame = Style::default().fg(block).bg(Color::Reset);
+    if x > inner.x {
+        buf[(x - 1, y)].set_symbol("▐").set_style(frame);
+    }
+    if x + 1 < inner.x + inner.width {
+        buf[(x + 1, y)].set_symbol("▌").set_style(frame);
+    }
+
+    // Status badge spanning the three cells above
+    if y > inner.y {
+        let badge = Style::default()
+            .fg(Color::Rgb(0, 0, 0))
+            .bg(STATUS_COLORS.get(agent.status.clone()))
+            .add_modifier(Modifier::BOLD);
+        let text = match agent.status {
+            AgentStatus::Active => "ACT",
+            AgentStatus::Thinking => "THK",
+            AgentStatus::Waiting => "WAI",
+            AgentStatus::Idle => "IDL",
+            AgentStatus::Error => "ERR",
+        };
+        for (cx, ch) in (x.saturating_sub(1)..).zip(text.chars()) {
+            if inner.contains((cx, y - 1).into()) {
+                buf[(cx, y - 1)].set_char(ch).set_style(badge);
+            }
+        }
+    }
+
+    // Label in full color, over whatever is beneath
+    let label = agent.short_name();
+    let label_start = x.saturating_sub(label.len() as u16 / 2);
+    let label_style = Style::default().fg(base_color).add_modifier(Modifier::BOLD);
+    for (i, ch) in label.chars().enumerate() {
+        let cx = label_start + i as u16;
+        if inner.contains((cx, y + 1).into()) {
+            buf[(cx, y + 1)].set_char(ch).set_style(label_style);
+        }
+    }
+}
+
+/// Color of an agent changing status: a brief flash, then the old status
+/// color easing into the new one while fading back to the agent's own `color`
+fn transition_color(color: Color, transition: &StatusTransition, status: &AgentStatus) -> Color {
+    let t = transition.progress.clamp(0.0, 1.0);
+    let from = STATUS_COLORS.get(transition.from.clone());
+    let to = STATUS_COLORS.get(status.clone());
+    let status_color = lerp_color(from, to, ease_out_cubic(t));
+
+    if t < TRANSITION_FLASH {
+        lerp_color(status_color, Color::Rgb(255, 255, 255), 1.0 - t / TRANSITION_FLASH)
+    } else {
+        lerp_color(status_color, color, ease_in_out_cubic((t - TRANSITION_FLASH) / (1.0 - TRANSITION_FLASH)))
+    }
 }
 
 /// Render all agents
@@ -139,6 +290,42 @@ pub fn render_agents(agents: Vec<&Agent>, area: Rect, buf: &mut Buffer, selected
     AgentsWidget::new(agents).selected(selected).render(area, buf);
 }
 
+/// Widget drawing another set of agents faintly and without labels, e.g. the
+/// live swarm over a replay. Cells taken by `present` agents are left alone.
+pub struct GhostsWidget<'a> {
+    ghosts: Vec<&'a Agent>,
+    present: Vec<&'a Agent>,
+}
+
+impl<'a> GhostsWidget<'a> {
+    pub fn new(ghosts: Vec<&'a Agent>, present: Vec<&'a Agent>) -> Self {
+        Self { ghosts, present }
+    }
+}
+
+impl Widget for GhostsWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let transform = FieldTransform::new(area);
+        let occupied: Vec<(u16, u16)> = self
+            .present
+            .iter()
+            .filter_map(|agent| transform.cell(&agent.position))
+            .collect();
+
+        for ghost in &self.ghosts {
+            let Some((draw_x, draw_y)) = transform.cell(&ghost.position) else {
+                continue;
+            };
+            if occupied.contains(&(draw_x, draw_y)) {
+                continue;
+            }
+
+            let style = Style::default().fg(dim_color(color_for_agent(ghost), 0.35));
+            buf[(draw_x, draw_y)].set_symbol(ghost.symbol()).set_style(style);
+        }
+    }
+}
+
 /// Widget for the agent detail popup
 pub struct AgentDetailWidget<'a> {
     agent: &'a Agent,
@@ -161,7 +348,7 @@ impl Widget for AgentDetailWidget<'_> {
         }
 
         // Border
-        let border_style = Style::default().fg(get_agent_color(self.agent.color_index));
+        let border_style = Style::default().fg(color_for_agent(self.agent));
 
         for x in area.x..area.x + area.width {
             buf[(x, area.y)].set_char('─').set_style(border_style);
@@ -191,7 +378,7 @@ impl Widget for AgentDetailWidget<'_> {
         // Content
         let content_width = area.width.saturating_sub(4) as usize;
         let title_style = Style::default()
-            .fg(get_agent_color(self.agent.color_index))
+            .fg(color_for_agent(self.agent))
             .add_modifier(Modifier::BOLD);
         let label_style = Style::default().fg(Color::Rgb(150, 150, 160));
         let value_style = Style::default().fg(Color::Rgb(200, 200, 210));
@@ -199,7 +386,7 @@ impl Widget for AgentDetailWidget<'_> {
         let mut y = area.y + 1;
 
         // Agent name
-        let name = &self.agent.id;
+        let name = self.agent.display_name();
         render_text(buf, area.x + 2, y, name, title_style, content_width);
         y += 1;
 
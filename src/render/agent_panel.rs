@@ -1,6 +1,7 @@
 //! Agent detail panel widget for hover display.
 //!
 //! Renders a small panel showing agent details when hovering over an agent.
+//! Pinned to the selected agent, the panel also lists its connection partners.
 
 use ratatui::{
     buffer::Buffer,
@@ -9,13 +10,21 @@ use ratatui::{
     widgets::Widget,
 };
 
-use crate::state::Agent;
-use super::colors::get_agent_color;
+use hive_core::event::Keyword;
+use hive_core::state::field::Partner;
+use hive_core::state::Agent;
+use super::colors;
 
 /// Panel dimensions
 const PANEL_WIDTH: u16 = 24;
 const PANEL_HEIGHT: u16 = 8;
 
+/// Partner rows shown at once
+const MAX_PARTNER_ROWS: usize = 5;
+
+/// Separator of the focus breadcrumb
+const TRAIL_SEPARATOR: &str = " → ";
+
 /// Widget for displaying agent details on hover.
 ///
 /// Renders a compact panel showing:
@@ -23,14 +32,39 @@ const PANEL_HEIGHT: u16 = 8;
 /// - Current focus keywords
 /// - Intensity bar
 /// - Recent message (truncated)
+/// - Script-assigned metadata
+/// - Focus breadcrumb and connection partners, when pinned
 pub struct AgentPanel<'a> {
     agent: &'a Agent,
+    /// Recent focus sets, oldest first
+    trail: &'a [Vec<Keyword>],
+    partners: &'a [Partner],
+    /// Highlighted partner
+    cursor: usize,
 }
 
 impl<'a> AgentPanel<'a> {
     /// Create a new agent panel widget.
     pub fn new(agent: &'a Agent) -> Self {
-        Self { agent }
+        Self {
+            agent,
+            trail: &[],
+            partners: &[],
+            cursor: 0,
+        }
+    }
+
+    /// Show the agent's recent focus sets as a breadcrumb
+    pub fn trail(mut self, trail: &'a [Vec<Keyword>]) -> Self {
+        self.trail = trail;
+        self
+    }
+
+    /// List the agent's connection partners, highlighting the one at `cursor`
+    pub fn partners(mut self, partners: &'a [Partner], cursor: usize) -> Self {
+        self.partners = partners;
+        self.cursor = cursor;
+        self
     }
 
     /// Get the preferred panel dimensions.
@@ -38,6 +72,21 @@ impl<'a> AgentPanel<'a> {
         (PANEL_WIDTH, PANEL_HEIGHT)
     }
 
+    /// Panel height, taller with a breadcrumb and a partner list
+    pub fn height(&self) -> u16 {
+        let partner_rows = if self.partners.is_empty() {
+            0
+        } else {
+            1 + self.partners.len().min(MAX_PARTNER_ROWS) as u16
+        };
+        PANEL_HEIGHT + self.trail_rows() + partner_rows
+    }
+
+    /// The breadcrumb is only worth a row once the focus has moved
+    fn trail_rows(&self) -> u16 {
+        u16::from(self.trail.len() > 1)
+    }
+
     /// Calculate the best position for the panel given agent position and screen bounds.
     ///
     /// Tries to place the panel near the agent without going off-screen.
@@ -45,10 +94,21 @@ impl<'a> AgentPanel<'a> {
         agent_x: u16,
         agent_y: u16,
         area: Rect,
+    ) -> (u16, u16) {
+        Self::calculate_position_for(agent_x, agent_y, area, PANEL_HEIGHT)
+    }
+
+    /// [`calculate_position`](Self::calculate_position) for a panel `height`
+    /// rows tall
+    pub fn calculate_position_for(
+        agent_x: u16,
+        agent_y: u16,
+        area: Rect,
+        height: u16,
     ) -> (u16, u16) {
         // Try to place panel to the right of the agent
         let mut panel_x = agent_x.saturating_add(2);
-        let mut panel_y = agent_y.saturating_sub(PANEL_HEIGHT / 2);
+        let mut panel_y = agent_y.saturating_sub(height / 2);
 
         // If panel would go off right edge, place it to the left
         if panel_x + PANEL_WIDTH > area.x + area.width {
@@ -66,8 +126,8 @@ impl<'a> AgentPanel<'a> {
         }
 
         // If panel would go off bottom, clamp to bottom
-        if panel_y + PANEL_HEIGHT > area.y + area.height {
-            panel_y = (area.y + area.height).saturating_sub(PANEL_HEIGHT + 1);
+        if panel_y + height > area.y + area.height {
+            panel_y = (area.y + area.height).saturating_sub(height + 1);
         }
 
         (panel_x, panel_y)
@@ -78,13 +138,13 @@ impl Widget for AgentPanel<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         // Use the minimum of requested area and panel size
         let width = area.width.min(PANEL_WIDTH);
-        let height = area.height.min(PANEL_HEIGHT);
+        let height = area.height.min(self.height());
 
         if width < 10 || height < 4 {
             return; // Too small to render
         }
 
-        let agent_color = get_agent_color(self.agent.color_index);
+        let agent_color = colors::color_for_agent(self.agent);
 
         // Background
         let bg_style = Style::default().bg(Color::Rgb(25, 25, 35));
@@ -146,18 +206,18 @@ impl Widget for AgentPanel<'_> {
         let name_style = Style::default()
             .fg(agent_color)
             .add_modifier(Modifier::BOLD);
-        let name = truncate(&self.agent.id, content_width);
+        let name = truncate(self.agent.display_name(), content_width);
         render_text(buf, content_x, y, &name, name_style);
         y += 1;
 
         // Status
         let status_str = format!("{:?}", self.agent.status);
         let status_color = match self.agent.status {
-            crate::event::AgentStatus::Active => Color::Rgb(100, 200, 150),
-            crate::event::AgentStatus::Thinking => Color::Rgb(150, 150, 255),
-            crate::event::AgentStatus::Waiting => Color::Rgb(200, 200, 100),
-            crate::event::AgentStatus::Idle => Color::Rgb(100, 100, 120),
-            crate::event::AgentStatus::Error => Color::Rgb(255, 100, 100),
+            hive_core::event::AgentStatus::Active => Color::Rgb(100, 200, 150),
+            hive_core::event::AgentStatus::Thinking => Color::Rgb(150, 150, 255),
+            hive_core::event::AgentStatus::Waiting => Color::Rgb(200, 200, 100),
+            hive_core::event::AgentStatus::Idle => Color::Rgb(100, 100, 120),
+            hive_core::event::AgentStatus::Error => Color::Rgb(255, 100, 100),
         };
         let status_style = Style::default().fg(status_color);
         render_text(buf, content_x, y, &status_str, status_style);
@@ -186,6 +246,62 @@ impl Widget for AgentPanel<'_> {
             let msg_truncated = truncate(&self.agent.message, content_width);
             let msg_style = Style::default().fg(Color::Rgb(120, 120, 140));
             render_text(buf, content_x, y, &msg_truncated, msg_style);
+            y += 1;
+        }
+
+        // Script-assigned metadata (if any and space allows)
+        if y < area.y + height - 1 && !self.agent.metadata.is_empty() {
+            let meta_str = self
+                .agent
+                .metadata
+                .iter()
+                .map(|(k, v)| format!("{}={}", k, v))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let meta_truncated = truncate(&meta_str, content_width);
+            let meta_style = Style::default().fg(Color::Rgb(200, 170, 120));
+            render_text(buf, content_x, y, &meta_truncated, meta_style);
+        }
+
+        // Focus breadcrumb, cut from the left to keep the latest in view
+        let mut y = area.y + PANEL_HEIGHT - 1;
+        if self.trail_rows() > 0 && y < area.y + height - 1 {
+            let crumbs: Vec<_> = self.trail.iter().map(|focus| focus.join("+")).collect();
+            let trail = truncate_start(&crumbs.join(TRAIL_SEPARATOR), content_width);
+            let trail_style = Style::default().fg(Color::Rgb(150, 200, 255));
+            render_text(buf, content_x, y, &trail, trail_style);
+            y += 1;
+        }
+
+        // Connection partners, scrolled to keep the cursor in view
+        if self.partners.is_empty() || y >= area.y + height - 1 {
+            return;
+        }
+        let header_style = Style::default().fg(Color::Rgb(100, 100, 120));
+        render_text(buf, content_x, y, &truncate("Partners (Enter)", content_width), header_style);
+        y += 1;
+
+        let first = self.cursor.saturating_sub(MAX_PARTNER_ROWS - 1);
+        for (idx, partner) in self.partners.iter().enumerate().skip(first).take(MAX_PARTNER_ROWS) {
+            if y >= area.y + height - 1 {
+                break;
+            }
+            let style = if idx == self.cursor {
+                Style::default().fg(Color::Rgb(230, 230, 240)).bg(Color::Rgb(50, 50, 70))
+            } else {
+                Style::default().fg(Color::Rgb(150, 150, 170))
+            };
+            let count = format!("×{}", partner.count);
+            let name_width = content_width.saturating_sub(count.chars().count() + 1).min(10);
+            let name = truncate(&partner.id, name_width);
+            let mut row = format!("{:<width$} {}", name, count, width = name_width);
+            if !partner.last_label.is_empty() {
+                row.push(' ');
+                row.push_str(&partner.last_label);
+            }
+            let row = format!("{:<width$}", truncate(&row, content_width), width = content_width);
+            render_text(buf, content_x, y, &row, style);
+            y += 1;
         }
     }
 }
@@ -220,6 +336,19 @@ fn truncate(s: &str, max_len: usize) -> String {
     }
 }
 
+/// Truncate a string to its last `max_len` characters
+fn truncate_start(s: &str, max_len: usize) -> String {
+    let len = s.chars().count();
+    if len <= max_len {
+        s.to_string()
+    } else if max_len > 1 {
+        let kept: String = s.chars().skip(len - (max_len - 1)).collect();
+        format!("…{}", kept)
+    } else {
+        "…".to_string()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -229,6 +358,8 @@ mod tests {
         assert_eq!(truncate("hello", 10), "hello");
         assert_eq!(truncate("hello world", 5), "hell…");
         assert_eq!(truncate("hi", 2), "hi");
+        assert_eq!(truncate_start("auth → tests", 8), "…→ tests");
+        assert_eq!(truncate_start("tests", 8), "tests");
     }
 
     #[test]
@@ -8,7 +8,8 @@
 
 use ratatui::style::Color;
 
-use crate::event::AgentStatus;
+use hive_core::event::AgentStatus;
+use hive_core::state::Agent;
 
 /// Color depth/mode for different terminal capabilities
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -77,6 +78,18 @@ pub const AGENT_COLORS_MONO: [Color; 8] = [
     Color::Rgb(100, 100, 100), // Darker gray
 ];
 
+/// Tints mixed into the agent palette per tagged event source, so agents of
+/// different sources read as distinct swarms
+pub const SOURCE_TINTS: [Color; 4] = [
+    Color::Rgb(80, 150, 255),  // Cool blue
+    Color::Rgb(255, 130, 60),  // Warm orange
+    Color::Rgb(90, 220, 120),  // Green
+    Color::Rgb(230, 90, 220),  // Magenta
+];
+
+/// How strongly a source tint pulls the agent color toward it
+const SOURCE_TINT_STRENGTH: f32 = 0.45;
+
 /// Status colors struct for different agent states
 #[derive(Debug, Clone, Copy)]
 pub struct StatusColors {
@@ -172,6 +185,19 @@ pub fn get_agent_color(index: usize) -> Color {
     AGENT_COLORS[index % AGENT_COLORS.len()]
 }
 
+/// Get the color of an agent: its palette color, tinted by its source when
+/// it came from a tagged source
+pub fn color_for_agent(agent: &Agent) -> Color {
+    let base = get_agent_color(agent.color_index);
+    match agent.source {
+        Some(source) => {
+            let tint = SOURCE_TINTS[source % SOURCE_TINTS.len()];
+            super::lerp_color(base, tint, SOURCE_TINT_STRENGTH)
+        }
+        None => base,
+    }
+}
+
 /// Get an agent color for a specific color mode
 ///
 /// # Arguments
@@ -244,6 +270,17 @@ mod tests {
         assert_eq!(color0, color8);
     }
 
+    #[test]
+    fn test_agent_color_tinted_by_source() {
+        let mut agent = Agent::new("ci/atlas".into(), 0);
+        assert_eq!(color_for_agent(&agent), AGENT_COLORS[0]);
+
+        agent.source = Some(1);
+        let tinted = color_for_agent(&agent);
+        assert_ne!(tinted, AGENT_COLORS[0]);
+        assert_eq!(tinted, crate::render::lerp_color(AGENT_COLORS[0], SOURCE_TINTS[1], SOURCE_TINT_STRENGTH));
+    }
+
     #[test]
     fn test_status_colors_get() {
         assert_eq!(STATUS_COLORS.get(AgentStatus::Active), STATUS_COLORS.active);
@@ -5,16 +5,26 @@ use ratatui::{
     widgets::Widget,
 };
 
-use crate::positioning::Position;
-use crate::state::field::ActiveConnection;
+use std::cell::RefCell;
+
+use hive_core::positioning::Position;
+use hive_core::state::field::ActiveConnection;
 
 use super::colors::dim_color;
+use super::hit_map::{HitMap, HitTarget};
+use super::transform::FieldTransform;
+
+/// Looks up an agent's current position by ID
+type PositionLookup<'a> = Box<dyn Fn(&str) -> Option<Position> + 'a>;
 
 /// Widget for rendering connections between agents
 pub struct ConnectionsWidget<'a> {
     connections: &'a [ActiveConnection],
     /// Function to get agent positions
-    get_position: Box<dyn Fn(&str) -> Option<Position> + 'a>,
+    get_position: PositionLookup<'a>,
+    /// Brightness factor applied on top of each connection's fade
+    opacity: f32,
+    hits: Option<&'a RefCell<HitMap>>,
 }
 
 impl<'a> ConnectionsWidget<'a> {
@@ -25,14 +35,28 @@ impl<'a> ConnectionsWidget<'a> {
         Self {
             connections,
             get_position: Box::new(get_position),
+            opacity: 1.0,
+            hits: None,
         }
     }
+
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Record the line and label cells in a hit-test map
+    pub fn hits(mut self, hits: Option<&'a RefCell<HitMap>>) -> Self {
+        self.hits = hits;
+        self
+    }
 }
 
 impl Widget for ConnectionsWidget<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let inner_width = area.width.saturating_sub(2);
-        let inner_height = area.height.saturating_sub(2);
+        let transform = FieldTransform::new(area);
+        let inner = transform.inner();
+        let mut hits = self.hits.map(|hits| hits.borrow_mut());
 
         for conn in self.connections {
             let Some(from_pos) = (self.get_position)(&conn.from) else {
@@ -42,28 +66,42 @@ impl Widget for ConnectionsWidget<'_> {
                 continue;
             };
 
-            let (x1, y1) = from_pos.to_terminal(inner_width, inner_height);
-            let (x2, y2) = to_pos.to_terminal(inner_width, inner_height);
+            let (Some((x1, y1)), Some((x2, y2))) = (transform.cell(&from_pos), transform.cell(&to_pos)) else {
+                continue;
+            };
+
+            let handle = hits.as_mut().map(|hits| {
+                hits.add(HitTarget::Connection {
+                    from: conn.from.clone(),
+                    to: conn.to.clone(),
+                })
+            });
+            let mut mark = |x, y| {
+                if let (Some(hits), Some(handle)) = (hits.as_mut(), handle) {
+                    hits.mark(x, y, handle);
+                }
+            };
 
             // Draw line between positions
             draw_line(
                 buf,
-                area.x + 1 + x1,
-                area.y + 1 + y1,
-                area.x + 1 + x2,
-                area.y + 1 + y2,
-                area,
-                conn.opacity,
+                x1,
+                y1,
+                x2,
+                y2,
+                inner,
+                conn.opacity * self.opacity,
+                &mut mark,
             );
 
             // Draw label at midpoint if opacity is high enough
             if conn.opacity > 0.5 && !conn.label.is_empty() {
-                let mid_x = (x1 + x2) / 2 + area.x + 1;
-                let mid_y = (y1 + y2) / 2 + area.y + 1;
+                let mid_x = (x1 + x2) / 2;
+                let mid_y = (y1 + y2) / 2;
 
                 let label_style = Style::default().fg(dim_color(
                     Color::Rgb(200, 200, 200),
-                    conn.opacity * 0.7,
+                    conn.opacity * self.opacity * 0.7,
                 ));
 
                 let label = truncate_label(&conn.label, 15);
@@ -71,11 +109,11 @@ impl Widget for ConnectionsWidget<'_> {
 
                 for (i, ch) in label.chars().enumerate() {
                     let x = label_start + i as u16;
-                    if x > area.x && x < area.x + area.width - 1 && mid_y > area.y && mid_y < area.y + area.height - 1
-                    {
+                    if x >= inner.x && x < inner.right() {
                         let cell = &mut buf[(x, mid_y)];
                         if is_line_char(cell.symbol()) || cell.symbol() == " " {
                             cell.set_char(ch).set_style(label_style);
+                            mark(x, mid_y);
                         }
                     }
                 }
@@ -84,7 +122,9 @@ impl Widget for ConnectionsWidget<'_> {
     }
 }
 
-/// Draw a line between two points using Bresenham's algorithm
+/// Draw a line between two points within `bounds` using Bresenham's algorithm, passing
+/// each cell drawn to `mark`
+#[allow(clippy::too_many_arguments)]
 fn draw_line(
     buf: &mut Buffer,
     x1: u16,
@@ -93,6 +133,7 @@ fn draw_line(
     y2: u16,
     bounds: Rect,
     opacity: f32,
+    mark: &mut dyn FnMut(u16, u16),
 ) {
     let color = dim_color(Color::Rgb(100, 150, 200), opacity);
     let style = Style::default().fg(color);
@@ -106,10 +147,10 @@ fn draw_line(
     let mut x = x1 as i32;
     let mut y = y1 as i32;
 
-    let min_x = bounds.x as i32 + 1;
-    let max_x = bounds.x as i32 + bounds.width as i32 - 2;
-    let min_y = bounds.y as i32 + 1;
-    let max_y = bounds.y as i32 + bounds.height as i32 - 2;
+    let min_x = bounds.x as i32;
+    let max_x = bounds.right() as i32 - 1;
+    let min_y = bounds.y as i32;
+    let max_y = bounds.bottom() as i32 - 1;
 
     loop {
         if x >= min_x && x <= max_x && y >= min_y && y <= max_y {
@@ -129,6 +170,7 @@ fn draw_line(
             // Only draw on empty cells or existing line chars
             if cell.symbol() == " " || is_line_char(cell.symbol()) {
                 cell.set_char(ch).set_style(style);
+                mark(x as u16, y as u16);
             }
         }
 
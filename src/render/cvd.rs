@@ -0,0 +1,139 @@
+//! Color vision deficiency preview.
+//!
+//! `:cvd <kind>` redraws every frame as someone with deuteranopia, protanopia
+//! or tritanopia would see it, so the agent palette and status colors can be
+//! checked for colors that run together. Colors are transformed with the
+//! Machado et al. (2009) matrices at full severity, in linear RGB. Indexed and
+//! named colors are left as they are, so the preview is only exact in
+//! truecolor terminals.
+
+use std::str::FromStr;
+
+use ratatui::{buffer::Buffer, layout::Rect, style::Color};
+
+/// Form of color blindness to simulate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorVision {
+    /// No green cones
+    Deuteranopia,
+    /// No red cones
+    Protanopia,
+    /// No blue cones
+    Tritanopia,
+}
+
+impl ColorVision {
+    pub fn name(self) -> &'static str {
+        match self {
+            ColorVision::Deuteranopia => "deuteranopia",
+            ColorVision::Protanopia => "protanopia",
+            ColorVision::Tritanopia => "tritanopia",
+        }
+    }
+
+    /// Transform in linear RGB (Machado, Oliveira and Fernandes, 2009)
+    fn matrix(self) -> [[f32; 3]; 3] {
+        match self {
+            ColorVision::Deuteranopia => [
+                [0.367322, 0.860646, -0.227968],
+                [0.280085, 0.672501, 0.047413],
+                [-0.011820, 0.042940, 0.968881],
+            ],
+            ColorVision::Protanopia => [
+                [0.152286, 1.052583, -0.204868],
+                [0.114503, 0.786281, 0.099216],
+                [-0.003882, -0.048116, 1.051998],
+            ],
+            ColorVision::Tritanopia => [
+                [1.255528, -0.076749, -0.178779],
+                [-0.078411, 0.930809, 0.147602],
+                [0.004733, 0.691367, 0.303900],
+            ],
+        }
+    }
+
+    /// How `color` looks with this deficiency
+    pub fn simulate(self, color: Color) -> Color {
+        let Color::Rgb(r, g, b) = color else {
+            return color;
+        };
+        let linear = [to_linear(r), to_linear(g), to_linear(b)];
+        let [r, g, b] = self
+            .matrix()
+            .map(|row| from_linear(row[0] * linear[0] + row[1] * linear[1] + row[2] * linear[2]));
+        Color::Rgb(r, g, b)
+    }
+
+    /// Recolor the cells of `area` in `buf`
+    pub fn apply(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        for y in area.y..area.y + area.height {
+            for x in area.x..area.x + area.width {
+                let cell = &mut buf[(x, y)];
+                cell.fg = self.simulate(cell.fg);
+                cell.bg = self.simulate(cell.bg);
+            }
+        }
+    }
+}
+
+impl FromStr for ColorVision {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "deuteranopia" | "deutan" | "d" => Ok(ColorVision::Deuteranopia),
+            "protanopia" | "protan" | "p" => Ok(ColorVision::Protanopia),
+            "tritanopia" | "tritan" | "t" => Ok(ColorVision::Tritanopia),
+            other => Err(format!(
+                "unknown color vision {:?} (expected deuteranopia, protanopia, tritanopia or off)",
+                other
+            )),
+        }
+    }
+}
+
+/// sRGB channel to linear light
+fn to_linear(channel: u8) -> f32 {
+    let c = channel as f32 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Linear light back to an sRGB channel
+fn from_linear(value: f32) -> u8 {
+    let c = value.clamp(0.0, 1.0);
+    let c = if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    (c * 255.0).round() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_red_and_green_merge_without_green_cones() {
+        let distance = |a: Color, b: Color| match (a, b) {
+            (Color::Rgb(r1, g1, b1), Color::Rgb(r2, g2, b2)) => {
+                (r1.abs_diff(r2) as u32).pow(2) + (g1.abs_diff(g2) as u32).pow(2) + (b1.abs_diff(b2) as u32).pow(2)
+            }
+            _ => unreachable!(),
+        };
+        let (red, green) = (Color::Rgb(200, 60, 40), Color::Rgb(90, 140, 40));
+        let vision: ColorVision = "deutan".parse().unwrap();
+        let seen = distance(vision.simulate(red), vision.simulate(green));
+        assert!(seen * 4 < distance(red, green));
+
+        // Grays and non-RGB colors stay as they are
+        assert_eq!(ColorVision::Tritanopia.simulate(Color::Rgb(255, 255, 255)), Color::Rgb(255, 255, 255));
+        assert_eq!(ColorVision::Protanopia.simulate(Color::Red), Color::Red);
+        assert!("achromatopsia".parse::<ColorVision>().is_err());
+    }
+}
@@ -6,8 +6,16 @@
 //! - **Minimal**: Clean view with agents and labels only
 //! - **Standard**: Balanced view with connections, trails, and activity
 //! - **Debug**: Full diagnostic view showing all available information
+//!
+//! Further modes can be defined in the config file ([`CustomDisplayMode`]) and
+//! selected with the number keys 4-9.
+
+use std::collections::HashMap;
+use std::str::FromStr;
 
-use super::{LayerVisibility, RenderLayer};
+use serde::Deserialize;
+
+use super::{LayerVisibility, RenderLayer, StatusItem};
 
 /// Display mode presets for the visualization.
 ///
@@ -75,6 +83,15 @@ impl DisplayMode {
         visibility
     }
 
+    /// Layer visibility showing just `layers` on top of Minimal
+    pub fn with_layers(layers: &[RenderLayer]) -> LayerVisibility {
+        let mut visibility = DisplayMode::Minimal.layer_visibility();
+        for layer in layers {
+            visibility.set_visible(*layer, true);
+        }
+        visibility
+    }
+
     /// Cycle to the next display mode.
     ///
     /// Order: Minimal -> Standard -> Debug -> Minimal
@@ -105,6 +122,56 @@ impl DisplayMode {
     }
 }
 
+impl FromStr for DisplayMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "minimal" => Ok(DisplayMode::Minimal),
+            "standard" => Ok(DisplayMode::Standard),
+            "debug" => Ok(DisplayMode::Debug),
+            other => Err(format!("unknown display mode {:?} (expected minimal, standard or debug)", other)),
+        }
+    }
+}
+
+/// A display mode defined in the `[[display_modes]]` config section
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CustomDisplayMode {
+    /// Shown in the status bar while the mode is active
+    pub name: String,
+    /// Number key (4-9) that selects the mode
+    pub key: u8,
+    /// Optional layers to show; the rest are hidden
+    #[serde(default)]
+    pub layers: Vec<RenderLayer>,
+    /// Whether to show the activity log panel
+    #[serde(default)]
+    pub activity_log: bool,
+    /// Status bar readouts, the Standard mode's when unset
+    #[serde(default)]
+    pub status_bar: Option<Vec<StatusItem>>,
+    /// Brightness of individual layers in percent (unlisted layers are at 100)
+    #[serde(default)]
+    pub opacity: HashMap<RenderLayer, u8>,
+}
+
+impl CustomDisplayMode {
+    /// Number keys available to custom modes (1-3 select the built-in ones)
+    pub const KEYS: std::ops::RangeInclusive<u8> = 4..=9;
+
+    /// Layer visibility for this mode: Minimal plus the listed layers, dimmed
+    /// as configured
+    pub fn layer_visibility(&self) -> LayerVisibility {
+        let mut visibility = DisplayMode::with_layers(&self.layers);
+        for (layer, percent) in &self.opacity {
+            visibility.set_opacity(*layer, *percent as f32 / 100.0);
+        }
+        visibility
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -165,10 +232,35 @@ mod tests {
         assert!(visibility.is_visible(RenderLayer::Grid));
     }
 
+    #[test]
+    fn test_custom_mode_layers() {
+        let mode = CustomDisplayMode {
+            name: "Heat".to_string(),
+            key: 4,
+            layers: vec![RenderLayer::Heatmap, RenderLayer::Zones],
+            activity_log: false,
+            status_bar: None,
+            opacity: HashMap::from([(RenderLayer::Heatmap, 40)]),
+        };
+        let visibility = mode.layer_visibility();
+
+        assert!(visibility.is_visible(RenderLayer::Agents));
+        assert!(visibility.is_visible(RenderLayer::Heatmap));
+        assert!(visibility.is_visible(RenderLayer::Zones));
+        assert!(!visibility.is_visible(RenderLayer::Trails));
+        assert_eq!(visibility.opacity(RenderLayer::Heatmap), 0.4);
+        assert_eq!(visibility.opacity(RenderLayer::Zones), 1.0);
+    }
+
     #[test]
     fn test_mode_names() {
         assert_eq!(DisplayMode::Minimal.name(), "Minimal");
         assert_eq!(DisplayMode::Standard.name(), "Standard");
         assert_eq!(DisplayMode::Debug.name(), "Debug");
+
+        for mode in [DisplayMode::Minimal, DisplayMode::Standard, DisplayMode::Debug] {
+            assert_eq!(mode.name().parse::<DisplayMode>(), Ok(mode));
+        }
+        assert!("verbose".parse::<DisplayMode>().is_err());
     }
 }
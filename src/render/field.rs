@@ -1,19 +1,73 @@
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     widgets::Widget,
 };
 
-use crate::state::field::StoredLandmark;
+use hive_core::state::field::StoredLandmark;
+use hive_core::state::Cluster;
+use std::cell::RefCell;
 use std::collections::HashMap;
 
-use crate::event::LandmarkId;
+use serde::Deserialize;
+
+use hive_core::event::LandmarkId;
+use hive_core::positioning::DEFAULT_MARGIN;
+
+use super::hit_map::{HitMap, HitTarget};
+use super::transform::FieldTransform;
+
+/// How the edge of the field is drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FieldBorder {
+    /// Box-drawing lines with square corners
+    #[default]
+    Square,
+    /// Box-drawing lines with rounded corners
+    Rounded,
+    /// No lines; the edge is left blank as padding
+    None,
+}
+
+impl FieldBorder {
+    /// Corner glyphs: top left, top right, bottom left, bottom right
+    fn corners(self) -> Option<[char; 4]> {
+        match self {
+            FieldBorder::Square => Some(['┌', '┐', '└', '┘']),
+            FieldBorder::Rounded => Some(['╭', '╮', '╰', '╯']),
+            FieldBorder::None => None,
+        }
+    }
+}
+
+/// `[field]` section of the config file
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FieldConfig {
+    /// Share of the field's width and height kept clear of agents and
+    /// landmarks on each side, from 0 to 0.4
+    pub margin: f32,
+    pub border: FieldBorder,
+}
+
+impl Default for FieldConfig {
+    fn default() -> Self {
+        Self {
+            margin: DEFAULT_MARGIN,
+            border: FieldBorder::default(),
+        }
+    }
+}
 
 /// The main field widget that renders the background and landmarks
 pub struct FieldWidget<'a> {
     landmarks: &'a HashMap<LandmarkId, StoredLandmark>,
     show_landmarks: bool,
+    focused: bool,
+    border: FieldBorder,
+    hits: Option<&'a RefCell<HitMap>>,
 }
 
 impl<'a> FieldWidget<'a> {
@@ -21,61 +75,86 @@ impl<'a> FieldWidget<'a> {
         Self {
             landmarks,
             show_landmarks: true,
+            focused: false,
+            border: FieldBorder::default(),
+            hits: None,
         }
     }
 
+    pub fn border(mut self, border: FieldBorder) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Highlight the border to show the field has keyboard focus
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
     pub fn show_landmarks(mut self, show: bool) -> Self {
         self.show_landmarks = show;
         self
     }
+
+    /// Record the landmark labels in a hit-test map
+    pub fn hits(mut self, hits: Option<&'a RefCell<HitMap>>) -> Self {
+        self.hits = hits;
+        self
+    }
 }
 
 impl Widget for FieldWidget<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         // Draw field border
-        let border_style = Style::default().fg(Color::Rgb(40, 40, 50));
+        let border_style = if self.focused {
+            Style::default().fg(Color::Rgb(70, 130, 105))
+        } else {
+            Style::default().fg(Color::Rgb(40, 40, 50))
+        };
 
-        // Top and bottom borders
-        for x in area.x..area.x + area.width {
-            buf[(x, area.y)].set_char('─').set_style(border_style);
-            buf[(x, area.y + area.height - 1)]
-                .set_char('─')
-                .set_style(border_style);
-        }
+        if let Some([top_left, top_right, bottom_left, bottom_right]) = self.border.corners() {
+            // Top and bottom borders
+            for x in area.x..area.x + area.width {
+                buf[(x, area.y)].set_char('─').set_style(border_style);
+                buf[(x, area.y + area.height - 1)]
+                    .set_char('─')
+                    .set_style(border_style);
+            }
 
-        // Left and right borders
-        for y in area.y..area.y + area.height {
-            buf[(area.x, y)].set_char('│').set_style(border_style);
-            buf[(area.x + area.width - 1, y)]
-                .set_char('│')
+            // Left and right borders
+            for y in area.y..area.y + area.height {
+                buf[(area.x, y)].set_char('│').set_style(border_style);
+                buf[(area.x + area.width - 1, y)]
+                    .set_char('│')
+                    .set_style(border_style);
+            }
+
+            // Corners
+            buf[(area.x, area.y)].set_char(top_left).set_style(border_style);
+            buf[(area.x + area.width - 1, area.y)]
+                .set_char(top_right)
+                .set_style(border_style);
+            buf[(area.x, area.y + area.height - 1)]
+                .set_char(bottom_left)
+                .set_style(border_style);
+            buf[(area.x + area.width - 1, area.y + area.height - 1)]
+                .set_char(bottom_right)
                 .set_style(border_style);
         }
 
-        // Corners
-        buf[(area.x, area.y)].set_char('┌').set_style(border_style);
-        buf[(area.x + area.width - 1, area.y)]
-            .set_char('┐')
-            .set_style(border_style);
-        buf[(area.x, area.y + area.height - 1)]
-            .set_char('└')
-            .set_style(border_style);
-        buf[(area.x + area.width - 1, area.y + area.height - 1)]
-            .set_char('┘')
-            .set_style(border_style);
-
         // Render landmarks as faint labels
         if self.show_landmarks {
             let landmark_style = Style::default().fg(Color::Rgb(50, 50, 60));
-            let inner_width = area.width.saturating_sub(2);
-            let inner_height = area.height.saturating_sub(2);
-
-            for landmark in self.landmarks.values() {
-                let (x, y) = landmark
-                    .position
-                    .to_terminal(inner_width, inner_height);
+            let transform = FieldTransform::new(area);
+            let inner = transform.inner();
 
-                let draw_x = area.x + 1 + x;
-                let draw_y = area.y + 1 + y;
+            let mut hits = self.hits.map(|hits| hits.borrow_mut());
+            for (id, landmark) in self.landmarks {
+                let Some((draw_x, draw_y)) = transform.cell(&landmark.position) else {
+                    break;
+                };
+                let handle = hits.as_mut().map(|hits| hits.add(HitTarget::Landmark(id.clone())));
 
                 // Draw landmark label
                 let label = &landmark.label;
@@ -83,9 +162,11 @@ impl Widget for FieldWidget<'_> {
 
                 for (i, ch) in label.chars().enumerate() {
                     let cx = label_start + i as u16;
-                    if cx > area.x && cx < area.x + area.width - 1 && draw_y > area.y && draw_y < area.y + area.height - 1
-                    {
+                    if cx >= inner.x && cx < inner.right() {
                         buf[(cx, draw_y)].set_char(ch).set_style(landmark_style);
+                        if let (Some(hits), Some(handle)) = (hits.as_mut(), handle) {
+                            hits.mark(cx, draw_y, handle);
+                        }
                     }
                 }
             }
@@ -93,6 +174,47 @@ impl Widget for FieldWidget<'_> {
     }
 }
 
+/// Labels for clusters of agents found without landmarks, drawn faintly at
+/// each cluster's center
+pub struct ClusterLabelsWidget<'a> {
+    clusters: &'a [Cluster],
+}
+
+impl<'a> ClusterLabelsWidget<'a> {
+    pub fn new(clusters: &'a [Cluster]) -> Self {
+        Self { clusters }
+    }
+}
+
+impl Widget for ClusterLabelsWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let style = Style::default()
+            .fg(Color::Rgb(75, 75, 95))
+            .add_modifier(Modifier::ITALIC);
+        let transform = FieldTransform::new(area);
+        let inner = transform.inner();
+
+        for cluster in self.clusters {
+            let Some((draw_x, draw_y)) = transform.cell(&cluster.center) else {
+                return;
+            };
+
+            let label = format!("~{}~", cluster.label);
+            let label_start = draw_x.saturating_sub(label.chars().count() as u16 / 2);
+            for (i, ch) in label.chars().enumerate() {
+                let cx = label_start + i as u16;
+                if cx < inner.x || cx >= inner.right() {
+                    continue;
+                }
+                let cell = &mut buf[(cx, draw_y)];
+                if cell.symbol() == " " {
+                    cell.set_char(ch).set_style(style);
+                }
+            }
+        }
+    }
+}
+
 /// Render the field background
 pub fn render_field(
     area: Rect,
@@ -0,0 +1,314 @@
+//! File browser for picking an events file.
+//!
+//! Lists the directories and files of one directory at a time. Directories
+//! come first; event files (`.jsonl`, `.json`) are highlighted. Hidden entries
+//! are skipped. Recently opened files can be listed above the starting
+//! directory.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::Widget,
+};
+
+/// One row of the listing
+#[derive(Debug, Clone, PartialEq)]
+pub struct BrowserEntry {
+    pub name: String,
+    pub path: PathBuf,
+    pub is_dir: bool,
+    /// Listed from the recent-files history rather than the directory
+    pub recent: bool,
+}
+
+impl BrowserEntry {
+    /// Whether this looks like a Hive events file
+    pub fn is_event_file(&self) -> bool {
+        !self.is_dir
+            && matches!(
+                self.path.extension().and_then(|e| e.to_str()),
+                Some("jsonl" | "json")
+            )
+    }
+}
+
+/// Directory listing with a selection cursor
+#[derive(Debug, Clone)]
+pub struct FileBrowser {
+    dir: PathBuf,
+    entries: Vec<BrowserEntry>,
+    selected: usize,
+}
+
+impl FileBrowser {
+    /// Open a browser on `dir`.
+    pub fn open(dir: &Path) -> io::Result<Self> {
+        let dir = dir.canonicalize()?;
+        let entries = read_entries(&dir)?;
+        Ok(Self {
+            dir,
+            entries,
+            selected: 0,
+        })
+    }
+
+    /// List `files` (that still exist) above the directory entries.
+    pub fn with_recent(mut self, files: &[PathBuf]) -> Self {
+        let recent = files.iter().filter(|f| f.is_file()).map(|f| BrowserEntry {
+            name: f.display().to_string(),
+            path: f.clone(),
+            is_dir: false,
+            recent: true,
+        });
+        self.entries.splice(0..0, recent);
+        self
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn entries(&self) -> &[BrowserEntry] {
+        &self.entries
+    }
+
+    pub fn selected(&self) -> Option<&BrowserEntry> {
+        self.entries.get(self.selected)
+    }
+
+    pub fn move_up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn move_down(&mut self) {
+        if self.selected + 1 < self.entries.len() {
+            self.selected += 1;
+        }
+    }
+
+    /// Enter the selected entry: directories are opened, a file is returned.
+    pub fn enter(&mut self) -> io::Result<Option<PathBuf>> {
+        let Some(entry) = self.selected().cloned() else {
+            return Ok(None);
+        };
+        if entry.is_dir {
+            self.change_dir(&entry.path)?;
+            Ok(None)
+        } else {
+            Ok(Some(entry.path))
+        }
+    }
+
+    /// Go to the parent directory, keeping the directory we came from selected.
+    pub fn parent(&mut self) -> io::Result<()> {
+        let Some(parent) = self.dir.parent().map(Path::to_path_buf) else {
+            return Ok(());
+        };
+        let from = self.dir.clone();
+        self.change_dir(&parent)?;
+        if let Some(idx) = self.entries.iter().position(|e| e.path == from) {
+            self.selected = idx;
+        }
+        Ok(())
+    }
+
+    fn change_dir(&mut self, dir: &Path) -> io::Result<()> {
+        self.entries = read_entries(dir)?;
+        self.dir = dir.to_path_buf();
+        self.selected = 0;
+        Ok(())
+    }
+}
+
+/// Sorted, non-hidden entries of `dir`: directories first, then files
+fn read_entries(dir: &Path) -> io::Result<Vec<BrowserEntry>> {
+    let mut entries: Vec<BrowserEntry> = std::fs::read_dir(dir)?
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if name.starts_with('.') {
+                return None;
+            }
+            let path = entry.path();
+            Some(BrowserEntry {
+                is_dir: path.is_dir(),
+                name,
+                path,
+                recent: false,
+            })
+        })
+        .collect();
+    entries.sort_by(|a, b| b.is_dir.cmp(&a.is_dir).then_with(|| a.name.cmp(&b.name)));
+    Ok(entries)
+}
+
+/// Widget drawing a [`FileBrowser`] as a bordered list
+pub struct FileBrowserWidget<'a> {
+    browser: &'a FileBrowser,
+    title: &'a str,
+}
+
+impl<'a> FileBrowserWidget<'a> {
+    pub fn new(browser: &'a FileBrowser) -> Self {
+        Self {
+            browser,
+            title: " Open events file ",
+        }
+    }
+
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = title;
+        self
+    }
+}
+
+impl Widget for FileBrowserWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.width < 10 || area.height < 5 {
+            return;
+        }
+
+        let border = Style::default().fg(Color::Rgb(100, 200, 150));
+        let background = Style::default().bg(Color::Rgb(35, 35, 45));
+        for y in area.y..area.y + area.height {
+            for x in area.x..area.x + area.width {
+                let ch = match (
+                    x == area.x,
+                    x == area.x + area.width - 1,
+                    y == area.y,
+                    y == area.y + area.height - 1,
+                ) {
+                    (true, _, true, _) => '╭',
+                    (_, true, true, _) => '╮',
+                    (true, _, _, true) => '╰',
+                    (_, true, _, true) => '╯',
+                    (true, _, _, _) | (_, true, _, _) => '│',
+                    (_, _, true, _) | (_, _, _, true) => '─',
+                    _ => ' ',
+                };
+                buf[(x, y)].set_char(ch).set_style(background.patch(border));
+            }
+        }
+
+        let inner_width = area.width.saturating_sub(4) as usize;
+        let title_style = border.add_modifier(Modifier::BOLD);
+        draw_text(buf, area.x + 2, area.y, inner_width, self.title, title_style);
+
+        let dir = self.browser.dir().display().to_string();
+        let dir_style = Style::default().fg(Color::Rgb(150, 150, 160));
+        draw_text(buf, area.x + 2, area.y + 1, inner_width, &dir, dir_style);
+
+        // Keep the selection visible
+        let rows = area.height.saturating_sub(4) as usize;
+        let first = self.browser.selected.saturating_sub(rows.saturating_sub(1));
+
+        if self.browser.entries.is_empty() {
+            let style = Style::default().fg(Color::Rgb(120, 120, 130));
+            draw_text(buf, area.x + 2, area.y + 2, inner_width, "(empty)", style);
+        }
+
+        for (row, (idx, entry)) in self
+            .browser
+            .entries
+            .iter()
+            .enumerate()
+            .skip(first)
+            .take(rows)
+            .enumerate()
+        {
+            let fg = if entry.is_dir {
+                Color::Rgb(120, 170, 240)
+            } else if entry.is_event_file() {
+                Color::Rgb(220, 220, 230)
+            } else {
+                Color::Rgb(120, 120, 130)
+            };
+            let mut style = Style::default().fg(fg);
+            if idx == self.browser.selected {
+                style = style.bg(Color::Rgb(60, 60, 80)).add_modifier(Modifier::BOLD);
+            }
+
+            let label = if entry.is_dir {
+                format!("{}/", entry.name)
+            } else if entry.recent {
+                format!("★ {}", entry.name)
+            } else {
+                entry.name.clone()
+            };
+            let y = area.y + 2 + row as u16;
+            draw_text(buf, area.x + 2, y, inner_width, &label, style);
+        }
+
+        let hint = "↑/↓ move  Enter open  ← up a level  Esc cancel";
+        let hint_style = Style::default().fg(Color::Rgb(120, 120, 130));
+        draw_text(buf, area.x + 2, area.y + area.height - 2, inner_width, hint, hint_style);
+    }
+}
+
+/// Write `text` at (x, y), truncated to `max_width` characters
+fn draw_text(buf: &mut Buffer, x: u16, y: u16, max_width: usize, text: &str, style: Style) {
+    for (cx, ch) in (x..).zip(text.chars().take(max_width)) {
+        buf[(cx, y)].set_char(ch).set_style(style);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("hive-browser-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("runs")).unwrap();
+        std::fs::write(dir.join("events.jsonl"), "").unwrap();
+        std::fs::write(dir.join("notes.txt"), "").unwrap();
+        std::fs::write(dir.join(".hidden"), "").unwrap();
+        std::fs::write(dir.join("runs").join("a.jsonl"), "").unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_lists_directories_first_and_skips_hidden() {
+        let dir = scratch_dir("list");
+        let browser = FileBrowser::open(&dir).unwrap();
+        let names: Vec<_> = browser.entries().iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["runs", "events.jsonl", "notes.txt"]);
+        assert!(browser.entries()[1].is_event_file());
+        assert!(!browser.entries()[2].is_event_file());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_navigate_into_directory_and_back() {
+        let dir = scratch_dir("nav");
+        let mut browser = FileBrowser::open(&dir).unwrap();
+
+        // "runs" is selected first
+        assert_eq!(browser.enter().unwrap(), None);
+        assert!(browser.dir().ends_with("runs"));
+
+        let picked = browser.enter().unwrap().unwrap();
+        assert!(picked.ends_with("a.jsonl"));
+
+        browser.parent().unwrap();
+        assert_eq!(browser.selected().unwrap().name, "runs");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_recent_files_listed_first() {
+        let dir = scratch_dir("recent");
+        let recent = vec![dir.join("runs").join("a.jsonl"), dir.join("gone.jsonl")];
+        let mut browser = FileBrowser::open(&dir).unwrap().with_recent(&recent);
+
+        // Missing files are skipped
+        assert_eq!(browser.entries().len(), 4);
+        assert!(browser.selected().unwrap().recent);
+        assert_eq!(browser.enter().unwrap(), Some(recent[0].clone()));
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
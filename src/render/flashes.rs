@@ -0,0 +1,78 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Style,
+    widgets::Widget,
+};
+
+use hive_core::state::Agent;
+
+use super::colors::{color_for_agent, dim_color};
+use super::transform::FieldTransform;
+
+/// Blinks per second while an agent is flashing
+const FLASH_RATE: f32 = 4.0;
+
+/// Widget for rendering event flashes behind flashing agents
+///
+/// Draws a blinking block of background color around each agent whose
+/// `flash_remaining` is still running; the agent layer draws on top of it.
+pub struct FlashesWidget<'a> {
+    agents: Vec<&'a Agent>,
+    /// Brightness factor applied to the flash color
+    opacity: f32,
+    /// How far between their last two positions agents are drawn
+    interpolation: f32,
+}
+
+impl<'a> FlashesWidget<'a> {
+    pub fn new(agents: Vec<&'a Agent>) -> Self {
+        Self {
+            agents,
+            opacity: 1.0,
+            interpolation: 1.0,
+        }
+    }
+
+    /// Follow agents drawn between positions (see [`AgentsWidget::interpolation`](super::agent::AgentsWidget::interpolation))
+    pub fn interpolation(mut self, alpha: f32) -> Self {
+        self.interpolation = alpha;
+        self
+    }
+
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity;
+        self
+    }
+}
+
+impl Widget for FlashesWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let transform = FieldTransform::new(area);
+        let inner = transform.inner();
+
+        for agent in &self.agents {
+            if agent.flash_remaining <= 0.0 {
+                continue;
+            }
+            // Off half of the blink cycle
+            if (agent.flash_remaining * FLASH_RATE * 2.0) as u32 % 2 == 1 {
+                continue;
+            }
+
+            let style = Style::default().bg(dim_color(color_for_agent(agent), 0.6 * self.opacity));
+
+            let Some((center_x, center_y)) = transform.cell(&agent.drawn_position(self.interpolation)) else {
+                continue;
+            };
+
+            for draw_y in center_y.saturating_sub(1)..=center_y + 1 {
+                for draw_x in center_x.saturating_sub(2)..=center_x + 2 {
+                    if inner.contains((draw_x, draw_y).into()) {
+                        buf[(draw_x, draw_y)].set_style(style);
+                    }
+                }
+            }
+        }
+    }
+}
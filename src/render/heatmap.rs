@@ -1,3 +1,6 @@
+use std::io;
+use std::path::Path;
+
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
@@ -5,7 +8,13 @@ use ratatui::{
     widgets::Widget,
 };
 
-use crate::positioning::Position;
+use serde::{Deserialize, Serialize};
+
+use hive_core::positioning::Position;
+use hive_core::state::Field;
+
+use super::colors::{dim_color, ColorMode};
+use super::transform::FieldTransform;
 
 /// Heat map grid resolution (cells per terminal character)
 const CELL_SIZE: u16 = 2;
@@ -16,16 +25,101 @@ const DEFAULT_DECAY_RATE: f32 = 0.98;
 /// Heat accumulation rate when agent is present
 const ACCUMULATION_RATE: f32 = 0.05;
 
+/// Heat an agent adds per step when heat shows presence, as much as a
+/// moderately busy agent adds to the intensity heat
+const PRESENCE_HEAT: f32 = 0.5;
+
 /// Default minimum heat threshold before clearing
 const DEFAULT_HEAT_THRESHOLD: f32 = 0.02;
 
-/// Configuration for heatmap behavior
-#[derive(Debug, Clone)]
+/// When heat accumulates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HeatAccumulation {
+    /// Every frame, so the map is already filled when the layer is shown
+    #[default]
+    Always,
+    /// Only while the heat map layer is visible
+    Visible,
+}
+
+/// What makes a spot of the field hot
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HeatSource {
+    /// Agents, by how hard they work there
+    #[default]
+    Intensity,
+    /// Agents, by how long they spend there, busy or not
+    Presence,
+    /// Ends of connections, by how many agents talk to each other there
+    Connections,
+}
+
+/// Number of heat sources
+const HEAT_SOURCES: usize = 3;
+
+impl HeatSource {
+    /// The source after this one, wrapping around
+    pub fn next(self) -> Self {
+        match self {
+            HeatSource::Intensity => HeatSource::Presence,
+            HeatSource::Presence => HeatSource::Connections,
+            HeatSource::Connections => HeatSource::Intensity,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            HeatSource::Intensity => "intensity",
+            HeatSource::Presence => "presence",
+            HeatSource::Connections => "connections",
+        }
+    }
+}
+
+/// How heat is drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HeatStyle {
+    /// Braille in monochrome terminals, background colors otherwise
+    #[default]
+    Auto,
+    /// Background colors
+    Background,
+    /// Braille dots, denser where it is hotter, for terminals without
+    /// background colors
+    Braille,
+}
+
+impl HeatStyle {
+    /// Whether heat is drawn as braille in a terminal with `color_mode`
+    pub fn braille(self, color_mode: ColorMode) -> bool {
+        match self {
+            HeatStyle::Auto => color_mode == ColorMode::Monochrome,
+            HeatStyle::Background => false,
+            HeatStyle::Braille => true,
+        }
+    }
+}
+
+/// Configuration for heatmap behavior; `accumulate`, `style` and `source`
+/// are also the `[heatmap]` section of the config file
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct HeatmapConfig {
     /// Heat decay rate per frame (default: 0.98, lower = faster decay)
+    #[serde(skip)]
     pub decay_rate: f32,
     /// Minimum heat threshold before clearing (default: 0.02)
+    #[serde(skip)]
     pub heat_threshold: f32,
+    /// Whether heat builds up while the layer is hidden
+    pub accumulate: HeatAccumulation,
+    /// Background colors or braille glyphs
+    pub style: HeatStyle,
+    /// Heat shown at startup
+    pub source: HeatSource,
 }
 
 impl Default for HeatmapConfig {
@@ -33,6 +127,9 @@ impl Default for HeatmapConfig {
         Self {
             decay_rate: DEFAULT_DECAY_RATE,
             heat_threshold: DEFAULT_HEAT_THRESHOLD,
+            accumulate: HeatAccumulation::default(),
+            style: HeatStyle::default(),
+            source: HeatSource::default(),
         }
     }
 }
@@ -51,12 +148,27 @@ impl HeatmapConfig {
     }
 }
 
-/// Heat map for visualizing agent activity over time
+/// Part of the recording a heat map is computed from, as timeline positions
+/// (0.0-1.0). `end` is `None` while only the start has been marked.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeatRange {
+    pub start: f32,
+    pub end: Option<f32>,
+}
+
+/// Heat map for visualizing agent activity over time.
+///
+/// Heat builds up for every [`HeatSource`] at once, so switching to another
+/// shows the heat it has gathered so far. Everything but
+/// [`accumulate`](Self::accumulate), [`decay`](Self::decay) and
+/// [`clear`](Self::clear) works on the heat of the current source.
 pub struct HeatMap {
-    grid: Vec<Vec<f32>>,
+    /// One grid per heat source
+    grids: [Vec<Vec<f32>>; HEAT_SOURCES],
     width: usize,
     height: usize,
     config: HeatmapConfig,
+    source: HeatSource,
 }
 
 impl HeatMap {
@@ -71,13 +183,32 @@ impl HeatMap {
         let grid_height = (height / CELL_SIZE).max(1) as usize;
 
         Self {
-            grid: vec![vec![0.0; grid_width]; grid_height],
+            grids: std::array::from_fn(|_| vec![vec![0.0; grid_width]; grid_height]),
             width: grid_width,
             height: grid_height,
+            source: config.source,
             config,
         }
     }
 
+    /// Heat shown
+    pub fn source(&self) -> HeatSource {
+        self.source
+    }
+
+    /// Show the heat of `source`
+    pub fn set_source(&mut self, source: HeatSource) {
+        self.source = source;
+    }
+
+    fn grid(&self) -> &Vec<Vec<f32>> {
+        &self.grids[self.source as usize]
+    }
+
+    fn grid_mut(&mut self) -> &mut Vec<Vec<f32>> {
+        &mut self.grids[self.source as usize]
+    }
+
     /// Get the current configuration
     pub fn config(&self) -> &HeatmapConfig {
         &self.config
@@ -93,48 +224,128 @@ impl HeatMap {
         self.config.decay_rate = decay_rate.clamp(0.9, 0.999);
     }
 
-    /// Resize the heat map grid (preserves config)
+    /// Resize the heat map grid to a field of `width` x `height` cells,
+    /// resampling the heat so it stays where it was on the field
     pub fn resize(&mut self, width: u16, height: u16) {
         let new_width = (width / CELL_SIZE).max(1) as usize;
         let new_height = (height / CELL_SIZE).max(1) as usize;
 
         if new_width != self.width || new_height != self.height {
-            self.grid = vec![vec![0.0; new_width]; new_height];
-            self.width = new_width;
-            self.height = new_height;
+            let old = std::mem::replace(self, Self::with_config(width, height, self.config.clone()));
+            self.source = old.source;
+            let scale = |i: usize, len: usize| i as f32 / (len.max(2) - 1) as f32;
+            for (grid, old_grid) in self.grids.iter_mut().zip(&old.grids) {
+                for (y, row) in grid.iter_mut().enumerate() {
+                    for (x, cell) in row.iter_mut().enumerate() {
+                        let position = Position::new(scale(x, new_width), scale(y, new_height));
+                        *cell = old.grid_cell(&position).map_or(0.0, |(x, y)| old_grid[y][x]);
+                    }
+                }
+            }
         }
     }
 
+    /// Grid cell holding the heat of `position`, nearest cell centre first
+    /// like [`Position::to_terminal`], or `None` off the field
+    fn grid_cell(&self, position: &Position) -> Option<(usize, usize)> {
+        let x = (position.x * (self.width - 1) as f32).round();
+        let y = (position.y * (self.height - 1) as f32).round();
+        let (x, y) = (x.max(0.0) as usize, y.max(0.0) as usize);
+        (x < self.width && y < self.height).then_some((x, y))
+    }
+
     /// Add heat at a position with given intensity
     pub fn add_heat(&mut self, position: &Position, intensity: f32) {
-        let x = (position.x * (self.width - 1) as f32) as usize;
-        let y = (position.y * (self.height - 1) as f32) as usize;
+        self.add_heat_to(self.source, position, intensity);
+    }
 
-        if x < self.width && y < self.height {
-            self.grid[y][x] = (self.grid[y][x] + intensity * ACCUMULATION_RATE).min(1.0);
+    /// Add heat to the grid of `source`
+    fn add_heat_to(&mut self, source: HeatSource, position: &Position, intensity: f32) {
+        if let Some((x, y)) = self.grid_cell(position) {
+            let (width, height) = (self.width, self.height);
+            let grid = &mut self.grids[source as usize];
+            grid[y][x] = (grid[y][x] + intensity * ACCUMULATION_RATE).min(1.0);
 
             // Add some spread to adjacent cells
             let spread = intensity * ACCUMULATION_RATE * 0.3;
             if x > 0 {
-                self.grid[y][x - 1] = (self.grid[y][x - 1] + spread).min(1.0);
+                grid[y][x - 1] = (grid[y][x - 1] + spread).min(1.0);
             }
-            if x < self.width - 1 {
-                self.grid[y][x + 1] = (self.grid[y][x + 1] + spread).min(1.0);
+            if x < width - 1 {
+                grid[y][x + 1] = (grid[y][x + 1] + spread).min(1.0);
             }
             if y > 0 {
-                self.grid[y - 1][x] = (self.grid[y - 1][x] + spread).min(1.0);
+                grid[y - 1][x] = (grid[y - 1][x] + spread).min(1.0);
             }
-            if y < self.height - 1 {
-                self.grid[y + 1][x] = (self.grid[y + 1][x] + spread).min(1.0);
+            if y < height - 1 {
+                grid[y + 1][x] = (grid[y + 1][x] + spread).min(1.0);
             }
         }
     }
 
+    /// Replace the heat with weighted `samples`, scaled so the hottest cell is 1.0.
+    ///
+    /// Used to show activity over a past time range instead of live heat.
+    pub fn load_samples(&mut self, samples: impl IntoIterator<Item = (Position, f32)>) {
+        let (width, height) = (self.width, self.height);
+        for cell in self.grid_mut().iter_mut().flatten() {
+            *cell = 0.0;
+        }
+        for (position, weight) in samples {
+            let clamped = Position::new(position.x.clamp(0.0, 1.0), position.y.clamp(0.0, 1.0));
+            let Some((x, y)) = self.grid_cell(&clamped) else {
+                continue;
+            };
+            let grid = self.grid_mut();
+            grid[y][x] += weight;
+
+            let spread = weight * 0.3;
+            if x > 0 {
+                grid[y][x - 1] += spread;
+            }
+            if x < width - 1 {
+                grid[y][x + 1] += spread;
+            }
+            if y > 0 {
+                grid[y - 1][x] += spread;
+            }
+            if y < height - 1 {
+                grid[y + 1][x] += spread;
+            }
+        }
+
+        let peak = self.grid().iter().flatten().fold(0.0f32, |max, &heat| max.max(heat));
+        if peak > 0.0 {
+            for cell in self.grid_mut().iter_mut().flatten() {
+                *cell /= peak;
+            }
+        }
+    }
+
+    /// Add heat for every source (at each agent's current position, and at
+    /// both ends of each connection), then decay the grids.
+    ///
+    /// Intended to be called once per simulation step.
+    pub fn accumulate(&mut self, field: &Field) {
+        for agent in field.agents.values() {
+            self.add_heat_to(HeatSource::Intensity, &agent.position, agent.intensity);
+            self.add_heat_to(HeatSource::Presence, &agent.position, PRESENCE_HEAT);
+        }
+        for connection in &field.connections {
+            for end in [&connection.from, &connection.to] {
+                if let Some(agent) = field.agents.get(end) {
+                    self.add_heat_to(HeatSource::Connections, &agent.position, connection.opacity);
+                }
+            }
+        }
+        self.decay();
+    }
+
     /// Decay all heat values using configured decay rate
     pub fn decay(&mut self) {
         let decay_rate = self.config.decay_rate;
         let threshold = self.config.heat_threshold;
-        for row in &mut self.grid {
+        for row in self.grids.iter_mut().flatten() {
             for cell in row {
                 *cell *= decay_rate;
                 if *cell < threshold {
@@ -146,19 +357,72 @@ impl HeatMap {
 
     /// Get heat value at a normalized position
     pub fn get_heat(&self, position: &Position) -> f32 {
-        let x = (position.x * (self.width - 1) as f32) as usize;
-        let y = (position.y * (self.height - 1) as f32) as usize;
+        self.grid_cell(position).map_or(0.0, |(x, y)| self.grid()[y][x])
+    }
 
-        if x < self.width && y < self.height {
-            self.grid[y][x]
-        } else {
-            0.0
+    /// Heat grid, one list per row, top to bottom
+    pub fn cells(&self) -> &[Vec<f32>] {
+        self.grid()
+    }
+
+    /// Replace the heat with `cells` (rows of a possibly different size,
+    /// scaled to fit the grid)
+    pub fn load_cells(&mut self, cells: &[Vec<f32>]) {
+        let rows = cells.len();
+        let columns = cells.first().map_or(0, Vec::len);
+        if rows == 0 || columns == 0 {
+            return;
+        }
+        let (width, height) = (self.width, self.height);
+        for (y, row) in self.grid_mut().iter_mut().enumerate() {
+            let source = &cells[y * rows / height];
+            for (x, cell) in row.iter_mut().enumerate() {
+                *cell = source.get(x * columns / width).copied().unwrap_or(0.0);
+            }
         }
     }
 
-    /// Clear all heat
+    /// Heat grid as CSV: one line per row, top to bottom
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        for row in self.grid() {
+            let cells: Vec<String> = row.iter().map(|heat| format!("{:.3}", heat)).collect();
+            csv.push_str(&cells.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// Heat grid as JSON: `{"source", "width", "height", "cells"}` with
+    /// `cells` a list of rows
+    pub fn to_json(&self) -> String {
+        serde_json::json!({
+            "source": self.source.name(),
+            "width": self.width,
+            "height": self.height,
+            "cells": self.grid(),
+        })
+        .to_string()
+    }
+
+    /// Write the heat grid to `path`, as JSON for `.json` and CSV otherwise.
+    pub fn export(&self, path: &Path) -> io::Result<()> {
+        let contents = match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => self.to_json(),
+            Some("png") => {
+                return Err(io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    "PNG export is not supported, use .csv or .json",
+                ))
+            }
+            _ => self.to_csv(),
+        };
+        std::fs::write(path, contents)
+    }
+
+    /// Clear all heat, of every source
     pub fn clear(&mut self) {
-        for row in &mut self.grid {
+        for row in self.grids.iter_mut().flatten() {
             for cell in row {
                 *cell = 0.0;
             }
@@ -169,40 +433,95 @@ impl HeatMap {
 /// Widget for rendering the heat map
 pub struct HeatMapWidget<'a> {
     heatmap: &'a HeatMap,
+    /// Brightness factor applied to the heat colors
+    opacity: f32,
+    /// Draw braille dots instead of background colors
+    braille: bool,
 }
 
 impl<'a> HeatMapWidget<'a> {
     pub fn new(heatmap: &'a HeatMap) -> Self {
-        Self { heatmap }
+        Self {
+            heatmap,
+            opacity: 1.0,
+            braille: false,
+        }
     }
+
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// Draw heat as braille dots, denser where it is hotter
+    pub fn braille(mut self, braille: bool) -> Self {
+        self.braille = braille;
+        self
+    }
+}
+
+/// Heat each dot of a braille cell needs to be raised, by (row, column).
+/// An ordered dither, so dots fill in evenly as the heat rises.
+const BRAILLE_THRESHOLDS: [[f32; 2]; 4] = [
+    [0.5 / 8.0, 4.5 / 8.0],
+    [6.5 / 8.0, 2.5 / 8.0],
+    [1.5 / 8.0, 5.5 / 8.0],
+    [7.5 / 8.0, 3.5 / 8.0],
+];
+
+/// Bit of each braille dot in its character, by (row, column)
+const BRAILLE_DOTS: [[u32; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// Braille character for a cell, from the heat at each of its 2x4 dots
+fn braille_glyph(heat_at: impl Fn(usize, usize) -> f32) -> char {
+    let mut bits = 0;
+    for (row, thresholds) in BRAILLE_THRESHOLDS.iter().enumerate() {
+        for (col, threshold) in thresholds.iter().enumerate() {
+            if heat_at(row, col) > *threshold {
+                bits |= BRAILLE_DOTS[row][col];
+            }
+        }
+    }
+    char::from_u32(0x2800 + bits).unwrap_or(' ')
 }
 
 impl Widget for HeatMapWidget<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let inner_x = area.x + 1;
-        let inner_y = area.y + 1;
-        let inner_width = area.width.saturating_sub(2);
-        let inner_height = area.height.saturating_sub(2);
-
-        for screen_y in 0..inner_height {
-            for screen_x in 0..inner_width {
-                let norm_x = screen_x as f32 / inner_width as f32;
-                let norm_y = screen_y as f32 / inner_height as f32;
-
-                let pos = Position::new(norm_x, norm_y);
+        // Sampled through the field transform, so heat lines up with the
+        // agents and trails drawn over it
+        let transform = FieldTransform::new(area);
+        let inner = transform.inner();
+
+        for screen_y in 0..inner.height {
+            for screen_x in 0..inner.width {
+                let pos = transform.position(screen_x as f32, screen_y as f32);
                 let heat = self.heatmap.get_heat(&pos);
 
                 if heat > 0.05 {
-                    let color = heat_to_color(heat);
-                    let style = Style::default().bg(color);
+                    let color = dim_color(heat_to_color(heat), self.opacity);
 
-                    let x = inner_x + screen_x;
-                    let y = inner_y + screen_y;
+                    let x = inner.x + screen_x;
+                    let y = inner.y + screen_y;
 
-                    // Only modify background if cell is otherwise empty
+                    // Only draw into cells that are otherwise empty
                     let cell = &mut buf[(x, y)];
-                    if cell.symbol() == " " {
-                        cell.set_style(style);
+                    if cell.symbol() != " " {
+                        continue;
+                    }
+                    if !self.braille {
+                        cell.set_style(Style::default().bg(color));
+                        continue;
+                    }
+                    let glyph = braille_glyph(|row, col| {
+                        // Dots spread around the cell's centre
+                        let pos = transform.position(
+                            screen_x as f32 + (col as f32 + 0.5) / 2.0 - 0.5,
+                            screen_y as f32 + (row as f32 + 0.5) / 4.0 - 0.5,
+                        );
+                        self.heatmap.get_heat(&pos)
+                    });
+                    if glyph != '\u{2800}' {
+                        cell.set_char(glyph).set_style(Style::default().fg(color));
                     }
                 }
             }
@@ -257,3 +576,133 @@ fn heat_to_color(heat: f32) -> Color {
 pub fn render_heatmap(heatmap: &HeatMap, area: Rect, buf: &mut Buffer) {
     HeatMapWidget::new(heatmap).render(area, buf);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_samples_scales_to_peak() {
+        let mut heatmap = HeatMap::new(40, 20);
+        heatmap.add_heat(&Position::new(0.9, 0.9), 1.0);
+
+        let left = Position::new(0.1, 0.5);
+        let right = Position::new(0.6, 0.5);
+        heatmap.load_samples([(left.clone(), 4.0), (right.clone(), 1.0)]);
+
+        assert_eq!(heatmap.get_heat(&left), 1.0);
+        assert!((heatmap.get_heat(&right) - 0.25).abs() < 1e-6);
+        // Previous heat is replaced
+        assert_eq!(heatmap.get_heat(&Position::new(0.9, 0.9)), 0.0);
+    }
+
+    #[test]
+    fn test_each_source_heats_its_own_grid() {
+        use hive_core::event::{AgentStatus, AgentUpdate, Connection, HiveEvent};
+
+        let mut field = Field::new();
+        for (agent_id, focus, intensity) in [("atlas", "database", 1.0), ("sage", "frontend", 0.0), ("idle", "testing", 0.0)] {
+            field.process_event(&HiveEvent::AgentUpdate(AgentUpdate {
+                agent_id: agent_id.into(),
+                status: AgentStatus::Active,
+                focus: vec![focus.into()],
+                intensity,
+                message: String::new(),
+                timestamp: 0,
+            }));
+        }
+        field.process_event(&HiveEvent::Connection(Connection {
+            from: "atlas".into(),
+            to: "sage".into(),
+            label: String::new(),
+            timestamp: 0,
+            ttl_ms: None,
+        }));
+        for agent in field.agents.values_mut() {
+            agent.position = agent.target_position.clone();
+        }
+        for connection in &mut field.connections {
+            connection.opacity = 1.0;
+        }
+
+        let mut heatmap = HeatMap::new(80, 40);
+        for _ in 0..10 {
+            heatmap.accumulate(&field);
+        }
+        let heat_of = |heatmap: &HeatMap, agent_id: &str| heatmap.get_heat(&field.agents[agent_id].position);
+
+        assert_eq!(heatmap.source(), HeatSource::Intensity);
+        assert!(heat_of(&heatmap, "atlas") > 0.0);
+        assert_eq!(heat_of(&heatmap, "sage"), 0.0);
+
+        heatmap.set_source(heatmap.source().next());
+        assert_eq!(heatmap.source(), HeatSource::Presence);
+        assert!(["atlas", "sage", "idle"].iter().all(|id| heat_of(&heatmap, id) > 0.0));
+
+        heatmap.set_source(heatmap.source().next());
+        assert_eq!(heatmap.source(), HeatSource::Connections);
+        assert!(heat_of(&heatmap, "atlas") > 0.0 && heat_of(&heatmap, "sage") > 0.0);
+        assert_eq!(heat_of(&heatmap, "idle"), 0.0);
+
+        assert_eq!(heatmap.source().next(), HeatSource::Intensity);
+        heatmap.clear();
+        assert_eq!(heat_of(&heatmap, "atlas"), 0.0);
+    }
+
+    #[test]
+    fn test_export_formats() {
+        let mut heatmap = HeatMap::new(6, 4);
+        heatmap.load_samples([(Position::new(0.0, 0.0), 1.0)]);
+
+        assert_eq!(heatmap.to_csv(), "1.000,0.300,0.000\n0.300,0.000,0.000\n");
+
+        let json: serde_json::Value = serde_json::from_str(&heatmap.to_json()).unwrap();
+        assert_eq!(json["source"], "intensity");
+        assert_eq!(json["width"], 3);
+        assert_eq!(json["height"], 2);
+        assert_eq!(json["cells"][0][0], 1.0);
+
+        let err = heatmap.export(Path::new("heat.png")).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Unsupported);
+    }
+
+    #[test]
+    fn test_heat_stays_under_agents_across_resizes() {
+        let position = Position::new(0.97, 0.12);
+        let mut heatmap = HeatMap::new(1, 1);
+        for area in [Rect::new(0, 0, 80, 24), Rect::new(0, 0, 133, 41), Rect::new(5, 2, 47, 17)] {
+            let transform = FieldTransform::new(area);
+            heatmap.resize(transform.inner().width, transform.inner().height);
+            if heatmap.get_heat(&position) == 0.0 {
+                heatmap.add_heat(&position, 20.0);
+            }
+
+            let mut buf = Buffer::empty(area);
+            HeatMapWidget::new(&heatmap).render(area, &mut buf);
+            let (x, y) = transform.cell(&position).unwrap();
+            assert_ne!(buf[(x, y)].bg, Color::Reset, "no heat under the agent in {:?}", area);
+        }
+    }
+
+    #[test]
+    fn test_braille_density_follows_heat() {
+        assert_eq!(braille_glyph(|_, _| 0.0), '\u{2800}');
+        assert_eq!(braille_glyph(|_, _| 1.0), '⣿');
+        let half = braille_glyph(|_, _| 0.5) as u32 - 0x2800;
+        assert_eq!(half.count_ones(), 4);
+
+        assert!(HeatStyle::Auto.braille(ColorMode::Monochrome));
+        assert!(!HeatStyle::Auto.braille(ColorMode::Basic16));
+        assert!(HeatStyle::Braille.braille(ColorMode::TrueColor));
+
+        // Drawn as glyphs into empty cells only
+        let mut heatmap = HeatMap::new(20, 10);
+        heatmap.load_samples([(Position::new(0.5, 0.5), 1.0)]);
+        let area = Rect::new(0, 0, 12, 8);
+        let mut buf = Buffer::empty(area);
+        HeatMapWidget::new(&heatmap).braille(true).render(area, &mut buf);
+        let hot = &buf[(6, 4)];
+        assert_ne!(hot.symbol(), " ");
+        assert_eq!(hot.bg, Color::Reset);
+    }
+}
@@ -0,0 +1,183 @@
+//! Hit-test map for mouse interaction.
+//!
+//! While a frame is drawn, the widgets record which entity (agent, landmark
+//! label, connection line) each cell shows. Mouse events look the cell up
+//! in the map of the last complete frame, so hit detection matches what is
+//! on screen whatever the layout. Later writes win, which follows the
+//! z-order the layers are drawn in.
+//!
+//! [`HitBuffers`] keeps two maps: the back one is filled during drawing and
+//! becomes the front one, used for lookups, once the frame is done.
+
+use std::cell::{RefCell, RefMut};
+
+use ratatui::layout::Rect;
+
+use hive_core::event::{AgentId, LandmarkId};
+
+/// Something on screen the mouse can point at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HitTarget {
+    Agent(AgentId),
+    Landmark(LandmarkId),
+    Connection { from: AgentId, to: AgentId },
+}
+
+/// Cell → entity map for one frame
+#[derive(Debug, Clone, Default)]
+pub struct HitMap {
+    area: Rect,
+    targets: Vec<HitTarget>,
+    /// Per cell, 1 + index into `targets`, or 0 for nothing
+    cells: Vec<u32>,
+}
+
+impl HitMap {
+    /// Clear the map for a frame covering `area`.
+    pub fn reset(&mut self, area: Rect) {
+        self.area = area;
+        self.targets.clear();
+        self.cells.clear();
+        self.cells.resize(area.width as usize * area.height as usize, 0);
+    }
+
+    /// Register `target`, returning a handle for [`mark`](Self::mark).
+    pub fn add(&mut self, target: HitTarget) -> u32 {
+        self.targets.push(target);
+        self.targets.len() as u32
+    }
+
+    /// Record that the cell at (x, y) shows the target of `handle`.
+    pub fn mark(&mut self, x: u16, y: u16, handle: u32) {
+        if let Some(idx) = self.index(x, y) {
+            self.cells[idx] = handle;
+        }
+    }
+
+    /// Forget the cells in `area`, for something drawn over them (a panel
+    /// or overlay) that does not take mouse input itself.
+    pub fn clear_area(&mut self, area: Rect) {
+        let area = area.intersection(self.area);
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                self.mark(x, y, 0);
+            }
+        }
+    }
+
+    /// What the cell at (x, y) shows, if anything
+    pub fn target_at(&self, x: u16, y: u16) -> Option<&HitTarget> {
+        let handle = *self.cells.get(self.index(x, y)?)?;
+        self.targets.get((handle as usize).checked_sub(1)?)
+    }
+
+    fn index(&self, x: u16, y: u16) -> Option<usize> {
+        let inside = x >= self.area.x
+            && y >= self.area.y
+            && x < self.area.x + self.area.width
+            && y < self.area.y + self.area.height;
+        inside.then(|| (y - self.area.y) as usize * self.area.width as usize + (x - self.area.x) as usize)
+    }
+}
+
+/// Front and back hit maps
+#[derive(Debug, Default)]
+pub struct HitBuffers {
+    front: HitMap,
+    back: RefCell<HitMap>,
+}
+
+impl HitBuffers {
+    /// Start filling the back map for a frame covering `area`; returns it
+    /// for the widgets to write into.
+    pub fn begin(&self, area: Rect) -> &RefCell<HitMap> {
+        self.back.borrow_mut().reset(area);
+        &self.back
+    }
+
+    /// The back map being filled
+    pub fn back(&self) -> RefMut<'_, HitMap> {
+        self.back.borrow_mut()
+    }
+
+    /// The frame is done: make the back map the one used for lookups.
+    pub fn swap(&mut self) {
+        std::mem::swap(&mut self.front, self.back.get_mut());
+    }
+
+    /// Clear both maps (e.g. when nothing hit-testable is on screen)
+    pub fn clear(&mut self) {
+        self.front.reset(Rect::default());
+        self.back.get_mut().reset(Rect::default());
+    }
+
+    /// What the cell at (x, y) showed in the last complete frame
+    pub fn target_at(&self, x: u16, y: u16) -> Option<&HitTarget> {
+        self.front.target_at(x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_later_marks_win_and_swap_publishes() {
+        let mut hits = HitBuffers::default();
+        {
+            let mut map = hits.begin(Rect::new(2, 1, 10, 5)).borrow_mut();
+            let line = map.add(HitTarget::Connection {
+                from: "a".into(),
+                to: "b".into(),
+            });
+            let agent = map.add(HitTarget::Agent("a".into()));
+            map.mark(3, 2, line);
+            map.mark(4, 2, line);
+            map.mark(4, 2, agent);
+            // Outside the area
+            map.mark(0, 0, agent);
+        }
+
+        // Not visible until the frame is done
+        assert_eq!(hits.target_at(4, 2), None);
+        hits.swap();
+
+        assert_eq!(hits.target_at(4, 2), Some(&HitTarget::Agent("a".into())));
+        assert!(matches!(hits.target_at(3, 2), Some(HitTarget::Connection { .. })));
+        assert_eq!(hits.target_at(5, 2), None);
+        assert_eq!(hits.target_at(0, 0), None);
+    }
+
+    #[test]
+    fn test_agents_record_glyph_and_label() {
+        use hive_core::positioning::Position;
+        use hive_core::state::Agent;
+        use ratatui::{buffer::Buffer, widgets::Widget};
+
+        use crate::render::agent::AgentsWidget;
+
+        let mut agent = Agent::new("atlas".into(), 0);
+        agent.position = Position { x: 0.5, y: 0.5 };
+        let area = Rect::new(0, 0, 30, 12);
+        let mut buf = Buffer::empty(area);
+
+        let mut hits = HitBuffers::default();
+        AgentsWidget::new(vec![&agent])
+            .hits(Some(hits.begin(area)))
+            .render(area, &mut buf);
+        hits.swap();
+
+        let glyph = buf
+            .content()
+            .iter()
+            .position(|cell| cell.symbol() != " ")
+            .map(|i| buf.pos_of(i))
+            .unwrap();
+        let atlas = Some(HitTarget::Agent("atlas".into()));
+        assert_eq!(hits.target_at(glyph.0, glyph.1).cloned(), atlas);
+        // The label below is part of the agent
+        assert_eq!(hits.target_at(glyph.0, glyph.1 + 1).cloned(), atlas);
+        assert_eq!(hits.target_at(glyph.0, glyph.1 + 2), None);
+        assert_eq!(hits.target_at(0, 0), None);
+    }
+}
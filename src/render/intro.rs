@@ -0,0 +1,181 @@
+//! Intro splash shown at startup with `--intro`.
+//!
+//! A banner with the logo, what Hive detected about the terminal and where
+//! the events come from, for demos and recordings. It goes away on the first
+//! key press, or on the first event once it has been up for
+//! [`INTRO_HOLD`], so a demo starting right away does not hide it at once.
+
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::Widget,
+};
+
+use super::ColorMode;
+
+/// Least time the splash stays up before events dismiss it
+pub const INTRO_HOLD: Duration = Duration::from_secs(2);
+
+/// "hive" in figlet's standard font
+const LOGO: [&str; 5] = [
+    r" _     _           ",
+    r"| |__ (_)_   _____ ",
+    r"| '_ \| \ \ / / _ \",
+    r"| | | | |\ V /  __/",
+    r"|_| |_|_| \_/ \___|",
+];
+
+/// What the splash reports
+#[derive(Debug, Clone)]
+pub struct Intro {
+    /// Where the events come from, e.g. "demo (Outage)" or a file name
+    source: String,
+    color_mode: ColorMode,
+    unicode: bool,
+    mouse: bool,
+    opened: Instant,
+}
+
+impl Intro {
+    pub fn new(source: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+            color_mode: ColorMode::default(),
+            unicode: true,
+            mouse: false,
+            opened: Instant::now(),
+        }
+    }
+
+    /// Capabilities detected once the terminal is set up
+    pub fn capabilities(mut self, color_mode: ColorMode, unicode: bool, mouse: bool) -> Self {
+        self.color_mode = color_mode;
+        self.unicode = unicode;
+        self.mouse = mouse;
+        self
+    }
+
+    /// Whether an event arriving at `now` dismisses the splash
+    pub fn dismissed_by_event(&self, now: Instant) -> bool {
+        now.duration_since(self.opened) >= INTRO_HOLD
+    }
+}
+
+/// Centered banner drawing an [`Intro`]
+pub struct IntroWidget<'a> {
+    intro: &'a Intro,
+}
+
+impl<'a> IntroWidget<'a> {
+    pub fn new(intro: &'a Intro) -> Self {
+        Self { intro }
+    }
+}
+
+impl Widget for IntroWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let intro = self.intro;
+        let colors = match intro.color_mode {
+            ColorMode::TrueColor => "truecolor",
+            ColorMode::Color256 => "256 colors",
+            ColorMode::Basic16 => "16 colors",
+            ColorMode::Monochrome => "monochrome",
+        };
+        let rows = [
+            ("Colors", colors.to_string()),
+            ("Symbols", if intro.unicode { "unicode" } else { "ASCII" }.to_string()),
+            ("Mouse", if intro.mouse { "hover and click" } else { "keyboard only" }.to_string()),
+            ("Source", intro.source.clone()),
+        ];
+
+        let width = 48.min(area.width.saturating_sub(2));
+        let height = (LOGO.len() + rows.len() + 6) as u16;
+        if width < 24 || height > area.height {
+            return;
+        }
+        let area = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        );
+
+        let border = Style::default().fg(Color::Rgb(100, 200, 150));
+        let background = Style::default().bg(Color::Rgb(25, 25, 35));
+        for y in area.y..area.y + area.height {
+            for x in area.x..area.x + area.width {
+                let ch = match (
+                    x == area.x,
+                    x == area.x + area.width - 1,
+                    y == area.y,
+                    y == area.y + area.height - 1,
+                ) {
+                    (true, _, true, _) => '╭',
+                    (_, true, true, _) => '╮',
+                    (true, _, _, true) => '╰',
+                    (_, true, _, true) => '╯',
+                    (true, _, _, _) | (_, true, _, _) => '│',
+                    (_, _, true, _) | (_, _, _, true) => '─',
+                    _ => ' ',
+                };
+                buf[(x, y)].set_char(ch).set_style(background.patch(border));
+            }
+        }
+
+        let inner_width = area.width - 4;
+        let logo = Style::default().fg(Color::Rgb(255, 200, 80)).add_modifier(Modifier::BOLD);
+        for (row, line) in LOGO.iter().enumerate() {
+            let x = area.x + (area.width.saturating_sub(line.chars().count() as u16)) / 2;
+            draw_text(buf, x.max(area.x + 2), area.y + 1 + row as u16, inner_width, line, logo);
+        }
+
+        let label = Style::default().fg(Color::Rgb(120, 120, 130));
+        let value = Style::default().fg(Color::Rgb(220, 220, 230));
+        let x = area.x + 2;
+        let top = area.y + 2 + LOGO.len() as u16;
+        for (row, (name, text)) in rows.iter().enumerate() {
+            let y = top + row as u16;
+            draw_text(buf, x, y, inner_width, &format!("{:<9}", name), label);
+            draw_text(buf, x + 9, y, inner_width.saturating_sub(9), text, value);
+        }
+
+        let hint = "press any key to start";
+        let hint_x = area.x + (area.width.saturating_sub(hint.len() as u16)) / 2;
+        draw_text(buf, hint_x, area.y + area.height - 2, inner_width, hint, label);
+    }
+}
+
+/// Write `text` at (x, y), truncated to `max_width` characters
+fn draw_text(buf: &mut Buffer, x: u16, y: u16, max_width: u16, text: &str, style: Style) {
+    for (cx, ch) in (x..).zip(text.chars().take(max_width as usize)) {
+        buf[(cx, y)].set_char(ch).set_style(style);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_banner_shows_capabilities_and_source() {
+        let intro = Intro::new("demo (Outage)").capabilities(ColorMode::Color256, false, true);
+        let area = Rect::new(0, 0, 80, 24);
+        let mut buf = Buffer::empty(area);
+        IntroWidget::new(&intro).render(area, &mut buf);
+        let text = (0..area.height)
+            .map(|y| (0..area.width).map(|x| buf[(x, y)].symbol()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(text.contains(r"|_| |_|_| \_/ \___|"));
+        assert!(text.contains("Colors   256 colors"));
+        assert!(text.contains("Symbols  ASCII"));
+        assert!(text.contains("Source   demo (Outage)"));
+
+        // Events only dismiss it after the hold
+        assert!(!intro.dismissed_by_event(intro.opened));
+        assert!(intro.dismissed_by_event(intro.opened + INTRO_HOLD));
+    }
+}
@@ -0,0 +1,321 @@
+//! In-app landmark editor.
+//!
+//! Lists the field's landmarks with a selection cursor. Keys add, rename,
+//! move and delete landmarks; the editor only tracks what is being typed or
+//! moved and hands each change back as an [`EditorAction`] for the app to
+//! record in its [`LandmarkEdits`](crate::landmarks::LandmarkEdits).
+
+use crossterm::event::KeyCode;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::Widget,
+};
+
+use hive_core::event::LandmarkId;
+use hive_core::state::field::StoredLandmark;
+
+/// Distance (in normalized field units) one arrow key press moves a landmark
+pub const NUDGE_STEP: f32 = 0.02;
+
+/// What the editor is doing
+#[derive(Debug, Clone, PartialEq)]
+pub enum EditorInput {
+    /// Moving the selection
+    Browse,
+    /// Typing a label: for the selected landmark, or a new one if `target` is None
+    Naming {
+        target: Option<LandmarkId>,
+        text: String,
+    },
+    /// Arrow keys move the selected landmark
+    Moving,
+}
+
+/// A change requested in the editor
+#[derive(Debug, Clone, PartialEq)]
+pub enum EditorAction {
+    None,
+    Close,
+    Add { label: String },
+    Rename { id: LandmarkId, label: String },
+    Nudge { id: LandmarkId, dx: f32, dy: f32 },
+    Delete { id: LandmarkId },
+}
+
+/// Landmark editor state
+#[derive(Debug, Clone)]
+pub struct LandmarkEditor {
+    selected: usize,
+    input: EditorInput,
+}
+
+impl Default for LandmarkEditor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LandmarkEditor {
+    pub fn new() -> Self {
+        Self {
+            selected: 0,
+            input: EditorInput::Browse,
+        }
+    }
+
+    pub fn input(&self) -> &EditorInput {
+        &self.input
+    }
+
+    /// Index of the selected row
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Handle a key press; `rows` are the landmark IDs in listed order.
+    pub fn handle_key(&mut self, code: KeyCode, rows: &[LandmarkId]) -> EditorAction {
+        self.selected = self.selected.min(rows.len().saturating_sub(1));
+        let current = rows.get(self.selected).cloned();
+
+        match self.input {
+            EditorInput::Browse => match code {
+                KeyCode::Esc | KeyCode::Char('q') => return EditorAction::Close,
+                KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+                KeyCode::Down | KeyCode::Char('j') if self.selected + 1 < rows.len() => {
+                    self.selected += 1;
+                }
+                KeyCode::Char('a') => {
+                    self.input = EditorInput::Naming {
+                        target: None,
+                        text: String::new(),
+                    };
+                }
+                KeyCode::Char('r') | KeyCode::Enter if current.is_some() => {
+                    self.input = EditorInput::Naming {
+                        target: current,
+                        text: String::new(),
+                    };
+                }
+                KeyCode::Char('m') if current.is_some() => self.input = EditorInput::Moving,
+                KeyCode::Char('d') | KeyCode::Delete => {
+                    if let Some(id) = current {
+                        return EditorAction::Delete { id };
+                    }
+                }
+                _ => {}
+            },
+
+            EditorInput::Naming {
+                ref target,
+                ref mut text,
+            } => match code {
+                KeyCode::Esc => self.input = EditorInput::Browse,
+                KeyCode::Enter => {
+                    let label = text.trim().to_string();
+                    let target = target.clone();
+                    self.input = EditorInput::Browse;
+                    if label.is_empty() {
+                        return EditorAction::None;
+                    }
+                    return match target {
+                        Some(id) => EditorAction::Rename { id, label },
+                        None => EditorAction::Add { label },
+                    };
+                }
+                KeyCode::Backspace => {
+                    text.pop();
+                }
+                KeyCode::Char(c) => text.push(c),
+                _ => {}
+            },
+
+            EditorInput::Moving => {
+                let Some(id) = current else {
+                    self.input = EditorInput::Browse;
+                    return EditorAction::None;
+                };
+                let (dx, dy) = match code {
+                    KeyCode::Left | KeyCode::Char('h') => (-NUDGE_STEP, 0.0),
+                    KeyCode::Right | KeyCode::Char('l') => (NUDGE_STEP, 0.0),
+                    KeyCode::Up | KeyCode::Char('k') => (0.0, -NUDGE_STEP),
+                    KeyCode::Down | KeyCode::Char('j') => (0.0, NUDGE_STEP),
+                    KeyCode::Enter | KeyCode::Esc | KeyCode::Char('m') => {
+                        self.input = EditorInput::Browse;
+                        return EditorAction::None;
+                    }
+                    _ => return EditorAction::None,
+                };
+                return EditorAction::Nudge { id, dx, dy };
+            }
+        }
+        EditorAction::None
+    }
+
+    /// Select the row of `id` (e.g. a landmark that was just added)
+    pub fn select(&mut self, id: &str, rows: &[LandmarkId]) {
+        if let Some(idx) = rows.iter().position(|row| row == id) {
+            self.selected = idx;
+        }
+    }
+}
+
+/// Widget drawing the [`LandmarkEditor`] as a bordered list
+pub struct LandmarkEditorWidget<'a> {
+    editor: &'a LandmarkEditor,
+    rows: &'a [&'a StoredLandmark],
+    /// Where edits are saved, shown under the title
+    saved_to: Option<&'a str>,
+}
+
+impl<'a> LandmarkEditorWidget<'a> {
+    pub fn new(editor: &'a LandmarkEditor, rows: &'a [&'a StoredLandmark]) -> Self {
+        Self {
+            editor,
+            rows,
+            saved_to: None,
+        }
+    }
+
+    pub fn saved_to(mut self, path: Option<&'a str>) -> Self {
+        self.saved_to = path;
+        self
+    }
+}
+
+impl Widget for LandmarkEditorWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.width < 16 || area.height < 6 {
+            return;
+        }
+
+        let border = Style::default().fg(Color::Rgb(100, 200, 150));
+        let background = Style::default().bg(Color::Rgb(35, 35, 45));
+        for y in area.y..area.y + area.height {
+            for x in area.x..area.x + area.width {
+                let ch = match (
+                    x == area.x,
+                    x == area.x + area.width - 1,
+                    y == area.y,
+                    y == area.y + area.height - 1,
+                ) {
+                    (true, _, true, _) => '╭',
+                    (_, true, true, _) => '╮',
+                    (true, _, _, true) => '╰',
+                    (_, true, _, true) => '╯',
+                    (true, _, _, _) | (_, true, _, _) => '│',
+                    (_, _, true, _) | (_, _, _, true) => '─',
+                    _ => ' ',
+                };
+                buf[(x, y)].set_char(ch).set_style(background.patch(border));
+            }
+        }
+
+        let inner_width = area.width.saturating_sub(4) as usize;
+        let subtle = Style::default().fg(Color::Rgb(120, 120, 130));
+        draw_text(buf, area.x + 2, area.y, inner_width, " Landmarks ", border.add_modifier(Modifier::BOLD));
+        let saved = match self.saved_to {
+            Some(path) => format!("saved to {}", path),
+            None => "not saved (no events file)".to_string(),
+        };
+        draw_text(buf, area.x + 2, area.y + 1, inner_width, &saved, subtle);
+
+        // Rows, plus one for a landmark being added
+        let adding = matches!(self.editor.input, EditorInput::Naming { target: None, .. });
+        let rows = area.height.saturating_sub(5) as usize;
+        let first = self.editor.selected.saturating_sub(rows.saturating_sub(1));
+        if self.rows.is_empty() && !adding {
+            draw_text(buf, area.x + 2, area.y + 2, inner_width, "(no landmarks)", subtle);
+        }
+
+        let mut y = area.y + 2;
+        for (idx, landmark) in self.rows.iter().enumerate().skip(first).take(rows) {
+            let selected = idx == self.editor.selected && !adding;
+            let mut style = Style::default().fg(Color::Rgb(220, 220, 230));
+            if selected {
+                style = style.bg(Color::Rgb(60, 60, 80)).add_modifier(Modifier::BOLD);
+            }
+            let label = match self.editor.input {
+                EditorInput::Naming {
+                    target: Some(ref id),
+                    ref text,
+                } if *id == landmark.id => format!("{}_", text),
+                EditorInput::Moving if selected => format!(
+                    "{}  ({:.2}, {:.2}) ✥",
+                    landmark.label, landmark.position.x, landmark.position.y
+                ),
+                _ => format!("{}  ({:.2}, {:.2})", landmark.label, landmark.position.x, landmark.position.y),
+            };
+            draw_text(buf, area.x + 2, y, inner_width, &label, style);
+            y += 1;
+        }
+        if let EditorInput::Naming { target: None, ref text } = self.editor.input {
+            let style = Style::default()
+                .fg(Color::Rgb(220, 220, 230))
+                .bg(Color::Rgb(60, 60, 80));
+            draw_text(buf, area.x + 2, y.min(area.y + area.height - 3), inner_width, &format!("+ {}_", text), style);
+        }
+
+        let hint = match self.editor.input {
+            EditorInput::Browse => "a add  r rename  m move  d delete  Esc close",
+            EditorInput::Naming { .. } => "type a label  Enter save  Esc cancel",
+            EditorInput::Moving => "←↑↓→ move  Enter done",
+        };
+        draw_text(buf, area.x + 2, area.y + area.height - 2, inner_width, hint, subtle);
+    }
+}
+
+/// Write `text` at (x, y), truncated to `max_width` characters
+fn draw_text(buf: &mut Buffer, x: u16, y: u16, max_width: usize, text: &str, style: Style) {
+    for (cx, ch) in (x..).zip(text.chars().take(max_width)) {
+        buf[(cx, y)].set_char(ch).set_style(style);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keys_produce_edits() {
+        let rows = vec!["auth".to_string(), "data".to_string()];
+        let mut editor = LandmarkEditor::new();
+
+        editor.handle_key(KeyCode::Down, &rows);
+        editor.handle_key(KeyCode::Char('r'), &rows);
+        for c in "DB".chars() {
+            editor.handle_key(KeyCode::Char(c), &rows);
+        }
+        assert_eq!(
+            editor.handle_key(KeyCode::Enter, &rows),
+            EditorAction::Rename {
+                id: "data".to_string(),
+                label: "DB".to_string()
+            }
+        );
+
+        editor.handle_key(KeyCode::Char('m'), &rows);
+        assert_eq!(
+            editor.handle_key(KeyCode::Left, &rows),
+            EditorAction::Nudge {
+                id: "data".to_string(),
+                dx: -NUDGE_STEP,
+                dy: 0.0
+            }
+        );
+        editor.handle_key(KeyCode::Enter, &rows);
+
+        editor.handle_key(KeyCode::Char('a'), &rows);
+        editor.handle_key(KeyCode::Char('x'), &rows);
+        assert_eq!(
+            editor.handle_key(KeyCode::Enter, &rows),
+            EditorAction::Add { label: "x".to_string() }
+        );
+        assert_eq!(
+            editor.handle_key(KeyCode::Char('d'), &rows),
+            EditorAction::Delete { id: "data".to_string() }
+        );
+        assert_eq!(editor.handle_key(KeyCode::Esc, &rows), EditorAction::Close);
+    }
+}
@@ -5,17 +5,21 @@
 //! visual hierarchy with proper element visibility.
 
 use ratatui::{buffer::Buffer, layout::Rect};
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::time::Duration;
 
-use crate::event::LandmarkId;
-use crate::positioning::Position;
-use crate::state::field::{ActiveConnection, StoredLandmark};
-use crate::state::{Agent, History};
+use serde::{Deserialize, Serialize};
+
+use hive_core::event::LandmarkId;
+use hive_core::positioning::Position;
+use hive_core::state::field::{ActiveConnection, StoredLandmark};
+use hive_core::state::{Agent, Cluster, Convergence, History};
 
 use super::{
     agent::AgentsWidget, connections::ConnectionsWidget, display_mode::DisplayMode,
-    field::FieldWidget, heatmap::HeatMapWidget, trails::TrailsWidget, ui::HelpOverlay,
-    ui::StatusBar, ui::TimelineWidget, HeatMap,
+    field::{ClusterLabelsWidget, FieldBorder, FieldWidget}, flashes::FlashesWidget, heatmap::HeatMapWidget, swarm::SwarmHaloWidget, trails::TrailsWidget, ui::HelpOverlay,
+    ui::ReplayClock, ui::StatusBar, ui::StatusItem, ui::TimelineWidget, FrameProfile, HeatMap, HeatRange, HitMap,
 };
 
 /// Render layers in strict z-order.
@@ -34,8 +38,11 @@ use super::{
 /// 9. Labels - agent name labels
 /// 10. StatusIndicators - status symbols above agents
 /// 11. UI - status bar and chrome
-/// 12. Overlays - tooltips, help panels, modals
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// 12. Panels - user slot for the app's floating panels (see [`Panel`])
+/// 13. Overlays - tooltips, help panels, modals
+/// 14. PinnedPanels - user slot for panels pinned above the overlays
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
 #[repr(u8)]
 pub enum RenderLayer {
     /// Base background layer (field border, zone fills)
@@ -59,14 +66,22 @@ pub enum RenderLayer {
     /// Status indicator symbols
     StatusIndicators = 9,
     /// UI chrome (status bar, etc.)
+    #[serde(rename = "ui")]
     UI = 10,
+    /// User slot: floating panels, drawn by the app
+    Panels = 11,
     /// Overlays (help, tooltips, modals)
-    Overlays = 11,
+    Overlays = 12,
+    /// User slot: panels configured to stay above the overlays
+    PinnedPanels = 13,
 }
 
+/// Number of render layers
+pub const LAYER_COUNT: usize = 14;
+
 impl RenderLayer {
     /// Get all layers in render order (background to foreground).
-    pub const fn all() -> [RenderLayer; 12] {
+    pub const fn all() -> [RenderLayer; LAYER_COUNT] {
         [
             RenderLayer::Background,
             RenderLayer::Zones,
@@ -79,10 +94,37 @@ impl RenderLayer {
             RenderLayer::Labels,
             RenderLayer::StatusIndicators,
             RenderLayer::UI,
+            RenderLayer::Panels,
             RenderLayer::Overlays,
+            RenderLayer::PinnedPanels,
         ]
     }
 
+    /// Name used for the layer in the config file and commands
+    pub const fn name(self) -> &'static str {
+        match self {
+            RenderLayer::Background => "background",
+            RenderLayer::Zones => "zones",
+            RenderLayer::Grid => "grid",
+            RenderLayer::Heatmap => "heatmap",
+            RenderLayer::Trails => "trails",
+            RenderLayer::Connections => "connections",
+            RenderLayer::Flashes => "flashes",
+            RenderLayer::Agents => "agents",
+            RenderLayer::Labels => "labels",
+            RenderLayer::StatusIndicators => "status_indicators",
+            RenderLayer::UI => "ui",
+            RenderLayer::Panels => "panels",
+            RenderLayer::Overlays => "overlays",
+            RenderLayer::PinnedPanels => "pinned_panels",
+        }
+    }
+
+    /// Look a layer up by [`name`](Self::name).
+    pub fn from_name(name: &str) -> Option<RenderLayer> {
+        Self::all().into_iter().find(|layer| layer.name() == name)
+    }
+
     /// Get the layer's z-index value.
     pub const fn z_index(self) -> u8 {
         self as u8
@@ -94,10 +136,12 @@ impl RenderLayer {
     }
 }
 
-/// Configuration for which layers are enabled.
+/// Configuration for which layers are enabled, and how bright they are drawn.
 #[derive(Debug, Clone)]
 pub struct LayerVisibility {
-    enabled: [bool; 12],
+    enabled: [bool; LAYER_COUNT],
+    /// Brightness factor (0.0-1.0) applied to each layer's colors
+    opacity: [f32; LAYER_COUNT],
 }
 
 impl Default for LayerVisibility {
@@ -110,7 +154,8 @@ impl LayerVisibility {
     /// Create new visibility config with all layers enabled by default.
     pub fn new() -> Self {
         Self {
-            enabled: [true; 12],
+            enabled: [true; LAYER_COUNT],
+            opacity: [1.0; LAYER_COUNT],
         }
     }
 
@@ -129,6 +174,62 @@ impl LayerVisibility {
         let idx = layer.z_index() as usize;
         self.enabled[idx] = !self.enabled[idx];
     }
+
+    /// Brightness factor for a layer (1.0 is full brightness).
+    pub fn opacity(&self, layer: RenderLayer) -> f32 {
+        self.opacity[layer.z_index() as usize]
+    }
+
+    /// Dim a layer; `opacity` is clamped to 0.0-1.0.
+    pub fn set_opacity(&mut self, layer: RenderLayer, opacity: f32) {
+        self.opacity[layer.z_index() as usize] = opacity.clamp(0.0, 1.0);
+    }
+
+    /// Take the layer brightness from `other`, keeping this visibility.
+    pub fn with_opacity_of(mut self, other: &LayerVisibility) -> Self {
+        self.opacity = other.opacity;
+        self
+    }
+}
+
+/// Floating panels whose place in the z-order can be configured
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Panel {
+    /// Connection matrix (`g`)
+    Matrix,
+    /// Session stats (`s`)
+    Stats,
+    /// Busiest agents of the recent past (`b`)
+    Leaderboard,
+    /// Alert toasts
+    Toasts,
+    /// Detail panel of the hovered agent
+    AgentDetail,
+}
+
+impl Panel {
+    /// Drawing order within a slot, bottom first
+    pub const ALL: [Panel; 5] = [Panel::Matrix, Panel::Stats, Panel::Leaderboard, Panel::Toasts, Panel::AgentDetail];
+}
+
+/// `[z_order]` section of the config file: which panels stay above the
+/// overlays (help, filter bar) instead of below them
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ZOrderConfig {
+    pub above_overlays: Vec<Panel>,
+}
+
+impl ZOrderConfig {
+    /// The user slot `panel` is drawn in
+    pub fn slot(&self, panel: Panel) -> RenderLayer {
+        if self.above_overlays.contains(&panel) {
+            RenderLayer::PinnedPanels
+        } else {
+            RenderLayer::Panels
+        }
+    }
 }
 
 /// Manages ordered layer rendering for the Hive visualization.
@@ -144,6 +245,8 @@ pub struct LayerRenderer<'a> {
     full_area: Rect,
     /// Which layers are currently visible
     visibility: &'a LayerVisibility,
+    /// Where the time spent on each layer is added up
+    profile: Option<&'a FrameProfile>,
 }
 
 impl<'a> LayerRenderer<'a> {
@@ -158,9 +261,16 @@ impl<'a> LayerRenderer<'a> {
             field_area,
             full_area,
             visibility,
+            profile: None,
         }
     }
 
+    /// Time each layer into `profile`
+    pub fn profile(mut self, profile: &'a FrameProfile) -> Self {
+        self.profile = Some(profile);
+        self
+    }
+
     /// Render all layers in order.
     ///
     /// This is the main entry point for layer-based rendering. It renders
@@ -169,10 +279,30 @@ impl<'a> LayerRenderer<'a> {
         &self,
         buf: &mut Buffer,
         state: &RenderState<'_>,
+    ) {
+        self.render_all_with(buf, state, |_, _| {});
+    }
+
+    /// Render all layers in order, calling `draw_panels` for the user slots
+    /// ([`RenderLayer::Panels`] and [`RenderLayer::PinnedPanels`]) so the
+    /// caller can draw its own panels between the built-in layers.
+    pub fn render_all_with(
+        &self,
+        buf: &mut Buffer,
+        state: &RenderState<'_>,
+        mut draw_panels: impl FnMut(RenderLayer, &mut Buffer),
     ) {
         for layer in RenderLayer::all() {
-            if self.visibility.is_visible(layer) {
-                self.render_layer(layer, buf, state);
+            if !self.visibility.is_visible(layer) {
+                continue;
+            }
+            let timer = self.profile.and_then(FrameProfile::timer);
+            match layer {
+                RenderLayer::Panels | RenderLayer::PinnedPanels => draw_panels(layer, buf),
+                _ => self.render_layer(layer, buf, state),
+            }
+            if let Some(profile) = self.profile {
+                profile.record_layer(layer, timer);
             }
         }
     }
@@ -196,6 +326,8 @@ impl<'a> LayerRenderer<'a> {
             RenderLayer::Labels => self.render_labels(buf, state),
             RenderLayer::StatusIndicators => self.render_status_indicators(buf, state),
             RenderLayer::UI => self.render_ui(buf, state),
+            // Drawn by the caller through render_all_with
+            RenderLayer::Panels | RenderLayer::PinnedPanels => {}
             RenderLayer::Overlays => self.render_overlays(buf, state),
         }
     }
@@ -203,13 +335,18 @@ impl<'a> LayerRenderer<'a> {
     /// Layer 0: Background (field border)
     fn render_background(&self, buf: &mut Buffer, state: &RenderState<'_>) {
         use ratatui::widgets::Widget;
-        FieldWidget::new(state.landmarks).render(self.field_area, buf);
+        FieldWidget::new(state.landmarks)
+            .focused(state.field_focused)
+            .border(state.field_border)
+            .hits(state.hits)
+            .render(self.field_area, buf);
     }
 
-    /// Layer 1: Zones (semantic zone labels - currently part of field)
-    fn render_zones(&self, _buf: &mut Buffer, _state: &RenderState<'_>) {
-        // Zone labels are currently rendered as part of the FieldWidget.
-        // Future enhancement: separate zone rendering for better control.
+    /// Layer 1: Zones (landmark labels are part of the field; this draws the
+    /// labels of clusters found away from any landmark)
+    fn render_zones(&self, buf: &mut Buffer, state: &RenderState<'_>) {
+        use ratatui::widgets::Widget;
+        ClusterLabelsWidget::new(state.clusters).render(self.field_area, buf);
     }
 
     /// Layer 2: Grid (optional grid overlay)
@@ -222,27 +359,44 @@ impl<'a> LayerRenderer<'a> {
     fn render_heatmap(&self, buf: &mut Buffer, state: &RenderState<'_>) {
         if let Some(heatmap) = state.heatmap {
             use ratatui::widgets::Widget;
-            HeatMapWidget::new(heatmap).render(self.field_area, buf);
+            HeatMapWidget::new(heatmap)
+                .opacity(self.visibility.opacity(RenderLayer::Heatmap))
+                .braille(state.heat_braille)
+                .render(self.field_area, buf);
         }
     }
 
     /// Layer 4: Trails
     fn render_trails(&self, buf: &mut Buffer, state: &RenderState<'_>) {
         use ratatui::widgets::Widget;
-        TrailsWidget::new(state.agents.to_vec()).render(self.field_area, buf);
+        TrailsWidget::new(state.agents.to_vec())
+            .opacity(self.visibility.opacity(RenderLayer::Trails))
+            .render(self.field_area, buf);
     }
 
     /// Layer 5: Connections
     fn render_connections(&self, buf: &mut Buffer, state: &RenderState<'_>) {
         use ratatui::widgets::Widget;
         let get_position = state.get_agent_position;
-        ConnectionsWidget::new(state.connections, get_position).render(self.field_area, buf);
+        ConnectionsWidget::new(state.connections, get_position)
+            .opacity(self.visibility.opacity(RenderLayer::Connections))
+            .hits(state.hits)
+            .render(self.field_area, buf);
     }
 
-    /// Layer 6: Event flashes
-    fn render_flashes(&self, _buf: &mut Buffer, _state: &RenderState<'_>) {
-        // Flash rendering is a future enhancement.
-        // Will show temporary visual indicators for events.
+    /// Layer 6: Event flashes and the swarm halo
+    fn render_flashes(&self, buf: &mut Buffer, state: &RenderState<'_>) {
+        use ratatui::widgets::Widget;
+        FlashesWidget::new(state.agents.to_vec())
+            .opacity(self.visibility.opacity(RenderLayer::Flashes))
+            .interpolation(state.interpolation)
+            .render(self.field_area, buf);
+
+        if let Some(convergence) = state.convergence {
+            SwarmHaloWidget::new(convergence)
+                .opacity(self.visibility.opacity(RenderLayer::Flashes))
+                .render(self.field_area, buf);
+        }
     }
 
     /// Layer 7: Agents
@@ -251,6 +405,10 @@ impl<'a> LayerRenderer<'a> {
         AgentsWidget::new(state.agents.to_vec())
             .selected(state.selected_agent)
             .hovered(state.hovered_agent)
+            .time(state.time)
+            .hits(state.hits)
+            .high_contrast(state.high_contrast)
+            .interpolation(state.interpolation)
             .render(self.field_area, buf);
     }
 
@@ -282,29 +440,42 @@ impl<'a> LayerRenderer<'a> {
             .paused(state.paused)
             .playback_speed(state.playback_speed)
             .replay_mode(state.history.replay_mode, state.history.position())
+            .items(state.status_items)
             .fps(state.fps)
+            .telemetry(state.event_rate, state.last_event_age)
+            .sampled(state.sampled)
             .display_mode(state.display_mode)
+            .mode_name(state.mode_name)
+            .swarm(state.convergence.map(|c| c.agents.len()))
             .render(status_area, buf);
 
-        // Timeline when in replay mode
+        // Timeline and the replayed events' clock when in replay mode
         if state.history.replay_mode {
+            ReplayClock::new(state.history).render(self.field_area, buf);
+
             let timeline_area = Rect::new(
                 self.full_area.x,
                 self.full_area.y + self.full_area.height - 2,
                 self.full_area.width,
                 1,
             );
-            TimelineWidget::new(state.history).render(timeline_area, buf);
+            TimelineWidget::new(state.history)
+                .focused(state.timeline_focused)
+                .heat_range(state.heat_range)
+                .playback(state.playback_speed, state.replay_rate)
+                .render(timeline_area, buf);
         }
     }
 
     /// Layer 11: Overlays (help, tooltips)
     fn render_overlays(&self, buf: &mut Buffer, state: &RenderState<'_>) {
         use ratatui::widgets::Widget;
-        use ratatui::style::{Color, Modifier, Style};
 
         if state.show_help {
             HelpOverlay.render(self.full_area, buf);
+            if let Some(hits) = state.hits {
+                hits.borrow_mut().clear_area(self.full_area);
+            }
         }
 
         // Render filter bar when filter mode is active or filter text exists
@@ -336,7 +507,7 @@ impl<'a> LayerRenderer<'a> {
         }
 
         // Label
-        let label = if is_editing { "Filter: " } else { "Filter: " };
+        let label = "Filter: ";
         let label_style = Style::default()
             .fg(Color::Rgb(150, 200, 255))
             .add_modifier(Modifier::BOLD);
@@ -399,6 +570,8 @@ pub struct RenderState<'a> {
     pub connections: &'a [ActiveConnection],
     /// Function to get agent position by ID
     pub get_agent_position: &'a dyn Fn(&str) -> Option<Position>,
+    /// Labelled clusters of agents away from the landmarks
+    pub clusters: &'a [Cluster],
     /// Landmarks on the field
     pub landmarks: &'a HashMap<LandmarkId, StoredLandmark>,
     /// History for replay mode
@@ -417,6 +590,39 @@ pub struct RenderState<'a> {
     pub filter_text: Option<&'a str>,
     /// Whether filter mode is active (typing)
     pub filter_mode: bool,
+    /// Whether the field has keyboard focus (highlighted border)
+    pub field_focused: bool,
+    /// How the edge of the field is drawn
+    pub field_border: FieldBorder,
+    /// Whether the replay timeline has keyboard focus
+    pub timeline_focused: bool,
+    /// Range of the recording the heat map was computed from, if any
+    pub heat_range: Option<HeatRange>,
+    /// Status bar readouts for the current display mode
+    pub status_items: &'a [StatusItem],
+    /// Name of the active custom display mode, if any
+    pub mode_name: Option<&'a str>,
+    /// Incoming events per second
+    pub event_rate: f32,
+    /// Time since the last incoming event
+    pub last_event_age: Option<Duration>,
+    /// Agent updates dropped by sampling, if any agent is sampled
+    pub sampled: Option<usize>,
+    /// Replayed events applied per second
+    pub replay_rate: f32,
+    /// Swarm of busy agents gathered in one spot, if any
+    pub convergence: Option<&'a Convergence>,
+    /// Animation clock in seconds, for the status animations
+    pub time: f32,
+    /// Hit-test map the field widgets record what they draw into, if any
+    pub hits: Option<&'a RefCell<HitMap>>,
+    /// Draw agents as filled, outlined blocks with status badges
+    pub high_contrast: bool,
+    /// Draw heat as braille dots instead of background colors
+    pub heat_braille: bool,
+    /// How far agents are drawn from their previous position towards their
+    /// current one (see [`AnimationLoop::interpolation`](hive_core::animation::AnimationLoop::interpolation))
+    pub interpolation: f32,
 }
 
 #[cfg(test)]
@@ -433,7 +639,7 @@ mod tests {
     #[test]
     fn test_layer_z_index() {
         assert_eq!(RenderLayer::Background.z_index(), 0);
-        assert_eq!(RenderLayer::Overlays.z_index(), 11);
+        assert_eq!(RenderLayer::Overlays.z_index(), 12);
     }
 
     #[test]
@@ -462,12 +668,41 @@ mod tests {
         assert!(visibility.is_visible(RenderLayer::Trails));
     }
 
+    #[test]
+    fn test_layer_opacity() {
+        let mut visibility = LayerVisibility::new();
+        assert_eq!(visibility.opacity(RenderLayer::Trails), 1.0);
+
+        visibility.set_opacity(RenderLayer::Trails, 0.6);
+        visibility.set_opacity(RenderLayer::Heatmap, 3.0);
+        assert_eq!(visibility.opacity(RenderLayer::Heatmap), 1.0);
+
+        // Switching modes keeps the dimming
+        let debug = DisplayMode::Debug.layer_visibility().with_opacity_of(&visibility);
+        assert_eq!(debug.opacity(RenderLayer::Trails), 0.6);
+
+        assert_eq!(RenderLayer::from_name("status_indicators"), Some(RenderLayer::StatusIndicators));
+        assert_eq!(RenderLayer::from_name("nope"), None);
+    }
+
+    #[test]
+    fn test_panel_slots() {
+        let config = ZOrderConfig {
+            above_overlays: vec![Panel::AgentDetail],
+        };
+        assert_eq!(config.slot(Panel::AgentDetail), RenderLayer::PinnedPanels);
+        assert_eq!(config.slot(Panel::Matrix), RenderLayer::Panels);
+        assert!(RenderLayer::PinnedPanels.renders_above(RenderLayer::Overlays));
+        assert!(RenderLayer::Overlays.renders_above(RenderLayer::Panels));
+    }
+
     #[test]
     fn test_all_layers_in_order() {
         let layers = RenderLayer::all();
-        assert_eq!(layers.len(), 12);
+        assert_eq!(layers.len(), LAYER_COUNT);
         assert_eq!(layers[0], RenderLayer::Background);
-        assert_eq!(layers[11], RenderLayer::Overlays);
+        assert_eq!(layers[12], RenderLayer::Overlays);
+        assert_eq!(layers[13], RenderLayer::PinnedPanels);
 
         // Verify monotonic ordering
         for i in 1..layers.len() {
@@ -0,0 +1,166 @@
+//! Leaderboard panel: the busiest agents of the recent past.
+//!
+//! Agents are ranked by intensity-seconds (intensity times the seconds spent
+//! at it) over a rolling window, so the hardest workers stand out even when
+//! the field is too crowded to tell them apart.
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::Widget,
+};
+use serde::Deserialize;
+
+use hive_core::state::activity::DEFAULT_ACTIVITY_WINDOW;
+use hive_core::state::Field;
+
+use super::colors::color_for_agent;
+use super::stats::format_duration;
+
+/// Width of the panel, borders included
+const PANEL_WIDTH: u16 = 40;
+
+/// Width of the bar showing an agent's share of the leader's work
+const BAR_WIDTH: usize = 8;
+
+/// `[leaderboard]` section of the config file
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LeaderboardConfig {
+    /// Length of the rolling window in seconds
+    pub window_secs: u64,
+    /// Agents listed
+    pub rows: usize,
+}
+
+impl Default for LeaderboardConfig {
+    fn default() -> Self {
+        Self {
+            window_secs: DEFAULT_ACTIVITY_WINDOW as u64,
+            rows: 10,
+        }
+    }
+}
+
+/// Panel in the top-left corner of the field ranking agents by recent work
+pub struct Leaderboard<'a> {
+    field: &'a Field,
+    rows: usize,
+}
+
+impl<'a> Leaderboard<'a> {
+    pub fn new(field: &'a Field) -> Self {
+        Self { field, rows: 10 }
+    }
+
+    /// Most agents listed
+    pub fn rows(mut self, rows: usize) -> Self {
+        self.rows = rows.max(1);
+        self
+    }
+}
+
+impl Widget for Leaderboard<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let ranking = self.field.activity.ranking();
+        let listed = ranking.len().clamp(1, self.rows);
+        let width = PANEL_WIDTH.min(area.width.saturating_sub(2));
+        let height = (listed as u16 + 3).min(area.height.saturating_sub(2));
+        if width < 24 || height < 4 {
+            return;
+        }
+        let area = Rect::new(area.x + 1, area.y + 1, width, height);
+
+        let border = Style::default().fg(Color::Rgb(100, 200, 150));
+        let background = Style::default().bg(Color::Rgb(35, 35, 45));
+        for y in area.y..area.y + area.height {
+            for x in area.x..area.x + area.width {
+                let ch = match (
+                    x == area.x,
+                    x == area.x + area.width - 1,
+                    y == area.y,
+                    y == area.y + area.height - 1,
+                ) {
+                    (true, _, true, _) => '╭',
+                    (_, true, true, _) => '╮',
+                    (true, _, _, true) => '╰',
+                    (_, true, _, true) => '╯',
+                    (true, _, _, _) | (_, true, _, _) => '│',
+                    (_, _, true, _) | (_, _, _, true) => '─',
+                    _ => ' ',
+                };
+                buf[(x, y)].set_char(ch).set_style(background.patch(border));
+            }
+        }
+
+        let x = area.x + 2;
+        let inner_width = area.width - 4;
+        let subtle = Style::default().fg(Color::Rgb(120, 120, 130));
+        let window = std::time::Duration::from_secs_f32(self.field.activity.secs());
+        let title = format!(" Busiest agents, last {} ", format_duration(window));
+        draw_text(buf, x, area.y, inner_width, &title, border.add_modifier(Modifier::BOLD));
+
+        if ranking.is_empty() {
+            draw_text(buf, x, area.y + 1, inner_width, "No work recorded yet", subtle);
+        }
+
+        // rank, name, bar, intensity-seconds
+        let rows = (area.height - 3) as usize;
+        let top = ranking.first().map_or(1.0, |(_, work)| work.max(f32::EPSILON));
+        for (row, (id, work)) in ranking.iter().take(rows).enumerate() {
+            let y = area.y + 1 + row as u16;
+            let agent = self.field.agents.get(*id);
+            let name = agent.map_or(&id[..], |agent| agent.display_name());
+            let color = agent.map_or(Color::Rgb(200, 200, 210), color_for_agent);
+
+            let label = format!("{:>2} {:<16}", row + 1, name.chars().take(16).collect::<String>());
+            draw_text(buf, x, y, inner_width, &label, Style::default().fg(color));
+
+            let bar_x = x + label.chars().count() as u16 + 1;
+            let filled = ((work / top) * BAR_WIDTH as f32).round() as usize;
+            let bar = format!("{:<width$} {:>5.0}", "█".repeat(filled), work, width = BAR_WIDTH);
+            let bar_style = Style::default().fg(Color::Rgb(100, 200, 150));
+            draw_text(buf, bar_x, y, (x + inner_width).saturating_sub(bar_x), &bar, bar_style);
+        }
+
+        draw_text(buf, x, area.y + area.height - 2, inner_width, "intensity-seconds  b close", subtle);
+    }
+}
+
+/// Write `text` at (x, y), truncated to `max_width` characters
+fn draw_text(buf: &mut Buffer, x: u16, y: u16, max_width: u16, text: &str, style: Style) {
+    for (cx, ch) in (x..).zip(text.chars().take(max_width as usize)) {
+        buf[(cx, y)].set_char(ch).set_style(style);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hive_core::event::{AgentStatus, AgentUpdate, HiveEvent};
+
+    #[test]
+    fn test_lists_busiest_agent_first() {
+        let mut field = Field::new();
+        for (id, intensity) in [("atlas", 0.2), ("nova", 0.9)] {
+            field.process_event(&HiveEvent::AgentUpdate(AgentUpdate {
+                agent_id: id.into(),
+                status: AgentStatus::Active,
+                focus: Vec::new(),
+                intensity,
+                message: String::new(),
+                timestamp: 0,
+            }));
+        }
+        field.tick(1.0);
+
+        let area = Rect::new(0, 0, 60, 12);
+        let mut buf = Buffer::empty(area);
+        Leaderboard::new(&field).render(area, &mut buf);
+        let line = |y: u16| (0..area.width).map(|x| buf[(x, y)].symbol()).collect::<String>();
+        assert!(line(1).contains("Busiest agents, last 5m 00s"));
+        assert!(line(2).contains(" 1 nova"));
+        assert!(line(3).contains(" 2 atlas"));
+    }
+}
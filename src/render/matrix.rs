@@ -0,0 +1,223 @@
+//! Connection matrix overlay.
+//!
+//! A table of agents × agents: each cell holds how many times the row agent
+//! connected to the column agent and how long ago the last contact was. It
+//! complements the spatial view when many agents talk at once and the lines on
+//! the field blur together.
+
+use std::time::Instant;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::Widget,
+};
+
+use hive_core::event::AgentId;
+use hive_core::state::{AgentOrder, Field};
+
+/// Width of the row label column ("12 name")
+const LABEL_WIDTH: u16 = 14;
+
+/// Width of one cell ("12 3s")
+const CELL_WIDTH: u16 = 7;
+
+/// Widget drawing the connection matrix of a [`Field`] as a centered box
+pub struct ConnectionMatrix<'a> {
+    field: &'a Field,
+    order: AgentOrder,
+    now: Instant,
+}
+
+impl<'a> ConnectionMatrix<'a> {
+    pub fn new(field: &'a Field) -> Self {
+        Self {
+            field,
+            order: AgentOrder::Id,
+            now: Instant::now(),
+        }
+    }
+
+    /// List the rows and columns in `order`
+    pub fn order(mut self, order: AgentOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Agents that sent or received a connection, in the chosen order; agents
+    /// no longer on the field come last, by ID
+    fn agents(&self) -> Vec<&'a AgentId> {
+        let mut agents: Vec<&AgentId> = self
+            .field
+            .contacts
+            .keys()
+            .flat_map(|(from, to)| [from, to])
+            .collect();
+        agents.sort_unstable();
+        agents.dedup();
+
+        let rank: std::collections::HashMap<&str, usize> = self
+            .field
+            .agents_ordered(self.order)
+            .into_iter()
+            .enumerate()
+            .map(|(rank, agent)| (&*agent.id, rank))
+            .collect();
+        agents.sort_by_key(|id| rank.get(&id[..]).copied().unwrap_or(usize::MAX));
+        agents
+    }
+
+    fn name(&self, id: &'a str) -> &'a str {
+        self.field.agents.get(id).map_or(id, |agent| agent.display_name())
+    }
+}
+
+/// Short age such as "3s", "2m" or "1h"
+fn short_age(secs: u64) -> String {
+    match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 => format!("{}m", secs / 60),
+        _ => format!("{}h", secs / 3600),
+    }
+}
+
+impl Widget for ConnectionMatrix<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let agents = self.agents();
+        let wanted_width = LABEL_WIDTH + CELL_WIDTH * agents.len().max(1) as u16 + 4;
+        let width = wanted_width.min(area.width.saturating_sub(4));
+        let height = (agents.len() as u16 + 5).min(area.height.saturating_sub(2));
+        if width < LABEL_WIDTH + CELL_WIDTH + 4 || height < 5 {
+            return;
+        }
+        let area = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        );
+
+        let border = Style::default().fg(Color::Rgb(100, 200, 150));
+        let background = Style::default().bg(Color::Rgb(35, 35, 45));
+        for y in area.y..area.y + area.height {
+            for x in area.x..area.x + area.width {
+                let ch = match (
+                    x == area.x,
+                    x == area.x + area.width - 1,
+                    y == area.y,
+                    y == area.y + area.height - 1,
+                ) {
+                    (true, _, true, _) => '╭',
+                    (_, true, true, _) => '╮',
+                    (true, _, _, true) => '╰',
+                    (_, true, _, true) => '╯',
+                    (true, _, _, _) | (_, true, _, _) => '│',
+                    (_, _, true, _) | (_, _, _, true) => '─',
+                    _ => ' ',
+                };
+                buf[(x, y)].set_char(ch).set_style(background.patch(border));
+            }
+        }
+
+        let inner_x = area.x + 2;
+        let inner_width = area.width - 4;
+        let columns = ((inner_width - LABEL_WIDTH) / CELL_WIDTH) as usize;
+        let rows = (area.height - 5) as usize;
+
+        let hidden = agents.len().saturating_sub(columns.min(rows));
+        let title = if hidden > 0 {
+            format!(" Connections (+{} more) ", hidden)
+        } else {
+            " Connections ".to_string()
+        };
+        draw_text(buf, inner_x, area.y, inner_width, &title, border.add_modifier(Modifier::BOLD));
+
+        // Column headers are the row numbers
+        let header = Style::default().fg(Color::Rgb(150, 150, 160));
+        let subtle = Style::default().fg(Color::Rgb(90, 90, 100));
+        draw_text(buf, inner_x, area.y + 1, LABEL_WIDTH, "from ↓  to →", subtle);
+        for col in 0..agents.len().min(columns) {
+            let x = inner_x + LABEL_WIDTH + col as u16 * CELL_WIDTH;
+            draw_text(buf, x, area.y + 1, CELL_WIDTH, &format!("{:>5}", col + 1), header);
+        }
+
+        for (row, from) in agents.iter().take(rows).enumerate() {
+            let y = area.y + 2 + row as u16;
+            let label = format!("{:>2} {}", row + 1, self.name(from));
+            draw_text(buf, inner_x, y, LABEL_WIDTH - 1, &label, header);
+
+            for (col, to) in agents.iter().take(columns).enumerate() {
+                let x = inner_x + LABEL_WIDTH + col as u16 * CELL_WIDTH;
+                let key = ((*from).clone(), (*to).clone());
+                let (text, style) = match self.field.contacts.get(&key) {
+                    Some(stats) => {
+                        let age = self.now.saturating_duration_since(stats.last_contact).as_secs();
+                        let fg = match age {
+                            0..=4 => Color::Rgb(100, 200, 150),
+                            5..=59 => Color::Rgb(200, 200, 210),
+                            _ => Color::Rgb(120, 120, 135),
+                        };
+                        (format!("{:>3} {}", stats.count, short_age(age)), Style::default().fg(fg))
+                    }
+                    None => (format!("{:>5}", "·"), subtle),
+                };
+                draw_text(buf, x, y, CELL_WIDTH, &text, style);
+            }
+        }
+
+        if agents.is_empty() {
+            draw_text(buf, inner_x, area.y + 2, inner_width, "No connections yet", subtle);
+        }
+
+        let hint = "count, time since last contact  g close";
+        draw_text(buf, inner_x, area.y + area.height - 2, inner_width, hint, subtle);
+    }
+}
+
+/// Write `text` at (x, y), truncated to `max_width` characters
+fn draw_text(buf: &mut Buffer, x: u16, y: u16, max_width: u16, text: &str, style: Style) {
+    for (cx, ch) in (x..).zip(text.chars().take(max_width as usize)) {
+        buf[(cx, y)].set_char(ch).set_style(style);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hive_core::event::{Connection, HiveEvent};
+
+    fn connect(field: &mut Field, from: &str, to: &str) {
+        field.process_event(&HiveEvent::Connection(Connection {
+            from: from.into(),
+            to: to.into(),
+            label: String::new(),
+            timestamp: 0,
+            ttl_ms: None,
+        }));
+    }
+
+    #[test]
+    fn test_counts_per_direction() {
+        let mut field = Field::new();
+        connect(&mut field, "atlas", "nova");
+        connect(&mut field, "atlas", "nova");
+        connect(&mut field, "nova", "echo");
+
+        let area = Rect::new(0, 0, 60, 12);
+        let mut buf = Buffer::empty(area);
+        ConnectionMatrix::new(&field).render(area, &mut buf);
+        let rows: Vec<String> = (0..area.height)
+            .map(|y| (0..area.width).map(|x| buf[(x, y)].symbol()).collect())
+            .collect();
+
+        // Rows and columns are sorted: 1 atlas, 2 echo, 3 nova
+        let atlas = rows.iter().find(|r| r.contains(" 1 atlas")).unwrap();
+        assert!(atlas.contains("  2 0s"), "{}", atlas);
+        let nova = rows.iter().find(|r| r.contains(" 3 nova")).unwrap();
+        assert!(nova.contains("  1 0s"), "{}", nova);
+
+        // Too small to draw anything useful
+        ConnectionMatrix::new(&field).render(Rect::new(0, 0, 10, 4), &mut buf);
+    }
+}
@@ -3,31 +3,64 @@ pub mod agent;
 pub mod agent_panel;
 pub mod colors;
 pub mod connections;
+pub mod cvd;
 pub mod display_mode;
 pub mod field;
+pub mod file_browser;
+pub mod flashes;
 pub mod heatmap;
+pub mod hit_map;
+pub mod intro;
+pub mod landmark_editor;
 pub mod layers;
-pub mod symbols;
+pub mod leaderboard;
+pub mod matrix;
+pub mod profile;
+pub mod search;
+pub mod stats;
+pub mod swarm;
+#[cfg(test)]
+mod snapshot;
+pub mod toast;
 pub mod trails;
+pub mod transform;
 pub mod ui;
 
 use ratatui::style::Color;
 
-pub use activity_log::{ActivityEntry, ActivityLog, ActivityLogWidget};
-pub use agent::render_agents;
+// Glyph tables live in hive-core so the swarm model can describe agents
+// without depending on the renderer.
+pub use hive_core::symbols;
+
+pub use activity_log::{
+    ActivityEntry, ActivityLog, ActivityLogConfig, ActivityLogWidget, LogSearch, TimestampStyle,
+};
+pub use agent::{render_agents, GhostsWidget};
 pub use agent_panel::AgentPanel;
 pub use connections::render_connections;
-pub use display_mode::DisplayMode;
-pub use field::render_field;
-pub use heatmap::{HeatMap, HeatmapConfig};
-pub use layers::{LayerRenderer, LayerVisibility, RenderLayer, RenderState};
+pub use cvd::ColorVision;
+pub use display_mode::{CustomDisplayMode, DisplayMode};
+pub use field::{render_field, FieldBorder, FieldConfig};
+pub use file_browser::{FileBrowser, FileBrowserWidget};
+pub use heatmap::{HeatAccumulation, HeatMap, HeatRange, HeatSource, HeatStyle, HeatmapConfig};
+pub use hit_map::{HitBuffers, HitMap, HitTarget};
+pub use intro::{Intro, IntroWidget};
+pub use landmark_editor::{EditorAction, LandmarkEditor, LandmarkEditorWidget};
+pub use layers::{LayerRenderer, LayerVisibility, Panel, RenderLayer, RenderState, ZOrderConfig};
+pub use leaderboard::{Leaderboard, LeaderboardConfig};
+pub use matrix::ConnectionMatrix;
+pub use profile::{FrameProfile, FrameTimes, ProfileWidget};
+pub use search::{find_agents, AgentSearch, AgentSearchWidget, SearchAction, SearchMatch};
+pub use stats::{session_summary, StatsOverlay};
+pub use toast::{Toast, ToastWidget, Toasts};
 pub use trails::render_trails;
-pub use ui::{render_ui, EmptyStateType, EmptyStateWidget};
+pub use transform::FieldTransform;
+pub use ui::{render_ui, CommandLine, EmptyStateType, EmptyStateWidget, LoadingWidget, ReplayClock, StatusBarConfig, StatusItem, TooSmallWidget};
 
 // Re-export colors module items for backward compatibility
 pub use colors::{
     AGENT_COLORS, STATUS_COLORS, StatusColors, ColorMode,
-    dim_color, get_agent_color,
+    color_for_agent, dim_color, get_agent_color,
 };
 
 // Re-export symbols module items
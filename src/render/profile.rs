@@ -0,0 +1,243 @@
+//! Frame profile overlay shown in Debug mode.
+//!
+//! While profiling is enabled, cheap timers around each render layer, the
+//! applying of events and the resolving of agent ID collisions add up what a
+//! frame spent where. The overlay shows the totals of the last complete frame,
+//! so a slow layer or a flood of events can be spotted on the user's own
+//! terminal and data.
+
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::Widget,
+};
+
+use super::layers::{RenderLayer, LAYER_COUNT};
+
+/// Width of the panel, borders included
+const PANEL_WIDTH: u16 = 34;
+
+/// Where the time of one frame went
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FrameTimes {
+    /// Time spent drawing each layer, `None` for layers not drawn
+    pub layers: [Option<Duration>; LAYER_COUNT],
+    /// Time spent applying events to the field
+    pub events: Duration,
+    /// Number of events applied
+    pub events_applied: usize,
+    /// Time spent resolving agent ID collisions between sources
+    pub collisions: Duration,
+}
+
+impl FrameTimes {
+    /// Time spent drawing all layers
+    pub fn render(&self) -> Duration {
+        self.layers.iter().flatten().sum()
+    }
+}
+
+/// Timers for the current frame and the totals of the last one
+#[derive(Debug, Default)]
+pub struct FrameProfile {
+    enabled: bool,
+    /// Layers are timed while the frame is drawn, which only borrows the app
+    current: Cell<FrameTimes>,
+    last: FrameTimes,
+}
+
+impl FrameProfile {
+    /// Start or stop timing; nothing is measured while stopped
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.current.take();
+            self.last = FrameTimes::default();
+        }
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Start a timer, if profiling
+    pub fn timer(&self) -> Option<Instant> {
+        self.enabled.then(Instant::now)
+    }
+
+    /// Add the time since `timer` to drawing `layer`
+    pub fn record_layer(&self, layer: RenderLayer, timer: Option<Instant>) {
+        let Some(started) = timer else {
+            return;
+        };
+        let mut times = self.current.get();
+        let spent = times.layers[layer.z_index() as usize].get_or_insert_default();
+        *spent += started.elapsed();
+        self.current.set(times);
+    }
+
+    /// Add the time since `timer` to applying `count` events
+    pub fn record_events(&mut self, timer: Option<Instant>, count: usize) {
+        let Some(started) = timer else {
+            return;
+        };
+        let times = self.current.get_mut();
+        times.events += started.elapsed();
+        times.events_applied += count;
+    }
+
+    /// Add the time since `timer` to resolving ID collisions
+    pub fn record_collisions(&mut self, timer: Option<Instant>) {
+        if let Some(started) = timer {
+            self.current.get_mut().collisions += started.elapsed();
+        }
+    }
+
+    /// Close the current frame; its totals become the ones shown
+    pub fn finish_frame(&mut self) {
+        if self.enabled {
+            self.last = self.current.take();
+        }
+    }
+
+    /// Totals of the last complete frame
+    pub fn last(&self) -> &FrameTimes {
+        &self.last
+    }
+}
+
+/// Panel in the bottom-left corner of the field listing a frame's timings
+pub struct ProfileWidget<'a> {
+    times: &'a FrameTimes,
+}
+
+impl<'a> ProfileWidget<'a> {
+    pub fn new(times: &'a FrameTimes) -> Self {
+        Self { times }
+    }
+
+    /// One line per drawn layer, then the totals
+    fn lines(&self) -> Vec<(String, Duration)> {
+        let mut lines: Vec<_> = RenderLayer::all()
+            .into_iter()
+            .filter_map(|layer| {
+                let spent = self.times.layers[layer.z_index() as usize]?;
+                Some((layer.name().to_string(), spent))
+            })
+            .collect();
+        lines.push(("all layers".to_string(), self.times.render()));
+        lines.push((format!("events ({})", self.times.events_applied), self.times.events));
+        lines.push(("collisions".to_string(), self.times.collisions));
+        lines
+    }
+}
+
+impl Widget for ProfileWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let lines = self.lines();
+        let width = PANEL_WIDTH.min(area.width.saturating_sub(2));
+        let height = (lines.len() as u16 + 2).min(area.height.saturating_sub(2));
+        if width < 24 || height < 4 {
+            return;
+        }
+        let area = Rect::new(area.x + 1, area.y + area.height - 1 - height, width, height);
+
+        let border = Style::default().fg(Color::Rgb(255, 200, 100));
+        let background = Style::default().bg(Color::Rgb(35, 35, 45));
+        for y in area.y..area.y + area.height {
+            for x in area.x..area.x + area.width {
+                let ch = match (
+                    x == area.x,
+                    x == area.x + area.width - 1,
+                    y == area.y,
+                    y == area.y + area.height - 1,
+                ) {
+                    (true, _, true, _) => '╭',
+                    (_, true, true, _) => '╮',
+                    (true, _, _, true) => '╰',
+                    (_, true, _, true) => '╯',
+                    (true, _, _, _) | (_, true, _, _) => '│',
+                    (_, _, true, _) | (_, _, _, true) => '─',
+                    _ => ' ',
+                };
+                buf[(x, y)].set_char(ch).set_style(background.patch(border));
+            }
+        }
+
+        let x = area.x + 2;
+        let inner_width = area.width - 4;
+        draw_text(buf, x, area.y, inner_width, " Last frame ", border.add_modifier(Modifier::BOLD));
+
+        // The totals below the layers, set apart by their color
+        let totals = lines.len() - 3;
+        let rows = (area.height - 2) as usize;
+        for (row, (label, spent)) in lines.iter().enumerate().take(rows) {
+            let y = area.y + 1 + row as u16;
+            let style = if row < totals {
+                Style::default().fg(Color::Rgb(200, 200, 210))
+            } else {
+                Style::default().fg(Color::Rgb(255, 200, 100))
+            };
+            let time = format_time(*spent);
+            let text = format!("{:<w$}{}", label, time, w = (inner_width as usize).saturating_sub(time.len()));
+            draw_text(buf, x, y, inner_width, &text, style);
+        }
+    }
+}
+
+/// Milliseconds with two decimals, e.g. "0.42ms"
+fn format_time(spent: Duration) -> String {
+    format!("{:.2}ms", spent.as_secs_f64() * 1000.0)
+}
+
+/// Write `text` at (x, y), truncated to `max_width` characters
+fn draw_text(buf: &mut Buffer, x: u16, y: u16, max_width: u16, text: &str, style: Style) {
+    for (cx, ch) in (x..).zip(text.chars().take(max_width as usize)) {
+        buf[(cx, y)].set_char(ch).set_style(style);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frames_are_timed_only_while_enabled() {
+        let mut profile = FrameProfile::default();
+        assert_eq!(profile.timer(), None);
+        profile.record_layer(RenderLayer::Agents, profile.timer());
+        profile.record_events(profile.timer(), 3);
+        profile.finish_frame();
+        assert_eq!(*profile.last(), FrameTimes::default());
+
+        profile.set_enabled(true);
+        profile.record_layer(RenderLayer::Agents, profile.timer());
+        profile.record_layer(RenderLayer::Agents, profile.timer());
+        profile.record_events(profile.timer(), 3);
+        profile.record_events(profile.timer(), 2);
+        // Still the previous frame until this one is finished
+        assert_eq!(profile.last().events_applied, 0);
+        profile.finish_frame();
+
+        let last = *profile.last();
+        assert_eq!(last.events_applied, 5);
+        assert!(last.layers[RenderLayer::Agents.z_index() as usize].is_some());
+        assert!(last.layers[RenderLayer::Heatmap.z_index() as usize].is_none());
+        assert_eq!(last.render(), last.layers[RenderLayer::Agents.z_index() as usize].unwrap());
+
+        // The next frame starts from nothing
+        profile.finish_frame();
+        assert_eq!(*profile.last(), FrameTimes::default());
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 60, 20));
+        ProfileWidget::new(&last).render(buf.area, &mut buf);
+        let text: String = buf.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(text.contains("agents"));
+        assert!(text.contains("events (5)"));
+        assert!(!text.contains("heatmap"));
+    }
+}
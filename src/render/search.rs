@@ -0,0 +1,266 @@
+//! Search overlay for finding agents by what they work on.
+//!
+//! Unlike the `/` filter, which narrows the field down by agent name, the
+//! search matches the query against names, focus keywords and the agents'
+//! recent messages, and lists the matches with the zone each agent is in.
+//! Picking one selects the agent.
+
+use std::collections::HashMap;
+
+use crossterm::event::KeyCode;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::Widget,
+};
+
+use hive_core::event::{AgentId, HiveEvent};
+use hive_core::state::{Field, History};
+
+/// Most recent events whose messages are searched
+const RECENT_EVENTS: usize = 2000;
+
+/// Messages searched per agent, most recent first
+const RECENT_MESSAGES: usize = 5;
+
+/// An agent matching the search
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchMatch {
+    pub id: AgentId,
+    pub name: String,
+    /// What matched: "name", "focus" or "message"
+    pub kind: &'static str,
+    /// The matching keyword or message
+    pub text: String,
+    /// Label of the landmark zone the agent is in
+    pub zone: Option<String>,
+}
+
+/// Agents matching `query` (case-insensitive), by name
+pub fn find_agents(field: &Field, history: &History, query: &str) -> Vec<SearchMatch> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let matches = |text: &str| text.to_lowercase().contains(&query);
+
+    // Recent messages per agent, newest first
+    let mut messages: HashMap<&str, Vec<&str>> = HashMap::new();
+    for event in history.events().iter().rev().take(RECENT_EVENTS) {
+        if let HiveEvent::AgentUpdate(ref update) = event.event {
+            let recent = messages.entry(&*update.agent_id).or_default();
+            if !update.message.is_empty() && recent.len() < RECENT_MESSAGES && !recent.contains(&update.message.as_str()) {
+                recent.push(&update.message);
+            }
+        }
+    }
+
+    let mut found: Vec<SearchMatch> = field
+        .agents_sorted()
+        .into_iter()
+        .filter_map(|agent| {
+            let (kind, text) = if matches(agent.display_name()) || matches(&agent.id) {
+                ("name", agent.focus.join(", "))
+            } else if let Some(keyword) = agent.focus.iter().find(|keyword| matches(keyword)) {
+                ("focus", keyword.to_string())
+            } else {
+                let recent = messages.get(&*agent.id).into_iter().flatten().copied();
+                let message = std::iter::once(agent.message.as_str()).chain(recent).find(|m| matches(m))?;
+                ("message", message.to_string())
+            };
+            Some(SearchMatch {
+                id: agent.id.clone(),
+                name: agent.display_name().to_string(),
+                kind,
+                text,
+                zone: field.nearest_landmark(&agent.position).map(|landmark| landmark.label.clone()),
+            })
+        })
+        .collect();
+    found.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.id.cmp(&b.id)));
+    found
+}
+
+/// What a key press in the search asks for
+#[derive(Debug, Clone, PartialEq)]
+pub enum SearchAction {
+    None,
+    Close,
+    /// Select this agent
+    Jump(AgentId),
+}
+
+/// Search overlay state: the query and the highlighted match
+#[derive(Debug, Clone, Default)]
+pub struct AgentSearch {
+    query: String,
+    selected: usize,
+}
+
+impl AgentSearch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Index of the highlighted match
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Handle a key press; `matches` are the matches listed for the query.
+    pub fn handle_key(&mut self, code: KeyCode, matches: &[SearchMatch]) -> SearchAction {
+        self.selected = self.selected.min(matches.len().saturating_sub(1));
+        match code {
+            KeyCode::Esc => return SearchAction::Close,
+            KeyCode::Enter => {
+                if let Some(found) = matches.get(self.selected) {
+                    return SearchAction::Jump(found.id.clone());
+                }
+            }
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down if self.selected + 1 < matches.len() => self.selected += 1,
+            KeyCode::Backspace => {
+                self.query.pop();
+                self.selected = 0;
+            }
+            KeyCode::Char(c) => {
+                self.query.push(c);
+                self.selected = 0;
+            }
+            _ => {}
+        }
+        SearchAction::None
+    }
+}
+
+/// Widget drawing the search box and its matches
+pub struct AgentSearchWidget<'a> {
+    search: &'a AgentSearch,
+    matches: &'a [SearchMatch],
+}
+
+impl<'a> AgentSearchWidget<'a> {
+    pub fn new(search: &'a AgentSearch, matches: &'a [SearchMatch]) -> Self {
+        Self { search, matches }
+    }
+}
+
+impl Widget for AgentSearchWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.width < 20 || area.height < 6 {
+            return;
+        }
+
+        let border = Style::default().fg(Color::Rgb(100, 200, 150));
+        let background = Style::default().bg(Color::Rgb(35, 35, 45));
+        for y in area.y..area.y + area.height {
+            for x in area.x..area.x + area.width {
+                let ch = match (
+                    x == area.x,
+                    x == area.x + area.width - 1,
+                    y == area.y,
+                    y == area.y + area.height - 1,
+                ) {
+                    (true, _, true, _) => '╭',
+                    (_, true, true, _) => '╮',
+                    (true, _, _, true) => '╰',
+                    (_, true, _, true) => '╯',
+                    (true, _, _, _) | (_, true, _, _) => '│',
+                    (_, _, true, _) | (_, _, _, true) => '─',
+                    _ => ' ',
+                };
+                buf[(x, y)].set_char(ch).set_style(background.patch(border));
+            }
+        }
+
+        let inner_width = area.width.saturating_sub(4) as usize;
+        draw_text(buf, area.x + 2, area.y, inner_width, " Find agents ", border.add_modifier(Modifier::BOLD));
+
+        let input = format!("> {}▏", self.search.query);
+        draw_text(buf, area.x + 2, area.y + 1, inner_width, &input, Style::default().fg(Color::Rgb(220, 220, 230)));
+
+        let rows = area.height.saturating_sub(4) as usize;
+        let selected = self.search.selected.min(self.matches.len().saturating_sub(1));
+        let first = selected.saturating_sub(rows.saturating_sub(1));
+        let dim = Style::default().fg(Color::Rgb(120, 120, 130));
+
+        if self.matches.is_empty() && !self.search.query.trim().is_empty() {
+            draw_text(buf, area.x + 2, area.y + 2, inner_width, "(no matching agents)", dim);
+        }
+
+        for (row, (idx, found)) in self.matches.iter().enumerate().skip(first).take(rows).enumerate() {
+            let mut style = Style::default().fg(Color::Rgb(200, 200, 210));
+            if idx == selected {
+                style = style.bg(Color::Rgb(60, 60, 80)).add_modifier(Modifier::BOLD);
+            }
+            let zone = found.zone.as_deref().map_or(String::new(), |zone| format!("  @ {}", zone));
+            let line = format!("{}  {}: {}{}", found.name, found.kind, found.text, zone);
+            let line = format!("{:<width$}", line, width = inner_width);
+            draw_text(buf, area.x + 2, area.y + 2 + row as u16, inner_width, &line, style);
+        }
+
+        let hint = "↑/↓ move  Enter select  Esc close";
+        draw_text(buf, area.x + 2, area.y + area.height - 2, inner_width, hint, dim);
+    }
+}
+
+/// Write `text` at (x, y), truncated to `max_width` characters
+fn draw_text(buf: &mut Buffer, x: u16, y: u16, max_width: usize, text: &str, style: Style) {
+    for (cx, ch) in (x..).zip(text.chars().take(max_width)) {
+        buf[(cx, y)].set_char(ch).set_style(style);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hive_core::event::{AgentStatus, AgentUpdate};
+
+    fn update(id: &str, focus: &[&str], message: &str) -> HiveEvent {
+        HiveEvent::AgentUpdate(AgentUpdate {
+            agent_id: id.into(),
+            status: AgentStatus::Active,
+            focus: focus.iter().map(|&f| f.into()).collect(),
+            intensity: 0.5,
+            message: message.to_string(),
+            timestamp: 0,
+        })
+    }
+
+    #[test]
+    fn test_matches_focus_and_recent_messages() {
+        let mut field = Field::new();
+        let mut history = History::new();
+        for event in [
+            update("atlas", &["auth", "jwt"], "Reading the middleware"),
+            update("nova", &["database"], "Migrating the users table"),
+            update("nova", &["database"], "Indexing sessions"),
+        ] {
+            field.process_event(&event);
+            history.record(event);
+        }
+
+        let found = find_agents(&field, &history, "JWT");
+        assert_eq!(found.len(), 1);
+        assert_eq!((&*found[0].id, found[0].kind, found[0].text.as_str()), ("atlas", "focus", "jwt"));
+
+        // An older message still matches
+        let found = find_agents(&field, &history, "users");
+        assert_eq!((&*found[0].id, found[0].kind), ("nova", "message"));
+
+        assert!(find_agents(&field, &history, "").is_empty());
+        assert!(find_agents(&field, &history, "kubernetes").is_empty());
+
+        let mut search = AgentSearch::new();
+        for c in "data".chars() {
+            search.handle_key(KeyCode::Char(c), &[]);
+        }
+        let found = find_agents(&field, &history, search.query());
+        assert_eq!(search.handle_key(KeyCode::Enter, &found), SearchAction::Jump("nova".into()));
+    }
+}
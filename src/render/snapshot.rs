@@ -0,0 +1,172 @@
+//! Golden-frame snapshot tests.
+//!
+//! Each test feeds a known event sequence into a [`HiveState`], settles it
+//! into a deterministic frame, draws it through a `TestBackend` terminal and
+//! compares the resulting characters with `src/render/snapshots/<name>.txt`.
+//! Layer regressions (overlap, z-order, truncation) show up as a diff.
+//!
+//! Run with `UPDATE_SNAPSHOTS=1 cargo test` to (re)write the stored frames
+//! after an intended rendering change, then review the diff before
+//! committing.
+
+use std::path::PathBuf;
+
+use ratatui::{backend::TestBackend, Terminal};
+
+use hive_core::event::{AgentStatus, AgentUpdate, Connection, HiveEvent, Landmark};
+
+use crate::render::{DisplayMode, LayerVisibility, RenderLayer};
+use crate::widget::{HiveState, HiveWidget};
+
+/// Frames of heat accumulated while settling
+const SETTLE_FRAMES: usize = 20;
+
+/// Apply `events` and put the state into a frame that does not depend on
+/// wall-clock time: agents sit on their targets, trails are cleared and
+/// connections are fully faded in.
+fn settled_state(events: &[HiveEvent]) -> HiveState {
+    let mut state = HiveState::new();
+    for event in events {
+        state.process_event(event);
+    }
+
+    for agent in state.field.agents.values_mut() {
+        agent.position = agent.target_position.clone();
+        agent.previous_position = agent.target_position.clone();
+        agent.trail.clear();
+    }
+    for connection in &mut state.field.connections {
+        connection.opacity = 1.0;
+    }
+    for _ in 0..SETTLE_FRAMES {
+        state.heatmap.accumulate(&state.field);
+    }
+    state
+}
+
+/// Draw `widget` at `width` x `height` and return the frame as text.
+fn draw(state: &mut HiveState, widget: HiveWidget, width: u16, height: u16) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal
+        .draw(|frame| frame.render_stateful_widget(widget, frame.area(), state))
+        .unwrap();
+
+    let buffer = terminal.backend().buffer();
+    let mut text = String::new();
+    for y in 0..buffer.area.height {
+        let line: String = (0..buffer.area.width)
+            .map(|x| buffer[(x, y)].symbol())
+            .collect();
+        text.push_str(line.trim_end());
+        text.push('\n');
+    }
+    text
+}
+
+/// Compare `actual` with the stored snapshot `name`.
+fn assert_snapshot(name: &str, actual: &str) {
+    let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "src", "render", "snapshots"]
+        .iter()
+        .collect::<PathBuf>()
+        .join(format!("{}.txt", name));
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, actual).unwrap();
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "missing snapshot {} (run with UPDATE_SNAPSHOTS=1 to create it)",
+            path.display()
+        )
+    });
+    if expected != actual {
+        panic!(
+            "snapshot {} does not match\n--- expected\n{}--- actual\n{}\
+             (run with UPDATE_SNAPSHOTS=1 to accept the new frame)",
+            name, expected, actual
+        );
+    }
+}
+
+fn landmark(id: &str, label: &str, keywords: &[&str]) -> HiveEvent {
+    HiveEvent::Landmark(Landmark {
+        id: id.to_string(),
+        label: label.to_string(),
+        keywords: keywords.iter().map(|k| k.to_string()).collect(),
+        timestamp: 0,
+    })
+}
+
+fn update(agent_id: &str, status: AgentStatus, focus: &[&str], intensity: f32) -> HiveEvent {
+    HiveEvent::AgentUpdate(AgentUpdate {
+        agent_id: agent_id.into(),
+        status,
+        focus: focus.iter().map(|&f| f.into()).collect(),
+        intensity,
+        message: String::new(),
+        timestamp: 0,
+    })
+}
+
+fn connection(from: &str, to: &str, label: &str) -> HiveEvent {
+    HiveEvent::Connection(Connection {
+        from: from.into(),
+        to: to.into(),
+        label: label.to_string(),
+        timestamp: 0,
+        ttl_ms: None,
+    })
+}
+
+/// Two zones, three agents and a connection
+fn small_swarm() -> Vec<HiveEvent> {
+    vec![
+        landmark("auth", "Authentication", &["auth", "jwt"]),
+        landmark("data", "Database", &["database", "schema"]),
+        update("atlas", AgentStatus::Active, &["database", "schema"], 0.8),
+        update("nova", AgentStatus::Thinking, &["auth", "jwt"], 0.5),
+        update("echo", AgentStatus::Error, &["auth"], 0.9),
+        connection("atlas", "nova", "schema review"),
+    ]
+}
+
+#[test]
+fn snapshot_standard_mode() {
+    let mut state = settled_state(&small_swarm());
+    let frame = draw(&mut state, HiveWidget::new(), 80, 24);
+    assert_snapshot("standard_mode", &frame);
+}
+
+#[test]
+fn snapshot_minimal_mode() {
+    let mut state = settled_state(&small_swarm());
+    let widget = HiveWidget::new().display_mode(DisplayMode::Minimal);
+    let frame = draw(&mut state, widget, 80, 24);
+    assert_snapshot("minimal_mode", &frame);
+}
+
+#[test]
+fn snapshot_narrow_area_truncates_labels() {
+    let mut state = settled_state(&small_swarm());
+    let frame = draw(&mut state, HiveWidget::new(), 30, 10);
+    assert_snapshot("narrow_area", &frame);
+}
+
+#[test]
+fn snapshot_overlapping_agents_z_order() {
+    // Identical focus puts both agents on the same cell; the later one in
+    // sorted order must be drawn on top
+    let events = vec![
+        landmark("data", "Database", &["database"]),
+        update("alpha", AgentStatus::Active, &["database"], 0.6),
+        update("beta", AgentStatus::Waiting, &["database"], 0.6),
+    ];
+    let mut state = settled_state(&events);
+    let mut layers = LayerVisibility::new();
+    layers.set_visible(RenderLayer::Heatmap, false);
+    let frame = draw(&mut state, HiveWidget::new().layers(layers), 60, 16);
+    assert_snapshot("overlapping_agents", &frame);
+}
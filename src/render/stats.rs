@@ -0,0 +1,219 @@
+//! Session statistics: where the swarm spent its time.
+//!
+//! Shown as an overlay on the field while running and printed as a summary
+//! when Hive exits.
+
+use std::fmt::Write as _;
+use std::time::Duration;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::Widget,
+};
+
+use hive_core::state::Field;
+
+/// Time all agents spent near one landmark
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZoneDwell {
+    pub label: String,
+    pub time: Duration,
+    /// Fraction of all recorded time (0.0-1.0)
+    pub share: f32,
+    /// Agent (display name) that spent the most time there, and how long
+    pub top_agent: Option<(String, Duration)>,
+}
+
+/// Dwell time per landmark, longest first
+pub fn zone_dwell(field: &Field) -> Vec<ZoneDwell> {
+    let total = field.dwell.total().as_secs_f32().max(f32::EPSILON);
+    field
+        .dwell
+        .zone_totals()
+        .into_iter()
+        .map(|(id, time)| ZoneDwell {
+            label: field.landmarks.get(id).map_or_else(|| id.clone(), |l| l.label.clone()),
+            time,
+            share: time.as_secs_f32() / total,
+            top_agent: field.dwell.top_agent(id).map(|(agent, time)| {
+                let name = field.agents.get(agent).map_or(&agent[..], |a| a.display_name());
+                (name.to_string(), time)
+            }),
+        })
+        .collect()
+}
+
+/// Duration such as "45s", "12m 03s" or "1h 02m"
+pub fn format_duration(time: Duration) -> String {
+    let secs = time.as_secs();
+    match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 => format!("{}m {:02}s", secs / 60, secs % 60),
+        _ => format!("{}h {:02}m", secs / 3600, secs % 3600 / 60),
+    }
+}
+
+/// Plain-text summary printed after the session, `None` if nothing was recorded
+pub fn session_summary(field: &Field) -> Option<String> {
+    if field.dwell.is_empty() {
+        return None;
+    }
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "Session summary: {} agents, {} connections",
+        field.agents.len(),
+        field.contacts.values().map(|c| c.count as u64).sum::<u64>()
+    );
+    let _ = writeln!(out);
+    let _ = writeln!(out, "Time near each zone:");
+    for zone in zone_dwell(field) {
+        let _ = write!(
+            out,
+            "  {:<20} {:>8}  {:>3.0}%",
+            zone.label,
+            format_duration(zone.time),
+            zone.share * 100.0
+        );
+        if let Some((agent, time)) = zone.top_agent {
+            let _ = write!(out, "  most: {} ({})", agent, format_duration(time));
+        }
+        let _ = writeln!(out);
+    }
+    Some(out)
+}
+
+/// Overlay with agent and connection counts and per-zone dwell time
+pub struct StatsOverlay<'a> {
+    field: &'a Field,
+}
+
+impl<'a> StatsOverlay<'a> {
+    pub fn new(field: &'a Field) -> Self {
+        Self { field }
+    }
+}
+
+impl Widget for StatsOverlay<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let zones = zone_dwell(self.field);
+        let width = area.width.saturating_sub(4).min(64);
+        let height = (zones.len().max(1) as u16 + 7).min(area.height.saturating_sub(2));
+        if width < 30 || height < 8 {
+            return;
+        }
+        let area = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        );
+
+        let border = Style::default().fg(Color::Rgb(100, 200, 150));
+        let background = Style::default().bg(Color::Rgb(35, 35, 45));
+        for y in area.y..area.y + area.height {
+            for x in area.x..area.x + area.width {
+                let ch = match (
+                    x == area.x,
+                    x == area.x + area.width - 1,
+                    y == area.y,
+                    y == area.y + area.height - 1,
+                ) {
+                    (true, _, true, _) => '╭',
+                    (_, true, true, _) => '╮',
+                    (true, _, _, true) => '╰',
+                    (_, true, _, true) => '╯',
+                    (true, _, _, _) | (_, true, _, _) => '│',
+                    (_, _, true, _) | (_, _, _, true) => '─',
+                    _ => ' ',
+                };
+                buf[(x, y)].set_char(ch).set_style(background.patch(border));
+            }
+        }
+
+        let x = area.x + 2;
+        let inner_width = area.width - 4;
+        let text = Style::default().fg(Color::Rgb(220, 220, 230));
+        let subtle = Style::default().fg(Color::Rgb(120, 120, 130));
+        draw_text(buf, x, area.y, inner_width, " Session stats ", border.add_modifier(Modifier::BOLD));
+
+        let connections: u64 = self.field.contacts.values().map(|c| c.count as u64).sum();
+        let counts = format!("{} agents  {} connections", self.field.agents.len(), connections);
+        draw_text(buf, x, area.y + 1, inner_width, &counts, text);
+        draw_text(buf, x, area.y + 3, inner_width, "Time near each zone", text.add_modifier(Modifier::BOLD));
+
+        if zones.is_empty() {
+            draw_text(buf, x, area.y + 4, inner_width, "No landmarks yet", subtle);
+        }
+
+        // label, time, share, bar, top agent
+        let rows = (area.height - 7) as usize;
+        for (row, zone) in zones.iter().take(rows).enumerate() {
+            let y = area.y + 4 + row as u16;
+            let line = format!(
+                "{:<16} {:>7} {:>3.0}% ",
+                zone.label.chars().take(16).collect::<String>(),
+                format_duration(zone.time),
+                zone.share * 100.0
+            );
+            draw_text(buf, x, y, inner_width, &line, text);
+
+            let bar_x = x + line.chars().count() as u16;
+            let bar_len = (zone.share * 10.0).round() as usize;
+            let bar: String = "█".repeat(bar_len);
+            let bar_style = Style::default().fg(Color::Rgb(100, 200, 150));
+            draw_text(buf, bar_x, y, (x + inner_width).saturating_sub(bar_x), &bar, bar_style);
+
+            if let Some((ref agent, _)) = zone.top_agent {
+                let agent_x = bar_x + 11;
+                draw_text(buf, agent_x, y, (x + inner_width).saturating_sub(agent_x), agent, subtle);
+            }
+        }
+
+        draw_text(buf, x, area.y + area.height - 2, inner_width, "s close", subtle);
+    }
+}
+
+/// Write `text` at (x, y), truncated to `max_width` characters
+fn draw_text(buf: &mut Buffer, x: u16, y: u16, max_width: u16, text: &str, style: Style) {
+    for (cx, ch) in (x..).zip(text.chars().take(max_width as usize)) {
+        buf[(cx, y)].set_char(ch).set_style(style);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hive_core::event::{HiveEvent, Landmark};
+
+    #[test]
+    fn test_summary_lists_zones_by_time() {
+        let mut field = Field::new();
+        assert_eq!(session_summary(&field), None);
+
+        for (id, label) in [("data", "Database"), ("auth", "Authentication")] {
+            field.process_event(&HiveEvent::Landmark(Landmark {
+                id: id.to_string(),
+                label: label.to_string(),
+                keywords: Vec::new(),
+                timestamp: 0,
+            }));
+        }
+        field.dwell.record(&"atlas".into(), "data", Duration::from_secs(90));
+        field.dwell.record(&"nova".into(), "auth", Duration::from_secs(30));
+
+        let summary = session_summary(&field).unwrap();
+        let database = summary.find("Database").unwrap();
+        assert!(database < summary.find("Authentication").unwrap());
+        assert!(summary.contains("1m 30s   75%  most: atlas (1m 30s)"), "{}", summary);
+
+        let area = Rect::new(0, 0, 80, 20);
+        let mut buf = Buffer::empty(area);
+        StatsOverlay::new(&field).render(area, &mut buf);
+        let content: String = buf.content().iter().map(|c| c.symbol()).collect();
+        assert!(content.contains("Time near each zone"));
+    }
+}
@@ -0,0 +1,82 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    widgets::Widget,
+};
+
+use hive_core::state::convergence::{Convergence, RADIUS};
+
+use super::colors::dim_color;
+use super::transform::FieldTransform;
+
+/// Seconds for a ripple to travel from the center to the halo
+const RIPPLE_PERIOD: f32 = 1.5;
+
+/// Halo color
+const SWARM_COLOR: Color = Color::Rgb(255, 200, 100);
+
+/// Widget drawing a halo around a swarm convergence, with a ripple running
+/// outwards from its center
+pub struct SwarmHaloWidget<'a> {
+    convergence: &'a Convergence,
+    opacity: f32,
+}
+
+impl<'a> SwarmHaloWidget<'a> {
+    pub fn new(convergence: &'a Convergence) -> Self {
+        Self {
+            convergence,
+            opacity: 1.0,
+        }
+    }
+
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity;
+        self
+    }
+}
+
+impl Widget for SwarmHaloWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let transform = FieldTransform::new(area);
+        if transform.inner().is_empty() {
+            return;
+        }
+
+        let phase = (self.convergence.since.elapsed().as_secs_f32() / RIPPLE_PERIOD).fract();
+
+        // Faint halo at the edge of the swarm, ripple fading as it grows
+        let halo = Style::default().fg(dim_color(SWARM_COLOR, 0.35 * self.opacity));
+        let ripple = Style::default().fg(dim_color(SWARM_COLOR, (1.0 - phase) * 0.7 * self.opacity));
+        draw_ring(buf, &transform, self.convergence, RADIUS, "·", halo);
+        draw_ring(buf, &transform, self.convergence, RADIUS * phase, "∘", ripple);
+    }
+}
+
+/// Draw a ring of `symbol` at `radius` (field units) around the swarm,
+/// over empty cells only
+fn draw_ring(buf: &mut Buffer, transform: &FieldTransform, convergence: &Convergence, radius: f32, symbol: &str, style: Style) {
+    let inner = transform.inner();
+    let (scale_x, scale_y) = transform.scale();
+    let cx = convergence.center.x * scale_x;
+    let cy = convergence.center.y * scale_y;
+    let rx = radius * scale_x;
+    let ry = radius * scale_y;
+
+    // Enough steps to leave no gaps in the ring
+    let steps = ((rx.max(ry) * 8.0) as usize).max(8);
+    for i in 0..steps {
+        let angle = i as f32 / steps as f32 * std::f32::consts::TAU;
+        let x = (cx + angle.cos() * rx).round();
+        let y = (cy + angle.sin() * ry).round();
+        if x < 0.0 || y < 0.0 || x >= inner.width as f32 || y >= inner.height as f32 {
+            continue;
+        }
+
+        let cell = &mut buf[(inner.x + x as u16, inner.y + y as u16)];
+        if cell.symbol() == " " {
+            cell.set_symbol(symbol).set_style(style);
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//! Transient toast notifications.
+//!
+//! Toasts stack in the top-right corner of the field and disappear after a
+//! few seconds. They are used for alerts that should catch the eye without
+//! taking over the screen.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::Widget,
+};
+
+/// How long a toast stays on screen
+const TOAST_DURATION: Duration = Duration::from_secs(5);
+
+/// Maximum number of toasts shown at once (older ones are dropped)
+const MAX_TOASTS: usize = 3;
+
+/// Maximum toast width in cells, including padding
+const MAX_TOAST_WIDTH: u16 = 48;
+
+/// A single toast message
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub message: String,
+    pub color: Color,
+    pub created: Instant,
+}
+
+/// Queue of currently visible toasts
+#[derive(Debug, Default)]
+pub struct Toasts {
+    items: VecDeque<Toast>,
+}
+
+impl Toasts {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show a new toast, dropping the oldest if the stack is full.
+    pub fn push(&mut self, message: impl Into<String>, color: Color) {
+        if self.items.len() >= MAX_TOASTS {
+            self.items.pop_front();
+        }
+        self.items.push_back(Toast {
+            message: message.into(),
+            color,
+            created: Instant::now(),
+        });
+    }
+
+    /// Remove toasts that have been shown long enough.
+    pub fn expire(&mut self, now: Instant) {
+        self.items
+            .retain(|toast| now.duration_since(toast.created) < TOAST_DURATION);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Toast> {
+        self.items.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+/// Widget that draws the toast stack in the top-right of an area
+pub struct ToastWidget<'a> {
+    toasts: &'a Toasts,
+}
+
+impl<'a> ToastWidget<'a> {
+    pub fn new(toasts: &'a Toasts) -> Self {
+        Self { toasts }
+    }
+}
+
+impl Widget for ToastWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        // Leave the field border alone
+        let right = area.x + area.width.saturating_sub(1);
+        let bottom = area.y + area.height.saturating_sub(1);
+
+        for (y, toast) in (area.y + 1..).zip(self.toasts.iter()) {
+            if y >= bottom {
+                break;
+            }
+
+            let text = format!(" ! {} ", toast.message);
+            let max_width = MAX_TOAST_WIDTH.min(area.width.saturating_sub(2)) as usize;
+            let text: String = text.chars().take(max_width).collect();
+            let width = text.chars().count() as u16;
+            let x = right.saturating_sub(width);
+
+            let style = Style::default()
+                .fg(Color::Rgb(20, 20, 25))
+                .bg(toast.color)
+                .add_modifier(Modifier::BOLD);
+            for (i, ch) in text.chars().enumerate() {
+                let cx = x + i as u16;
+                if cx < buf.area.width && y < buf.area.height {
+                    buf[(cx, y)].set_char(ch).set_style(style);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toasts_cap_and_expire() {
+        let mut toasts = Toasts::new();
+        for i in 0..5 {
+            toasts.push(format!("toast {}", i), Color::Red);
+        }
+        let messages: Vec<_> = toasts.iter().map(|t| t.message.as_str()).collect();
+        assert_eq!(messages, vec!["toast 2", "toast 3", "toast 4"]);
+
+        toasts.expire(Instant::now() + TOAST_DURATION);
+        assert!(toasts.is_empty());
+    }
+}
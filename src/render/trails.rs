@@ -6,9 +6,10 @@ use ratatui::{
 };
 use std::time::{Duration, Instant};
 
-use crate::state::Agent;
+use hive_core::state::Agent;
 
-use super::colors::{dim_color, get_agent_color};
+use super::colors::{color_for_agent, dim_color};
+use super::transform::FieldTransform;
 
 /// Trail symbols from newest to oldest
 const TRAIL_SYMBOLS: [&str; 5] = ["•", "∙", "·", "˙", " "];
@@ -16,25 +17,41 @@ const TRAIL_SYMBOLS: [&str; 5] = ["•", "∙", "·", "˙", " "];
 /// Maximum age for trail points before they're invisible
 const MAX_TRAIL_AGE: Duration = Duration::from_secs(5);
 
+/// Symbol marking where an agent left after a focus change
+const WAYPOINT_SYMBOL: &str = "◇";
+
+/// Longest focus keyword shown next to a waypoint
+const WAYPOINT_LABEL_LEN: usize = 10;
+
 /// Widget for rendering agent trails
 pub struct TrailsWidget<'a> {
     agents: Vec<&'a Agent>,
+    /// Brightness factor applied on top of the age fade
+    opacity: f32,
 }
 
 impl<'a> TrailsWidget<'a> {
     pub fn new(agents: Vec<&'a Agent>) -> Self {
-        Self { agents }
+        Self {
+            agents,
+            opacity: 1.0,
+        }
+    }
+
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity;
+        self
     }
 }
 
 impl Widget for TrailsWidget<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let inner_width = area.width.saturating_sub(2);
-        let inner_height = area.height.saturating_sub(2);
+        let transform = FieldTransform::new(area);
+        let inner = transform.inner();
         let now = Instant::now();
 
         for agent in &self.agents {
-            let base_color = get_agent_color(agent.color_index);
+            let base_color = color_for_agent(agent);
 
             for point in &agent.trail {
                 let age = now.duration_since(point.timestamp);
@@ -50,19 +67,12 @@ impl Widget for TrailsWidget<'_> {
                     continue;
                 }
 
-                let (x, y) = point.position.to_terminal(inner_width, inner_height);
-                let draw_x = area.x + 1 + x;
-                let draw_y = area.y + 1 + y;
-
-                if draw_x <= area.x || draw_x >= area.x + area.width - 1 {
+                let Some((draw_x, draw_y)) = transform.cell(&point.position) else {
                     continue;
-                }
-                if draw_y <= area.y || draw_y >= area.y + area.height - 1 {
-                    continue;
-                }
+                };
 
                 // Dim color based on age
-                let color = dim_color(base_color, age_factor * 0.5);
+                let color = dim_color(base_color, age_factor * 0.5 * self.opacity);
                 let style = Style::default().fg(color);
 
                 let cell = &mut buf[(draw_x, draw_y)];
@@ -71,6 +81,26 @@ impl Widget for TrailsWidget<'_> {
                     cell.set_symbol(symbol).set_style(style);
                 }
             }
+
+            // Waypoints stay until pushed out by newer ones
+            let style = Style::default().fg(dim_color(base_color, 0.45 * self.opacity));
+            for waypoint in &agent.waypoints {
+                let Some((draw_x, draw_y)) = transform.cell(&waypoint.position) else {
+                    continue;
+                };
+
+                buf[(draw_x, draw_y)].set_symbol(WAYPOINT_SYMBOL).set_style(style);
+
+                // Old focus keyword to the right, only over empty cells
+                let label = waypoint.focus.chars().take(WAYPOINT_LABEL_LEN);
+                for (lx, ch) in (draw_x + 1..inner.right()).zip(label) {
+                    let cell = &mut buf[(lx, draw_y)];
+                    if cell.symbol() != " " {
+                        break;
+                    }
+                    cell.set_char(ch).set_style(style);
+                }
+            }
         }
     }
 }
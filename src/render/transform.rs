@@ -0,0 +1,81 @@
+//! Where field positions land on screen.
+//!
+//! Every spatial layer (heat, trails, connections, landmarks, halos, agents)
+//! maps positions through one [`FieldTransform`] built from the field's area,
+//! and the heat map is sampled through its inverse, so the layers stay lined
+//! up with each other at any terminal size.
+
+use ratatui::layout::{Margin, Rect};
+
+use hive_core::positioning::Position;
+
+/// Maps normalized field positions to cells of the field area and back
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldTransform {
+    /// The field inside its border
+    inner: Rect,
+}
+
+impl FieldTransform {
+    /// Transform for a field drawn in `area`, border included
+    pub fn new(area: Rect) -> Self {
+        Self {
+            inner: area.inner(Margin::new(1, 1)),
+        }
+    }
+
+    /// Cells positions are drawn in
+    pub fn inner(&self) -> Rect {
+        self.inner
+    }
+
+    /// Cell `position` is drawn in, or `None` when the field has no room
+    pub fn cell(&self, position: &Position) -> Option<(u16, u16)> {
+        if self.inner.is_empty() {
+            return None;
+        }
+        let (x, y) = position.to_terminal(self.inner.width, self.inner.height);
+        Some((self.inner.x + x, self.inner.y + y))
+    }
+
+    /// Field position at (`col`, `row`) of the inner area, in cells. Whole
+    /// numbers are cell centres, so the position of a cell maps back to it.
+    pub fn position(&self, col: f32, row: f32) -> Position {
+        let (scale_x, scale_y) = self.scale();
+        Position::new(col / scale_x.max(1.0), row / scale_y.max(1.0))
+    }
+
+    /// Cells per unit of field distance, across and down
+    pub fn scale(&self) -> (f32, f32) {
+        (
+            self.inner.width.saturating_sub(1) as f32,
+            self.inner.height.saturating_sub(1) as f32,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cells_and_positions_round_trip() {
+        for area in [Rect::new(0, 0, 80, 24), Rect::new(10, 3, 37, 11), Rect::new(0, 0, 200, 60)] {
+            let transform = FieldTransform::new(area);
+            let inner = transform.inner();
+            for row in 0..inner.height {
+                for col in 0..inner.width {
+                    let position = transform.position(col as f32, row as f32);
+                    assert_eq!(transform.cell(&position), Some((inner.x + col, inner.y + row)));
+                }
+            }
+            // Corners of the field are the corners of the inner area
+            assert_eq!(transform.cell(&Position::new(0.0, 0.0)), Some((inner.x, inner.y)));
+            assert_eq!(
+                transform.cell(&Position::new(1.0, 1.0)),
+                Some((inner.right() - 1, inner.bottom() - 1))
+            );
+        }
+        assert_eq!(FieldTransform::new(Rect::new(0, 0, 2, 2)).cell(&Position::new(0.5, 0.5)), None);
+    }
+}
@@ -5,18 +5,92 @@ use ratatui::{
     widgets::Widget,
 };
 
-use crate::state::{Agent, History};
-use super::DisplayMode;
+use std::time::Duration;
+
+use chrono::{Local, TimeZone};
+use serde::Deserialize;
+
+use hive_core::event::AgentStatus;
+use hive_core::state::{Agent, History};
+use super::activity_log::format_age;
+use super::colors::STATUS_COLORS;
+use super::{DisplayMode, HeatRange};
+
+/// Statuses counted by the agents readout, with their abbreviations
+const STATUS_ABBREVIATIONS: [(AgentStatus, &str); 5] = [
+    (AgentStatus::Active, "act"),
+    (AgentStatus::Thinking, "thk"),
+    (AgentStatus::Waiting, "wait"),
+    (AgentStatus::Error, "err"),
+    (AgentStatus::Idle, "idle"),
+];
+
+/// Readout on the left of the status bar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatusItem {
+    /// Total agents and how many have each status
+    Agents,
+    /// Playback speed
+    Speed,
+    /// Frames per second
+    Fps,
+    /// Incoming events per second
+    EventRate,
+    /// Time since the last incoming event
+    LastEvent,
+    /// Agent updates dropped by `[sampling]`
+    Sampled,
+}
+
+/// `[status_bar]` section of the config file: the readouts of each display mode
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StatusBarConfig {
+    pub minimal: Vec<StatusItem>,
+    pub standard: Vec<StatusItem>,
+    pub debug: Vec<StatusItem>,
+}
+
+impl Default for StatusBarConfig {
+    fn default() -> Self {
+        use StatusItem::*;
+        Self {
+            minimal: vec![Agents, Speed],
+            standard: vec![Agents, Speed, EventRate, LastEvent],
+            debug: vec![Agents, Speed, Fps, EventRate, LastEvent, Sampled],
+        }
+    }
+}
+
+impl StatusBarConfig {
+    /// Readouts shown in `mode`
+    pub fn items(&self, mode: DisplayMode) -> &[StatusItem] {
+        match mode {
+            DisplayMode::Minimal => &self.minimal,
+            DisplayMode::Standard => &self.standard,
+            DisplayMode::Debug => &self.debug,
+        }
+    }
+}
 
 /// Status bar at the bottom of the screen
 pub struct StatusBar<'a> {
     agents: &'a [&'a Agent],
+    items: &'a [StatusItem],
     paused: bool,
     playback_speed: f32,
     replay_mode: bool,
     replay_position: f32,
     fps: u32,
+    event_rate: f32,
+    last_event_age: Option<Duration>,
+    sampled: Option<usize>,
     display_mode: DisplayMode,
+    /// Name of the active custom display mode, shown instead of `display_mode`
+    mode_name: Option<&'a str>,
+    /// Size of the swarm convergence, if one is going on
+    swarm: Option<usize>,
     /// Optional filter text to display when filtering is active
     filter_text: Option<&'a str>,
 }
@@ -25,16 +99,53 @@ impl<'a> StatusBar<'a> {
     pub fn new(agents: &'a [&'a Agent]) -> Self {
         Self {
             agents,
+            items: &[StatusItem::Agents, StatusItem::Speed],
             paused: false,
             playback_speed: 1.0,
             replay_mode: false,
             replay_position: 0.0,
             fps: 30,
+            event_rate: 0.0,
+            last_event_age: None,
+            sampled: None,
             display_mode: DisplayMode::default(),
+            mode_name: None,
+            swarm: None,
             filter_text: None,
         }
     }
 
+    /// Show a custom display mode's name in place of the built-in mode
+    pub fn mode_name(mut self, name: Option<&'a str>) -> Self {
+        self.mode_name = name;
+        self
+    }
+
+    /// Show the SWARM badge for a convergence of `agents` agents
+    pub fn swarm(mut self, agents: Option<usize>) -> Self {
+        self.swarm = agents;
+        self
+    }
+
+    /// Readouts to show, in order
+    pub fn items(mut self, items: &'a [StatusItem]) -> Self {
+        self.items = items;
+        self
+    }
+
+    /// Incoming events per second and time since the last one
+    pub fn telemetry(mut self, event_rate: f32, last_event_age: Option<Duration>) -> Self {
+        self.event_rate = event_rate;
+        self.last_event_age = last_event_age;
+        self
+    }
+
+    /// Agent updates dropped by sampling; the readout is left out for `None`
+    pub fn sampled(mut self, sampled: Option<usize>) -> Self {
+        self.sampled = sampled;
+        self
+    }
+
     /// Set the filter text to display when filtering is active.
     pub fn filter_text(mut self, filter: Option<&'a str>) -> Self {
         self.filter_text = filter;
@@ -68,6 +179,31 @@ impl<'a> StatusBar<'a> {
     }
 }
 
+impl StatusBar<'_> {
+    /// Draw the agent count broken down by status, each in its status color,
+    /// returning where the next readout starts
+    fn render_agent_counts(&self, mut x: u16, area: Rect, buf: &mut Buffer, style: Style) -> u16 {
+        let mut segments = vec![(format!("Agents: {}", self.agents.len()), style)];
+        for (status, short) in STATUS_ABBREVIATIONS {
+            let count = self.agents.iter().filter(|agent| agent.status == status).count();
+            if count > 0 {
+                segments.push((format!(" {}{}", count, short), Style::default().fg(STATUS_COLORS.get(status))));
+            }
+        }
+
+        for (text, style) in segments {
+            for ch in text.chars() {
+                if x >= area.x + area.width - 1 {
+                    break;
+                }
+                buf[(x, area.y)].set_char(ch).set_style(style);
+                x += 1;
+            }
+        }
+        x + 2
+    }
+}
+
 impl Widget for StatusBar<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         // Background
@@ -91,28 +227,34 @@ impl Widget for StatusBar<'_> {
         }
         x += 2;
 
-        // Agent count
-        let active_count = self.agents.iter().filter(|a| a.intensity > 0.1).count();
-        let count_text = format!("Agents: {}/{}", active_count, self.agents.len());
-        for ch in count_text.chars() {
-            if x >= area.x + area.width - 1 {
-                break;
-            }
-            buf[(x, area.y)].set_char(ch).set_style(value_style);
-            x += 1;
-        }
-        x += 2;
-
-        // Speed indicator
-        let speed_text = format!("Speed: {:.1}x", self.playback_speed);
-        for ch in speed_text.chars() {
-            if x >= area.x + area.width - 1 {
-                break;
+        // Configured readouts
+        for item in self.items {
+            let (text, style) = match item {
+                StatusItem::Agents => {
+                    x = self.render_agent_counts(x, area, buf, value_style);
+                    continue;
+                }
+                StatusItem::Speed => (format!("Speed: {:.1}x", self.playback_speed), label_style),
+                StatusItem::Fps => (format!("FPS: {}", self.fps), label_style),
+                StatusItem::EventRate => (format!("{:.1} ev/s", self.event_rate), value_style),
+                StatusItem::LastEvent => match self.last_event_age {
+                    Some(age) => (format!("Last: {}", format_age(age.as_secs())), label_style),
+                    None => ("No events yet".to_string(), label_style),
+                },
+                StatusItem::Sampled => match self.sampled {
+                    Some(dropped) => (format!("Sampled: {}", dropped), label_style),
+                    None => continue,
+                },
+            };
+            for ch in text.chars() {
+                if x >= area.x + area.width - 1 {
+                    break;
+                }
+                buf[(x, area.y)].set_char(ch).set_style(style);
+                x += 1;
             }
-            buf[(x, area.y)].set_char(ch).set_style(label_style);
-            x += 1;
+            x += 2;
         }
-        x += 2;
 
         // Pause indicator
         if self.paused {
@@ -145,13 +287,31 @@ impl Widget for StatusBar<'_> {
             x += 2;
         }
 
+        // Swarm convergence badge
+        if let Some(agents) = self.swarm {
+            let swarm_style = Style::default()
+                .fg(Color::Rgb(25, 25, 35))
+                .bg(Color::Rgb(255, 200, 100))
+                .add_modifier(Modifier::BOLD);
+            let swarm_text = format!(" SWARM {} ", agents);
+            for ch in swarm_text.chars() {
+                if x >= area.x + area.width - 1 {
+                    break;
+                }
+                buf[(x, area.y)].set_char(ch).set_style(swarm_style);
+                x += 1;
+            }
+            x += 2;
+        }
+
         // Display mode indicator
-        let mode_style = match self.display_mode {
-            DisplayMode::Minimal => Style::default().fg(Color::Rgb(150, 200, 255)),
-            DisplayMode::Standard => Style::default().fg(Color::Rgb(100, 200, 150)),
-            DisplayMode::Debug => Style::default().fg(Color::Rgb(255, 200, 100)),
+        let mode_style = match (self.mode_name, self.display_mode) {
+            (Some(_), _) => Style::default().fg(Color::Rgb(200, 150, 255)),
+            (None, DisplayMode::Minimal) => Style::default().fg(Color::Rgb(150, 200, 255)),
+            (None, DisplayMode::Standard) => Style::default().fg(Color::Rgb(100, 200, 150)),
+            (None, DisplayMode::Debug) => Style::default().fg(Color::Rgb(255, 200, 100)),
         };
-        let mode_text = format!("[{}]", self.display_mode.name());
+        let mode_text = format!("[{}]", self.mode_name.unwrap_or(self.display_mode.name()));
         for ch in mode_text.chars() {
             if x >= area.x + area.width - 1 {
                 break;
@@ -177,13 +337,12 @@ impl Widget for StatusBar<'_> {
         // Right-aligned help hint with mode key reminder
         let help_text = "m:mode ?:help";
         let help_x = area.x + area.width - help_text.len() as u16 - 1;
-        let mut hx = help_x;
-        for ch in help_text.chars() {
+        for (i, ch) in help_text.chars().enumerate() {
+            let hx = help_x + i as u16;
             if hx >= area.x + area.width - 1 {
                 break;
             }
             buf[(hx, area.y)].set_char(ch).set_style(label_style);
-            hx += 1;
         }
     }
 }
@@ -203,7 +362,7 @@ impl Widget for HelpOverlay {
 
         // Help box dimensions
         let box_width = 50u16;
-        let box_height = 18u16;
+        let box_height = 23u16;
         let box_x = area.x + (area.width.saturating_sub(box_width)) / 2;
         let box_y = area.y + (area.height.saturating_sub(box_height)) / 2;
 
@@ -263,18 +422,26 @@ impl Widget for HelpOverlay {
             ("Space", "Pause/Resume"),
             ("+/-", "Speed up/down"),
             ("r", "Toggle replay mode"),
-            ("←/→", "Seek backward/forward (replay)"),
+            ("←/→ PgUp/Dn", "Seek 5% / 25% (Shift+←/→: 1%)"),
             ("m", "Cycle display mode"),
-            ("1/2/3", "Minimal/Standard/Debug mode"),
+            ("1-3, 4-9", "Built-in / custom display mode"),
             ("h", "Toggle heat map"),
+            ("H", "Heat: intensity/presence/links"),
             ("t", "Toggle trails"),
             ("l", "Toggle landmarks"),
             ("c", "Clear heat map"),
+            ("e, :export", "Export heat map"),
+            ("g", "Connection matrix"),
+            ("s", "Session stats"),
+            ("v", "Live ghosts over replay"),
+            ("x", "Inject chaos (demo mode)"),
+            ("o, :open", "Open an events file"),
+            ("Tab", "Focus next pane (Esc: field)"),
             ("?", "Toggle this help"),
         ];
 
-        let mut y = box_y + 2;
-        for (key, desc) in controls {
+        for (row, (key, desc)) in controls.into_iter().enumerate() {
+            let y = box_y + 2 + row as u16;
             if y >= box_y + box_height - 1 {
                 break;
             }
@@ -298,8 +465,6 @@ impl Widget for HelpOverlay {
                 buf[(x, y)].set_char(ch).set_style(desc_style);
                 x += 1;
             }
-
-            y += 1;
         }
 
         // Footer
@@ -314,14 +479,91 @@ impl Widget for HelpOverlay {
     }
 }
 
+/// `:` command line drawn over the status bar
+pub struct CommandLine<'a> {
+    text: &'a str,
+}
+
+impl<'a> CommandLine<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self { text }
+    }
+}
+
+impl Widget for CommandLine<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+
+        let style = Style::default()
+            .fg(Color::Rgb(220, 220, 230))
+            .bg(Color::Rgb(25, 25, 35));
+        for x in area.x..area.x + area.width {
+            buf[(x, area.y)].set_char(' ').set_style(style);
+        }
+
+        // Keep the end of long input (and the cursor) visible
+        let line: Vec<char> = std::iter::once(':').chain(self.text.chars()).collect();
+        let visible = (area.width as usize).saturating_sub(1);
+        let skip = line.len().saturating_sub(visible);
+        let mut x = area.x;
+        for &ch in &line[skip..] {
+            buf[(x, area.y)].set_char(ch).set_style(style);
+            x += 1;
+        }
+        if x < area.x + area.width {
+            buf[(x, area.y)].set_char('█').set_style(style.fg(Color::Rgb(100, 200, 150)));
+        }
+    }
+}
+
 /// Replay timeline slider
 pub struct TimelineWidget<'a> {
     history: &'a History,
+    focused: bool,
+    heat_range: Option<HeatRange>,
+    /// Playback speed and replayed events per second
+    playback: Option<(f32, f32)>,
 }
 
 impl<'a> TimelineWidget<'a> {
     pub fn new(history: &'a History) -> Self {
-        Self { history }
+        Self {
+            history,
+            focused: false,
+            heat_range: None,
+            playback: None,
+        }
+    }
+
+    /// Show the playback speed and the rate events are replayed at
+    pub fn playback(mut self, speed: f32, events_per_sec: f32) -> Self {
+        self.playback = Some((speed, events_per_sec));
+        self
+    }
+
+    /// Highlight the timeline to show it has keyboard focus
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Mark the range the heat map is computed from
+    pub fn heat_range(mut self, range: Option<HeatRange>) -> Self {
+        self.heat_range = range;
+        self
+    }
+
+    /// Wall-clock time of the event at `position`, or its number when the
+    /// event carries no timestamp
+    fn clock_at(&self, position: f32) -> String {
+        let index = self.history.index_at(position);
+        let timestamp = self.history.events().get(index).map_or(0, |e| e.event.timestamp());
+        match Local.timestamp_opt(timestamp as i64, 0).single() {
+            Some(time) if timestamp > 0 => time.format("%H:%M:%S").to_string(),
+            _ => format!("#{}", index + 1),
+        }
     }
 }
 
@@ -336,6 +578,12 @@ impl Widget for TimelineWidget<'_> {
             buf[(x, area.y)].set_style(bg_style);
         }
 
+        // Focus marker
+        if self.focused {
+            let marker_style = Style::default().fg(Color::Rgb(100, 200, 150));
+            buf[(area.x, area.y)].set_char('▶').set_style(marker_style);
+        }
+
         // Track
         let track_style = Style::default().fg(Color::Rgb(60, 60, 70));
         let filled_style = Style::default().fg(Color::Rgb(100, 200, 150));
@@ -361,6 +609,40 @@ impl Widget for TimelineWidget<'_> {
             buf[(x, area.y)].set_char(ch).set_style(style);
         }
 
+        // Heat map range
+        let range_style = Style::default().fg(Color::Rgb(230, 140, 60));
+        if let Some(range) = self.heat_range {
+            let column = |position: f32| track_start + (position * track_width as f32) as u16;
+            let start_x = column(range.start).min(track_end - 1);
+            let label = match range.end {
+                Some(end) => {
+                    for x in start_x..=column(end).min(track_end - 1) {
+                        buf[(x, area.y)].set_char('━').set_style(range_style);
+                    }
+                    format!(" heat {}–{} ", self.clock_at(range.start), self.clock_at(end))
+                }
+                None => {
+                    buf[(start_x, area.y)].set_char('[').set_style(range_style);
+                    format!(" heat from {} ", self.clock_at(range.start))
+                }
+            };
+            for (x, ch) in (track_start + 1..track_end).zip(label.chars()) {
+                buf[(x, area.y)].set_char(ch).set_style(range_style);
+            }
+        }
+
+        // Playback speed and replay rate at the right end of the track
+        if let Some((speed, rate)) = self.playback {
+            let label = format!(" {}x · {:.1} ev/s ", speed, rate);
+            let width = label.chars().count() as u16;
+            if width + 2 < track_width {
+                let rate_style = Style::default().fg(Color::Rgb(140, 140, 160)).bg(Color::Rgb(30, 30, 40));
+                for (x, ch) in (track_end - width..track_end).zip(label.chars()) {
+                    buf[(x, area.y)].set_char(ch).set_style(rate_style);
+                }
+            }
+        }
+
         // Playhead
         let playhead_x = track_start + filled_width;
         if playhead_x < track_end {
@@ -372,13 +654,12 @@ impl Widget for TimelineWidget<'_> {
         // Event count
         let count_text = format!(" {} events", self.history.len());
         let count_style = Style::default().fg(Color::Rgb(100, 100, 120));
-        let mut x = track_end + 1;
-        for ch in count_text.chars() {
+        for (i, ch) in count_text.chars().enumerate() {
+            let x = track_end + 1 + i as u16;
             if x >= area.x + area.width {
                 break;
             }
             buf[(x, area.y)].set_char(ch).set_style(count_style);
-            x += 1;
         }
     }
 }
@@ -484,7 +765,122 @@ impl Widget for EmptyStateWidget {
     }
 }
 
+/// Wall-clock time of the events being replayed, drawn into the bottom
+/// border of the field so it can be matched against other logs
+pub struct ReplayClock<'a> {
+    history: &'a History,
+}
+
+impl<'a> ReplayClock<'a> {
+    pub fn new(history: &'a History) -> Self {
+        Self { history }
+    }
+}
+
+impl Widget for ReplayClock<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let Some(time) = self
+            .history
+            .current_timestamp()
+            .and_then(|timestamp| Local.timestamp_opt(timestamp as i64, 0).single())
+        else {
+            return;
+        };
+
+        let text = format!(" events at {} ", time.format("%Y-%m-%d %H:%M:%S"));
+        let width = text.chars().count() as u16;
+        if area.height == 0 || width + 4 > area.width {
+            return;
+        }
+
+        let style = Style::default()
+            .fg(Color::Rgb(200, 200, 140))
+            .bg(Color::Rgb(30, 30, 40));
+        let x = area.x + area.width - width - 2;
+        let y = area.y + area.height - 1;
+        for (cx, ch) in (x..).zip(text.chars()) {
+            buf[(cx, y)].set_char(ch).set_style(style);
+        }
+    }
+}
+
+/// Progress of the initial load of the events files, shown in place of the
+/// field until their existing events are in
+pub struct LoadingWidget<'a> {
+    title: &'a str,
+    detail: &'a str,
+    progress: f32,
+}
+
+impl<'a> LoadingWidget<'a> {
+    pub fn new(title: &'a str, detail: &'a str, progress: f32) -> Self {
+        Self { title, detail, progress }
+    }
+}
+
+impl Widget for LoadingWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.height < 4 || area.width < 20 {
+            return;
+        }
+
+        let bar_width = (area.width - 4).min(40);
+        let filled = (self.progress.clamp(0.0, 1.0) * bar_width as f32) as u16;
+        let bar: String = (0..bar_width).map(|x| if x < filled { '█' } else { '░' }).collect();
+        let lines = [
+            (self.title, Style::default().fg(Color::Rgb(150, 150, 160))),
+            (bar.as_str(), Style::default().fg(Color::Rgb(100, 200, 150))),
+            (self.detail, Style::default().fg(Color::Rgb(100, 100, 110))),
+            ("Esc skips to new events", Style::default().fg(Color::Rgb(100, 100, 110))),
+        ];
+
+        let top = area.y + area.height.saturating_sub(lines.len() as u16) / 2;
+        for (y, (text, style)) in (top..area.y + area.height).zip(lines) {
+            let x = area.x + area.width.saturating_sub(text.chars().count() as u16) / 2;
+            for (cx, ch) in (x..area.x + area.width).zip(text.chars()) {
+                buf[(cx, y)].set_char(ch).set_style(style);
+            }
+        }
+    }
+}
+
+/// Shown in place of the field when the terminal is smaller than `need`
+/// (columns, rows).
+pub struct TooSmallWidget {
+    need: (u16, u16),
+}
+
+impl TooSmallWidget {
+    pub fn new(need: (u16, u16)) -> Self {
+        Self { need }
+    }
+}
+
+impl Widget for TooSmallWidget {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+
+        let message = format!("terminal too small (need {}x{})", self.need.0, self.need.1);
+        let current = format!("currently {}x{}", area.width, area.height);
+        let lines = [
+            (message, Style::default().fg(Color::Rgb(240, 190, 90))),
+            (current, Style::default().fg(Color::Rgb(100, 100, 110))),
+        ];
+
+        let top = area.y + area.height.saturating_sub(lines.len() as u16) / 2;
+        for (y, (text, style)) in (top..area.y + area.height).zip(lines) {
+            let x = area.x + area.width.saturating_sub(text.chars().count() as u16) / 2;
+            for (cx, ch) in (x..area.x + area.width).zip(text.chars()) {
+                buf[(cx, y)].set_char(ch).set_style(style);
+            }
+        }
+    }
+}
+
 /// Render the UI elements
+#[allow(clippy::too_many_arguments)]
 pub fn render_ui(
     area: Rect,
     buf: &mut Buffer,
@@ -0,0 +1,183 @@
+//! Session metrics exported as JSON.
+//!
+//! `S` or `:stats [path]` writes what Hive has aggregated so far (per-agent
+//! figures, time and agents per zone, and the connection matrix) to a JSON
+//! file for analysis outside Hive.
+
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+use std::time::Instant;
+
+use serde::Serialize;
+
+use hive_core::event::{AgentId, AgentStatus, Keyword};
+use hive_core::state::Field;
+
+/// Aggregated metrics of the field
+#[derive(Debug, Clone, Serialize)]
+pub struct StatsReport {
+    /// Unix time of the export in seconds
+    pub generated_at: i64,
+    pub agents: Vec<AgentStats>,
+    pub zones: Vec<ZoneStats>,
+    pub connections: Vec<ConnectionStats>,
+}
+
+/// Figures of one agent
+#[derive(Debug, Clone, Serialize)]
+pub struct AgentStats {
+    pub id: AgentId,
+    pub name: String,
+    pub status: AgentStatus,
+    pub intensity: f32,
+    pub focus: Vec<Keyword>,
+    /// Intensity-seconds over the leaderboard window
+    pub recent_work: f32,
+    /// Seconds spent nearest to each landmark, by landmark ID
+    pub zone_seconds: BTreeMap<String, f64>,
+}
+
+/// Time spent in one landmark's zone and who is there now
+#[derive(Debug, Clone, Serialize)]
+pub struct ZoneStats {
+    pub id: String,
+    pub label: String,
+    /// Seconds all agents together spent nearest to it
+    pub seconds: f64,
+    /// Agents in the zone at the time of the export
+    pub agents: Vec<AgentId>,
+}
+
+/// Connections from one agent to another
+#[derive(Debug, Clone, Serialize)]
+pub struct ConnectionStats {
+    pub from: AgentId,
+    pub to: AgentId,
+    pub count: u32,
+    pub last_label: String,
+    /// Seconds since the last of them
+    pub seconds_since_last: f64,
+}
+
+impl StatsReport {
+    /// The metrics of `field` as of now
+    pub fn capture(field: &Field) -> Self {
+        let now = Instant::now();
+        let recent: BTreeMap<_, _> = field.activity.ranking().into_iter().collect();
+
+        let agents = field
+            .agents_sorted()
+            .into_iter()
+            .map(|agent| AgentStats {
+                id: agent.id.clone(),
+                name: agent.display_name().to_string(),
+                status: agent.status.clone(),
+                intensity: agent.intensity,
+                focus: agent.focus.clone(),
+                recent_work: recent.get(&agent.id).copied().unwrap_or(0.0),
+                zone_seconds: field
+                    .dwell
+                    .for_agent(&agent.id)
+                    .into_iter()
+                    .flatten()
+                    .map(|(landmark, time)| (landmark.clone(), time.as_secs_f64()))
+                    .collect(),
+            })
+            .collect();
+
+        let mut occupants: BTreeMap<&str, Vec<AgentId>> = BTreeMap::new();
+        for agent in field.agents_sorted() {
+            if let Some(landmark) = field.nearest_landmark(&agent.position) {
+                occupants.entry(landmark.id.as_str()).or_default().push(agent.id.clone());
+            }
+        }
+        let dwell: BTreeMap<_, _> = field.dwell.zone_totals().into_iter().collect();
+        let mut zones: Vec<_> = field
+            .landmarks
+            .values()
+            .map(|landmark| ZoneStats {
+                id: landmark.id.clone(),
+                label: landmark.label.clone(),
+                seconds: dwell.get(&landmark.id).map_or(0.0, |time| time.as_secs_f64()),
+                agents: occupants.remove(landmark.id.as_str()).unwrap_or_default(),
+            })
+            .collect();
+        zones.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let mut connections: Vec<_> = field
+            .contacts
+            .iter()
+            .map(|((from, to), stats)| ConnectionStats {
+                from: from.clone(),
+                to: to.clone(),
+                count: stats.count,
+                last_label: stats.last_label.clone(),
+                seconds_since_last: now.duration_since(stats.last_contact).as_secs_f64(),
+            })
+            .collect();
+        connections.sort_by(|a, b| (&a.from, &a.to).cmp(&(&b.from, &b.to)));
+
+        Self {
+            generated_at: chrono::Utc::now().timestamp(),
+            agents,
+            zones,
+            connections,
+        }
+    }
+
+    /// Write the report to `path` as pretty-printed JSON
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let contents = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        std::fs::write(path, contents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hive_core::event::{AgentUpdate, Connection, HiveEvent, Landmark};
+
+    #[test]
+    fn test_report_lists_agents_zones_and_connections() {
+        let mut field = Field::new();
+        field.process_event(&HiveEvent::Landmark(Landmark {
+            id: "auth".to_string(),
+            label: "Authentication".to_string(),
+            keywords: vec!["jwt".to_string()],
+            timestamp: 0,
+        }));
+        for id in ["atlas", "nova"] {
+            field.process_event(&HiveEvent::AgentUpdate(AgentUpdate {
+                agent_id: id.into(),
+                status: AgentStatus::Active,
+                focus: vec!["jwt".into()],
+                intensity: 0.5,
+                message: String::new(),
+                timestamp: 0,
+            }));
+        }
+        field.process_event(&HiveEvent::Connection(Connection {
+            from: "atlas".into(),
+            to: "nova".into(),
+            label: "review".to_string(),
+            timestamp: 0,
+            ttl_ms: None,
+        }));
+        for agent in field.agents.values_mut() {
+            agent.position = agent.target_position.clone();
+        }
+        field.tick(2.0);
+
+        let report = StatsReport::capture(&field);
+        assert_eq!(report.agents.len(), 2);
+        assert_eq!(report.agents[0].zone_seconds["auth"], 2.0);
+        assert_eq!(report.agents[0].recent_work, 1.0);
+        assert_eq!(report.zones[0].agents, [AgentId::from("atlas"), AgentId::from("nova")]);
+        assert_eq!(report.zones[0].seconds, 4.0);
+        assert_eq!((report.connections[0].count, report.connections[0].last_label.as_str()), (1, "review"));
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["agents"][0]["status"], "active");
+    }
+}
@@ -0,0 +1,339 @@
+//! Sampling of chatty agents.
+//!
+//! An agent sending a hundred updates a second turns into a blur. The
+//! `[sampling]` config section thins out live agent updates: `every` keeps one
+//! in N of an agent's updates and `min_interval_ms` keeps at most one per
+//! interval. `[[sampling.agents]]` entries override either setting for agents
+//! whose ID matches a regex; the first matching entry wins. An update that
+//! changes the agent's status is always kept, so errors and hand-offs show as
+//! they happen. The latest dropped update of an agent is not lost for good:
+//! it is applied once the interval allows another update (and, with `every`,
+//! once the agent has gone quiet), so an agent does not stay shown as its
+//! last kept update said. Connections and landmarks are never sampled.
+//!
+//! ```toml
+//! [sampling]
+//! min_interval_ms = 100
+//!
+//! [[sampling.agents]]
+//! pattern = "^indexer-"
+//! every = 10
+//! ```
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use regex::Regex;
+use serde::Deserialize;
+
+use hive_core::event::{AgentId, AgentStatus, AgentUpdate};
+
+/// How long an agent sampled with `every` has to be quiet before its last
+/// dropped update is applied
+const SETTLE: Duration = Duration::from_millis(250);
+
+/// `[sampling]` section of the config file
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SamplingConfig {
+    /// Keep one in this many updates of each agent
+    pub every: u32,
+    /// Keep at most one update of each agent per this many milliseconds
+    pub min_interval_ms: u64,
+    /// Settings for agents matching a pattern, tried in order
+    pub agents: Vec<SamplingRule>,
+}
+
+impl Default for SamplingConfig {
+    fn default() -> Self {
+        Self {
+            every: 1,
+            min_interval_ms: 0,
+            agents: Vec::new(),
+        }
+    }
+}
+
+/// One `[[sampling.agents]]` entry of the config file
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SamplingRule {
+    /// Regex matched against the agent ID
+    pub pattern: String,
+    /// Overrides `every` for matching agents
+    pub every: Option<u32>,
+    /// Overrides `min_interval_ms` for matching agents
+    pub min_interval_ms: Option<u64>,
+}
+
+/// How one agent's updates are thinned out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Rate {
+    every: u32,
+    min_interval: Duration,
+}
+
+impl Rate {
+    fn new(every: u32, min_interval_ms: u64) -> Self {
+        Self {
+            every: every.max(1),
+            min_interval: Duration::from_millis(min_interval_ms),
+        }
+    }
+
+    fn keeps_all(self) -> bool {
+        self.every == 1 && self.min_interval.is_zero()
+    }
+}
+
+/// Compiled sampling settings
+#[derive(Debug, Clone)]
+pub struct SamplingRules {
+    rate: Rate,
+    agents: Vec<(Regex, Rate)>,
+}
+
+impl Default for SamplingRules {
+    fn default() -> Self {
+        Self {
+            rate: Rate::new(1, 0),
+            agents: Vec::new(),
+        }
+    }
+}
+
+impl SamplingRules {
+    /// Compile `config`, failing on the first invalid pattern.
+    pub fn new(config: &SamplingConfig) -> Result<Self, regex::Error> {
+        let agents = config
+            .agents
+            .iter()
+            .map(|rule| {
+                let rate = Rate::new(
+                    rule.every.unwrap_or(config.every),
+                    rule.min_interval_ms.unwrap_or(config.min_interval_ms),
+                );
+                Ok((Regex::new(&rule.pattern)?, rate))
+            })
+            .collect::<Result<_, regex::Error>>()?;
+        Ok(Self {
+            rate: Rate::new(config.every, config.min_interval_ms),
+            agents,
+        })
+    }
+
+    /// Whether every update of every agent is kept
+    pub fn keeps_all(&self) -> bool {
+        self.rate.keeps_all() && self.agents.iter().all(|(_, rate)| rate.keeps_all())
+    }
+
+    fn rate_for(&self, agent_id: &str) -> Rate {
+        self.agents
+            .iter()
+            .find(|(pattern, _)| pattern.is_match(agent_id))
+            .map_or(self.rate, |&(_, rate)| rate)
+    }
+}
+
+/// What the sampler remembers about one agent
+#[derive(Debug)]
+struct Seen {
+    rate: Rate,
+    status: AgentStatus,
+    /// Updates dropped since the last one kept
+    skipped: u32,
+    last_kept: Instant,
+    /// When the last update arrived
+    last_seen: Instant,
+    /// Latest update dropped since the last one kept
+    pending: Option<AgentUpdate>,
+}
+
+/// Decides which live agent updates are applied
+#[derive(Debug, Default)]
+pub struct Sampler {
+    rules: SamplingRules,
+    agents: HashMap<AgentId, Seen>,
+    /// Updates dropped so far
+    dropped: usize,
+}
+
+impl Sampler {
+    pub fn new(rules: SamplingRules) -> Self {
+        Self {
+            rules,
+            agents: HashMap::new(),
+            dropped: 0,
+        }
+    }
+
+    /// Whether `update`, arriving at `now`, should be applied
+    pub fn keep(&mut self, update: &AgentUpdate, now: Instant) -> bool {
+        if self.rules.keeps_all() {
+            return true;
+        }
+        let Some(seen) = self.agents.get_mut(&update.agent_id) else {
+            // First update of the agent
+            let rate = self.rules.rate_for(&update.agent_id);
+            self.agents.insert(
+                update.agent_id.clone(),
+                Seen {
+                    rate,
+                    status: update.status.clone(),
+                    skipped: 0,
+                    last_kept: now,
+                    last_seen: now,
+                    pending: None,
+                },
+            );
+            return true;
+        };
+
+        let kept = seen.status != update.status
+            || (seen.skipped + 1 >= seen.rate.every && now.duration_since(seen.last_kept) >= seen.rate.min_interval);
+        seen.last_seen = now;
+        if kept {
+            seen.status = update.status.clone();
+            seen.skipped = 0;
+            seen.last_kept = now;
+            if seen.pending.take().is_some() {
+                self.dropped += 1;
+            }
+        } else {
+            seen.skipped = seen.skipped.saturating_add(1);
+            if seen.pending.replace(update.clone()).is_some() {
+                self.dropped += 1;
+            }
+        }
+        kept
+    }
+
+    /// Take the dropped updates to apply at `now`: the latest one of each
+    /// agent whose interval has passed since its last kept update, and that
+    /// has gone quiet if it is sampled with `every`
+    pub fn due(&mut self, now: Instant) -> Vec<AgentUpdate> {
+        let mut due = Vec::new();
+        for seen in self.agents.values_mut() {
+            let allowed = now.duration_since(seen.last_kept) >= seen.rate.min_interval
+                && (seen.rate.every == 1 || now.duration_since(seen.last_seen) >= SETTLE);
+            if !allowed {
+                continue;
+            }
+            if let Some(update) = seen.pending.take() {
+                seen.skipped = 0;
+                seen.last_kept = now;
+                due.push(update);
+            }
+        }
+        due
+    }
+
+    /// Number of updates dropped for good so far, or `None` when nothing is
+    /// sampled
+    pub fn dropped(&self) -> Option<usize> {
+        (!self.rules.keeps_all()).then_some(self.dropped)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn update(agent_id: &str, status: AgentStatus) -> AgentUpdate {
+        AgentUpdate {
+            agent_id: agent_id.into(),
+            status,
+            focus: Vec::new(),
+            intensity: 0.5,
+            message: String::new(),
+            timestamp: 0,
+        }
+    }
+
+    #[test]
+    fn test_chatty_agents_are_thinned_out() {
+        let config: SamplingConfig = toml::from_str(
+            r#"
+            min_interval_ms = 100
+
+            [[agents]]
+            pattern = "^indexer-"
+            every = 3
+            min_interval_ms = 0
+            "#,
+        )
+        .unwrap();
+        let mut sampler = Sampler::new(SamplingRules::new(&config).unwrap());
+        let start = Instant::now();
+
+        // One in three for the indexer, whatever the timing
+        let kept: Vec<bool> = (0..7)
+            .map(|_| sampler.keep(&update("indexer-1", AgentStatus::Active), start))
+            .collect();
+        assert_eq!(kept, [true, false, false, true, false, false, true]);
+
+        // At most one per 100ms for everyone else
+        let planner = update("planner", AgentStatus::Active);
+        assert!(sampler.keep(&planner, start));
+        assert!(!sampler.keep(&planner, start + Duration::from_millis(50)));
+        assert!(sampler.keep(&planner, start + Duration::from_millis(100)));
+
+        // A change of status always goes through
+        assert!(sampler.keep(&update("planner", AgentStatus::Error), start + Duration::from_millis(120)));
+        assert_eq!(sampler.dropped(), Some(5));
+    }
+
+    #[test]
+    fn test_last_dropped_update_is_applied_later() {
+        let config: SamplingConfig = toml::from_str(
+            r#"
+            min_interval_ms = 100
+
+            [[agents]]
+            pattern = "^indexer-"
+            every = 3
+            min_interval_ms = 0
+            "#,
+        )
+        .unwrap();
+        let mut sampler = Sampler::new(SamplingRules::new(&config).unwrap());
+        let start = Instant::now();
+        let at = |ms| start + Duration::from_millis(ms);
+
+        // The last of a burst waits for the interval, not for another update
+        let mut planner = update("planner", AgentStatus::Active);
+        assert!(sampler.keep(&planner, at(0)));
+        for (ms, intensity) in [(10, 0.6), (20, 0.7)] {
+            planner.intensity = intensity;
+            assert!(!sampler.keep(&planner, at(ms)));
+        }
+        assert!(sampler.due(at(50)).is_empty());
+        let due = sampler.due(at(100));
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].intensity, 0.7);
+        assert!(sampler.due(at(300)).is_empty());
+        assert!(!sampler.keep(&planner, at(150)));
+
+        // One in three otherwise waits for the agent to go quiet
+        let indexer = update("indexer-1", AgentStatus::Active);
+        assert!(sampler.keep(&indexer, at(0)));
+        assert!(!sampler.keep(&indexer, at(100)));
+        assert!(sampler.due(at(200)).iter().all(|update| &*update.agent_id != "indexer-1"));
+        let due = sampler.due(at(400));
+        assert_eq!(due.len(), 1);
+        assert_eq!(&*due[0].agent_id, "indexer-1");
+
+        // Only updates replaced before they were applied count as dropped
+        assert_eq!(sampler.dropped(), Some(1));
+    }
+
+    #[test]
+    fn test_default_keeps_everything() {
+        let rules = SamplingRules::new(&SamplingConfig::default()).unwrap();
+        assert!(rules.keeps_all());
+        let mut sampler = Sampler::new(rules);
+        let planner = update("planner", AgentStatus::Active);
+        assert!((0..5).all(|_| sampler.keep(&planner, Instant::now())));
+        assert_eq!(sampler.dropped(), None);
+    }
+}
@@ -0,0 +1,147 @@
+//! Field state saved across restarts.
+//!
+//! With `persist_state` on in the `[sources]` config section, the agents,
+//! landmarks and heat of the open files are saved to a sidecar file next to
+//! the first one (`events.jsonl` → `events.state.json`) when Hive quits or
+//! switches files. Opening the same file again starts from that state and only
+//! loads the events written after it, so a long-running swarm does not come
+//! back to an empty map.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use hive_core::event::{AgentUpdate, HiveEvent, Landmark};
+use hive_core::state::Field;
+
+use crate::render::{HeatMap, HeatSource};
+
+/// Saved state of the field
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SavedState {
+    /// Timestamp of the newest event seen when the state was saved
+    pub timestamp: u64,
+    /// Agents, as the updates that bring them back
+    pub agents: Vec<AgentUpdate>,
+    /// Landmarks sent by the emitters (auto landmarks are derived again)
+    pub landmarks: Vec<Landmark>,
+    /// Heat grid, one list per row
+    pub heat: Vec<Vec<f32>>,
+    /// What the heat grid shows
+    pub heat_source: HeatSource,
+}
+
+impl SavedState {
+    /// Sidecar file holding the state for `events`
+    pub fn sidecar_path(events: &Path) -> PathBuf {
+        events.with_extension("state.json")
+    }
+
+    /// The state of `field` and `heatmap` as of the event at `timestamp`
+    pub fn capture(field: &Field, heatmap: &HeatMap, timestamp: u64) -> Self {
+        let mut agents: Vec<_> = field
+            .agents
+            .values()
+            .map(|agent| AgentUpdate {
+                agent_id: agent.id.clone(),
+                status: agent.status.clone(),
+                focus: agent.focus.clone(),
+                intensity: agent.intensity,
+                message: agent.message.clone(),
+                timestamp,
+            })
+            .collect();
+        agents.sort_by(|a, b| a.agent_id.cmp(&b.agent_id));
+
+        let mut landmarks: Vec<_> = field
+            .landmarks
+            .values()
+            .filter(|landmark| !landmark.auto)
+            .map(|landmark| Landmark {
+                id: landmark.id.clone(),
+                label: landmark.label.clone(),
+                keywords: landmark.keywords.clone(),
+                timestamp,
+            })
+            .collect();
+        landmarks.sort_by(|a, b| a.id.cmp(&b.id));
+
+        Self {
+            timestamp,
+            agents,
+            landmarks,
+            heat: heatmap.cells().to_vec(),
+            heat_source: heatmap.source(),
+        }
+    }
+
+    /// Events recreating the saved landmarks and agents
+    pub fn events(&self) -> impl Iterator<Item = HiveEvent> + '_ {
+        let landmarks = self.landmarks.iter().cloned().map(HiveEvent::Landmark);
+        landmarks.chain(self.agents.iter().cloned().map(HiveEvent::AgentUpdate))
+    }
+
+    /// Load the state saved in `path`; a missing file means none.
+    pub fn load(path: &Path) -> io::Result<Option<Self>> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map(Some)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), e))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Write the state to `path`
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let contents = serde_json::to_string(self).map_err(io::Error::other)?;
+        std::fs::write(path, contents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hive_core::event::AgentStatus;
+
+    #[test]
+    fn test_state_round_trip() {
+        let mut field = Field::new();
+        field.process_event(&HiveEvent::Landmark(Landmark {
+            id: "auth".to_string(),
+            label: "Authentication".to_string(),
+            keywords: vec!["jwt".to_string()],
+            timestamp: 10,
+        }));
+        field.process_event(&HiveEvent::AgentUpdate(AgentUpdate {
+            agent_id: "atlas".into(),
+            status: AgentStatus::Thinking,
+            focus: vec!["jwt".into()],
+            intensity: 0.7,
+            message: "Reading".to_string(),
+            timestamp: 12,
+        }));
+        let mut heatmap = HeatMap::new(20, 10);
+        heatmap.accumulate(&field);
+
+        let path = std::env::temp_dir().join(format!("hive-state-{}.json", std::process::id()));
+        SavedState::capture(&field, &heatmap, 12).save(&path).unwrap();
+        let saved = SavedState::load(&path).unwrap().unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let mut restored = Field::new();
+        for event in saved.events() {
+            restored.process_event(&event);
+        }
+        let agent = &restored.agents["atlas"];
+        assert_eq!(agent.status, AgentStatus::Thinking);
+        assert_eq!(agent.message, "Reading");
+        assert!(restored.landmarks.contains_key("auth"));
+        assert_eq!(saved.timestamp, 12);
+        assert_eq!(saved.heat, heatmap.cells());
+
+        assert!(SavedState::load(&path).unwrap().is_none());
+    }
+}
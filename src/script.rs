@@ -0,0 +1,276 @@
+//! Rhai scripting hooks for incoming events.
+//!
+//! A script may define `on_event(event)`, which is called for every event, the
+//! ones already in the events file as well as live ones, before it is recorded
+//! or reaches the field, so replays show the events as the script left them.
+//! The event arrives as an object map in the same shape as the JSON line, plus
+//! a read-only `zone` key holding the label of the landmark zone an agent
+//! update lands in (empty when outside every zone).
+//!
+//! The return value decides what happens to the event:
+//! - an object map replaces the event (so scripts can rename agents, rewrite
+//!   focus keywords, clamp intensity, ...)
+//! - `false` drops the event
+//! - anything else, including `()`, keeps the original event
+//!
+//! Scripts can also call back into Hive:
+//! - `notify(message)` adds a line to the activity log
+//! - `beep()` rings the terminal bell
+//! - `flash(agent_id)` / `flash(agent_id, seconds)` flashes an agent
+//! - `set_meta(agent_id, key, value)` annotates an agent (shown in its hover panel)
+//!
+//! ```rhai
+//! fn on_event(event) {
+//!     if event["type"] == "agent_update" && event.status == "error" && event.zone == "Database" {
+//!         flash(event.agent_id);
+//!         beep();
+//!         notify(`${event.agent_id} failed in Database`);
+//!     }
+//! }
+//! ```
+
+use std::cell::RefCell;
+use std::io;
+use std::path::Path;
+use std::rc::Rc;
+
+use rhai::{CallFnOptions, Dynamic, Engine, Map, Scope, AST};
+
+use hive_core::event::HiveEvent;
+
+/// Name of the per-event hook function
+const HOOK_FN: &str = "on_event";
+
+/// Default flash duration in seconds
+const DEFAULT_FLASH_SECS: f32 = 1.5;
+
+/// Upper bound on script work per call, so a runaway loop cannot stall rendering
+const MAX_OPERATIONS: u64 = 100_000;
+
+/// Side effect requested by a script
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScriptAction {
+    Notify(String),
+    Beep,
+    Flash { agent_id: String, seconds: f32 },
+    SetMeta { agent_id: String, key: String, value: String },
+}
+
+/// A compiled event script
+pub struct ScriptHooks {
+    engine: Engine,
+    ast: AST,
+    scope: Scope<'static>,
+    actions: Rc<RefCell<Vec<ScriptAction>>>,
+    has_hook: bool,
+}
+
+impl ScriptHooks {
+    /// Compile a script file.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let source = std::fs::read_to_string(path)?;
+        Self::from_source(&source).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {}", path.display(), e),
+            )
+        })
+    }
+
+    /// Compile a script and run its top-level statements once.
+    pub fn from_source(source: &str) -> Result<Self, String> {
+        let actions = Rc::new(RefCell::new(Vec::new()));
+        let engine = build_engine(&actions);
+
+        let ast = engine.compile(source).map_err(|e| e.to_string())?;
+        let mut scope = Scope::new();
+        engine
+            .run_ast_with_scope(&mut scope, &ast)
+            .map_err(|e| e.to_string())?;
+
+        let has_hook = ast.iter_functions().any(|f| f.name == HOOK_FN);
+
+        Ok(Self {
+            engine,
+            ast,
+            scope,
+            actions,
+            has_hook,
+        })
+    }
+
+    /// Run the hook for one event.
+    ///
+    /// Returns the event to process (`None` if the script dropped it). On a
+    /// script error the original event is kept and the error is returned
+    /// alongside it so the caller can report it.
+    pub fn on_event(
+        &mut self,
+        event: HiveEvent,
+        zone: Option<&str>,
+    ) -> (Option<HiveEvent>, Option<String>) {
+        if !self.has_hook {
+            return (Some(event), None);
+        }
+
+        let mut map = match rhai::serde::to_dynamic(&event).map(|d| d.try_cast::<Map>()) {
+            Ok(Some(map)) => map,
+            Ok(None) => return (Some(event), None),
+            Err(e) => return (Some(event), Some(e.to_string())),
+        };
+        map.insert("zone".into(), zone.unwrap_or_default().into());
+
+        let options = CallFnOptions::new().eval_ast(false).rewind_scope(true);
+        let result = self.engine.call_fn_with_options::<Dynamic>(
+            options,
+            &mut self.scope,
+            &self.ast,
+            HOOK_FN,
+            (Dynamic::from_map(map),),
+        );
+
+        match result {
+            Ok(value) if value.is_map() => match rhai::serde::from_dynamic::<HiveEvent>(&value) {
+                Ok(replaced) => (Some(replaced), None),
+                Err(e) => (Some(event), Some(format!("invalid event returned: {}", e))),
+            },
+            Ok(value) if value.as_bool() == Ok(false) => (None, None),
+            Ok(_) => (Some(event), None),
+            Err(e) => (Some(event), Some(e.to_string())),
+        }
+    }
+
+    /// Take the side effects requested since the last call.
+    pub fn take_actions(&self) -> Vec<ScriptAction> {
+        std::mem::take(&mut *self.actions.borrow_mut())
+    }
+}
+
+/// Create an engine with the Hive host functions registered.
+fn build_engine(actions: &Rc<RefCell<Vec<ScriptAction>>>) -> Engine {
+    let mut engine = Engine::new();
+    engine.set_max_operations(MAX_OPERATIONS);
+
+    let sink = actions.clone();
+    engine.register_fn("notify", move |message: &str| {
+        sink.borrow_mut().push(ScriptAction::Notify(message.to_string()));
+    });
+
+    let sink = actions.clone();
+    engine.register_fn("beep", move || {
+        sink.borrow_mut().push(ScriptAction::Beep);
+    });
+
+    let sink = actions.clone();
+    engine.register_fn("flash", move |agent_id: &str| {
+        sink.borrow_mut().push(ScriptAction::Flash {
+            agent_id: agent_id.to_string(),
+            seconds: DEFAULT_FLASH_SECS,
+        });
+    });
+
+    let sink = actions.clone();
+    engine.register_fn("flash", move |agent_id: &str, seconds: f64| {
+        sink.borrow_mut().push(ScriptAction::Flash {
+            agent_id: agent_id.to_string(),
+            seconds: seconds as f32,
+        });
+    });
+
+    let sink = actions.clone();
+    engine.register_fn("set_meta", move |agent_id: &str, key: &str, value: Dynamic| {
+        sink.borrow_mut().push(ScriptAction::SetMeta {
+            agent_id: agent_id.to_string(),
+            key: key.to_string(),
+            value: value.to_string(),
+        });
+    });
+
+    engine
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hive_core::event::{AgentStatus, AgentUpdate};
+
+    fn update(agent_id: &str, status: AgentStatus) -> HiveEvent {
+        HiveEvent::AgentUpdate(AgentUpdate {
+            agent_id: agent_id.into(),
+            status,
+            focus: vec!["sql".into()],
+            intensity: 0.5,
+            message: String::new(),
+            timestamp: 1,
+        })
+    }
+
+    #[test]
+    fn test_error_in_zone_triggers_actions() {
+        let mut hooks = ScriptHooks::from_source(
+            r#"
+            fn on_event(event) {
+                if event["type"] == "agent_update" && event.status == "error" && event.zone == "Database" {
+                    flash(event.agent_id);
+                    beep();
+                    set_meta(event.agent_id, "failures", 1);
+                }
+            }
+            "#,
+        )
+        .unwrap();
+
+        let (event, error) = hooks.on_event(update("atlas", AgentStatus::Error), Some("Database"));
+        assert!(event.is_some());
+        assert!(error.is_none());
+        assert_eq!(
+            hooks.take_actions(),
+            vec![
+                ScriptAction::Flash {
+                    agent_id: "atlas".to_string(),
+                    seconds: DEFAULT_FLASH_SECS
+                },
+                ScriptAction::Beep,
+                ScriptAction::SetMeta {
+                    agent_id: "atlas".to_string(),
+                    key: "failures".to_string(),
+                    value: "1".to_string()
+                },
+            ]
+        );
+
+        // Outside the zone nothing fires
+        hooks.on_event(update("atlas", AgentStatus::Error), None);
+        assert!(hooks.take_actions().is_empty());
+    }
+
+    #[test]
+    fn test_transform_and_drop() {
+        let mut hooks = ScriptHooks::from_source(
+            r#"
+            fn on_event(event) {
+                if event.agent_id == "noisy" { return false; }
+                event.agent_id = "renamed";
+                event
+            }
+            "#,
+        )
+        .unwrap();
+
+        let (dropped, _) = hooks.on_event(update("noisy", AgentStatus::Active), None);
+        assert!(dropped.is_none());
+
+        match hooks.on_event(update("atlas", AgentStatus::Active), None) {
+            (Some(HiveEvent::AgentUpdate(u)), None) => assert_eq!(&*u.agent_id, "renamed"),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_runtime_error_keeps_event() {
+        let mut hooks = ScriptHooks::from_source("fn on_event(event) { event.missing.field }").unwrap();
+        let (event, error) = hooks.on_event(update("atlas", AgentStatus::Active), None);
+        assert!(event.is_some());
+        assert!(error.is_some());
+    }
+}
@@ -0,0 +1,71 @@
+//! Targets of the `:seek` command.
+//!
+//! `:seek 14:32:05` (or `14:32`) jumps to the event recorded closest to that
+//! time of day; `:seek +2m` / `:seek -30s` moves relative to the events being
+//! replayed. Times are matched against the events' `timestamp` fields.
+
+use chrono::{Local, NaiveTime, TimeZone};
+
+use crate::backfill::parse_duration;
+
+/// Where `:seek` should go
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeekTarget {
+    /// A local time of day, on the day of the replayed events
+    Clock(NaiveTime),
+    /// Seconds before (negative) or after the replayed events
+    Offset(i64),
+}
+
+impl SeekTarget {
+    /// Parse `HH:MM[:SS]` or `+N[s|m|h|d]` / `-N[s|m|h|d]`
+    pub fn parse(text: &str) -> Option<Self> {
+        if let Some(sign) = text.chars().next().filter(|c| matches!(c, '+' | '-')) {
+            let secs = i64::try_from(parse_duration(&text[1..])?).ok()?;
+            return Some(Self::Offset(if sign == '-' { -secs } else { secs }));
+        }
+
+        NaiveTime::parse_from_str(text, "%H:%M:%S")
+            .or_else(|_| NaiveTime::parse_from_str(text, "%H:%M"))
+            .ok()
+            .map(Self::Clock)
+    }
+
+    /// Unix timestamp to seek to, given the timestamp of the events being
+    /// replayed
+    pub fn resolve(self, reference: u64) -> Option<u64> {
+        match self {
+            Self::Offset(secs) => Some(reference.saturating_add_signed(secs)),
+            Self::Clock(time) => {
+                let day = Local.timestamp_opt(reference as i64, 0).single()?.date_naive();
+                let local = Local.from_local_datetime(&day.and_time(time)).earliest()?;
+                u64::try_from(local.timestamp()).ok()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_resolve() {
+        assert_eq!(SeekTarget::parse("+2m"), Some(SeekTarget::Offset(120)));
+        assert_eq!(SeekTarget::parse("-30s"), Some(SeekTarget::Offset(-30)));
+        assert_eq!(SeekTarget::parse("+1h"), Some(SeekTarget::Offset(3600)));
+        assert_eq!(SeekTarget::parse("+45"), Some(SeekTarget::Offset(45)));
+        assert_eq!(SeekTarget::parse("+2w"), None);
+        assert_eq!(SeekTarget::parse("soon"), None);
+        assert_eq!(
+            SeekTarget::parse("14:32"),
+            Some(SeekTarget::Clock(NaiveTime::from_hms_opt(14, 32, 0).unwrap()))
+        );
+
+        let reference = Local.with_ymd_and_hms(2024, 2, 1, 9, 0, 0).unwrap().timestamp() as u64;
+        assert_eq!(SeekTarget::Offset(-60).resolve(reference), Some(reference - 60));
+        let clock = SeekTarget::parse("14:32:05").unwrap();
+        let expected = Local.with_ymd_and_hms(2024, 2, 1, 14, 32, 5).unwrap().timestamp() as u64;
+        assert_eq!(clock.resolve(reference), Some(expected));
+    }
+}
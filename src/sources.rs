@@ -0,0 +1,70 @@
+//! Events files named on the command line.
+//!
+//! `--file` takes paths or wildcard patterns (`runs/*.jsonl`), so the logs of
+//! a whole set of agent runners can be watched without listing each one.
+//! Patterns are matched once, at startup.
+
+use std::io;
+use std::path::PathBuf;
+
+/// Whether `path` is a pattern rather than a plain path
+fn is_pattern(path: &str) -> bool {
+    path.contains(['*', '?', '['])
+}
+
+/// The files `paths` name, with patterns replaced by the files they match in
+/// alphabetical order. A file named twice is kept once; a pattern matching no
+/// file is an error.
+pub fn expand_file_patterns(paths: &[PathBuf]) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for path in paths {
+        let Some(pattern) = path.to_str().filter(|p| is_pattern(p)) else {
+            if !files.contains(path) {
+                files.push(path.clone());
+            }
+            continue;
+        };
+
+        let matches = glob::glob(pattern).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e.to_string()))?;
+        let before = files.len();
+        for found in matches {
+            let found = found.map_err(io::Error::from)?;
+            if found.is_file() && !files.contains(&found) {
+                files.push(found);
+            }
+        }
+        if files.len() == before {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no events files match {}", pattern),
+            ));
+        }
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_patterns_expand_to_matching_files() {
+        let dir = std::env::temp_dir().join(format!("hive-sources-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("nested.jsonl")).unwrap();
+        for name in ["b.jsonl", "a.jsonl", "notes.txt"] {
+            std::fs::write(dir.join(name), "").unwrap();
+        }
+
+        let pattern = dir.join("*.jsonl");
+        let extra = dir.join("notes.txt");
+        let files = expand_file_patterns(&[dir.join("b.jsonl"), pattern.clone(), extra.clone()]).unwrap();
+        assert_eq!(files, [dir.join("b.jsonl"), dir.join("a.jsonl"), extra]);
+
+        // Plain paths are kept even if they do not exist yet
+        let missing = dir.join("later.jsonl");
+        assert_eq!(expand_file_patterns(std::slice::from_ref(&missing)).unwrap(), [missing]);
+        assert!(expand_file_patterns(&[dir.join("*.csv")]).is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
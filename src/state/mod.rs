@@ -1,7 +0,0 @@
-pub mod agent;
-pub mod field;
-pub mod history;
-
-pub use agent::Agent;
-pub use field::Field;
-pub use history::History;
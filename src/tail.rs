@@ -0,0 +1,234 @@
+//! Event echo for emitter authors (`hive tail`).
+//!
+//! Prints each line of an events file as the event it parses to, one per
+//! line and colored by kind and status, without opening the field. Lines that
+//! do not parse are shown with the parser's error, and events that parse but
+//! will not show the way their author likely meant (an intensity outside 0-1,
+//! no focus keywords, a timestamp in milliseconds) get a note below them.
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, IsTerminal, Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use chrono::{Local, TimeZone};
+use crossterm::style::{Color, Stylize};
+
+use hive_core::event::{AgentStatus, EventFormat, HiveEvent};
+
+/// How often the file is checked for new lines
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Timestamps past this are taken to be in milliseconds (year 5138 in seconds)
+const MILLIS_THRESHOLD: u64 = 100_000_000_000;
+
+/// Characters of an unparseable line shown next to its error
+const RAW_PREVIEW: usize = 60;
+
+/// Settings for `hive tail`
+#[derive(Debug, Clone)]
+pub struct TailOptions {
+    pub path: PathBuf,
+    pub format: EventFormat,
+    /// Existing lines to show before following, from the end of the file
+    pub lines: usize,
+    /// Keep printing lines as they are appended
+    pub follow: bool,
+}
+
+/// Print the last lines of the file, then follow it if asked
+pub fn run(options: &TailOptions) -> io::Result<()> {
+    if options.format.is_framed() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{} events are framed, not lines, and cannot be tailed", options.format),
+        ));
+    }
+    let mut file = File::open(&options.path)
+        .map_err(|e| io::Error::new(e.kind(), format!("cannot open {}: {}", options.path.display(), e)))?;
+    let color = io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none();
+    let mut out = io::stdout().lock();
+
+    // Existing lines, keeping the last ones
+    let mut existing = Vec::new();
+    BufReader::new(&mut file).read_to_end(&mut existing)?;
+    let whole = existing.iter().rposition(|&b| b == b'\n').map_or(0, |at| at + 1);
+    let lines: Vec<&[u8]> = existing[..whole].split_inclusive(|&b| b == b'\n').collect();
+    let first = lines.len().saturating_sub(options.lines);
+    for (number, line) in (first + 1..).zip(&lines[first..]) {
+        print_line(&mut out, number, line, options.format, color)?;
+    }
+    if !options.follow {
+        return Ok(());
+    }
+
+    let mut number = lines.len() + 1;
+    let mut position = whole as u64;
+    loop {
+        std::thread::sleep(POLL_INTERVAL);
+        let size = file.metadata()?.len();
+        if size < position {
+            writeln!(out, "{}", paint("-- file truncated, reading from the start --", Color::DarkGrey, color))?;
+            position = 0;
+            number = 1;
+        }
+        if size == position {
+            continue;
+        }
+
+        // Whole lines only; the writer may be in the middle of the last one
+        file.seek(SeekFrom::Start(position))?;
+        let mut reader = BufReader::new(&mut file);
+        let mut line = Vec::new();
+        while reader.read_until(b'\n', &mut line)? > 0 && line.ends_with(b"\n") {
+            position += line.len() as u64;
+            print_line(&mut out, number, &line, options.format, color)?;
+            number += 1;
+            line.clear();
+        }
+        out.flush()?;
+    }
+}
+
+fn print_line(out: &mut impl Write, number: usize, line: &[u8], format: EventFormat, color: bool) -> io::Result<()> {
+    let text = String::from_utf8_lossy(line);
+    let event = match format.parse_line(&text) {
+        Ok(Some(event)) => event,
+        Ok(None) => return Ok(()),
+        Err(e) => {
+            let raw: String = text.trim_end().chars().take(RAW_PREVIEW).collect();
+            let error = format!("line {}: {}", number, e);
+            return writeln!(out, "{} {}  {}", paint("✗", Color::Red, color), paint(&error, Color::Red, color), paint(&raw, Color::DarkGrey, color));
+        }
+    };
+
+    let (kind, kind_color) = match event {
+        HiveEvent::AgentUpdate(ref update) => ("update", status_color(&update.status)),
+        HiveEvent::Connection(_) => ("connection", Color::Magenta),
+        HiveEvent::Landmark(_) => ("landmark", Color::Cyan),
+    };
+    let time = Local
+        .timestamp_opt(event.timestamp() as i64, 0)
+        .single()
+        .map_or_else(|| event.timestamp().to_string(), |time| time.format("%H:%M:%S").to_string());
+    writeln!(
+        out,
+        "{}  {}  {}",
+        paint(&time, Color::DarkGrey, color),
+        paint(&format!("{:<10}", kind), kind_color, color),
+        describe(&event)
+    )?;
+    for note in notes(&event) {
+        writeln!(out, "{:>10}{}", "", paint(&format!("⚠ {}", note), Color::Yellow, color))?;
+    }
+    Ok(())
+}
+
+/// What the event says, in one line
+fn describe(event: &HiveEvent) -> String {
+    match event {
+        HiveEvent::AgentUpdate(update) => {
+            let status = format!("{:?}", update.status).to_lowercase();
+            let focus: Vec<&str> = update.focus.iter().map(|keyword| &**keyword).collect();
+            let mut text = format!("{}  {:<8}  {:.2}  [{}]", update.agent_id, status, update.intensity, focus.join(", "));
+            if !update.message.is_empty() {
+                text.push_str(&format!("  {}", update.message));
+            }
+            text
+        }
+        HiveEvent::Connection(connection) => {
+            let mut text = format!("{} → {}", connection.from, connection.to);
+            if !connection.label.is_empty() {
+                text.push_str(&format!("  {:?}", connection.label));
+            }
+            if let Some(ttl) = connection.ttl_ms {
+                text.push_str(&format!("  ttl {}ms", ttl));
+            }
+            text
+        }
+        HiveEvent::Landmark(landmark) => {
+            format!("{} {:?}  [{}]", landmark.id, landmark.label, landmark.keywords.join(", "))
+        }
+    }
+}
+
+/// Things about the event that parse fine but likely aren't what was meant
+fn notes(event: &HiveEvent) -> Vec<String> {
+    let mut notes = Vec::new();
+    if event.timestamp() >= MILLIS_THRESHOLD {
+        notes.push("timestamp looks like milliseconds; Hive expects seconds".to_string());
+    }
+    match event {
+        HiveEvent::AgentUpdate(update) => {
+            if update.agent_id.trim().is_empty() {
+                notes.push("agent_id is empty".to_string());
+            }
+            if !(0.0..=1.0).contains(&update.intensity) {
+                notes.push(format!("intensity {} is outside 0-1 and will be clamped", update.intensity));
+            }
+            if update.focus.is_empty() {
+                notes.push("no focus keywords; the agent is placed in the middle of the field".to_string());
+            }
+        }
+        HiveEvent::Connection(connection) => {
+            if connection.from == connection.to {
+                notes.push("connection from an agent to itself has no line to draw".to_string());
+            }
+        }
+        HiveEvent::Landmark(landmark) => {
+            if landmark.keywords.is_empty() {
+                notes.push("landmark has no keywords, so no agent is drawn to it".to_string());
+            }
+        }
+    }
+    notes
+}
+
+fn status_color(status: &AgentStatus) -> Color {
+    match status {
+        AgentStatus::Active => Color::Green,
+        AgentStatus::Thinking => Color::Blue,
+        AgentStatus::Waiting => Color::Yellow,
+        AgentStatus::Idle => Color::Grey,
+        AgentStatus::Error => Color::Red,
+    }
+}
+
+/// `text` in `color`, or as is when color is off
+fn paint(text: &str, color: Color, enabled: bool) -> String {
+    if enabled {
+        text.with(color).to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines_are_echoed_with_notes() {
+        let lines = [
+            r#"{"type":"agent_update","agent_id":"scout","status":"thinking","focus":[],"intensity":1.5,"message":"Planning","timestamp":1706812345000}"#,
+            r#"{"type":"connection","from":"scout","to":"scout","label":"","timestamp":1}"#,
+            r#"{"type":"agent_upd"#,
+            "",
+        ];
+        let mut out = Vec::new();
+        for (number, line) in (1..).zip(lines) {
+            print_line(&mut out, number, line.as_bytes(), EventFormat::Json, false).unwrap();
+        }
+        let out = String::from_utf8(out).unwrap();
+        let printed: Vec<&str> = out.lines().collect();
+
+        assert!(printed[0].contains("update      scout  thinking  1.50  []  Planning"));
+        assert!(printed[1].contains("milliseconds"));
+        assert!(printed[2].contains("outside 0-1"));
+        assert!(printed[3].contains("no focus keywords"));
+        assert!(printed[4].contains("connection  scout → scout"));
+        assert!(printed[5].contains("to itself"));
+        assert!(printed[6].starts_with("✗ line 3: "));
+        assert_eq!(printed.len(), 7);
+    }
+}
@@ -0,0 +1,76 @@
+//! Live event telemetry for the status bar.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Window the event rate is averaged over
+const RATE_WINDOW: Duration = Duration::from_secs(5);
+
+/// Counts incoming events to report their rate and the time since the last one
+#[derive(Debug, Clone, Default)]
+pub struct EventMeter {
+    /// Arrival times within the rate window, oldest first
+    recent: VecDeque<Instant>,
+    last: Option<Instant>,
+}
+
+impl EventMeter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count an event arriving at `now`
+    pub fn record(&mut self, now: Instant) {
+        self.recent.push_back(now);
+        self.last = Some(now);
+        self.expire(now);
+    }
+
+    /// Events per second over the last few seconds
+    pub fn rate(&self, now: Instant) -> f32 {
+        let count = self
+            .recent
+            .iter()
+            .filter(|&&t| now.saturating_duration_since(t) <= RATE_WINDOW)
+            .count();
+        count as f32 / RATE_WINDOW.as_secs_f32()
+    }
+
+    /// Time since the last event, `None` before the first one
+    pub fn last_event_age(&self, now: Instant) -> Option<Duration> {
+        self.last.map(|last| now.saturating_duration_since(last))
+    }
+
+    /// Drop arrivals that fell out of the window
+    fn expire(&mut self, now: Instant) {
+        while self
+            .recent
+            .front()
+            .is_some_and(|&t| now.saturating_duration_since(t) > RATE_WINDOW)
+        {
+            self.recent.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_covers_recent_window() {
+        let start = Instant::now();
+        let mut meter = EventMeter::new();
+        assert_eq!(meter.last_event_age(start), None);
+
+        for i in 0..10 {
+            meter.record(start + Duration::from_millis(i * 100));
+        }
+        let now = start + Duration::from_secs(1);
+        assert_eq!(meter.rate(now), 2.0);
+        assert_eq!(meter.last_event_age(now), Some(Duration::from_millis(100)));
+
+        // Older than the window
+        assert_eq!(meter.rate(start + Duration::from_secs(10)), 0.0);
+    }
+}
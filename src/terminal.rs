@@ -0,0 +1,252 @@
+//! Terminal capabilities probed at startup.
+//!
+//! Hive needs a terminal of at least [`MIN_WIDTH`] x [`MIN_HEIGHT`] cells;
+//! smaller ones get a "terminal too small" screen instead of the field. The
+//! color depth comes from `COLORTERM` and `TERM`, and mouse support from
+//! whether mouse capture could be turned on.
+//!
+//! While running, Hive keeps the window title on the swarm's size
+//! ("hive — 12 agents, 5 active") and, in terminals that understand OSC 9,
+//! shows alerts as notifications and the initial load as taskbar progress.
+
+use std::io::{self, Write};
+
+use serde::Deserialize;
+
+use crate::render::ColorMode;
+
+/// Narrowest terminal the field is drawn in
+pub const MIN_WIDTH: u16 = 60;
+
+/// Shortest terminal the field is drawn in
+pub const MIN_HEIGHT: u16 = 20;
+
+/// What the terminal can do
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TerminalCaps {
+    /// Size in cells (columns, rows)
+    pub size: (u16, u16),
+    pub color_mode: ColorMode,
+    /// Whether mouse capture is on (hover and click on agents)
+    pub mouse: bool,
+    /// Whether the terminal understands OSC 9 notifications and progress
+    pub osc9: bool,
+}
+
+impl TerminalCaps {
+    /// Probe the terminal; mouse support is only known once capture has been
+    /// turned on, so it starts off unset.
+    pub fn probe() -> Self {
+        let colorterm = std::env::var("COLORTERM").unwrap_or_default();
+        let term = std::env::var("TERM").unwrap_or_default();
+        Self {
+            size: crossterm::terminal::size().unwrap_or((0, 0)),
+            color_mode: color_mode_from_env(&colorterm, &term),
+            mouse: false,
+            osc9: osc9_from_env(
+                &std::env::var("TERM_PROGRAM").unwrap_or_default(),
+                &term,
+                std::env::var_os("WT_SESSION").is_some() || std::env::var_os("ConEmuANSI").is_some(),
+            ),
+        }
+    }
+
+    /// Notes about missing capabilities, for the activity log
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if self.color_mode != ColorMode::TrueColor {
+            warnings.push(
+                "terminal does not report truecolor support; colors are approximated".to_string(),
+            );
+        }
+        if !self.mouse {
+            warnings.push("mouse capture unavailable; use the keyboard to select agents".to_string());
+        }
+        warnings
+    }
+}
+
+/// Whether a `width` x `height` area can hold the field
+pub fn fits(width: u16, height: u16) -> bool {
+    width >= MIN_WIDTH && height >= MIN_HEIGHT
+}
+
+/// Color depth from the `COLORTERM` and `TERM` variables
+pub fn color_mode_from_env(colorterm: &str, term: &str) -> ColorMode {
+    if matches!(colorterm, "truecolor" | "24bit") || term.contains("direct") {
+        ColorMode::TrueColor
+    } else if term.contains("256color") {
+        ColorMode::Color256
+    } else if term.is_empty() || term == "dumb" {
+        ColorMode::Monochrome
+    } else {
+        ColorMode::Basic16
+    }
+}
+
+/// Whether the terminal understands OSC 9, from `TERM_PROGRAM`, `TERM` and
+/// whether it runs under Windows Terminal or ConEmu
+pub fn osc9_from_env(term_program: &str, term: &str, windows_terminal: bool) -> bool {
+    windows_terminal
+        || matches!(term_program, "iTerm.app" | "WezTerm" | "ghostty")
+        || term.contains("kitty")
+        || term.contains("ghostty")
+}
+
+/// `[terminal]` section of the config file
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TerminalConfig {
+    /// Keep the window title on the number of agents
+    pub title: bool,
+    /// Send OSC 9 notifications on alerts and progress while loading, in
+    /// terminals that support them
+    pub osc9: bool,
+}
+
+impl Default for TerminalConfig {
+    fn default() -> Self {
+        Self { title: true, osc9: true }
+    }
+}
+
+/// Window title and OSC 9 sequences sent to the terminal
+#[derive(Debug, Clone, Default)]
+pub struct TerminalStatus {
+    config: TerminalConfig,
+    /// Whether the terminal understands OSC 9
+    osc9_supported: bool,
+    /// Title last set, to only write changes
+    title: Option<String>,
+    /// Progress percentage last reported
+    progress: Option<u8>,
+}
+
+impl TerminalStatus {
+    pub fn new(config: TerminalConfig) -> Self {
+        Self {
+            config,
+            ..Self::default()
+        }
+    }
+
+    /// Send OSC 9 sequences from now on if `caps` says they are understood
+    pub fn set_caps(&mut self, caps: &TerminalCaps) {
+        self.osc9_supported = caps.osc9;
+    }
+
+    fn osc9(&self) -> bool {
+        self.config.osc9 && self.osc9_supported
+    }
+
+    /// Put the agent counts in the window title, saving the old title first
+    pub fn update_title(&mut self, agents: usize, active: usize) {
+        if !self.config.title {
+            return;
+        }
+        let noun = if agents == 1 { "agent" } else { "agents" };
+        let title = format!("hive — {} {}, {} active", agents, noun, active);
+        if self.title.as_ref() == Some(&title) {
+            return;
+        }
+        let mut sequence = String::new();
+        if self.title.is_none() {
+            // Push the current title so it comes back on exit
+            sequence.push_str("\x1b[22;0t");
+        }
+        sequence.push_str(&title_sequence(&title));
+        write_sequence(&sequence);
+        self.title = Some(title);
+    }
+
+    /// Show `message` as a desktop notification through the terminal
+    pub fn notify(&self, message: &str) {
+        if self.osc9() {
+            write_sequence(&notify_sequence(message));
+        }
+    }
+
+    /// Report load progress from 0 to 1, or `None` once done
+    pub fn progress(&mut self, progress: Option<f32>) {
+        if !self.osc9() {
+            return;
+        }
+        let percent = progress.map(|p| (p.clamp(0.0, 1.0) * 100.0) as u8);
+        if percent != self.progress {
+            write_sequence(&progress_sequence(percent));
+            self.progress = percent;
+        }
+    }
+
+    /// Clear the progress and bring the old title back
+    pub fn restore(&mut self) {
+        self.progress(None);
+        if self.title.take().is_some() {
+            write_sequence("\x1b[23;0t");
+        }
+    }
+}
+
+/// OSC 2 sequence setting the window title
+fn title_sequence(title: &str) -> String {
+    format!("\x1b]2;{}\x07", printable(title))
+}
+
+/// OSC 9 sequence showing a notification
+fn notify_sequence(message: &str) -> String {
+    format!("\x1b]9;{}\x07", printable(message))
+}
+
+/// OSC 9;4 sequence setting the progress (state 1) or clearing it (state 0)
+fn progress_sequence(percent: Option<u8>) -> String {
+    match percent {
+        Some(percent) => format!("\x1b]9;4;1;{}\x07", percent),
+        None => "\x1b]9;4;0;0\x07".to_string(),
+    }
+}
+
+/// `text` without control characters, which would end the sequence early
+fn printable(text: &str) -> String {
+    text.chars().map(|c| if c.is_control() { ' ' } else { c }).collect()
+}
+
+/// Write a sequence straight to the terminal, between frames
+fn write_sequence(sequence: &str) {
+    let mut stdout = io::stdout();
+    let _ = stdout.write_all(sequence.as_bytes());
+    let _ = stdout.flush();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_mode_from_env() {
+        assert_eq!(color_mode_from_env("truecolor", "xterm"), ColorMode::TrueColor);
+        assert_eq!(color_mode_from_env("", "xterm-direct"), ColorMode::TrueColor);
+        assert_eq!(color_mode_from_env("", "screen-256color"), ColorMode::Color256);
+        assert_eq!(color_mode_from_env("", "linux"), ColorMode::Basic16);
+        assert_eq!(color_mode_from_env("", "dumb"), ColorMode::Monochrome);
+    }
+
+    #[test]
+    fn test_osc_sequences() {
+        assert_eq!(title_sequence("hive — 3 agents, 1 active"), "\x1b]2;hive — 3 agents, 1 active\x07");
+        assert_eq!(notify_sequence("atlas\x07 failed\n"), "\x1b]9;atlas  failed \x07");
+        assert_eq!(progress_sequence(Some(42)), "\x1b]9;4;1;42\x07");
+        assert_eq!(progress_sequence(None), "\x1b]9;4;0;0\x07");
+
+        assert!(osc9_from_env("WezTerm", "xterm-256color", false));
+        assert!(osc9_from_env("", "xterm-kitty", false));
+        assert!(osc9_from_env("", "xterm", true));
+        assert!(!osc9_from_env("Apple_Terminal", "xterm-256color", false));
+    }
+
+    #[test]
+    fn test_minimum_size() {
+        assert!(fits(MIN_WIDTH, MIN_HEIGHT));
+        assert!(!fits(MIN_WIDTH - 1, 40));
+        assert!(!fits(0, 0));
+    }
+}
@@ -0,0 +1,133 @@
+//! Watchdog for a render loop that cannot keep up.
+//!
+//! When most recent frames take longer than the frame budget, the app sheds
+//! work one step at a time: first the heat map, then trail points, then frame
+//! rate. Each step is logged, and the next one is only taken if frames are
+//! still slow a full window later, so a busy moment costs one step rather
+//! than all of them.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use serde::Deserialize;
+
+/// Frames looked back over
+const WINDOW: usize = 60;
+
+/// Slow frames within the window that call for shedding work
+const SLOW_FRAMES: usize = 45;
+
+/// `[watchdog]` section of the config file
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WatchdogConfig {
+    /// Shed work when frames keep running over budget
+    pub enabled: bool,
+    /// Frames taking longer than this many milliseconds count as slow
+    pub frame_budget_ms: u64,
+}
+
+impl Default for WatchdogConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            frame_budget_ms: 50,
+        }
+    }
+}
+
+/// Work that can be shed, in the order it is shed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Degradation {
+    /// Hide the heat map and stop building it up
+    HideHeatmap,
+    /// Keep fewer trail points
+    ShortenTrails,
+    /// Draw fewer frames per second
+    LowerFrameRate,
+}
+
+impl Degradation {
+    pub const ALL: [Degradation; 3] = [
+        Degradation::HideHeatmap,
+        Degradation::ShortenTrails,
+        Degradation::LowerFrameRate,
+    ];
+}
+
+/// Counts slow frames and says when to shed work
+#[derive(Debug)]
+pub struct Watchdog {
+    /// Frame budget, `None` when disabled
+    budget: Option<Duration>,
+    /// Whether each of the recent frames was slow, oldest first
+    recent: VecDeque<bool>,
+    slow: usize,
+}
+
+impl Watchdog {
+    pub fn new(config: &WatchdogConfig) -> Self {
+        Self {
+            budget: config.enabled.then(|| Duration::from_millis(config.frame_budget_ms.max(1))),
+            recent: VecDeque::with_capacity(WINDOW),
+            slow: 0,
+        }
+    }
+
+    /// Frame budget, `None` when disabled
+    pub fn budget(&self) -> Option<Duration> {
+        self.budget
+    }
+
+    /// Record a frame that took `spent`. Returns true when it is time to shed
+    /// work, after which counting starts over.
+    pub fn record(&mut self, spent: Duration) -> bool {
+        let Some(budget) = self.budget else {
+            return false;
+        };
+
+        let slow = spent > budget;
+        self.recent.push_back(slow);
+        self.slow += slow as usize;
+        if self.recent.len() > WINDOW && self.recent.pop_front() == Some(true) {
+            self.slow -= 1;
+        }
+
+        if self.slow < SLOW_FRAMES {
+            return false;
+        }
+        self.recent.clear();
+        self.slow = 0;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sheds_work_only_when_most_frames_are_slow() {
+        let config = WatchdogConfig::default();
+        let slow = Duration::from_millis(config.frame_budget_ms + 20);
+        let fast = Duration::from_millis(5);
+        let mut watchdog = Watchdog::new(&config);
+
+        // Now and then a slow frame is fine
+        for n in 0..WINDOW * 4 {
+            assert!(!watchdog.record(if n % 3 == 0 { slow } else { fast }));
+        }
+
+        // Slow frames trigger it, then it counts afresh
+        let mut watchdog = Watchdog::new(&config);
+        let triggered: Vec<_> = (0..SLOW_FRAMES * 2).map(|_| watchdog.record(slow)).collect();
+        assert_eq!(triggered.iter().filter(|&&t| t).count(), 2);
+        assert!(triggered[SLOW_FRAMES - 1]);
+
+        let mut disabled = Watchdog::new(&WatchdogConfig {
+            enabled: false,
+            ..config
+        });
+        assert!((0..WINDOW * 2).all(|_| !disabled.record(slow)));
+    }
+}
@@ -0,0 +1,269 @@
+//! Embeddable swarm view for other ratatui applications.
+//!
+//! [`HiveWidget`] draws the field layers (zones, heat map, trails, connections
+//! and agents) into any `Rect`. The data it draws lives in a [`HiveState`] that
+//! the host application owns, feeds events into and ticks once per frame. The
+//! Hive status bar, help overlay and activity log are not part of the widget, so
+//! the swarm view fits into one pane of the host's own layout.
+//!
+//! ```no_run
+//! use hive::widget::{HiveState, HiveWidget};
+//! use hive_core::event::HiveEvent;
+//! # fn draw(frame: &mut ratatui::Frame, events: Vec<HiveEvent>, state: &mut HiveState) {
+//! for event in &events {
+//!     state.process_event(event);
+//! }
+//! state.tick();
+//! frame.render_stateful_widget(HiveWidget::new(), frame.area(), state);
+//! # }
+//! ```
+
+use std::collections::HashMap;
+
+use ratatui::{buffer::Buffer, layout::Rect, widgets::StatefulWidget};
+
+use hive_core::animation::{AnimationLoop, TICK_STEP};
+use hive_core::event::HiveEvent;
+use hive_core::state::{Field, History};
+
+use crate::render::{
+    ColorMode, DisplayMode, FieldBorder, FieldTransform, HeatMap, LayerRenderer, LayerVisibility, RenderLayer, RenderState,
+};
+
+/// Swarm state rendered by [`HiveWidget`].
+///
+/// Owns the field (agents, connections, landmarks), the heat map and the
+/// animation clock. Feed it events with [`process_event`](Self::process_event)
+/// and call [`tick`](Self::tick) once per frame before rendering.
+pub struct HiveState {
+    pub field: Field,
+    pub heatmap: HeatMap,
+    pub animation: AnimationLoop,
+    /// Agent drawn with the selection highlight
+    pub selected_agent: Option<String>,
+    /// Agent drawn with the hover highlight
+    pub hovered_agent: Option<String>,
+}
+
+impl HiveState {
+    pub fn new() -> Self {
+        Self {
+            field: Field::new(),
+            heatmap: HeatMap::new(80, 24),
+            animation: AnimationLoop::new(),
+            selected_agent: None,
+            hovered_agent: None,
+        }
+    }
+
+    /// Apply an incoming event to the field.
+    pub fn process_event(&mut self, event: &HiveEvent) {
+        self.field.process_event(event);
+    }
+
+    /// Advance animations by the time elapsed since the previous tick, in
+    /// fixed simulation steps.
+    pub fn tick(&mut self) {
+        for _ in 0..self.animation.fixed_steps() {
+            self.field.tick(TICK_STEP);
+            self.heatmap.accumulate(&self.field);
+        }
+        self.animation.frame_rendered();
+    }
+}
+
+impl Default for HiveState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Ratatui widget that renders a [`HiveState`] into an arbitrary area.
+#[derive(Debug, Clone)]
+pub struct HiveWidget {
+    display_mode: DisplayMode,
+    layers: LayerVisibility,
+    high_contrast: bool,
+    border: FieldBorder,
+}
+
+impl HiveWidget {
+    /// Create a widget showing the Standard display mode's layers.
+    pub fn new() -> Self {
+        Self::default().display_mode(DisplayMode::default())
+    }
+
+    /// Use the layer set of a display mode preset.
+    pub fn display_mode(mut self, mode: DisplayMode) -> Self {
+        self.display_mode = mode;
+        self.layers = field_layers(mode.layer_visibility());
+        self
+    }
+
+    /// Draw agents as filled, outlined blocks with status badges, for low
+    /// vision and far-away displays.
+    pub fn high_contrast(mut self, on: bool) -> Self {
+        self.high_contrast = on;
+        self
+    }
+
+    /// Draw the field's edge with rounded corners, or leave it blank.
+    pub fn border(mut self, border: FieldBorder) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Use an explicit layer set. UI chrome and overlays are always skipped.
+    pub fn layers(mut self, layers: LayerVisibility) -> Self {
+        self.layers = field_layers(layers);
+        self
+    }
+}
+
+impl Default for HiveWidget {
+    fn default() -> Self {
+        Self {
+            display_mode: DisplayMode::default(),
+            layers: field_layers(DisplayMode::default().layer_visibility()),
+            high_contrast: false,
+            border: FieldBorder::default(),
+        }
+    }
+}
+
+/// Strip the layers that belong to the Hive app rather than the field.
+fn field_layers(mut layers: LayerVisibility) -> LayerVisibility {
+    layers.set_visible(RenderLayer::UI, false);
+    layers.set_visible(RenderLayer::Overlays, false);
+    layers
+}
+
+impl StatefulWidget for HiveWidget {
+    type State = HiveState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        // Need at least one cell inside the field border
+        if area.width < 3 || area.height < 3 {
+            return;
+        }
+
+        let inner = FieldTransform::new(area).inner();
+        state.heatmap.resize(inner.width, inner.height);
+
+        let agents = state.field.agents_sorted();
+        let empty_landmarks = HashMap::new();
+        let landmarks = if self.layers.is_visible(RenderLayer::Zones) {
+            &state.field.landmarks
+        } else {
+            &empty_landmarks
+        };
+        let heatmap = if self.layers.is_visible(RenderLayer::Heatmap) {
+            Some(&state.heatmap)
+        } else {
+            None
+        };
+        // The UI layer is never drawn, so an empty history is enough
+        let history = History::new();
+
+        let alpha = state.animation.interpolation();
+        let get_agent_position = |id: &str| state.field.agents.get(id).map(|agent| agent.drawn_position(alpha));
+        let render_state = RenderState {
+            agents: &agents,
+            selected_agent: state.selected_agent.as_deref(),
+            hovered_agent: state.hovered_agent.as_deref(),
+            heatmap,
+            connections: &state.field.connections,
+            get_agent_position: &get_agent_position,
+            landmarks,
+            clusters: &state.field.clusters,
+            history: &history,
+            paused: state.field.paused,
+            playback_speed: state.field.playback_speed,
+            show_help: false,
+            fps: state.animation.fps(),
+            display_mode: self.display_mode,
+            filter_text: None,
+            filter_mode: false,
+            field_focused: false,
+            field_border: self.border,
+            timeline_focused: false,
+            heat_range: None,
+            status_items: &[],
+            mode_name: None,
+            event_rate: 0.0,
+            last_event_age: None,
+            sampled: None,
+            replay_rate: 0.0,
+            convergence: state.field.convergence.as_ref(),
+            time: state.animation.time(),
+            hits: None,
+            high_contrast: self.high_contrast,
+            heat_braille: state.heatmap.config().style.braille(ColorMode::default()),
+            interpolation: state.animation.interpolation(),
+        };
+
+        LayerRenderer::new(area, area, &self.layers).render_all(buf, &render_state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hive_core::event::{AgentStatus, AgentUpdate};
+
+    fn update(agent_id: &str) -> HiveEvent {
+        HiveEvent::AgentUpdate(AgentUpdate {
+            agent_id: agent_id.into(),
+            status: AgentStatus::Active,
+            focus: vec!["database".into()],
+            intensity: 0.5,
+            message: String::new(),
+            timestamp: 0,
+        })
+    }
+
+    #[test]
+    fn test_renders_field_without_app_chrome() {
+        let mut state = HiveState::new();
+        state.process_event(&update("atlas"));
+        state.tick();
+
+        let area = Rect::new(0, 0, 40, 12);
+        let mut buf = Buffer::empty(area);
+        HiveWidget::new().render(area, &mut buf, &mut state);
+
+        // Field border occupies the whole area, including the bottom row
+        // where the app would otherwise draw its status bar
+        assert_eq!(buf[(0, 0)].symbol(), "┌");
+        assert_eq!(buf[(0, 11)].symbol(), "└");
+
+        let content: String = buf.content().iter().map(|c| c.symbol()).collect();
+        assert!(content.contains("atlas"));
+    }
+
+    #[test]
+    fn test_border_styles() {
+        let mut state = HiveState::new();
+        let area = Rect::new(0, 0, 20, 8);
+
+        let mut buf = Buffer::empty(area);
+        HiveWidget::new().border(FieldBorder::Rounded).render(area, &mut buf, &mut state);
+        assert_eq!(buf[(0, 0)].symbol(), "╭");
+        assert_eq!(buf[(19, 7)].symbol(), "╯");
+        assert_eq!(buf[(5, 0)].symbol(), "─");
+
+        let mut buf = Buffer::empty(area);
+        HiveWidget::new().border(FieldBorder::None).render(area, &mut buf, &mut state);
+        assert_eq!(buf[(0, 0)].symbol(), " ");
+        assert_eq!(buf[(0, 4)].symbol(), " ");
+    }
+
+    #[test]
+    fn test_tiny_area_is_ignored() {
+        let mut state = HiveState::new();
+        let area = Rect::new(0, 0, 2, 2);
+        let mut buf = Buffer::empty(area);
+        HiveWidget::new().render(area, &mut buf, &mut state);
+        assert!(buf.content().iter().all(|c| c.symbol() == " "));
+    }
+}
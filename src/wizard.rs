@@ -0,0 +1,456 @@
+//! First-run setup wizard.
+//!
+//! Shown when `hive` is started without an event source and without a config
+//! file, instead of exiting with a usage error. The user picks demo mode, an
+//! events file (through the file browser), a socket to listen on or a stream
+//! URL to follow, and the choice is handed back to the binary to start the
+//! app with.
+
+use std::io;
+use std::path::PathBuf;
+
+use crossterm::{
+    event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{
+    backend::CrosstermBackend,
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::Widget,
+    Terminal,
+};
+
+use crate::render::{FileBrowser, FileBrowserWidget};
+
+/// What the user picked
+#[derive(Debug, Clone, PartialEq)]
+pub enum WizardChoice {
+    Demo,
+    File(PathBuf),
+    /// Listen on a Unix socket at this path
+    Socket(PathBuf),
+    /// Follow the event stream at this URL
+    Url(String),
+}
+
+/// A row of the start menu
+struct MenuItem {
+    label: &'static str,
+    detail: &'static str,
+    /// Unavailable items are shown but cannot be picked
+    available: bool,
+}
+
+const MENU: [MenuItem; 4] = [
+    MenuItem {
+        label: "Demo mode",
+        detail: "Watch a simulated swarm of agents",
+        available: true,
+    },
+    MenuItem {
+        label: "Open a file",
+        detail: "Watch a JSON lines events file",
+        available: true,
+    },
+    MenuItem {
+        label: "Listen on a socket",
+        detail: if cfg!(unix) {
+            "Accept events written to a Unix socket"
+        } else {
+            "Not supported on this platform"
+        },
+        available: cfg!(unix),
+    },
+    MenuItem {
+        label: "Connect to a URL",
+        detail: if cfg!(feature = "http") {
+            "Follow a Server-Sent Events or JSON lines stream"
+        } else {
+            "Needs hive built with the `http` feature"
+        },
+        available: cfg!(feature = "http"),
+    },
+];
+
+/// Menu indices
+const DEMO: usize = 0;
+const OPEN_FILE: usize = 1;
+const LISTEN: usize = 2;
+const CONNECT: usize = 3;
+
+/// Socket path offered when listening on a socket
+const DEFAULT_SOCKET: &str = "/tmp/hive.sock";
+
+/// Start of the URL offered when connecting to a stream
+const DEFAULT_URL: &str = "http://";
+
+/// Wizard screen state
+enum Screen {
+    Menu,
+    Browse(FileBrowser),
+    Prompt(Prompt),
+}
+
+/// A line of text typed for a menu item, e.g. the socket path
+struct Prompt {
+    label: &'static str,
+    input: String,
+    /// Turns the confirmed input into the choice
+    choice: fn(String) -> WizardChoice,
+}
+
+struct Wizard {
+    screen: Screen,
+    selected: usize,
+    /// Last error (e.g. an unreadable directory), shown under the menu
+    error: Option<String>,
+}
+
+impl Wizard {
+    fn new() -> Self {
+        Self {
+            screen: Screen::Menu,
+            selected: 0,
+            error: None,
+        }
+    }
+
+    /// Handle a key press. Returns `Some(result)` when the wizard is done.
+    fn handle_key(&mut self, code: KeyCode) -> Option<Option<WizardChoice>> {
+        match self.screen {
+            Screen::Menu => match code {
+                KeyCode::Char('q') | KeyCode::Esc => return Some(None),
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.selected = self.selected.saturating_sub(1);
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    self.selected = (self.selected + 1).min(MENU.len() - 1);
+                }
+                KeyCode::Enter => return self.pick(),
+                _ => {}
+            },
+            Screen::Browse(ref mut browser) => {
+                let result = match code {
+                    KeyCode::Esc => {
+                        self.screen = Screen::Menu;
+                        return None;
+                    }
+                    KeyCode::Up | KeyCode::Char('k') => {
+                        browser.move_up();
+                        Ok(None)
+                    }
+                    KeyCode::Down | KeyCode::Char('j') => {
+                        browser.move_down();
+                        Ok(None)
+                    }
+                    KeyCode::Left | KeyCode::Backspace | KeyCode::Char('h') => {
+                        browser.parent().map(|_| None)
+                    }
+                    KeyCode::Enter | KeyCode::Right | KeyCode::Char('l') => browser.enter(),
+                    _ => Ok(None),
+                };
+                match result {
+                    Ok(Some(path)) => return Some(Some(WizardChoice::File(path))),
+                    Ok(None) => self.error = None,
+                    Err(e) => self.error = Some(e.to_string()),
+                }
+            }
+            Screen::Prompt(ref mut prompt) => match code {
+                KeyCode::Esc => {
+                    self.error = None;
+                    self.screen = Screen::Menu;
+                }
+                KeyCode::Enter => {
+                    let input = prompt.input.trim();
+                    if !input.is_empty() {
+                        return Some(Some((prompt.choice)(input.to_string())));
+                    }
+                    self.error = Some(format!("{} is empty", prompt.label));
+                }
+                KeyCode::Backspace => {
+                    prompt.input.pop();
+                }
+                KeyCode::Char(c) => prompt.input.push(c),
+                _ => {}
+            },
+        }
+        None
+    }
+
+    fn pick(&mut self) -> Option<Option<WizardChoice>> {
+        if !MENU[self.selected].available {
+            return None;
+        }
+        match self.selected {
+            DEMO => return Some(Some(WizardChoice::Demo)),
+            OPEN_FILE => {
+                let start = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+                match FileBrowser::open(&start) {
+                    Ok(browser) => {
+                        self.error = None;
+                        self.screen = Screen::Browse(browser);
+                    }
+                    Err(e) => self.error = Some(e.to_string()),
+                }
+            }
+            LISTEN => {
+                self.error = None;
+                self.screen = Screen::Prompt(Prompt {
+                    label: "Socket path",
+                    input: DEFAULT_SOCKET.to_string(),
+                    choice: |input| WizardChoice::Socket(input.into()),
+                });
+            }
+            CONNECT => {
+                self.error = None;
+                self.screen = Screen::Prompt(Prompt {
+                    label: "Stream URL",
+                    input: DEFAULT_URL.to_string(),
+                    choice: WizardChoice::Url,
+                });
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer) {
+        let background = Style::default().bg(Color::Rgb(20, 20, 30));
+        for y in area.y..area.y + area.height {
+            for x in area.x..area.x + area.width {
+                buf[(x, y)].set_char(' ').set_style(background);
+            }
+        }
+
+        let width = area.width.min(60);
+        let x = area.x + (area.width - width) / 2;
+
+        match self.screen {
+            Screen::Menu => self.render_menu(Rect::new(x, area.y, width, area.height), buf),
+            Screen::Browse(ref browser) => {
+                let height = area.height.saturating_sub(2).min(24);
+                let y = area.y + (area.height - height) / 2;
+                FileBrowserWidget::new(browser).render(Rect::new(x, y, width, height), buf);
+            }
+            Screen::Prompt(ref prompt) => Self::render_prompt(prompt, Rect::new(x, area.y, width, area.height), buf),
+        }
+
+        if let Some(ref error) = self.error {
+            let style = Style::default().fg(Color::Rgb(255, 110, 110));
+            draw_line(buf, x, area.y + area.height - 1, width, error, style);
+        }
+    }
+
+    fn render_menu(&self, area: Rect, buf: &mut Buffer) {
+        let height = (MENU.len() * 2 + 6) as u16;
+        let mut y = area.y + area.height.saturating_sub(height) / 2;
+
+        let title = Style::default()
+            .fg(Color::Rgb(100, 200, 150))
+            .add_modifier(Modifier::BOLD);
+        draw_line(buf, area.x, y, area.width, "◈ Welcome to Hive", title);
+        y += 1;
+        let subtle = Style::default().fg(Color::Rgb(150, 150, 160));
+        draw_line(buf, area.x, y, area.width, "Where should the agents come from?", subtle);
+        y += 2;
+
+        for (idx, item) in MENU.iter().enumerate() {
+            let selected = idx == self.selected;
+            let marker = if selected { "▸ " } else { "  " };
+            let mut style = if item.available {
+                Style::default().fg(Color::Rgb(220, 220, 230))
+            } else {
+                Style::default().fg(Color::Rgb(100, 100, 110))
+            };
+            if selected {
+                style = style.add_modifier(Modifier::BOLD);
+            }
+            draw_line(buf, area.x, y, area.width, &format!("{}{}", marker, item.label), style);
+            draw_line(buf, area.x + 4, y + 1, area.width.saturating_sub(4), item.detail, subtle);
+            y += 2;
+        }
+
+        y += 1;
+        let hint = "↑/↓ move  Enter select  q quit";
+        draw_line(buf, area.x, y, area.width, hint, Style::default().fg(Color::Rgb(120, 120, 130)));
+    }
+
+    fn render_prompt(prompt: &Prompt, area: Rect, buf: &mut Buffer) {
+        let mut y = area.y + area.height.saturating_sub(5) / 2;
+
+        let title = Style::default()
+            .fg(Color::Rgb(100, 200, 150))
+            .add_modifier(Modifier::BOLD);
+        draw_line(buf, area.x, y, area.width, prompt.label, title);
+        y += 2;
+        // Keep the end of a long input, where the cursor is, in view
+        let line = format!("▸ {}▏", prompt.input);
+        let skip = line.chars().count().saturating_sub(area.width as usize);
+        let line: String = line.chars().skip(skip).collect();
+        draw_line(buf, area.x, y, area.width, &line, Style::default().fg(Color::Rgb(220, 220, 230)));
+        y += 2;
+        let hint = "Enter confirm  Esc back";
+        draw_line(buf, area.x, y, area.width, hint, Style::default().fg(Color::Rgb(120, 120, 130)));
+    }
+}
+
+/// Write `text` at (x, y), truncated to `width` characters
+fn draw_line(buf: &mut Buffer, x: u16, y: u16, width: u16, text: &str, style: Style) {
+    if y >= buf.area.y + buf.area.height {
+        return;
+    }
+    for (cx, ch) in (x..).zip(text.chars().take(width as usize)) {
+        buf[(cx, y)].set_char(ch).set_style(style);
+    }
+}
+
+/// Show the setup wizard and return the user's choice (`None` if they quit).
+pub fn run_setup_wizard() -> io::Result<Option<WizardChoice>> {
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(stdout))?;
+
+    let result = event_loop(&mut terminal);
+
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+
+    result
+}
+
+fn event_loop(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+) -> io::Result<Option<WizardChoice>> {
+    let mut wizard = Wizard::new();
+    loop {
+        terminal.draw(|frame| {
+            let area = frame.area();
+            wizard.render(area, frame.buffer_mut());
+        })?;
+
+        if let Event::Key(key) = event::read()? {
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+                return Ok(None);
+            }
+            if let Some(result) = wizard.handle_key(key.code) {
+                return Ok(result);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_menu_picks_demo_and_skips_unavailable() {
+        let mut wizard = Wizard::new();
+        assert_eq!(wizard.handle_key(KeyCode::Enter), Some(Some(WizardChoice::Demo)));
+
+        // Down stops at the last entry; it opens only if it is available
+        for _ in 0..MENU.len() {
+            wizard.handle_key(KeyCode::Down);
+        }
+        assert_eq!(wizard.selected, CONNECT);
+        assert_eq!(wizard.handle_key(KeyCode::Enter), None);
+        assert_eq!(matches!(wizard.screen, Screen::Prompt(_)), MENU[CONNECT].available);
+
+        wizard.screen = Screen::Menu;
+        assert_eq!(wizard.handle_key(KeyCode::Char('q')), Some(None));
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn test_url_prompts_for_a_url() {
+        let mut wizard = Wizard::new();
+        wizard.selected = CONNECT;
+        assert_eq!(wizard.handle_key(KeyCode::Enter), None);
+        for c in "127.0.0.1:8080/events".chars() {
+            wizard.handle_key(KeyCode::Char(c));
+        }
+        assert_eq!(
+            wizard.handle_key(KeyCode::Enter),
+            Some(Some(WizardChoice::Url("http://127.0.0.1:8080/events".to_string())))
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_socket_prompts_for_a_path() {
+        let mut wizard = Wizard::new();
+        wizard.handle_key(KeyCode::Down);
+        wizard.handle_key(KeyCode::Down);
+        assert_eq!(wizard.handle_key(KeyCode::Enter), None);
+        let Screen::Prompt(ref prompt) = wizard.screen else {
+            panic!("not prompting");
+        };
+        assert_eq!(prompt.input, DEFAULT_SOCKET);
+
+        // Typed keys edit the path, 'q' included
+        for _ in 0..DEFAULT_SOCKET.len() {
+            wizard.handle_key(KeyCode::Backspace);
+        }
+        assert_eq!(wizard.handle_key(KeyCode::Enter), None);
+        assert!(wizard.error.is_some());
+        for c in "/tmp/q.sock".chars() {
+            assert_eq!(wizard.handle_key(KeyCode::Char(c)), None);
+        }
+        assert_eq!(
+            wizard.handle_key(KeyCode::Enter),
+            Some(Some(WizardChoice::Socket(PathBuf::from("/tmp/q.sock"))))
+        );
+
+        wizard.screen = Screen::Menu;
+        wizard.handle_key(KeyCode::Enter);
+        wizard.handle_key(KeyCode::Esc);
+        assert!(matches!(wizard.screen, Screen::Menu));
+    }
+
+    #[test]
+    fn test_open_file_enters_browser_and_esc_returns() {
+        let mut wizard = Wizard::new();
+        wizard.handle_key(KeyCode::Down);
+        assert_eq!(wizard.handle_key(KeyCode::Enter), None);
+        assert!(matches!(wizard.screen, Screen::Browse(_)));
+
+        wizard.handle_key(KeyCode::Esc);
+        assert!(matches!(wizard.screen, Screen::Menu));
+    }
+
+    #[test]
+    fn test_renders_at_any_size() {
+        let mut wizard = Wizard::new();
+        for (width, height) in [(80, 24), (20, 6), (1, 1)] {
+            let area = Rect::new(0, 0, width, height);
+            let mut buf = Buffer::empty(area);
+            wizard.render(area, &mut buf);
+        }
+
+        let area = Rect::new(0, 0, 80, 24);
+        let mut buf = Buffer::empty(area);
+        wizard.render(area, &mut buf);
+        let content: String = buf.content().iter().map(|c| c.symbol()).collect();
+        assert!(content.contains("Welcome to Hive"));
+
+        wizard.handle_key(KeyCode::Down);
+        wizard.handle_key(KeyCode::Enter);
+        wizard.render(Rect::new(0, 0, 20, 6), &mut Buffer::empty(Rect::new(0, 0, 20, 6)));
+
+        wizard.screen = Screen::Menu;
+        wizard.handle_key(KeyCode::Down);
+        wizard.handle_key(KeyCode::Enter);
+        for (width, height) in [(80, 24), (10, 3), (1, 1)] {
+            let area = Rect::new(0, 0, width, height);
+            wizard.render(area, &mut Buffer::empty(area));
+        }
+    }
+}
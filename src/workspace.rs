@@ -0,0 +1,144 @@
+//! Per-source workspace state.
+//!
+//! The view settings in use for an event source (display mode, layers and
+//! their brightness, filter, selected agent and open panels) are remembered
+//! in `workspaces.toml` next to the config file, keyed by the source's path,
+//! and restored the next time the same source is opened. Landmark edits are
+//! kept separately, in the source's [`LandmarkEdits`](crate::landmarks::LandmarkEdits)
+//! sidecar file.
+
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use hive_core::event::AgentId;
+use hive_core::state::AgentOrder;
+
+use crate::config::default_path;
+use crate::filter::StatusFilter;
+use crate::render::RenderLayer;
+
+/// View settings saved for one event source
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Workspace {
+    /// Name of the display mode, built-in or custom
+    pub mode: String,
+    /// Layers that were visible
+    pub layers: Vec<RenderLayer>,
+    /// Brightness of dimmed layers in percent
+    pub opacity: BTreeMap<RenderLayer, u8>,
+    /// Agent filter text
+    pub filter: String,
+    /// Quick status filter
+    pub status_filter: StatusFilter,
+    /// Order agents were listed in
+    pub order: AgentOrder,
+    /// Agent whose detail panel was open
+    pub selected_agent: Option<AgentId>,
+    pub show_matrix: bool,
+    pub show_stats: bool,
+    pub show_leaderboard: bool,
+    pub show_ghosts: bool,
+}
+
+/// Saved workspaces of all sources
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Workspaces {
+    workspaces: BTreeMap<String, Workspace>,
+    #[serde(skip)]
+    path: Option<PathBuf>,
+}
+
+impl Workspaces {
+    /// Load the workspaces from their default location; a missing or
+    /// unreadable file gives none.
+    pub fn load() -> Self {
+        match default_path().and_then(|config| Some(config.parent()?.join("workspaces.toml"))) {
+            Some(path) => Self::load_from(path),
+            None => Self::default(),
+        }
+    }
+
+    /// Load the workspaces from `path`, saving back to the same file.
+    pub fn load_from(path: PathBuf) -> Self {
+        let mut workspaces: Self = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|contents| toml::from_str(&contents).ok())
+            .unwrap_or_default();
+        workspaces.path = Some(path);
+        workspaces
+    }
+
+    /// Key of the workspace for the event files `paths` (several files
+    /// merged together share one workspace)
+    pub fn key(paths: &[PathBuf]) -> String {
+        paths
+            .iter()
+            .map(|path| canonical(path).display().to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Workspace> {
+        self.workspaces.get(key)
+    }
+
+    pub fn set(&mut self, key: String, workspace: Workspace) {
+        self.workspaces.insert(key, workspace);
+    }
+
+    /// Write the workspaces back to disk.
+    pub fn save(&self) -> io::Result<()> {
+        let Some(ref path) = self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let contents = toml::to_string(self).map_err(io::Error::other)?;
+        std::fs::write(path, contents)
+    }
+}
+
+fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_workspace_round_trip() {
+        let dir = std::env::temp_dir().join(format!("hive-workspaces-{}", std::process::id()));
+        let path = dir.join("workspaces.toml");
+        let key = Workspaces::key(&[PathBuf::from("/tmp/a.jsonl"), PathBuf::from("/tmp/b.jsonl")]);
+
+        let mut workspaces = Workspaces::load_from(path.clone());
+        assert!(workspaces.get(&key).is_none());
+        let workspace = Workspace {
+            mode: "Debug".to_string(),
+            layers: vec![RenderLayer::Agents, RenderLayer::UI],
+            opacity: BTreeMap::from([(RenderLayer::Heatmap, 40)]),
+            filter: "auth".to_string(),
+            status_filter: StatusFilter::HideIdle,
+            order: AgentOrder::Intensity,
+            selected_agent: Some("worker-1".into()),
+            show_matrix: true,
+            show_stats: false,
+            show_leaderboard: true,
+            show_ghosts: true,
+        };
+        workspaces.set(key.clone(), workspace.clone());
+        workspaces.save().unwrap();
+
+        let loaded = Workspaces::load_from(path);
+        assert_eq!(loaded.get(&key), Some(&workspace));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}